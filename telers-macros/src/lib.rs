@@ -12,6 +12,7 @@ pub(crate) mod stream;
 
 mod from_context;
 mod from_event;
+mod md;
 
 use proc_macro::TokenStream;
 use quote::{quote, ToTokens};
@@ -244,6 +245,27 @@ pub fn derive_from_event(item: TokenStream) -> TokenStream {
     expand_with(item, from_event::expand)
 }
 
+/// Creates a `MarkdownV2` text like [`format!`], but the interpolated values are escaped,
+/// so only the formatting in the literal is applied.
+///
+/// Positional arguments, named arguments and variables captured in the literal by name (`{name}`) are escaped.
+/// Width and precision arguments (`{:width$}`) aren't escaped, because they must be numbers.
+///
+/// Use this macro re-exported from `telers` crate as `telers::md!`.
+/// # Examples
+/// ```rust
+/// use telers::md;
+///
+/// let name = "*John_Doe*";
+///
+/// assert_eq!(md!("Hello, *{}*\\!", name), r"Hello, *\*John\_Doe\**\!");
+/// assert_eq!(md!("Hello, *{name}*\\!"), r"Hello, *\*John\_Doe\**\!");
+/// ```
+#[proc_macro]
+pub fn md(input: TokenStream) -> TokenStream {
+    expand_with(input, |input| Ok(md::expand(input)))
+}
+
 fn expand_with<F, I, K>(input: TokenStream, f: F) -> TokenStream
 where
     F: FnOnce(I) -> syn::Result<K>,
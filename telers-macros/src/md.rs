@@ -0,0 +1,169 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    Expr, Ident, LitStr, Token,
+};
+
+/// Input of the `md!` macro: format string literal with positional and named arguments
+/// # Examples
+/// ```not_rust
+/// md!("*{}* {name} {value}", first, name = "second")
+/// ```
+pub(crate) struct Input {
+    fmt: LitStr,
+    positional: Vec<Expr>,
+    named: Vec<(Ident, Expr)>,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let fmt = input.parse()?;
+        let mut positional = vec![];
+        let mut named = vec![];
+
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+
+            if input.is_empty() {
+                break;
+            }
+
+            if input.peek(Ident) && input.peek2(Token![=]) && !input.peek2(Token![==]) {
+                let name = input.parse()?;
+                input.parse::<Token![=]>()?;
+                named.push((name, input.parse()?));
+            } else if named.is_empty() {
+                positional.push(input.parse()?);
+            } else {
+                return Err(input.error("positional arguments cannot follow named arguments"));
+            }
+        }
+
+        Ok(Self {
+            fmt,
+            positional,
+            named,
+        })
+    }
+}
+
+/// Captures of the format string, which are not passed as arguments explicitly
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Captures {
+    /// Variables inserted to the text, for example, `name` in `{name}` or `{name:?}`
+    values: Vec<String>,
+    /// Variables used as width or precision, for example, `width` in `{:width$}`
+    counts: Vec<String>,
+}
+
+fn is_identifier(val: &str) -> bool {
+    val.chars()
+        .next()
+        .map_or(false, |ch| ch.is_alphabetic() || ch == '_')
+        && val.chars().all(|ch| ch.is_alphanumeric() || ch == '_')
+}
+
+fn push_unique(names: &mut Vec<String>, name: &str) {
+    if !names.iter().any(|val| val == name) {
+        names.push(name.to_owned());
+    }
+}
+
+/// Finds variables captured in the format string by name
+fn captures(fmt: &str) -> Captures {
+    let mut captures = Captures::default();
+    let mut rest = fmt;
+
+    while let Some(start) = rest.find('{') {
+        rest = &rest[start + 1..];
+
+        // Escaped `{{`
+        if let Some(stripped) = rest.strip_prefix('{') {
+            rest = stripped;
+            continue;
+        }
+
+        let end = match rest.find('}') {
+            Some(end) => end,
+            None => break,
+        };
+        let placeholder = &rest[..end];
+        rest = &rest[end + 1..];
+
+        let (argument, spec) = placeholder.split_once(':').unwrap_or((placeholder, ""));
+        let argument = argument.trim();
+
+        if is_identifier(argument) {
+            push_unique(&mut captures.values, argument);
+        }
+
+        // Each part before `$` ends with the name of width or precision argument
+        let parts = spec.split('$').collect::<Vec<_>>();
+        for part in &parts[..parts.len() - 1] {
+            let name = part
+                .rsplit(|ch: char| !(ch.is_alphanumeric() || ch == '_'))
+                .next()
+                .unwrap_or_default();
+
+            if is_identifier(name) {
+                push_unique(&mut captures.counts, name);
+            }
+        }
+    }
+
+    captures
+}
+
+pub(crate) fn expand(
+    Input {
+        fmt,
+        positional,
+        named,
+    }: Input,
+) -> TokenStream {
+    let escaped = quote! { ::telers::utils::text::markdown_formatter::Escaped };
+
+    let Captures { values, counts } = captures(&fmt.value());
+    let is_named = |name: &String| named.iter().any(|(ident, _)| ident == name);
+
+    // Width and precision must be `usize`, so they aren't escaped
+    let counts = counts
+        .iter()
+        .filter(|name| !is_named(name) && !values.contains(name))
+        .map(|name| Ident::new(name, fmt.span()))
+        .collect::<Vec<_>>();
+    let values = values
+        .iter()
+        .filter(|name| !is_named(name))
+        .map(|name| Ident::new(name, fmt.span()))
+        .collect::<Vec<_>>();
+    let (named_idents, named_exprs): (Vec<_>, Vec<_>) = named.into_iter().unzip();
+
+    quote! {
+        ::std::format!(
+            #fmt
+            #(, #escaped(&#positional))*
+            #(, #named_idents = #escaped(&#named_exprs))*
+            #(, #values = #escaped(&#values))*
+            #(, #counts = #counts)*
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_captures() {
+        assert_eq!(captures("{} {0} {{name}} {:?}"), Captures::default());
+        assert_eq!(
+            captures("{name} {name:?} {other:>width$.precision$} {:1$}"),
+            Captures {
+                values: vec!["name".to_owned(), "other".to_owned()],
+                counts: vec!["width".to_owned(), "precision".to_owned()],
+            }
+        );
+    }
+}
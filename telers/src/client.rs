@@ -3,14 +3,31 @@
 //!
 //! Components are:
 //! - [`bot`] module with the main entry point for the library
+//! - [`broadcast`] module with helper for sending the same request to many chats
+//! - [`chat_action`] module with guard for sending chat action while a long work is running
+//! - [`reply_to`] module with extractor for sending requests to the chat of the current update
 //! - [`session`] module with components for sending requests
+//! - [`sticker_set`] module with helpers for creating and filling sticker sets
 //! - [`telegram`] module with configuration of the Telegram Bot API
+//! - [`webhook`] module with helpers for configuring webhook integration
 //!
 //! Check each submodule for more information.
 
 pub mod bot;
+pub mod broadcast;
+pub mod chat_action;
+pub mod reply_to;
 pub mod session;
+#[cfg(feature = "stickers")]
+pub mod sticker_set;
 pub mod telegram;
+pub mod webhook;
 
 pub use bot::Bot;
+pub use broadcast::BroadcastReport;
+pub use chat_action::ChatActionGuard;
+pub use reply_to::ReplyTo;
 pub use session::{Reqwest, Session};
+#[cfg(feature = "stickers")]
+pub use sticker_set::StickerSetError;
+pub use webhook::WebhookError;
@@ -7,7 +7,8 @@
 //!
 //! # Notes
 //!
-//! This structure is cheap to clone, because it contains only [`String`], [`i64`] fields and a client.
+//! This structure is cheap to clone, because it contains only [`String`], [`i64`] fields, cached bot user and a client.
+//! Clones share the cached bot user, so [`Bot::me`] sends `getMe` request only once for all of them.
 //! Default client is [`Reqwest`], which also is cheap to clone.
 //!
 //! You can use custom client by using [`Bot::with_client`] method.
@@ -27,35 +28,96 @@
 //! You also can use [`Bot::send_with_timeout`] method to send requests with timeout:
 //!
 //! ```rust
+//! use std::time::Duration;
 //! use telers::{Bot, methods::SendMessage};
 //!
 //! async fn call_method(bot: Bot) {
 //!     let chat_id = 1;
 //!     let text = "Hello, world!";
-//!     let timeout = 10.0; // 10 seconds
+//!     let timeout = Duration::from_secs(10);
 //!
 //!     let _ = bot.send_with_timeout(SendMessage::new(chat_id, text), timeout).await;
 //! }
 //! ```
 //!
+//! Default timeout of [`Bot::send`] can be changed for the bot by [`Bot::request_timeout`] method,
+//! for example, if the bot uploads heavy files.
+//! Also, the bot can have default parse mode, see [`Bot::parse_mode`].
+//!
+//! You also can use [`Bot::paginate`] method to get all items of the paginated method page by page:
+//!
+//! ```rust
+//! use futures::StreamExt as _;
+//! use telers::{Bot, methods::GetUserProfilePhotos};
+//!
+//! async fn call_method(bot: Bot) {
+//!     let user_id = 1;
+//!     let page_size = 100;
+//!
+//!     let mut photos = Box::pin(bot.paginate(GetUserProfilePhotos::new(user_id), page_size));
+//!
+//!     while let Some(Ok(photo)) = photos.next().await {
+//!         println!("{photo:?}");
+//!     }
+//! }
+//! ```
+//!
+//! Updates can be received as the stream without the dispatcher by [`Bot::updates_stream`] method:
+//!
+//! ```rust
+//! use futures::StreamExt as _;
+//! use telers::{Bot, enums::UpdateType};
+//!
+//! async fn receive_updates(bot: Bot) {
+//!     let mut updates = Box::pin(bot.updates_stream([UpdateType::Message], Some(30)));
+//!
+//!     while let Some(update) = updates.next().await {
+//!         println!("{update:?}");
+//!     }
+//! }
+//! ```
+//!
 //! More production examples can be found in [`examples`] directory.
 //!
 //! [`examples`]: https://github.com/Desiders/telers/tree/dev-1.x/examples
 //! [`methods`]: crate::methods
 
-use super::{session::base::Session, Reqwest};
+use super::{
+    session::base::Session,
+    telegram::{APIServer, APIServerError},
+    Reqwest,
+};
 
-use crate::{errors::SessionErrorKind, methods::TelegramMethod, utils::token};
+use crate::{
+    dispatcher::{Polling, UpdateStream as _},
+    enums::{ParseMode, UpdateType},
+    errors::SessionErrorKind,
+    methods::{
+        AnswerWebAppQuery, GetMe, PaginatedMethod, SendMessage, SetMessageReaction, TelegramMethod,
+    },
+    types::{
+        ChatIdKind, InlineQueryResult, Message, MessageId, ReactionType, SentWebAppMessage, Update,
+        User,
+    },
+    utils::{text::markdown_quote, token},
+};
 
+use backoff::exponential::ExponentialBackoff;
+use futures::stream::{self, Stream};
 use std::{
+    borrow::Cow,
+    collections::VecDeque,
     env,
     fmt::{self, Debug, Display, Formatter},
+    sync::Arc,
+    time::Duration,
 };
+use tokio::sync::OnceCell;
 use tracing::instrument;
 
 /// Represents a bot with its token and ID, also contains client for sending requests to Telegram API.
 /// # Notes
-/// This structure is cheap to clone, because it contains only [`String`], [`i64`] fields and a client.
+/// This structure is cheap to clone, because it contains only [`String`], [`i64`] fields, cached bot user and a client.
 ///
 /// Default client is [`Reqwest`], which also is cheap to clone.
 ///
@@ -70,6 +132,14 @@ pub struct Bot<Client: ?Sized = Reqwest> {
     pub hidden_token: String,
     /// Bot id, extracted from the token
     pub bot_id: i64,
+    /// Default timeout for requests sent by [`Bot::send`], if it's `None`, then the timeout of the client is used
+    pub request_timeout: Option<Duration>,
+    /// Default parse mode for methods with `parse_mode` field, which is used if neither parse mode nor entities are set explicitly
+    pub parse_mode: Option<String>,
+    /// Default business connection for methods with `business_connection_id` field, which is used if it isn't set explicitly
+    pub business_connection_id: Option<String>,
+    /// Bot user, which is received by `getMe` request on the first call of [`Bot::me`] and shared between clones
+    me: Arc<OnceCell<User>>,
     /// Client for sending requests to Telegram API
     client: Client,
 }
@@ -90,9 +160,66 @@ impl<Client> Bot<Client> {
             token,
             hidden_token,
             bot_id,
+            request_timeout: None,
+            parse_mode: None,
+            business_connection_id: None,
+            me: Arc::default(),
             client,
         }
     }
+
+    /// Default timeout for requests sent by [`Bot::send`]
+    #[must_use]
+    pub fn request_timeout(self, val: Duration) -> Self {
+        Self {
+            request_timeout: Some(val),
+            ..self
+        }
+    }
+
+    /// Gets bot id, which is extracted from the token, so it doesn't require `getMe` request
+    #[must_use]
+    pub const fn id(&self) -> i64 {
+        self.bot_id
+    }
+
+    /// Gets client for sending requests to Telegram API
+    #[must_use]
+    pub const fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Default parse mode for methods with `parse_mode` field (for example, [`SendMessage`] or [`SendPhoto`]),
+    /// which is used if neither parse mode nor entities are set explicitly.
+    /// Use [`Bot::send_without_parse_mode`] to send the method without the default parse mode.
+    ///
+    /// [`SendMessage`]: crate::methods::SendMessage
+    /// [`SendPhoto`]: crate::methods::SendPhoto
+    #[must_use]
+    pub fn parse_mode(self, val: impl Into<String>) -> Self {
+        Self {
+            parse_mode: Some(val.into()),
+            ..self
+        }
+    }
+
+    /// Default business connection for methods with `business_connection_id` field (for example, [`SendMessage`]),
+    /// which is used if it isn't set explicitly.
+    /// Usually, it's set by [`BusinessConnection`] middleware for bots, which handle business messages.
+    ///
+    /// [`SendMessage`]: crate::methods::SendMessage
+    /// [`BusinessConnection`]: crate::middlewares::outer::BusinessConnection
+    #[must_use]
+    pub fn business_connection_id(self, val: impl Into<String>) -> Self {
+        Self {
+            business_connection_id: Some(val.into()),
+            ..self
+        }
+    }
+
+    pub(crate) fn request_timeout_secs(&self) -> Option<f32> {
+        self.request_timeout.map(|timeout| timeout.as_secs_f32())
+    }
 }
 
 impl Bot<Reqwest> {
@@ -124,6 +251,45 @@ impl Bot<Reqwest> {
     pub fn from_env() -> Self {
         Self::from_env_by_key("BOT_TOKEN")
     }
+
+    /// Creates a bot with the default client, which sends requests to the API server,
+    /// for example, to the [`local Bot API server`](https://core.telegram.org/bots/api#using-a-local-bot-api-server)
+    /// or the [`TEST`](super::telegram::TEST) server
+    /// # Errors
+    /// If URL templates of the API server are invalid (see [`APIServer::validate`])
+    /// # Panics
+    /// Panics if the token is invalid
+    pub fn with_api_server(
+        token: impl Into<String>,
+        api: impl Into<Cow<'static, APIServer>>,
+    ) -> Result<Self, APIServerError> {
+        let mut bot = Self::new(token);
+        bot.set_api_server(api)?;
+        Ok(bot)
+    }
+
+    /// Sends requests of the bot to the Telegram test environment (see [`APIServer::test`]).
+    /// Note that bots and users of the test environment are separate from the production ones, so the token is different too.
+    #[must_use]
+    pub fn use_test_environment(mut self) -> Self {
+        // The test server is known to be valid, so it isn't validated
+        self.client = self.client.clone().with_api_server(&*super::telegram::TEST);
+        self
+    }
+
+    /// Sets the API server to which requests of the bot are sent
+    /// # Errors
+    /// If URL templates of the API server are invalid (see [`APIServer::validate`]), the API server of the bot isn't changed
+    pub fn set_api_server(
+        &mut self,
+        api: impl Into<Cow<'static, APIServer>>,
+    ) -> Result<(), APIServerError> {
+        let api = api.into();
+        api.validate()?;
+
+        self.client = self.client.clone().with_api_server(api);
+        Ok(())
+    }
 }
 
 impl<Client> Debug for Bot<Client> {
@@ -155,8 +321,8 @@ impl<Client: Session> Bot<Client> {
     /// - If the response cannot be parsed
     /// - If the response represents an Telegram API error
     /// # Notes
-    /// This method uses default timeout for requests, which is 30 seconds.
-    /// If you want to use custom timeout, use [`Bot::send_with_timeout`] method.
+    /// This method uses [`Bot::request_timeout`] if it's set, otherwise the default timeout of the client (30 seconds for [`Reqwest`]).
+    /// If you want to use custom timeout for the request, use [`Bot::send_with_timeout`] method.
     #[instrument(skip(self, method))]
     pub async fn send<T, TRef>(&self, method: TRef) -> Result<T::Return, SessionErrorKind>
     where
@@ -164,8 +330,146 @@ impl<Client: Session> Bot<Client> {
         T::Method: Send + Sync,
         TRef: AsRef<T>,
     {
+        self.send_request(method.as_ref(), self.request_timeout_secs())
+            .await
+    }
+
+    /// Use this method to send requests to Telegram API, which return the sent message or its identifier,
+    /// when only the identifier of the message is needed
+    /// # Arguments
+    /// * `method` - Telegram API method, which returns [`Message`] or [`MessageId`]
+    /// # Errors
+    /// - If the request cannot be send or decoded
+    /// - If the response cannot be parsed
+    /// - If the response represents an Telegram API error
+    pub async fn send_and_get_id<T, TRef>(&self, method: TRef) -> Result<i64, SessionErrorKind>
+    where
+        T: TelegramMethod + Send + Sync,
+        T::Method: Send + Sync,
+        T::Return: Into<MessageId>,
+        TRef: AsRef<T>,
+    {
+        self.send(method).await.map(|message| message.into().id)
+    }
+
+    /// Use this method to send requests to Telegram API without the default parse mode of the bot,
+    /// for example, to send the text without formatting
+    /// # Errors
+    /// - If the request cannot be send or decoded
+    /// - If the response cannot be parsed
+    /// - If the response represents an Telegram API error
+    pub async fn send_without_parse_mode<T, TRef>(
+        &self,
+        method: TRef,
+    ) -> Result<T::Return, SessionErrorKind>
+    where
+        T: TelegramMethod + Send + Sync,
+        T::Method: Send + Sync,
+        TRef: AsRef<T>,
+    {
+        self.send_request_without_parse_mode(method.as_ref(), self.request_timeout_secs())
+            .await
+    }
+
+    /// Sends the request with the defaults of the bot (see [`Bot::with_defaults`])
+    pub(crate) async fn send_request<T>(
+        &self,
+        method: &T,
+        request_timeout: Option<f32>,
+    ) -> Result<T::Return, SessionErrorKind>
+    where
+        T: TelegramMethod + Send + Sync,
+        T::Method: Send + Sync,
+    {
+        let with_defaults = self.with_defaults(method);
+
         self.client
-            .make_request_and_get_result(self, method.as_ref(), None)
+            .make_request_and_get_result(
+                self,
+                with_defaults.as_ref().unwrap_or(method),
+                request_timeout,
+            )
+            .await
+    }
+
+    /// Sends the request with the defaults of the bot except the parse mode
+    /// (see [`Bot::with_default_business_connection_id`])
+    async fn send_request_without_parse_mode<T>(
+        &self,
+        method: &T,
+        request_timeout: Option<f32>,
+    ) -> Result<T::Return, SessionErrorKind>
+    where
+        T: TelegramMethod + Send + Sync,
+        T::Method: Send + Sync,
+    {
+        let with_defaults = self.with_default_business_connection_id(method);
+
+        self.client
+            .make_request_and_get_result(
+                self,
+                with_defaults.as_ref().unwrap_or(method),
+                request_timeout,
+            )
+            .await
+    }
+
+    /// Applies the defaults of the bot to the method: the parse mode and the business connection identifier,
+    /// if they're set and supported by the method
+    /// # Returns
+    /// The method with the defaults applied, if any of them was applied, otherwise `None`
+    fn with_defaults<T>(&self, method: &T) -> Option<T>
+    where
+        T: TelegramMethod,
+    {
+        let with_parse_mode = self
+            .parse_mode
+            .as_deref()
+            .and_then(|parse_mode| method.with_default_parse_mode(parse_mode));
+
+        self.with_default_business_connection_id(with_parse_mode.as_ref().unwrap_or(method))
+            .or(with_parse_mode)
+    }
+
+    /// Applies the default business connection identifier of the bot to the method,
+    /// if it's set and supported by the method
+    /// # Returns
+    /// The method with the default applied, if it was applied, otherwise `None`
+    fn with_default_business_connection_id<T>(&self, method: &T) -> Option<T>
+    where
+        T: TelegramMethod,
+    {
+        self.business_connection_id
+            .as_deref()
+            .and_then(|business_connection_id| {
+                method.with_default_business_connection_id(business_connection_id)
+            })
+    }
+
+    /// Use this method to send requests to Telegram API and get the result as raw JSON value.
+    /// It's useful for debugging and for accessing fields of the result, which aren't modeled yet.
+    /// # Errors
+    /// - If the request cannot be send or decoded
+    /// - If the response cannot be parsed as JSON
+    /// - If the response represents an Telegram API error
+    pub async fn send_raw<T, TRef>(
+        &self,
+        method: TRef,
+    ) -> Result<serde_json::Value, SessionErrorKind>
+    where
+        T: TelegramMethod + Send + Sync,
+        T::Method: Send + Sync,
+        TRef: AsRef<T>,
+    {
+        let method = method.as_ref();
+        let with_defaults = self.with_defaults(method);
+
+        self.client
+            .make_request_raw(
+                self,
+                with_defaults.as_ref().unwrap_or(method),
+                self.request_timeout_secs(),
+            )
             .await
     }
 
@@ -183,15 +487,684 @@ impl<Client: Session> Bot<Client> {
     pub async fn send_with_timeout<T, TRef>(
         &self,
         method: TRef,
-        request_timeout: f32,
+        request_timeout: Duration,
     ) -> Result<T::Return, SessionErrorKind>
     where
         T: TelegramMethod + Send + Sync,
         T::Method: Send + Sync,
         TRef: AsRef<T>,
     {
-        self.client
-            .make_request_and_get_result(self, method.as_ref(), Some(request_timeout))
+        self.send_request(method.as_ref(), Some(request_timeout.as_secs_f32()))
+            .await
+    }
+
+    /// Use this method to get the bot user.
+    /// The user is received by `getMe` request on the first call and cached, next calls return the cached user.
+    /// # Errors
+    /// - If the request cannot be send or decoded
+    /// - If the response cannot be parsed
+    /// - If the response represents an Telegram API error
+    /// # Notes
+    /// If the request fails, then the user isn't cached and the next call sends the request again.
+    pub async fn me(&self) -> Result<&User, SessionErrorKind> {
+        self.me
+            .get_or_try_init(|| {
+                self.client.make_request_and_get_result(
+                    self,
+                    &GetMe {},
+                    self.request_timeout_secs(),
+                )
+            })
+            .await
+    }
+
+    /// Use this method to get the bot username.
+    /// Check [`Bot::me`] for more information about caching.
+    /// # Errors
+    /// If the bot user can't be received, check [`Bot::me`]
+    pub async fn username(&self) -> Result<&str, SessionErrorKind> {
+        // Bot always has username, so the default value is never used
+        self.me()
+            .await
+            .map(|user| user.username.as_deref().unwrap_or_default())
+    }
+
+    /// Use this method to set the reaction on the message.
+    /// Check [`SetMessageReaction`] builder to set several reactions, custom emoji or paid reactions.
+    /// # Arguments
+    /// * `chat_id` - Unique identifier for the target chat or username of the target channel
+    /// * `message_id` - Identifier of the target message
+    /// * `reaction` - Reaction to set, emoji strings are converted to [`ReactionType::Emoji`]
+    /// # Errors
+    /// - If the request cannot be send or decoded
+    /// - If the response cannot be parsed
+    /// - If the response represents an Telegram API error
+    pub async fn react(
+        &self,
+        chat_id: impl Into<ChatIdKind>,
+        message_id: i64,
+        reaction: impl Into<ReactionType>,
+    ) -> Result<bool, SessionErrorKind> {
+        self.send(SetMessageReaction::new(chat_id, message_id).reaction(reaction))
+            .await
+    }
+
+    /// Use this method to send the text message with the `MarkdownV2` parse mode, the text is escaped,
+    /// so it's displayed as is. Use [`md!`](crate::md) to build the text with formatting and escaped values.
+    /// # Arguments
+    /// * `chat_id` - Unique identifier for the target chat or username of the target channel
+    /// * `text` - Text of the message to be sent
+    /// # Errors
+    /// - If the request cannot be send or decoded
+    /// - If the response cannot be parsed
+    /// - If the response represents an Telegram API error
+    pub async fn send_markdown(
+        &self,
+        chat_id: impl Into<ChatIdKind>,
+        text: impl AsRef<str>,
+    ) -> Result<Message, SessionErrorKind> {
+        self.send(SendMessage::new(chat_id, markdown_quote(text)).parse_mode(ParseMode::MarkdownV2))
+            .await
+    }
+
+    /// Use this method to set the result of an interaction with a Web App and send a corresponding message on behalf of the user
+    /// to the chat from which the query originated. See [`AnswerWebAppQuery`] for more details.
+    /// # Arguments
+    /// * `web_app_query_id` - Unique identifier for the query to be answered, it's passed to the Web App in the `query_id` field of `initData`
+    /// * `result` - Message to be sent
+    /// # Errors
+    /// - If the request cannot be send or decoded
+    /// - If the response cannot be parsed
+    /// - If the response represents an Telegram API error
+    pub async fn answer_web_app(
+        &self,
+        web_app_query_id: impl Into<String>,
+        result: impl Into<InlineQueryResult>,
+    ) -> Result<SentWebAppMessage, SessionErrorKind> {
+        self.send(AnswerWebAppQuery::new(web_app_query_id, result))
+            .await
+    }
+
+    /// Use this method to receive updates by long polling as the stream, without the dispatcher and routers.
+    /// Offset is managed internally, so each update is yielded once.
+    /// Server-side errors and network errors are handled with the backoff, see [`Polling`] for more details.
+    /// # Arguments
+    /// * `allowed_updates` - Update types you want your bot to receive, if empty, then all update types except some of them are received
+    /// * `timeout` - Timeout in seconds for long polling
+    /// # Returns
+    /// Infinite stream of updates
+    pub fn updates_stream(
+        &self,
+        allowed_updates: impl IntoIterator<Item = UpdateType>,
+        timeout: Option<i64>,
+    ) -> impl Stream<Item = Update>
+    where
+        Client: Session + Clone,
+    {
+        struct State<Client> {
+            bot: Bot<Client>,
+            polling: Polling,
+            updates: VecDeque<Update>,
+        }
+
+        let state = State {
+            bot: self.clone(),
+            polling: Polling::new(ExponentialBackoff::default())
+                .timeout(timeout)
+                .allowed_updates(allowed_updates),
+            updates: VecDeque::new(),
+        };
+
+        stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(update) = state.updates.pop_front() {
+                    return Some((update, state));
+                }
+
+                state
+                    .updates
+                    .extend(state.polling.next_updates(&state.bot).await?);
+            }
+        })
+    }
+
+    /// Use this method to get all items of the paginated Telegram API method page by page.
+    /// Offset of the method is advanced automatically until an empty page is returned.
+    /// # Arguments
+    /// * `method` - Paginated Telegram API method, its offset and limit are overridden
+    /// * `page_size` - Number of items requested per page
+    /// # Returns
+    /// Stream of items. If a request fails, the error is returned and the stream is finished.
+    pub fn paginate<T>(
+        &self,
+        method: T,
+        page_size: i64,
+    ) -> impl Stream<Item = Result<T::Item, SessionErrorKind>>
+    where
+        Client: Clone,
+        T: PaginatedMethod + Send + Sync,
+        T::Method: Send + Sync,
+    {
+        struct State<Client, T: PaginatedMethod> {
+            bot: Bot<Client>,
+            method: T,
+            offset: i64,
+            items: VecDeque<T::Item>,
+            exhausted: bool,
+        }
+
+        let state = State {
+            bot: self.clone(),
+            method,
+            offset: 0,
+            items: VecDeque::new(),
+            exhausted: false,
+        };
+
+        stream::unfold(state, move |mut state| async move {
+            loop {
+                if let Some(item) = state.items.pop_front() {
+                    return Some((Ok(item), state));
+                }
+                if state.exhausted {
+                    return None;
+                }
+
+                let method = state.method.clone().page(state.offset, page_size);
+
+                match state
+                    .bot
+                    .client
+                    .make_request_and_get_result(
+                        &state.bot,
+                        &method,
+                        state.bot.request_timeout_secs(),
+                    )
+                    .await
+                {
+                    Ok(result) => {
+                        let items = T::page_items(result);
+
+                        if items.is_empty() {
+                            state.exhausted = true;
+                        } else {
+                            state.offset = T::next_offset(state.offset, &items);
+                            state.items.extend(items);
+                        }
+                    }
+                    Err(err) => {
+                        state.exhausted = true;
+
+                        return Some((Err(err), state));
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        methods::{GetMe, GetUserProfilePhotos},
+        test::{self, MockBot},
+    };
+
+    use futures::StreamExt as _;
+    use serde_json::json;
+
+    fn photo(file_id: &str) -> serde_json::Value {
+        json!([{"file_id": file_id, "file_unique_id": file_id, "width": 1, "height": 1}])
+    }
+
+    #[tokio::test]
+    async fn test_me() {
+        let mock = MockBot::new();
+        mock.push_error::<GetMe>(500, "Internal Server Error");
+        mock.push_result::<GetMe>(json!({
+            "id": crate::test::USER_ID,
+            "is_bot": true,
+            "first_name": "Bot",
+            "username": "test_bot",
+        }));
+
+        let bot = mock.bot();
+
+        // Failed request isn't cached
+        bot.me().await.unwrap_err();
+
+        assert_eq!(
+            bot.me().await.unwrap().username.as_deref(),
+            Some("test_bot")
+        );
+        assert_eq!(bot.username().await.unwrap(), "test_bot");
+        // Clones share the cache
+        assert_eq!(bot.clone().username().await.unwrap(), "test_bot");
+        assert_eq!(bot.id(), bot.bot_id);
+
+        assert_eq!(
+            mock.calls()
+                .iter()
+                .filter(|call| call.is::<GetMe>())
+                .count(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_timeout() {
+        use crate::methods::SendChatAction;
+        use std::time::Duration;
+
+        let mock = MockBot::new();
+        for _ in 0..3 {
+            mock.push_result::<SendChatAction>(true);
+        }
+
+        let bot = mock.bot();
+        let method = SendChatAction::new(1, "typing");
+
+        bot.send(&method).await.unwrap();
+        bot.send_with_timeout(&method, Duration::from_millis(1500))
             .await
+            .unwrap();
+
+        let bot = bot.request_timeout(Duration::from_secs(60));
+
+        bot.send(&method).await.unwrap();
+
+        let timeouts = mock
+            .calls()
+            .into_iter()
+            .map(|call| call.timeout)
+            .collect::<Vec<_>>();
+
+        assert_eq!(timeouts, [None, Some(1.5), Some(60.0)]);
+    }
+
+    #[tokio::test]
+    async fn test_parse_mode() {
+        use crate::{
+            enums::ParseMode,
+            methods::{SendChatAction, SendMessage},
+            types::MessageEntity,
+        };
+
+        let mock = MockBot::new();
+        for _ in 0..4 {
+            mock.push_result::<SendMessage>(test::sent_message("text"));
+        }
+        mock.push_result::<SendChatAction>(true);
+
+        let bot = mock.bot().parse_mode(ParseMode::HTML);
+
+        bot.send(SendMessage::new(1, "<b>text</b>")).await.unwrap();
+        bot.send(SendMessage::new(1, "*text*").parse_mode(ParseMode::MarkdownV2))
+            .await
+            .unwrap();
+        bot.send(SendMessage::new(1, "text").entities([MessageEntity::new_bold(0, 4)]))
+            .await
+            .unwrap();
+        bot.send_without_parse_mode(SendMessage::new(1, "<b>text</b>"))
+            .await
+            .unwrap();
+        bot.send(SendChatAction::new(1, "typing")).await.unwrap();
+
+        let calls = mock.calls();
+
+        assert_eq!(calls[0].data["parse_mode"], "HTML");
+        assert_eq!(calls[1].data["parse_mode"], "MarkdownV2");
+        assert!(calls[2].data.get("parse_mode").is_none());
+        assert!(calls[3].data.get("parse_mode").is_none());
+        assert!(calls[4].data.get("parse_mode").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_send_markdown() {
+        use crate::{enums::ParseMode, md, methods::SendMessage};
+
+        let mock = MockBot::new();
+        for _ in 0..2 {
+            mock.push_result::<SendMessage>(test::sent_message("text"));
+        }
+
+        let bot = mock.bot().parse_mode(ParseMode::HTML);
+        let input = "*not_bold* 2.0";
+
+        bot.send_markdown(1, input).await.unwrap();
+        bot.send(SendMessage::new(1, md!("*Input:* {}", input)).parse_mode(ParseMode::MarkdownV2))
+            .await
+            .unwrap();
+
+        let calls = mock.calls();
+
+        assert_eq!(calls[0].data["parse_mode"], "MarkdownV2");
+        assert_eq!(calls[0].data["text"], r"\*not\_bold\* 2\.0");
+        assert_eq!(calls[1].data["parse_mode"], "MarkdownV2");
+        assert_eq!(calls[1].data["text"], r"*Input:* \*not\_bold\* 2\.0");
+    }
+
+    #[tokio::test]
+    async fn test_answer_web_app() {
+        use crate::{
+            methods::AnswerWebAppQuery,
+            types::{InlineQueryResultArticle, InputTextMessageContent},
+        };
+
+        let mock = MockBot::new();
+        mock.push_result::<AnswerWebAppQuery>(json!({"inline_message_id": "inline_id"}));
+
+        let bot = mock.bot();
+        let sent = bot
+            .answer_web_app(
+                "query_id",
+                InlineQueryResultArticle::new("1", "Order", InputTextMessageContent::new("Done")),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(sent.inline_message_id.as_deref(), Some("inline_id"));
+
+        let calls = mock.calls();
+
+        assert!(calls[0].is::<AnswerWebAppQuery>());
+        assert_eq!(calls[0].data["web_app_query_id"], "query_id");
+        assert_eq!(calls[0].data["result"]["type"], "article");
+        assert_eq!(
+            calls[0].data["result"]["input_message_content"]["message_text"],
+            "Done"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_and_get_id() {
+        use crate::methods::{CopyMessage, ForwardMessage, SendMessage};
+
+        let mock = MockBot::new();
+        mock.push_result::<SendMessage>(json!({
+            "message_id": 5,
+            "date": 0,
+            "chat": {"id": 1, "type": "private"},
+            "text": "text",
+        }));
+        mock.push_result::<ForwardMessage>(json!({
+            "message_id": 6,
+            "date": 0,
+            "chat": {"id": 1, "type": "private"},
+            "text": "text",
+        }));
+        mock.push_result::<CopyMessage>(json!({"message_id": 7}));
+
+        let bot = mock.bot();
+
+        assert_eq!(
+            bot.send_and_get_id(SendMessage::new(1, "text"))
+                .await
+                .unwrap(),
+            5
+        );
+        assert_eq!(
+            bot.send_and_get_id(ForwardMessage::new(1, 2, 3))
+                .await
+                .unwrap(),
+            6
+        );
+        assert_eq!(
+            bot.send_and_get_id(CopyMessage::new(1, 2, 3))
+                .await
+                .unwrap(),
+            7
+        );
+    }
+
+    #[tokio::test]
+    async fn test_paginate() {
+        let mock = MockBot::new();
+        mock.push_result::<GetUserProfilePhotos>(json!({
+            "total_count": 3,
+            "photos": [photo("1"), photo("2")],
+        }));
+        mock.push_result::<GetUserProfilePhotos>(json!({
+            "total_count": 3,
+            "photos": [photo("3")],
+        }));
+        mock.push_result::<GetUserProfilePhotos>(json!({
+            "total_count": 3,
+            "photos": [],
+        }));
+
+        let bot = mock.bot();
+        let mut stream = Box::pin(bot.paginate(GetUserProfilePhotos::new(1), 2));
+
+        let mut file_ids = vec![];
+        while let Some(photo) = stream.next().await {
+            file_ids.push(photo.unwrap()[0].file_id.to_string());
+        }
+
+        assert_eq!(file_ids, ["1", "2", "3"]);
+
+        let offsets = mock
+            .calls()
+            .into_iter()
+            .map(|call| (call.data["offset"].clone(), call.data["limit"].clone()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            offsets,
+            [
+                (json!(0), json!(2)),
+                (json!(2), json!(2)),
+                (json!(3), json!(2))
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_paginate_error() {
+        let mock = MockBot::new();
+        mock.push_error::<GetUserProfilePhotos>(400, "Bad Request: user not found");
+
+        let bot = mock.bot();
+        let mut stream = Box::pin(bot.paginate(GetUserProfilePhotos::new(1), 2));
+
+        assert!(stream.next().await.unwrap().is_err());
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_with_api_server() {
+        use crate::{
+            client::{
+                telegram::{APIServer, BareFilesPathWrapper},
+                Bot,
+            },
+            methods::DeleteWebhook,
+            test::TOKEN,
+        };
+
+        use std::{
+            io::{Read as _, Write as _},
+            net::TcpListener,
+            thread,
+        };
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+
+            let mut request = vec![];
+            let mut buf = [0; 1024];
+            while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+                let len = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..len]);
+            }
+
+            let body = r#"{"ok":true,"result":true}"#;
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len(),
+            )
+            .unwrap();
+
+            String::from_utf8_lossy(&request)
+                .lines()
+                .next()
+                .unwrap()
+                .to_owned()
+        });
+
+        let bot = Bot::with_api_server(
+            TOKEN,
+            APIServer::new(
+                &format!("http://{addr}/bot{{token}}/{{method_name}}"),
+                &format!("http://{addr}/file/bot{{token}}/{{path}}"),
+                true,
+                BareFilesPathWrapper,
+            ),
+        )
+        .unwrap();
+
+        assert!(bot.send(DeleteWebhook::new()).await.unwrap());
+        assert_eq!(
+            server.join().unwrap(),
+            format!("POST /bot{TOKEN}/deleteWebhook HTTP/1.1")
+        );
+    }
+
+    #[test]
+    fn test_use_test_environment() {
+        use crate::client::{Bot, Session as _};
+
+        let bot = Bot::new(crate::test::TOKEN).use_test_environment();
+
+        assert!(bot
+            .client()
+            .api()
+            .api_url(&bot.token, "getMe")
+            .ends_with("/test/getMe"));
+        assert!(bot
+            .client()
+            .api()
+            .file_url(&bot.token, "file")
+            .ends_with("/test/file"));
+    }
+
+    #[tokio::test]
+    async fn test_react() {
+        use crate::methods::SetMessageReaction;
+
+        let mock = MockBot::new();
+        mock.push_result::<SetMessageReaction>(true);
+
+        assert!(mock.bot().react(1, 2, "👍").await.unwrap());
+
+        let calls = mock.take_calls();
+
+        assert_eq!(calls.len(), 1);
+        assert!(calls[0].is::<SetMessageReaction>());
+        assert_eq!(
+            calls[0].data,
+            json!({
+                "chat_id": 1,
+                "message_id": 2,
+                "reaction": [{"type": "emoji", "emoji": "👍"}],
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_raw() {
+        use crate::methods::SendMessage;
+
+        let message = json!({
+            "message_id": 2,
+            "date": 0,
+            "chat": {"id": 1, "type": "private"},
+            "text": "text",
+            "unknown_field": {"value": 1},
+        });
+
+        let mock = MockBot::new();
+        mock.push_result::<SendMessage>(&message);
+        mock.push_error::<SendMessage>(400, "Bad Request: chat not found");
+
+        let bot = mock.bot().parse_mode("HTML");
+
+        assert_eq!(
+            bot.send_raw(SendMessage::new(1, "text")).await.unwrap(),
+            message
+        );
+        assert!(bot.send_raw(SendMessage::new(1, "text")).await.is_err());
+
+        let calls = mock.take_calls();
+
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].data["parse_mode"], "HTML");
+    }
+
+    #[test]
+    fn test_set_invalid_api_server() {
+        use crate::client::{
+            telegram::{APIServer, APIServerError, BareFilesPathWrapper},
+            Bot, Session as _,
+        };
+
+        let mut bot = Bot::default();
+
+        assert_eq!(
+            bot.set_api_server(APIServer::new(
+                "http://localhost:8081/bot{token}",
+                "http://localhost:8081/file/bot{token}/{path}",
+                true,
+                BareFilesPathWrapper,
+            )),
+            Err(APIServerError::MissingPlaceholder {
+                url: "http://localhost:8081/bot{token}".into(),
+                placeholder: "{method_name}",
+            })
+        );
+        // The API server isn't changed
+        assert_eq!(
+            bot.client().api().api_url("token", "getMe").as_ref(),
+            "https://api.telegram.org/bottoken/getMe"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_updates_stream() {
+        use crate::{enums::UpdateType, methods::GetUpdates};
+
+        let message = |id| json!({"message_id": id, "date": 0, "chat": {"id": 1, "type": "private"}, "text": "text"});
+
+        let mock = MockBot::new();
+        mock.push_result::<GetUpdates>(json!([
+            {"update_id": 10, "message": message(1)},
+            {"update_id": 11, "message": message(2)},
+        ]));
+        mock.push_result::<GetUpdates>(json!([]));
+        mock.push_result::<GetUpdates>(json!([{"update_id": 12, "message": message(3)}]));
+
+        let updates = mock
+            .bot()
+            .updates_stream([UpdateType::Message], Some(5))
+            .take(3)
+            .map(|update| update.id)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(updates, [10, 11, 12]);
+
+        let calls = mock.calls();
+
+        assert_eq!(calls.len(), 3);
+        assert_eq!(calls[0].data["allowed_updates"], json!(["message"]));
+        assert_eq!(calls[0].data["timeout"], 5);
+        assert!(calls[0].data.get("offset").is_none());
+        // Empty batch doesn't change the offset
+        assert_eq!(calls[1].data["offset"], 12);
+        assert_eq!(calls[2].data["offset"], 12);
     }
 }
@@ -0,0 +1,189 @@
+//! This module contains [`Bot::broadcast`] method that sends the same request to many chats
+//! and [`BroadcastReport`] struct with results of the sending.
+//!
+//! Requests are sent concurrently, but no more than [`BROADCAST_CONCURRENCY`] at the same time.
+//! If Telegram responds with flood control error, the request is sent again after the requested delay,
+//! but no more than [`BROADCAST_MAX_RETRIES`] times.
+//! To also keep the rate of requests below the limits, use [`RateLimited`] session.
+//!
+//! # Examples
+//! ```rust
+//! use telers::{methods::SendMessage, Bot};
+//!
+//! async fn announce(bot: Bot, chat_ids: Vec<i64>, text: String) {
+//!     let report = bot
+//!         .broadcast(chat_ids, |chat_id| SendMessage::new(chat_id, text.clone()))
+//!         .await;
+//!
+//!     println!(
+//!         "Sent: {}, blocked: {}, failed: {}",
+//!         report.succeeded.len(),
+//!         report.blocked.len(),
+//!         report.failed.len(),
+//!     );
+//! }
+//! ```
+//!
+//! [`RateLimited`]: crate::client::session::RateLimited
+
+use super::{session::base::Session, Bot};
+
+use crate::{
+    errors::{SessionErrorKind, TelegramErrorKind},
+    methods::TelegramMethod,
+};
+
+use futures::stream::{self, StreamExt as _};
+use std::time::Duration;
+use tracing::{event, Level};
+
+/// Maximum number of requests sent at the same time
+pub const BROADCAST_CONCURRENCY: usize = 30;
+/// Maximum number of retries of one request after flood control errors
+pub const BROADCAST_MAX_RETRIES: u32 = 3;
+
+/// Results of [`Bot::broadcast`]
+#[derive(Debug)]
+pub struct BroadcastReport<Id, T> {
+    /// Chats to which the request was sent successfully with the results
+    pub succeeded: Vec<(Id, T)>,
+    /// Chats in which the bot can't send the request (the bot is blocked by the user, kicked from the chat or the user is deactivated)
+    pub blocked: Vec<(Id, SessionErrorKind)>,
+    /// Chats to which the request wasn't sent because of other errors
+    pub failed: Vec<(Id, SessionErrorKind)>,
+}
+
+impl<Id, T> Default for BroadcastReport<Id, T> {
+    fn default() -> Self {
+        Self {
+            succeeded: vec![],
+            blocked: vec![],
+            failed: vec![],
+        }
+    }
+}
+
+impl<Client: Session> Bot<Client> {
+    /// Use this method to send requests built by `build` to the chats concurrently.
+    /// Check [module docs](crate::client::broadcast) for more information about concurrency and flood control.
+    /// # Arguments
+    /// * `chat_ids` - Identifiers of the chats, which are passed to `build`
+    /// * `build` - Builds the request for the chat
+    /// # Returns
+    /// Report with results of the requests, which are separated by blocked chats and other errors
+    pub async fn broadcast<Id, T, F>(
+        &self,
+        chat_ids: impl IntoIterator<Item = Id>,
+        build: F,
+    ) -> BroadcastReport<Id, T::Return>
+    where
+        Id: Clone,
+        T: TelegramMethod + Send + Sync,
+        T::Method: Send + Sync,
+        F: Fn(Id) -> T,
+    {
+        let mut report = BroadcastReport::default();
+
+        let mut results = stream::iter(chat_ids)
+            .map(|chat_id| {
+                let method = build(chat_id.clone());
+
+                async move { (chat_id, self.send_with_retries(&method).await) }
+            })
+            .buffer_unordered(BROADCAST_CONCURRENCY);
+
+        while let Some((chat_id, result)) = results.next().await {
+            match result {
+                Ok(result) => report.succeeded.push((chat_id, result)),
+                Err(err @ SessionErrorKind::Telegram(TelegramErrorKind::Forbidden { .. })) => {
+                    report.blocked.push((chat_id, err));
+                }
+                Err(err) => report.failed.push((chat_id, err)),
+            }
+        }
+
+        report
+    }
+
+    async fn send_with_retries<T>(&self, method: &T) -> Result<T::Return, SessionErrorKind>
+    where
+        T: TelegramMethod + Send + Sync,
+        T::Method: Send + Sync,
+    {
+        let mut retries = 0;
+
+        loop {
+            match self.send_request(method, self.request_timeout_secs()).await {
+                Err(SessionErrorKind::Telegram(TelegramErrorKind::RetryAfter {
+                    retry_after,
+                    ..
+                })) if retries < BROADCAST_MAX_RETRIES => {
+                    event!(
+                        Level::WARN,
+                        retry_after,
+                        "Flood control exceeded, retry after delay"
+                    );
+
+                    tokio::time::sleep(Duration::from_secs(retry_after.unsigned_abs())).await;
+
+                    retries += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{client::session::ClientResponse, methods::SendMessage, test::MockBot};
+
+    #[tokio::test]
+    async fn test_broadcast() {
+        let mock = MockBot::new();
+
+        let message = |chat_id: i64| {
+            serde_json::json!({
+                "message_id": 1,
+                "date": 0,
+                "chat": {"id": chat_id, "type": "private"},
+                "text": "text",
+            })
+        };
+
+        mock.push_result::<SendMessage>(message(1));
+        mock.push_error::<SendMessage>(403, "Forbidden: bot was blocked by the user");
+        mock.push_response::<SendMessage>(ClientResponse::new(
+            429,
+            serde_json::json!({
+                "ok": false,
+                "error_code": 429,
+                "description": "Too Many Requests: retry after 0",
+                "parameters": {"retry_after": 0},
+            })
+            .to_string(),
+        ));
+        mock.push_error::<SendMessage>(400, "Bad Request: chat not found");
+        mock.push_result::<SendMessage>(message(3));
+
+        let report = mock
+            .bot()
+            .broadcast([1, 2, 3, 4], |chat_id| SendMessage::new(chat_id, "text"))
+            .await;
+
+        let mut succeeded = report
+            .succeeded
+            .iter()
+            .map(|(chat_id, _)| *chat_id)
+            .collect::<Vec<_>>();
+        succeeded.sort_unstable();
+
+        // Request to the chat `3` is retried after the flood control error
+        assert_eq!(succeeded, [1, 3]);
+        assert_eq!(report.blocked.len(), 1);
+        assert_eq!(report.blocked[0].0, 2);
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].0, 4);
+        assert_eq!(mock.calls().len(), 5);
+    }
+}
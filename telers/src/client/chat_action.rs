@@ -0,0 +1,136 @@
+//! This module contains [`ChatActionGuard`] struct that sends chat action (for example, "typing...")
+//! periodically while the guard is alive.
+//!
+//! Telegram clients show the chat action for 5 seconds or less, so for slow handlers the action should be re-sent.
+//! Guard sends the action immediately and then every [`CHAT_ACTION_INTERVAL`] until it's dropped.
+//!
+//! # Examples
+//! ```rust
+//! use telers::{enums::ChatAction, methods::SendMessage, Bot};
+//!
+//! async fn slow_handler(bot: Bot, chat_id: i64) {
+//!     let guard = bot.chat_action_guard(chat_id, ChatAction::Typing);
+//!
+//!     // Some long work...
+//!
+//!     // Stop sending the action before sending the response
+//!     drop(guard);
+//!
+//!     let _ = bot.send(SendMessage::new(chat_id, "Done!")).await;
+//! }
+//! ```
+
+use super::{session::base::Session, Bot};
+
+use crate::{enums::ChatAction, methods::SendChatAction, types::ChatIdKind};
+
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{event, Level};
+
+/// Interval between chat action requests, which is a bit less than the time Telegram clients show the action
+pub const CHAT_ACTION_INTERVAL: Duration = Duration::from_secs(4);
+
+/// Guard that sends chat action periodically in the background task and stops on drop
+#[derive(Debug)]
+#[must_use = "chat action is stopped when the guard is dropped"]
+pub struct ChatActionGuard {
+    handle: JoinHandle<()>,
+}
+
+impl ChatActionGuard {
+    /// Spawns a task that sends the chat action every [`CHAT_ACTION_INTERVAL`]
+    pub fn new<Client>(bot: Bot<Client>, method: SendChatAction) -> Self
+    where
+        Client: Session + Clone + 'static,
+    {
+        let handle = tokio::spawn(async move {
+            loop {
+                if let Err(err) = bot.send(&method).await {
+                    event!(Level::ERROR, error = %err, "Failed to send chat action");
+                }
+
+                tokio::time::sleep(CHAT_ACTION_INTERVAL).await;
+            }
+        });
+
+        Self { handle }
+    }
+}
+
+impl Drop for ChatActionGuard {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+impl<Client> Bot<Client>
+where
+    Client: Session + Clone + 'static,
+{
+    /// Sends the chat action immediately and then every [`CHAT_ACTION_INTERVAL`] until the returned guard is dropped
+    /// # Notes
+    /// Use [`Bot::chat_action_guard_with`] to send the action to the message thread or on behalf of the business connection
+    pub fn chat_action_guard(
+        &self,
+        chat_id: impl Into<ChatIdKind>,
+        action: ChatAction,
+    ) -> ChatActionGuard {
+        self.chat_action_guard_with(SendChatAction::new(chat_id, <&'static str>::from(action)))
+    }
+
+    /// Sends the chat action request immediately and then every [`CHAT_ACTION_INTERVAL`] until the returned guard is dropped
+    pub fn chat_action_guard_with(&self, method: SendChatAction) -> ChatActionGuard {
+        ChatActionGuard::new(self.clone(), method)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::MockBot;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_chat_action_guard() {
+        let mock = MockBot::new();
+        for _ in 0..3 {
+            mock.push_result::<SendChatAction>(true);
+        }
+
+        let bot = mock.bot();
+
+        let guard = bot.chat_action_guard_with(
+            SendChatAction::new(1, "typing")
+                .message_thread_id(2)
+                .business_connection_id("3"),
+        );
+        tokio::time::sleep(CHAT_ACTION_INTERVAL / 2).await;
+
+        assert_eq!(mock.calls().len(), 1);
+
+        // The action is resent after the interval
+        tokio::time::sleep(CHAT_ACTION_INTERVAL).await;
+
+        let calls = mock.calls();
+
+        assert_eq!(calls.len(), 2);
+        for call in &calls {
+            assert!(call.is::<SendChatAction>());
+            assert_eq!(call.data["action"], "typing");
+            assert_eq!(call.data["message_thread_id"], 2);
+            assert_eq!(call.data["business_connection_id"], "3");
+        }
+
+        drop(guard);
+        tokio::time::sleep(CHAT_ACTION_INTERVAL * 3).await;
+
+        assert_eq!(mock.calls().len(), 2);
+
+        let guard = bot.chat_action_guard(1, ChatAction::UploadPhoto);
+        // Guard is dropped before the task is started, so the action isn't sent
+        drop(guard);
+        tokio::time::sleep(CHAT_ACTION_INTERVAL * 3).await;
+
+        assert_eq!(mock.calls().len(), 2);
+    }
+}
@@ -0,0 +1,220 @@
+//! This module contains [`ReplyTo`] extractor that sends requests to the chat (and the message thread) of the current update.
+//!
+//! Methods are sent with the chat identifier of the update, if the chat identifier of the method is unset,
+//! so pass `0` (or [`ChatIdKind::UNSET`]) to the method constructor to send it to the chat of the update.
+//! Methods with the explicitly set chat identifier are sent to the passed chat as is.
+//! Message thread and business connection identifiers of the update are used only if they aren't set explicitly.
+//! Methods without `chat_id` field are sent as is, see [`TelegramMethod::with_chat`] for more details.
+//!
+//! [`ChatIdKind::UNSET`]: crate::types::ChatIdKind::UNSET
+//!
+//! # Examples
+//! ```rust
+//! use telers::{
+//!     client::ReplyTo,
+//!     event::{telegram::HandlerResult, EventReturn},
+//!     methods::SendMessage,
+//! };
+//!
+//! async fn handler(reply: ReplyTo) -> HandlerResult {
+//!     reply.send(SendMessage::new(0, "Hello!")).await?;
+//!
+//!     Ok(EventReturn::Finish)
+//! }
+//! ```
+
+use super::{session::base::Session, Bot, Reqwest};
+
+use crate::{
+    context::Context,
+    errors::{ExtractionError, SessionErrorKind},
+    extractors::FromEventAndContext,
+    methods::TelegramMethod,
+    types::Update,
+};
+
+use std::sync::Arc;
+
+/// Bot bound to the chat, the message thread and the business connection of the current update
+#[derive(Debug)]
+pub struct ReplyTo<Client = Reqwest> {
+    pub bot: Arc<Bot<Client>>,
+    /// Identifier of the chat, to which the requests are sent
+    pub chat_id: i64,
+    /// Identifier of the message thread, which is used if it isn't set in the method explicitly
+    pub message_thread_id: Option<i64>,
+    /// Identifier of the business connection, which is used if it isn't set in the method explicitly
+    pub business_connection_id: Option<Box<str>>,
+}
+
+impl<Client> ReplyTo<Client> {
+    #[must_use]
+    pub fn new(
+        bot: Arc<Bot<Client>>,
+        chat_id: i64,
+        message_thread_id: Option<i64>,
+        business_connection_id: Option<impl Into<Box<str>>>,
+    ) -> Self {
+        Self {
+            bot,
+            chat_id,
+            message_thread_id,
+            business_connection_id: business_connection_id.map(Into::into),
+        }
+    }
+}
+
+impl<Client: Session> ReplyTo<Client> {
+    /// Sends the request to the chat (and the message thread) of the update
+    /// # Errors
+    /// - If the request cannot be send or decoded
+    /// - If the response cannot be parsed
+    /// - If the response represents an Telegram API error
+    pub async fn send<T, TRef>(&self, method: TRef) -> Result<T::Return, SessionErrorKind>
+    where
+        T: TelegramMethod + Send + Sync,
+        T::Method: Send + Sync,
+        TRef: AsRef<T>,
+    {
+        let method = method.as_ref();
+        let targeted = method.with_chat(self.chat_id, self.message_thread_id);
+        let method = targeted.as_ref().unwrap_or(method);
+
+        let with_business_connection = self
+            .business_connection_id
+            .as_deref()
+            .and_then(|id| method.with_default_business_connection_id(id));
+        let method = with_business_connection.as_ref().unwrap_or(method);
+
+        self.bot
+            .send_request(method, self.bot.request_timeout_secs())
+            .await
+    }
+}
+
+impl<Client> Clone for ReplyTo<Client> {
+    fn clone(&self) -> Self {
+        Self {
+            bot: Arc::clone(&self.bot),
+            chat_id: self.chat_id,
+            message_thread_id: self.message_thread_id,
+            business_connection_id: self.business_connection_id.clone(),
+        }
+    }
+}
+
+impl<Client> FromEventAndContext<Client> for ReplyTo<Client> {
+    type Error = ExtractionError;
+
+    fn extract(
+        bot: Arc<Bot<Client>>,
+        update: Arc<Update>,
+        _context: Arc<Context>,
+    ) -> Result<Self, Self::Error> {
+        let chat_id = update
+            .chat_id()
+            .ok_or_else(|| ExtractionError::new("Update doesn't have a chat to reply to"))?;
+
+        Ok(Self::new(
+            bot,
+            chat_id,
+            update.message_thread_id(),
+            update.business_connection_id(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        event::{telegram::HandlerResult, EventReturn},
+        methods::{SendMessage, SendPhoto},
+        test::{self, MockBot},
+        types::{InputFile, Message, MessageText, UpdateKind},
+        Router,
+    };
+
+    use serde_json::json;
+
+    fn topic_message() -> Update {
+        Update {
+            kind: UpdateKind::Message(Message::Text(Box::new(MessageText {
+                id: 1,
+                thread_id: Some(3),
+                is_topic_message: Some(true),
+                chat: serde_json::from_value(json!({
+                    "id": -1,
+                    "type": "supergroup",
+                    "title": "Forum",
+                    "is_forum": true,
+                }))
+                .unwrap(),
+                text: "text".into(),
+                ..Default::default()
+            }))),
+            ..Default::default()
+        }
+    }
+
+    async fn handler(reply: ReplyTo<MockBot>) -> HandlerResult {
+        reply.send(SendMessage::new(0, "auto")).await?;
+        reply
+            .send(SendMessage::new(0, "explicit").message_thread_id(4))
+            .await?;
+        reply
+            .send(SendPhoto::new(test::CHAT_ID, InputFile::id("file_id")))
+            .await?;
+
+        Ok(EventReturn::Finish)
+    }
+
+    #[tokio::test]
+    async fn test_reply_to() {
+        let mock = MockBot::new();
+        let result = json!({
+            "message_id": 2,
+            "date": 0,
+            "chat": {"id": -1, "type": "supergroup", "title": "Forum"},
+            "text": "text",
+        });
+        mock.push_result::<SendMessage>(&result);
+        mock.push_result::<SendMessage>(&result);
+        mock.push_result::<SendPhoto>(json!({
+            "message_id": 2,
+            "date": 0,
+            "chat": {"id": -1, "type": "supergroup", "title": "Forum"},
+            "photo": [],
+        }));
+
+        let mut router = Router::new("main");
+        router.message.register(handler);
+
+        let calls = mock.propagate(router, topic_message()).await.unwrap();
+
+        assert_eq!(calls.len(), 3);
+        for call in &calls {
+            assert!(call.data.get("business_connection_id").is_none());
+        }
+        assert_eq!(calls[0].data["chat_id"], -1);
+        assert_eq!(calls[0].data["message_thread_id"], 3);
+        // Explicit thread isn't overridden
+        assert_eq!(calls[1].data["chat_id"], -1);
+        assert_eq!(calls[1].data["message_thread_id"], 4);
+        // Explicit target chat isn't overridden, so the thread of the update isn't used too
+        assert_eq!(calls[2].data["chat_id"], test::CHAT_ID);
+        assert!(calls[2].data.get("message_thread_id").is_none());
+    }
+
+    #[test]
+    fn test_extract_without_chat() {
+        let bot = Arc::new(MockBot::new().bot());
+
+        assert!(ReplyTo::extract(
+            bot,
+            Arc::new(test::callback_query("data")),
+            Arc::new(Context::new())
+        )
+        .is_err());
+    }
+}
@@ -3,11 +3,20 @@
 //! Components are:
 //! - [`base`] module with basic types and traits for sending requests
 //! - [`reqwest`] module with reqwest client implementation
+//! - [`migrate`] module with session decorator that sends requests to the new chat after the group migration
+//! - [`rate_limit`] module with session decorator that limits the rate of requests
+//! - [`retry`] module with session decorator that retries requests on transient failures
 //!
 //! Check each submodule for more information.
 
 pub mod base;
+pub mod migrate;
+pub mod rate_limit;
 pub mod reqwest;
+pub mod retry;
 
-pub use self::reqwest::Reqwest;
+pub use self::reqwest::{Builder as ReqwestBuilder, Reqwest};
 pub use base::{ClientResponse, Session, StatusCode};
+pub use migrate::Migrating;
+pub use rate_limit::RateLimited;
+pub use retry::Retrying;
@@ -310,6 +310,41 @@ pub trait Session: Send + Sync {
         Ok(response.result.unwrap())
     }
 
+    /// Makes a request to Telegram API and get result from it as raw JSON value without typed deserialization.
+    /// This is useful for debugging and for accessing fields, which aren't modeled yet.
+    /// # Arguments
+    /// * `bot` - Bot instance for building and sending request, it is mainly used for getting bot token
+    /// * `method` - Telegram method for building and sending request
+    /// * `timeout` - Request timeout.
+    /// If `None`, then client timeout will be used, which is [`DEFAULT_TIMEOUT`] by default.
+    /// # Errors
+    /// - If the request cannot be send or decoded
+    /// - If the response cannot be parsed as JSON
+    /// - If the response represents an telegram api error
+    #[instrument(skip(self, bot, method, timeout), fields(bot_id))]
+    async fn make_request_raw<Client, T>(
+        &self,
+        bot: &Bot<Client>,
+        method: &T,
+        timeout: Option<f32>,
+    ) -> Result<serde_json::Value, SessionErrorKind>
+    where
+        Client: Session,
+        T: TelegramMethod + Send + Sync,
+        T::Method: Send + Sync,
+    {
+        Span::current().record("bot_id", bot.bot_id);
+
+        let response = self.send_request(bot, method, timeout).await?;
+        let telegram_response =
+            serde_json::from_str::<Response<serde_json::Value>>(&response.content)?;
+
+        self.check_response(&telegram_response, &response.status_code)?;
+
+        // Unwrap safe because we checked it in `check_response`
+        Ok(telegram_response.result.unwrap())
+    }
+
     /// Close client session. Default implementation does nothing.
     async fn close(&self) -> Result<(), anyhow::Error> {
         Ok(())
@@ -0,0 +1,283 @@
+//! This module contains [`Migrating`] struct that wraps a [`Session`] and handles migration of groups to supergroups.
+//!
+//! When a group is upgraded to a supergroup, requests to the old chat fail with an error,
+//! which contains identifier of the new chat (`migrate_to_chat_id`).
+//! This decorator remembers the new identifier and sends the request again to the new chat,
+//! next requests to the old chat are sent to the new chat immediately.
+//!
+//! # Notes
+//!
+//! Identifiers are remembered in memory only, so use [`Migrating::on_migrate`] to persist them
+//! and [`Migrating::migrated_chats`] to restore them on startup.
+//!
+//! Only integer `chat_id` argument of the method is replaced, usernames of the channels don't change on migration.
+//!
+//! This structure is cheap to clone, because all clones share the same remembered identifiers.
+
+use super::{
+    base::{ClientResponse, Session},
+    Reqwest,
+};
+
+use crate::{
+    client::{telegram::APIServer, Bot},
+    methods::{Request, Response, TelegramMethod},
+};
+
+use async_trait::async_trait;
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    fmt::{self, Debug, Formatter},
+    sync::{Arc, Mutex},
+};
+use tracing::{event, instrument, Level};
+
+type OnMigrate = Arc<dyn Fn(i64, i64) + Send + Sync>;
+
+/// Session decorator that sends requests to the new chat after the group is migrated to the supergroup
+/// # Examples
+/// ```rust
+/// use telers::{client::{session::Migrating, Reqwest}, Bot};
+///
+/// let client = Migrating::new(Reqwest::default())
+///     .migrated_chats([(-123, -100_123)])
+///     .on_migrate(|old_chat_id, new_chat_id| {
+///         println!("Chat {old_chat_id} is migrated to {new_chat_id}");
+///     });
+///
+/// let bot = Bot::with_client("1234567890:ABC-DEF1234ghIkl-zyx57W2v1u123ew11", client);
+/// ```
+#[derive(Clone)]
+pub struct Migrating<S = Reqwest> {
+    session: S,
+    chat_ids: Arc<Mutex<HashMap<i64, i64>>>,
+    on_migrate: Option<OnMigrate>,
+}
+
+impl<S> Migrating<S> {
+    #[must_use]
+    pub fn new(session: S) -> Self {
+        Self {
+            session,
+            chat_ids: Arc::default(),
+            on_migrate: None,
+        }
+    }
+
+    /// Adds already known migrations from old chat identifiers to new ones, for example, restored from the database
+    /// # Panics
+    /// If the state mutex is poisoned
+    #[must_use]
+    pub fn migrated_chats(self, val: impl IntoIterator<Item = (i64, i64)>) -> Self {
+        self.chat_ids.lock().unwrap().extend(val);
+        self
+    }
+
+    /// Callback that is called with old and new chat identifiers when the chat migration is detected
+    #[must_use]
+    pub fn on_migrate<F>(self, val: F) -> Self
+    where
+        F: Fn(i64, i64) + Send + Sync + 'static,
+    {
+        Self {
+            on_migrate: Some(Arc::new(val)),
+            ..self
+        }
+    }
+
+    /// Gets the wrapped session
+    #[must_use]
+    pub const fn session(&self) -> &S {
+        &self.session
+    }
+
+    /// Gets identifier of the chat to which the chat is migrated
+    /// # Panics
+    /// If the state mutex is poisoned
+    #[must_use]
+    pub fn migrated_chat_id(&self, chat_id: i64) -> Option<i64> {
+        self.chat_ids.lock().unwrap().get(&chat_id).copied()
+    }
+
+    fn migrate(&self, old_chat_id: i64, new_chat_id: i64) {
+        event!(Level::INFO, old_chat_id, new_chat_id, "Chat is migrated");
+
+        self.chat_ids
+            .lock()
+            .unwrap()
+            .insert(old_chat_id, new_chat_id);
+
+        if let Some(on_migrate) = &self.on_migrate {
+            on_migrate(old_chat_id, new_chat_id);
+        }
+    }
+}
+
+impl<S: Debug> Debug for Migrating<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Migrating")
+            .field("session", &self.session)
+            .field("chat_ids", &self.chat_ids)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Method with replaced `chat_id` argument
+struct Remapped<'a, T> {
+    method: &'a T,
+    data: Value,
+}
+
+impl<'a, T> TelegramMethod for Remapped<'a, T>
+where
+    T: TelegramMethod,
+{
+    type Method = Value;
+    type Return = T::Return;
+
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<'_, Self::Method> {
+        let request = self.method.build_request(bot);
+
+        Request::new(request.method_name, &self.data, request.files)
+    }
+
+    fn build_response(&self, content: &str) -> Result<Response<Self::Return>, serde_json::Error> {
+        self.method.build_response(content)
+    }
+}
+
+/// Gets integer `chat_id` argument of the method
+fn chat_id(data: &Value) -> Option<i64> {
+    data.get("chat_id")?.as_i64()
+}
+
+#[async_trait]
+impl<S> Session for Migrating<S>
+where
+    S: Session,
+{
+    fn api(&self) -> &APIServer {
+        self.session.api()
+    }
+
+    #[instrument(skip(self, bot, method, timeout))]
+    async fn send_request<Client, T>(
+        &self,
+        bot: &Bot<Client>,
+        method: &T,
+        timeout: Option<f32>,
+    ) -> Result<ClientResponse, anyhow::Error>
+    where
+        Client: Session,
+        T: TelegramMethod + Send + Sync,
+        T::Method: Send + Sync,
+    {
+        let mut data = serde_json::to_value(method.build_request(bot).data)?;
+
+        let Some(chat_id) = chat_id(&data) else {
+            return self.session.send_request(bot, method, timeout).await;
+        };
+
+        let response = match self.migrated_chat_id(chat_id) {
+            Some(new_chat_id) => {
+                data["chat_id"] = new_chat_id.into();
+
+                self.session
+                    .send_request(
+                        bot,
+                        &Remapped {
+                            method,
+                            data: data.clone(),
+                        },
+                        timeout,
+                    )
+                    .await?
+            }
+            None => self.session.send_request(bot, method, timeout).await?,
+        };
+
+        if response.status_code.is_success() {
+            return Ok(response);
+        }
+
+        let Some(new_chat_id) = method
+            .build_response(&response.content)
+            .ok()
+            .and_then(|response| response.parameters)
+            .and_then(|parameters| parameters.migrate_to_chat_id)
+        else {
+            return Ok(response);
+        };
+
+        self.migrate(chat_id, new_chat_id);
+
+        data["chat_id"] = new_chat_id.into();
+
+        self.session
+            .send_request(bot, &Remapped { method, data }, timeout)
+            .await
+    }
+
+    async fn close(&self) -> Result<(), anyhow::Error> {
+        self.session.close().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{methods::SendMessage, test::MockBot};
+
+    #[tokio::test]
+    async fn test_migrate() {
+        let mock = MockBot::new();
+        let migrations = Arc::new(Mutex::new(vec![]));
+
+        let bot = Bot::with_client(
+            crate::test::TOKEN,
+            Migrating::new(mock.clone()).on_migrate({
+                let migrations = Arc::clone(&migrations);
+
+                move |old_chat_id, new_chat_id| {
+                    migrations.lock().unwrap().push((old_chat_id, new_chat_id));
+                }
+            }),
+        );
+
+        let message = serde_json::json!({
+            "message_id": 1,
+            "date": 0,
+            "chat": {"id": -100, "type": "supergroup", "title": "Test"},
+            "text": "text",
+        });
+
+        mock.push_response::<SendMessage>(ClientResponse::new(
+            400,
+            serde_json::json!({
+                "ok": false,
+                "error_code": 400,
+                "description": "Bad Request: group chat was upgraded to a supergroup chat",
+                "parameters": {"migrate_to_chat_id": -100},
+            })
+            .to_string(),
+        ));
+        // Requests to the new chat are sent with replaced `chat_id`
+        mock.push_result::<Remapped<SendMessage>>(message.clone());
+        mock.push_result::<Remapped<SendMessage>>(message);
+
+        bot.send(SendMessage::new(-1, "text")).await.unwrap();
+        bot.send(SendMessage::new(-1, "text")).await.unwrap();
+
+        let calls = mock.calls();
+
+        // The first request is sent again to the new chat, the next one is sent to the new chat immediately
+        assert_eq!(calls.len(), 3);
+        assert_eq!(calls[0].data["chat_id"], -1);
+        assert_eq!(calls[1].data["chat_id"], -100);
+        assert_eq!(calls[1].data["text"], "text");
+        assert_eq!(calls[2].data["chat_id"], -100);
+        assert_eq!(*migrations.lock().unwrap(), [(-1, -100)]);
+        assert_eq!(bot.client().migrated_chat_id(-1), Some(-100));
+    }
+}
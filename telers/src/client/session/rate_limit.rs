@@ -0,0 +1,298 @@
+//! This module contains [`RateLimited`] struct that wraps a [`Session`] and limits the rate of requests
+//! to avoid flood control errors from the Telegram Bot API.
+//!
+//! Telegram limits bots to about 30 messages per second globally and to about 1 message per second in one chat.
+//! See <https://core.telegram.org/bots/faq#my-bot-is-hitting-limits-how-do-i-avoid-this> for more information.
+//!
+//! # Notes
+//!
+//! Requests aren't rejected when the limit is reached, they are delayed until the limit allows them.
+//!
+//! Only requests with `chat_id` argument (send, edit, copy and other chat methods) are limited,
+//! other requests (for example, `getUpdates` or `answerCallbackQuery`) are sent immediately.
+//!
+//! This structure is cheap to clone, because all clones share the same rate limit state.
+
+use super::{
+    base::{ClientResponse, Session},
+    Reqwest,
+};
+
+use crate::{
+    client::{telegram::APIServer, Bot},
+    methods::TelegramMethod,
+};
+
+use async_trait::async_trait;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::time::{self, Instant};
+use tracing::{event, instrument, Level};
+
+/// Default interval between requests to any chats, which is equal to 30 requests per second
+pub const DEFAULT_GLOBAL_INTERVAL: Duration = Duration::from_nanos(1_000_000_000 / 30);
+/// Default interval between requests to one chat, which is equal to 1 request per second
+pub const DEFAULT_CHAT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Number of chats after which chats without delayed requests are removed from the state
+const CHATS_PRUNE_THRESHOLD: usize = 1024;
+
+#[derive(Debug, Default)]
+struct State {
+    next_global: Option<Instant>,
+    next_by_chat: HashMap<Box<str>, Instant>,
+}
+
+/// Session decorator that enforces client-side rate limits by delaying requests
+/// # Examples
+/// ```rust
+/// use std::time::Duration;
+/// use telers::{client::{session::RateLimited, Reqwest}, Bot};
+///
+/// let client = RateLimited::new(Reqwest::default())
+///     .global_interval(Duration::from_millis(50))
+///     .chat_interval(Duration::from_secs(3));
+///
+/// let bot = Bot::with_client("1234567890:ABC-DEF1234ghIkl-zyx57W2v1u123ew11", client);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RateLimited<S = Reqwest> {
+    session: S,
+    global_interval: Duration,
+    chat_interval: Duration,
+    state: Arc<Mutex<State>>,
+}
+
+impl<S> RateLimited<S> {
+    /// Creates a new [`RateLimited`] session with [`DEFAULT_GLOBAL_INTERVAL`] and [`DEFAULT_CHAT_INTERVAL`]
+    #[must_use]
+    pub fn new(session: S) -> Self {
+        Self {
+            session,
+            global_interval: DEFAULT_GLOBAL_INTERVAL,
+            chat_interval: DEFAULT_CHAT_INTERVAL,
+            state: Arc::default(),
+        }
+    }
+
+    /// Minimal interval between requests to any chats
+    #[must_use]
+    pub fn global_interval(self, val: Duration) -> Self {
+        Self {
+            global_interval: val,
+            ..self
+        }
+    }
+
+    /// Minimal interval between requests to one chat
+    #[must_use]
+    pub fn chat_interval(self, val: Duration) -> Self {
+        Self {
+            chat_interval: val,
+            ..self
+        }
+    }
+
+    /// Gets the wrapped session
+    #[must_use]
+    pub const fn session(&self) -> &S {
+        &self.session
+    }
+}
+
+impl<S> RateLimited<S> {
+    /// Reserves a time slot for the request to the chat
+    /// # Returns
+    /// Time at which the request can be sent
+    /// # Panics
+    /// If the state mutex is poisoned
+    fn reserve(&self, chat_id: &str) -> Instant {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+
+        let mut slot = now;
+        if let Some(next_global) = state.next_global {
+            slot = slot.max(next_global);
+        }
+        if let Some(next_chat) = state.next_by_chat.get(chat_id) {
+            slot = slot.max(*next_chat);
+        }
+
+        if state.next_by_chat.len() >= CHATS_PRUNE_THRESHOLD {
+            state.next_by_chat.retain(|_, next_chat| *next_chat > now);
+        }
+
+        state.next_global = Some(slot + self.global_interval);
+        state
+            .next_by_chat
+            .insert(chat_id.into(), slot + self.chat_interval);
+
+        slot
+    }
+}
+
+/// Gets `chat_id` argument of the method
+fn chat_id<T>(data: &T) -> Option<Box<str>>
+where
+    T: serde::Serialize + ?Sized,
+{
+    match serde_json::to_value(data).ok()?.get("chat_id")? {
+        serde_json::Value::Number(number) => Some(number.to_string().into()),
+        serde_json::Value::String(username) => Some(username.as_str().into()),
+        _ => None,
+    }
+}
+
+#[async_trait]
+impl<S> Session for RateLimited<S>
+where
+    S: Session,
+{
+    fn api(&self) -> &APIServer {
+        self.session.api()
+    }
+
+    #[instrument(skip(self, bot, method, timeout))]
+    async fn send_request<Client, T>(
+        &self,
+        bot: &Bot<Client>,
+        method: &T,
+        timeout: Option<f32>,
+    ) -> Result<ClientResponse, anyhow::Error>
+    where
+        Client: Session,
+        T: TelegramMethod + Send + Sync,
+        T::Method: Send + Sync,
+    {
+        let request = method.build_request(bot);
+
+        if let Some(chat_id) = chat_id(request.data) {
+            let slot = self.reserve(&chat_id);
+            let delay = slot.saturating_duration_since(Instant::now());
+
+            if !delay.is_zero() {
+                event!(
+                    Level::DEBUG,
+                    %chat_id,
+                    ?delay,
+                    method_name = request.method_name,
+                    "Request is delayed by rate limit",
+                );
+
+                time::sleep_until(slot).await;
+            }
+        }
+
+        self.session.send_request(bot, method, timeout).await
+    }
+
+    async fn close(&self) -> Result<(), anyhow::Error> {
+        self.session.close().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        methods::{GetMe, SendMessage},
+        test::{self, MockBot},
+        types::User,
+    };
+
+    const INTERVAL: Duration = Duration::from_millis(50);
+
+    fn push_message(mock: &MockBot) {
+        mock.push_result::<SendMessage>(test::sent_message("text"));
+    }
+
+    fn rate_limited(mock: &MockBot) -> Bot<RateLimited<MockBot>> {
+        Bot::with_client(
+            crate::test::TOKEN,
+            RateLimited::new(mock.clone())
+                .global_interval(Duration::ZERO)
+                .chat_interval(INTERVAL),
+        )
+    }
+
+    #[test]
+    fn test_chat_id() {
+        assert_eq!(chat_id(&SendMessage::new(1, "text")).as_deref(), Some("1"));
+        assert_eq!(
+            chat_id(&SendMessage::new("@channel", "text")).as_deref(),
+            Some("@channel")
+        );
+        assert_eq!(chat_id(&GetMe::new()), None);
+    }
+
+    #[tokio::test]
+    async fn test_chat_interval() {
+        let mock = MockBot::new();
+        let bot = rate_limited(&mock);
+
+        let count = 5;
+        for _ in 0..count {
+            push_message(&mock);
+        }
+
+        let start = Instant::now();
+        let mut sent_at = vec![];
+
+        for _ in 0..count {
+            bot.send(SendMessage::new(1, "text")).await.unwrap();
+
+            sent_at.push(Instant::now());
+        }
+
+        // Each request is sent not earlier than its slot, which is spaced by the interval from the previous one
+        for (index, sent_at) in (0..).zip(sent_at) {
+            assert!(sent_at - start >= INTERVAL * index);
+        }
+        assert_eq!(mock.calls().len(), count as usize);
+    }
+
+    #[tokio::test]
+    async fn test_different_chats() {
+        let mock = MockBot::new();
+        let bot = rate_limited(&mock);
+
+        push_message(&mock);
+        push_message(&mock);
+        mock.push_result::<GetMe>(User::default());
+        mock.push_result::<GetMe>(User::default());
+
+        let start = Instant::now();
+
+        // Requests to different chats and requests without chat aren't delayed by the chat interval
+        bot.send(SendMessage::new(1, "text")).await.unwrap();
+        bot.send(SendMessage::new(2, "text")).await.unwrap();
+        bot.send(GetMe::new()).await.unwrap();
+        bot.send(GetMe::new()).await.unwrap();
+
+        assert!(start.elapsed() < INTERVAL);
+    }
+
+    #[tokio::test]
+    async fn test_global_interval() {
+        let mock = MockBot::new();
+        let bot = Bot::with_client(
+            crate::test::TOKEN,
+            RateLimited::new(mock.clone())
+                .global_interval(INTERVAL)
+                .chat_interval(Duration::ZERO),
+        );
+
+        push_message(&mock);
+        push_message(&mock);
+
+        let start = Instant::now();
+
+        bot.send(SendMessage::new(1, "text")).await.unwrap();
+        bot.send(SendMessage::new(2, "text")).await.unwrap();
+
+        assert!(start.elapsed() >= INTERVAL);
+    }
+}
@@ -0,0 +1,294 @@
+//! This module contains [`Retrying`] struct that wraps a [`Session`] and retries requests
+//! on transient failures (network errors and server-side errors of the Telegram Bot API).
+//!
+//! # Notes
+//!
+//! Only safe methods are retried, because a retry of the method with side effects can duplicate it
+//! (for example, send the same message twice if the first response is lost).
+//! By default, safe methods are methods which names start with `get` (for example, `getChat` or `getMe`),
+//! other methods are retried only if they are added to the whitelist by [`Retrying::retry_method`] method.
+//!
+//! Errors with `429 Too Many Requests` status code aren't retried by this decorator,
+//! because they are flood control errors and not transient failures.
+//!
+//! This structure is cheap to clone, if the wrapped session is cheap to clone.
+
+use super::{
+    base::{ClientResponse, Session},
+    Reqwest,
+};
+
+use crate::{
+    client::{telegram::APIServer, Bot},
+    methods::TelegramMethod,
+};
+
+use async_trait::async_trait;
+use std::{sync::Arc, time::Duration};
+use tracing::{event, instrument, Level};
+
+/// Default maximum number of attempts to send the request, including the first one
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+/// Default delay before the first retry, next delays are doubled
+pub const DEFAULT_DELAY: Duration = Duration::from_millis(500);
+/// Default maximum delay between retries
+pub const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// Session decorator that retries safe requests on network and server-side errors with exponential backoff
+/// # Examples
+/// ```rust
+/// use std::time::Duration;
+/// use telers::{client::{session::Retrying, Reqwest}, Bot};
+///
+/// let client = Retrying::new(Reqwest::default())
+///     .max_attempts(5)
+///     .delay(Duration::from_secs(1))
+///     .retry_method("answerCallbackQuery");
+///
+/// let bot = Bot::with_client("1234567890:ABC-DEF1234ghIkl-zyx57W2v1u123ew11", client);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Retrying<S = Reqwest> {
+    session: S,
+    max_attempts: u32,
+    delay: Duration,
+    max_delay: Duration,
+    methods: Arc<[Box<str>]>,
+}
+
+impl<S> Retrying<S> {
+    /// Creates a new [`Retrying`] session with [`DEFAULT_MAX_ATTEMPTS`], [`DEFAULT_DELAY`] and [`DEFAULT_MAX_DELAY`]
+    #[must_use]
+    pub fn new(session: S) -> Self {
+        Self {
+            session,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            delay: DEFAULT_DELAY,
+            max_delay: DEFAULT_MAX_DELAY,
+            methods: Arc::new([]),
+        }
+    }
+
+    /// Maximum number of attempts to send the request, including the first one
+    #[must_use]
+    pub fn max_attempts(self, val: u32) -> Self {
+        Self {
+            max_attempts: val,
+            ..self
+        }
+    }
+
+    /// Delay before the first retry, next delays are doubled
+    #[must_use]
+    pub fn delay(self, val: Duration) -> Self {
+        Self { delay: val, ..self }
+    }
+
+    /// Maximum delay between retries, doubled delays are clamped to it
+    #[must_use]
+    pub fn max_delay(self, val: Duration) -> Self {
+        Self {
+            max_delay: val,
+            ..self
+        }
+    }
+
+    /// Adds the Telegram API method name (for example, `answerCallbackQuery`) to the whitelist of retried methods
+    #[must_use]
+    pub fn retry_method(self, val: impl Into<Box<str>>) -> Self {
+        Self {
+            methods: self
+                .methods
+                .iter()
+                .cloned()
+                .chain(Some(val.into()))
+                .collect(),
+            ..self
+        }
+    }
+
+    /// Adds the Telegram API method names to the whitelist of retried methods
+    #[must_use]
+    pub fn retry_methods<T, I>(self, val: I) -> Self
+    where
+        T: Into<Box<str>>,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            methods: self
+                .methods
+                .iter()
+                .cloned()
+                .chain(val.into_iter().map(Into::into))
+                .collect(),
+            ..self
+        }
+    }
+
+    /// Gets the wrapped session
+    #[must_use]
+    pub const fn session(&self) -> &S {
+        &self.session
+    }
+
+    /// Checks if the method can be retried
+    #[must_use]
+    pub fn is_retried(&self, method_name: &str) -> bool {
+        method_name.starts_with("get") || self.methods.iter().any(|method| **method == *method_name)
+    }
+
+    /// Gets the delay before the next retry: the doubled previous delay clamped to the maximum delay
+    fn next_delay(&self, delay: Duration) -> Duration {
+        delay.saturating_mul(2).min(self.max_delay)
+    }
+}
+
+#[async_trait]
+impl<S> Session for Retrying<S>
+where
+    S: Session,
+{
+    fn api(&self) -> &APIServer {
+        self.session.api()
+    }
+
+    #[instrument(skip(self, bot, method, timeout))]
+    async fn send_request<Client, T>(
+        &self,
+        bot: &Bot<Client>,
+        method: &T,
+        timeout: Option<f32>,
+    ) -> Result<ClientResponse, anyhow::Error>
+    where
+        Client: Session,
+        T: TelegramMethod + Send + Sync,
+        T::Method: Send + Sync,
+    {
+        let method_name = method.build_request(bot).method_name;
+
+        if !self.is_retried(method_name) {
+            return self.session.send_request(bot, method, timeout).await;
+        }
+
+        let mut delay = self.delay.min(self.max_delay);
+        let mut attempt = 1;
+
+        loop {
+            let result = self.session.send_request(bot, method, timeout).await;
+
+            let failed = match result {
+                Ok(ref response) => response.status_code.as_u16() >= 500,
+                Err(_) => true,
+            };
+
+            if !failed || attempt >= self.max_attempts {
+                return result;
+            }
+
+            event!(
+                Level::WARN,
+                method_name,
+                attempt,
+                ?delay,
+                "Request failed, retry after delay",
+            );
+
+            tokio::time::sleep(delay).await;
+
+            delay = self.next_delay(delay);
+            attempt += 1;
+        }
+    }
+
+    async fn close(&self) -> Result<(), anyhow::Error> {
+        self.session.close().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        methods::{GetChat, SendMessage},
+        test::MockBot,
+    };
+
+    fn retrying(mock: &MockBot) -> Bot<Retrying<MockBot>> {
+        Bot::with_client(
+            crate::test::TOKEN,
+            Retrying::new(mock.clone()).delay(Duration::ZERO),
+        )
+    }
+
+    #[test]
+    fn test_is_retried() {
+        let session = Retrying::new(MockBot::new()).retry_method("answerCallbackQuery");
+
+        assert!(session.is_retried("getChat"));
+        assert!(session.is_retried("answerCallbackQuery"));
+        assert!(!session.is_retried("sendMessage"));
+    }
+
+    #[test]
+    fn test_next_delay() {
+        let session = Retrying::new(MockBot::new()).max_delay(Duration::from_secs(10));
+
+        assert_eq!(
+            session.next_delay(Duration::from_secs(2)),
+            Duration::from_secs(4)
+        );
+        assert_eq!(
+            session.next_delay(Duration::from_secs(8)),
+            Duration::from_secs(10)
+        );
+        // Doesn't overflow
+        assert_eq!(session.next_delay(Duration::MAX), Duration::from_secs(10));
+    }
+
+    #[tokio::test]
+    async fn test_retry_get_chat() {
+        let mock = MockBot::new();
+        let bot = retrying(&mock);
+
+        mock.push_error::<GetChat>(502, "Bad Gateway");
+        mock.push_error::<GetChat>(500, "Internal Server Error");
+        mock.push_result::<GetChat>(serde_json::json!({
+            "id": 1,
+            "type": "private",
+            "accent_color_id": 0,
+            "max_reaction_count": 0,
+        }));
+
+        bot.send(GetChat::new(1)).await.unwrap();
+
+        assert_eq!(mock.take_calls().len(), 3);
+
+        // Attempts are exhausted
+        for _ in 0..DEFAULT_MAX_ATTEMPTS {
+            mock.push_error::<GetChat>(500, "Internal Server Error");
+        }
+
+        bot.send(GetChat::new(1)).await.unwrap_err();
+
+        assert_eq!(mock.calls().len(), DEFAULT_MAX_ATTEMPTS as usize);
+    }
+
+    #[tokio::test]
+    async fn test_not_retry_send_message() {
+        let mock = MockBot::new();
+        let bot = retrying(&mock);
+
+        mock.push_error::<SendMessage>(500, "Internal Server Error");
+
+        bot.send(SendMessage::new(1, "text")).await.unwrap_err();
+
+        assert_eq!(mock.take_calls().len(), 1);
+
+        // Client errors aren't retried
+        mock.push_error::<GetChat>(400, "Bad Request: chat not found");
+
+        bot.send(GetChat::new(1)).await.unwrap_err();
+
+        assert_eq!(mock.calls().len(), 1);
+    }
+}
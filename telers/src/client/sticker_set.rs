@@ -0,0 +1,277 @@
+//! This module contains [`Bot::create_sticker_set_with_files`] and [`Bot::add_stickers`] methods
+//! that wrap multi-step workflows of sticker set management.
+//!
+//! Stickers are validated before sending any request, so the set isn't left half-filled because of the invalid sticker.
+//! Local files of the stickers (see [`InputFile::fs`]) are uploaded in `multipart/form-data` and referenced by `attach://<id>`.
+//!
+//! # Examples
+//! ```rust
+//! use telers::{methods::CreateNewStickerSet, types::{InputFile, InputSticker}, Bot};
+//!
+//! async fn create_set(bot: Bot, user_id: i64) {
+//!     let stickers = ["first.png", "second.png"]
+//!         .map(|path| InputSticker::new(InputFile::fs(path), "static").emoji("👍"));
+//!
+//!     let _ = bot
+//!         .create_sticker_set_with_files(CreateNewStickerSet::new(
+//!             user_id,
+//!             "animals_by_bot",
+//!             "Animals",
+//!             stickers,
+//!         ))
+//!         .await;
+//! }
+//! ```
+//!
+//! [`InputFile::fs`]: crate::types::InputFile::fs
+
+use super::{session::base::Session, Bot};
+
+use crate::{
+    enums::StickerFormat,
+    errors::SessionErrorKind,
+    methods::{AddStickerToSet, CreateNewStickerSet},
+    types::{InputFile, InputSticker},
+};
+
+use std::str::FromStr as _;
+
+/// Maximum number of initial stickers in the sticker set
+pub const MAX_INITIAL_STICKERS: usize = 50;
+/// Maximum number of emoji associated with the sticker
+pub const MAX_EMOJI: usize = 20;
+/// Maximum number of search keywords of the sticker
+pub const MAX_KEYWORDS: usize = 20;
+
+/// Error that can occur when managing sticker sets
+#[derive(Debug, thiserror::Error)]
+pub enum StickerSetError {
+    #[error("Sticker set must contain 1-{MAX_INITIAL_STICKERS} initial stickers, got {0}")]
+    InvalidStickersCount(usize),
+    #[error("Sticker #{index} must have 1-{MAX_EMOJI} emoji, got {len}")]
+    InvalidEmojiList { index: usize, len: usize },
+    #[error("Sticker #{index} must have at most {MAX_KEYWORDS} keywords, got {len}")]
+    InvalidKeywords { index: usize, len: usize },
+    #[error("Sticker #{index} has unknown format `{format}`")]
+    InvalidFormat { index: usize, format: Box<str> },
+    #[error("Sticker #{index} has `{format}` format, which can't be uploaded via HTTP URL")]
+    UrlNotAllowed { index: usize, format: StickerFormat },
+    #[error(transparent)]
+    Session(#[from] SessionErrorKind),
+}
+
+/// Checks emoji list, keywords and format of the stickers
+/// # Errors
+/// If any sticker is invalid, the index of the first invalid sticker is returned in the error
+pub fn validate_stickers<'a>(
+    stickers: impl IntoIterator<Item = &'a InputSticker<'a>>,
+) -> Result<(), StickerSetError> {
+    for (index, sticker) in stickers.into_iter().enumerate() {
+        let len = sticker.emoji_list.len();
+        if len == 0 || len > MAX_EMOJI {
+            return Err(StickerSetError::InvalidEmojiList { index, len });
+        }
+
+        let len = sticker.keywords.as_ref().map_or(0, Vec::len);
+        if len > MAX_KEYWORDS {
+            return Err(StickerSetError::InvalidKeywords { index, len });
+        }
+
+        let Ok(format) = StickerFormat::from_str(&sticker.format) else {
+            return Err(StickerSetError::InvalidFormat {
+                index,
+                format: sticker.format.as_str().into(),
+            });
+        };
+
+        if format != StickerFormat::Static && matches!(sticker.sticker, InputFile::Url(_)) {
+            return Err(StickerSetError::UrlNotAllowed { index, format });
+        }
+    }
+
+    Ok(())
+}
+
+impl<Client: Session> Bot<Client> {
+    /// Validates the stickers and creates the sticker set with them, local files of the stickers are uploaded in the same request
+    /// # Errors
+    /// - If the number of stickers isn't in range 1-[`MAX_INITIAL_STICKERS`]
+    /// - If any sticker is invalid (see [`validate_stickers`])
+    /// - If the request fails
+    pub async fn create_sticker_set_with_files(
+        &self,
+        method: CreateNewStickerSet<'_>,
+    ) -> Result<(), StickerSetError> {
+        let len = method.stickers.len();
+        if len == 0 || len > MAX_INITIAL_STICKERS {
+            return Err(StickerSetError::InvalidStickersCount(len));
+        }
+
+        validate_stickers(&method.stickers)?;
+
+        self.send(method).await?;
+
+        Ok(())
+    }
+
+    /// Validates the stickers and adds them to the sticker set one by one, so they are added in the passed order
+    /// # Errors
+    /// - If any sticker is invalid (see [`validate_stickers`]), then no stickers are added
+    /// - If any request fails, then next stickers aren't added
+    pub async fn add_stickers<'a>(
+        &self,
+        user_id: i64,
+        name: &str,
+        stickers: impl IntoIterator<Item = InputSticker<'a>>,
+    ) -> Result<(), StickerSetError> {
+        let stickers = stickers.into_iter().collect::<Vec<_>>();
+
+        validate_stickers(&stickers)?;
+
+        for sticker in stickers {
+            self.send(AddStickerToSet::new(user_id, name, sticker))
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::MockBot;
+
+    #[test]
+    fn test_validate_stickers() {
+        let sticker = || InputSticker::new(InputFile::id("id"), "static").emoji("👍");
+
+        assert!(validate_stickers(&[sticker(), sticker().format("video")]).is_ok());
+        assert!(matches!(
+            validate_stickers(&[sticker(), InputSticker::new(InputFile::id("id"), "static")]),
+            Err(StickerSetError::InvalidEmojiList { index: 1, len: 0 })
+        ));
+        assert!(matches!(
+            validate_stickers(&[sticker().emojis(["👍"; MAX_EMOJI])]),
+            Err(StickerSetError::InvalidEmojiList { index: 0, .. })
+        ));
+        assert!(matches!(
+            validate_stickers(&[sticker().keywords(["cat"; MAX_KEYWORDS + 1])]),
+            Err(StickerSetError::InvalidKeywords { index: 0, .. })
+        ));
+        assert!(matches!(
+            validate_stickers(&[sticker().format("gif")]),
+            Err(StickerSetError::InvalidFormat { index: 0, .. })
+        ));
+        assert!(matches!(
+            validate_stickers(&[sticker()
+                .sticker(InputFile::url("https://example.com/sticker.tgs"))
+                .format("animated")]),
+            Err(StickerSetError::UrlNotAllowed {
+                index: 0,
+                format: StickerFormat::Animated
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_create_sticker_set_with_files() {
+        let mock = MockBot::new();
+        mock.push_result::<CreateNewStickerSet>(true);
+
+        let bot = mock.bot();
+
+        let first = InputFile::fs("first.png");
+        let second = InputFile::fs("second.webm");
+
+        bot.create_sticker_set_with_files(CreateNewStickerSet::new(
+            1,
+            "set_by_bot",
+            "Set",
+            [
+                InputSticker::new(first.clone(), "static").emoji("👍"),
+                InputSticker::new(second.clone(), "video")
+                    .emojis(["😀", "😃"])
+                    .keyword("smile"),
+            ],
+        ))
+        .await
+        .unwrap();
+
+        let calls = mock.take_calls();
+
+        assert_eq!(calls.len(), 1);
+        assert!(calls[0].is::<CreateNewStickerSet>());
+        assert_eq!(
+            &*calls[0].files,
+            [first.str_to_file().into(), second.str_to_file().into()]
+        );
+
+        let stickers = &calls[0].data["stickers"];
+
+        assert_eq!(stickers[0]["sticker"], first.str_to_file());
+        assert!(stickers[0]["sticker"]
+            .as_str()
+            .unwrap()
+            .starts_with("attach://"));
+        assert_eq!(stickers[0]["format"], "static");
+        assert_eq!(stickers[0]["emoji_list"], serde_json::json!(["👍"]));
+        assert!(stickers[0].get("keywords").is_none());
+        assert_eq!(stickers[1]["sticker"], second.str_to_file());
+        assert_eq!(stickers[1]["format"], "video");
+        assert_eq!(stickers[1]["emoji_list"], serde_json::json!(["😀", "😃"]));
+        assert_eq!(stickers[1]["keywords"], serde_json::json!(["smile"]));
+
+        // Invalid stickers aren't sent
+        let err = bot
+            .create_sticker_set_with_files(CreateNewStickerSet::new(1, "set_by_bot", "Set", []))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, StickerSetError::InvalidStickersCount(0)));
+        assert!(mock.calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_add_stickers() {
+        let mock = MockBot::new();
+        mock.push_result::<AddStickerToSet>(true);
+        mock.push_result::<AddStickerToSet>(true);
+
+        let bot = mock.bot();
+
+        bot.add_stickers(
+            1,
+            "set_by_bot",
+            [
+                InputSticker::new(InputFile::fs("first.png"), "static").emoji("👍"),
+                InputSticker::new(InputFile::id("id"), "static").emoji("👎"),
+            ],
+        )
+        .await
+        .unwrap();
+
+        let calls = mock.take_calls();
+
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].files.len(), 1);
+        assert_eq!(calls[0].data["sticker"]["emoji_list"][0], "👍");
+        assert!(calls[1].files.is_empty());
+        assert_eq!(calls[1].data["sticker"]["sticker"], "id");
+
+        let err = bot
+            .add_stickers(
+                1,
+                "set_by_bot",
+                [InputSticker::new(InputFile::id("id"), "static")],
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            StickerSetError::InvalidEmojiList { index: 0, len: 0 }
+        ));
+        assert!(mock.calls().is_empty());
+    }
+}
@@ -12,6 +12,7 @@
 use once_cell::sync::Lazy;
 use pathdiff::diff_paths;
 use std::{
+    borrow::Cow,
     fmt::Debug,
     path::{Path, PathBuf},
     sync::Arc,
@@ -104,6 +105,18 @@ impl FilesPathWrapper for FilesDiffPathWrapper {
     }
 }
 
+/// Error that can occur when validating URL templates of the [`APIServer`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum APIServerError {
+    #[error("URL `{url}` must start with `http://` or `https://`")]
+    InvalidScheme { url: Box<str> },
+    #[error("URL `{url}` must contain `{placeholder}` placeholder")]
+    MissingPlaceholder {
+        url: Box<str>,
+        placeholder: &'static str,
+    },
+}
+
 /// Configuration of Telegram Bot API server endpoints and local mode
 #[derive(Debug, Clone)]
 pub struct APIServer {
@@ -131,6 +144,18 @@ impl APIServer {
         }
     }
 
+    /// Creates configuration of the Telegram [`test environment`](https://core.telegram.org/bots/webapps#using-bots-in-the-test-environment),
+    /// where method and file URLs have `/test` segment after the token
+    #[must_use]
+    pub fn test() -> Self {
+        Self::new(
+            "https://api.telegram.org/bot{token}/test/{method_name}",
+            "https://api.telegram.org/file/bot{token}/test/{path}",
+            false,
+            BareFilesPathWrapper,
+        )
+    }
+
     /// Get base URL for API
     #[must_use]
     pub fn base_url(&self) -> &str {
@@ -155,6 +180,33 @@ impl APIServer {
         &*self.files_path_wrapper
     }
 
+    /// Checks that base URL contains `{token}` and `{method_name}` placeholders,
+    /// files URL contains `{token}` and `{path}` placeholders and both URLs use HTTP(S) scheme
+    /// # Errors
+    /// If any URL template is invalid
+    pub fn validate(&self) -> Result<(), APIServerError> {
+        for (url, placeholders) in [
+            (&self.base_url, ["{token}", "{method_name}"]),
+            (&self.files_url, ["{token}", "{path}"]),
+        ] {
+            if !url.starts_with("http://") && !url.starts_with("https://") {
+                return Err(APIServerError::InvalidScheme { url: url.clone() });
+            }
+
+            if let Some(placeholder) = placeholders
+                .into_iter()
+                .find(|placeholder| !url.contains(placeholder))
+            {
+                return Err(APIServerError::MissingPlaceholder {
+                    url: url.clone(),
+                    placeholder,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
     /// Generate URL for API method
     /// # Arguments
     /// * `token` - Bot token
@@ -180,6 +232,18 @@ impl APIServer {
     }
 }
 
+impl From<APIServer> for Cow<'_, APIServer> {
+    fn from(api: APIServer) -> Self {
+        Cow::Owned(api)
+    }
+}
+
+impl<'a> From<&'a APIServer> for Cow<'a, APIServer> {
+    fn from(api: &'a APIServer) -> Self {
+        Cow::Borrowed(api)
+    }
+}
+
 impl Default for APIServer {
     #[must_use]
     fn default() -> Self {
@@ -193,14 +257,7 @@ impl Default for APIServer {
 }
 
 pub static PRODUCTION: Lazy<APIServer> = Lazy::new(APIServer::default);
-pub static TEST: Lazy<APIServer> = Lazy::new(|| {
-    APIServer::new(
-        "https://api.telegram.org/bot{token}/test/{method_name}",
-        "https://api.telegram.org/file/bot{token}/test/{path}",
-        false,
-        BareFilesPathWrapper,
-    )
-});
+pub static TEST: Lazy<APIServer> = Lazy::new(APIServer::test);
 
 #[cfg(test)]
 mod tests {
@@ -267,6 +324,74 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_test_environment() {
+        let server = APIServer::test();
+
+        assert_eq!(
+            &*server.api_url("1234567890:ABC-DEF1234ghIkl-zyx57W2v1u123ew11", "getMe"),
+            "https://api.telegram.org/bot1234567890:ABC-DEF1234ghIkl-zyx57W2v1u123ew11/test/getMe"
+        );
+        assert_eq!(
+            &*server.file_url("1234567890:ABC-DEF1234ghIkl-zyx57W2v1u123ew11", "photos/file_0.jpg"),
+            "https://api.telegram.org/file/bot1234567890:ABC-DEF1234ghIkl-zyx57W2v1u123ew11/test/photos/file_0.jpg"
+        );
+        assert!(!server.is_local());
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(PRODUCTION.validate().is_ok());
+        assert!(TEST.validate().is_ok());
+        assert!(APIServer::new(
+            "http://localhost:8081/bot{token}/{method_name}",
+            "http://localhost:8081/file/bot{token}/{path}",
+            true,
+            BareFilesPathWrapper,
+        )
+        .validate()
+        .is_ok());
+
+        assert_eq!(
+            APIServer::new(
+                "localhost:8081/bot{token}/{method_name}",
+                "http://localhost:8081/file/bot{token}/{path}",
+                true,
+                BareFilesPathWrapper,
+            )
+            .validate(),
+            Err(APIServerError::InvalidScheme {
+                url: "localhost:8081/bot{token}/{method_name}".into()
+            })
+        );
+        assert_eq!(
+            APIServer::new(
+                "http://localhost:8081/bot{token}",
+                "http://localhost:8081/file/bot{token}/{path}",
+                true,
+                BareFilesPathWrapper,
+            )
+            .validate(),
+            Err(APIServerError::MissingPlaceholder {
+                url: "http://localhost:8081/bot{token}".into(),
+                placeholder: "{method_name}",
+            })
+        );
+        assert_eq!(
+            APIServer::new(
+                "http://localhost:8081/bot{token}/{method_name}",
+                "http://localhost:8081/file/{path}",
+                true,
+                BareFilesPathWrapper,
+            )
+            .validate(),
+            Err(APIServerError::MissingPlaceholder {
+                url: "http://localhost:8081/file/{path}".into(),
+                placeholder: "{token}",
+            })
+        );
+    }
+
     #[test]
     fn test_bare_files_path_wrapper() {
         let wrapper = BareFilesPathWrapper;
@@ -0,0 +1,211 @@
+//! This module contains helpers for configuring webhook integration of the bot:
+//! [`Bot::configure_webhook`] and [`Bot::remove_webhook`].
+//!
+//! Before the `setWebhook` request is sent, the URL and the secret token are validated,
+//! so the misconfiguration is found at startup instead of silently losing updates.
+//! Webhook URL must use HTTPS scheme, except for the [local Bot API server](https://core.telegram.org/bots/api#using-a-local-bot-api-server),
+//! which also accepts HTTP URLs.
+//!
+//! # Examples
+//! ```rust
+//! use telers::{enums::UpdateType, Bot};
+//!
+//! async fn configure(bot: Bot) {
+//!     bot.configure_webhook(
+//!         "https://example.com/webhook",
+//!         "my_secret_token",
+//!         [UpdateType::Message, UpdateType::CallbackQuery],
+//!     )
+//!     .await
+//!     .expect("Failed to configure webhook");
+//! }
+//! ```
+
+use super::{session::base::Session, Bot};
+
+use crate::{
+    enums::UpdateType,
+    errors::SessionErrorKind,
+    methods::{DeleteWebhook, SetWebhook},
+};
+
+/// Maximum length of the webhook secret token
+pub const SECRET_TOKEN_MAX_LEN: usize = 256;
+
+/// Error that can occur when configuring webhook
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookError {
+    #[error("Webhook URL `{0}` must use HTTPS scheme")]
+    InvalidUrl(Box<str>),
+    #[error(
+        "Webhook secret token must be 1-{SECRET_TOKEN_MAX_LEN} characters long \
+        and contain only `A-Z`, `a-z`, `0-9`, `_` and `-` characters"
+    )]
+    InvalidSecretToken,
+    #[error(transparent)]
+    Session(#[from] SessionErrorKind),
+}
+
+/// Checks that the webhook URL uses HTTPS scheme, or HTTP scheme if `is_local` is `true`
+/// # Errors
+/// If the URL uses another scheme
+pub fn validate_url(url: &str, is_local: bool) -> Result<(), WebhookError> {
+    if url.starts_with("https://") || (is_local && url.starts_with("http://")) {
+        Ok(())
+    } else {
+        Err(WebhookError::InvalidUrl(url.into()))
+    }
+}
+
+/// Checks that the secret token is 1-256 characters long and contains only allowed characters
+/// # Errors
+/// If the secret token is empty, too long or contains not allowed characters
+pub fn validate_secret_token(secret_token: &str) -> Result<(), WebhookError> {
+    if (1..=SECRET_TOKEN_MAX_LEN).contains(&secret_token.len())
+        && secret_token
+            .chars()
+            .all(|char| char.is_ascii_alphanumeric() || char == '_' || char == '-')
+    {
+        Ok(())
+    } else {
+        Err(WebhookError::InvalidSecretToken)
+    }
+}
+
+impl<Client: Session> Bot<Client> {
+    /// Use this method to validate and set webhook with the secret token and allowed update types.
+    /// If `allowed_updates` is empty, all update types except *chat_member* and reactions are received.
+    /// # Notes
+    /// Use [`Bot::configure_webhook_with`] to specify max connections, IP address or certificate
+    /// # Errors
+    /// - If the URL or the secret token is invalid
+    /// - If the request fails, check [`Bot::send`]
+    pub async fn configure_webhook(
+        &self,
+        url: impl Into<String>,
+        secret_token: impl Into<String>,
+        allowed_updates: impl IntoIterator<Item = UpdateType>,
+    ) -> Result<bool, WebhookError> {
+        self.configure_webhook_with(
+            SetWebhook::new(url)
+                .secret_token(secret_token)
+                .allowed_updates(allowed_updates.into_iter().map(<&'static str>::from)),
+        )
+        .await
+    }
+
+    /// Use this method to validate the URL and the secret token of the method and set webhook
+    /// # Errors
+    /// - If the URL or the secret token is invalid
+    /// - If the request fails, check [`Bot::send`]
+    pub async fn configure_webhook_with(
+        &self,
+        method: SetWebhook<'_>,
+    ) -> Result<bool, WebhookError> {
+        validate_url(&method.url, self.client().api().is_local())?;
+
+        if let Some(secret_token) = &method.secret_token {
+            validate_secret_token(secret_token)?;
+        }
+
+        self.send(method).await.map_err(Into::into)
+    }
+
+    /// Use this method to remove webhook integration
+    /// # Errors
+    /// If the request fails, check [`Bot::send`]
+    pub async fn remove_webhook(
+        &self,
+        drop_pending_updates: bool,
+    ) -> Result<bool, SessionErrorKind> {
+        self.send(DeleteWebhook::new().drop_pending_updates(drop_pending_updates))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::MockBot;
+
+    #[test]
+    fn test_validate() {
+        validate_url("https://example.com/webhook", false).unwrap();
+        validate_url("http://localhost:8080/webhook", true).unwrap();
+        validate_url("http://example.com/webhook", false).unwrap_err();
+        validate_url("example.com/webhook", true).unwrap_err();
+
+        validate_secret_token("my_secret-TOKEN_123").unwrap();
+        validate_secret_token(&"a".repeat(SECRET_TOKEN_MAX_LEN)).unwrap();
+        validate_secret_token("").unwrap_err();
+        validate_secret_token("my secret").unwrap_err();
+        validate_secret_token(&"a".repeat(SECRET_TOKEN_MAX_LEN + 1)).unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn test_configure_webhook() {
+        let mock = MockBot::new();
+        let bot = mock.bot();
+
+        mock.push_result::<SetWebhook>(true);
+        bot.configure_webhook(
+            "https://example.com/webhook",
+            "secret",
+            [UpdateType::Message, UpdateType::ChatMember],
+        )
+        .await
+        .unwrap();
+
+        mock.push_result::<SetWebhook>(true);
+        bot.configure_webhook_with(
+            SetWebhook::new("https://example.com/webhook")
+                .max_connections(10)
+                .ip_address("1.2.3.4")
+                .drop_pending_updates(true),
+        )
+        .await
+        .unwrap();
+
+        let calls = mock.take_calls();
+
+        assert_eq!(calls.len(), 2);
+        assert!(calls[0].is::<SetWebhook>());
+        assert_eq!(calls[0].data["url"], "https://example.com/webhook");
+        assert_eq!(calls[0].data["secret_token"], "secret");
+        assert_eq!(
+            calls[0].data["allowed_updates"],
+            serde_json::json!(["message", "chat_member"])
+        );
+        assert_eq!(calls[1].data["max_connections"], 10);
+        assert_eq!(calls[1].data["ip_address"], "1.2.3.4");
+        assert_eq!(calls[1].data["drop_pending_updates"], true);
+        assert!(calls[1].data.get("secret_token").is_none());
+
+        // Invalid options aren't sent
+        assert!(matches!(
+            bot.configure_webhook("http://example.com", "secret", [])
+                .await,
+            Err(WebhookError::InvalidUrl(_))
+        ));
+        assert!(matches!(
+            bot.configure_webhook("https://example.com", "my secret", [])
+                .await,
+            Err(WebhookError::InvalidSecretToken)
+        ));
+        assert!(mock.calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_remove_webhook() {
+        let mock = MockBot::new();
+        mock.push_result::<DeleteWebhook>(true);
+
+        mock.bot().remove_webhook(true).await.unwrap();
+
+        let calls = mock.calls();
+
+        assert_eq!(calls.len(), 1);
+        assert!(calls[0].is::<DeleteWebhook>());
+        assert_eq!(calls[0].data["drop_pending_updates"], true);
+    }
+}
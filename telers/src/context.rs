@@ -21,7 +21,44 @@
 //! so you don't need to pass context as parameter of handler and extract data from context manually.
 //! Check [`extractors module`] documentation for more information (**recommended**).
 //!
+//! Context lives only for the current propagation, so it isn't suitable for app-wide singletons like database pools or config.
+//! Put them to the [`SharedState`] once with [`DispatcherBuilder::data`] and extract them in handlers as [`Data<T>`] (or its alias [`Dep<T>`]).
+//! Values are registered by their types without string keys, and extraction of not registered type fails with the error naming the type:
+//!
+//! ```rust
+//! use telers::{
+//!     client::Reqwest,
+//!     context::Data,
+//!     event::{telegram::HandlerResult, EventReturn},
+//!     Dispatcher, Router,
+//! };
+//!
+//! struct Config {
+//!     admin_id: i64,
+//! }
+//!
+//! async fn handler(config: Data<Config>) -> HandlerResult {
+//!     println!("Admin id: {}", config.admin_id);
+//!
+//!     Ok(EventReturn::Finish)
+//! }
+//!
+//! let mut router = Router::<Reqwest>::new("main");
+//! router.message.register(handler);
+//!
+//! let dispatcher = Dispatcher::builder()
+//!     .main_router(router)
+//!     .data(Config { admin_id: 1 })
+//!     .build();
+//! ```
+//!
+//! When several middlewares put the data by the same key, the last one wins silently.
+//! Use [`ContextExt`] methods to compose them deterministically:
+//! [`ContextExt::insert_if_absent`] and [`ContextExt::get_or_insert_with`] keep the value of the first middleware,
+//! and [`ContextExt::insert_or_warn`] replaces the value, but logs the overwrite, which is useful for debugging.
+//!
 //! [`Dispatcher`]: crate::Dispatcher
+//! [`DispatcherBuilder::data`]: crate::dispatcher::Builder::data
 //! [`OuterMiddleware`]: crate::middlewares::OuterMiddleware
 //! [`InnerMiddleware`]: crate::middlewares::InnerMiddleware
 //! [`RouterRequest`]: crate::router::Request
@@ -35,15 +72,186 @@
 //! [`filter module`]: crate::filters
 //! [`extractors module`]: crate::extractors
 
-use dashmap::DashMap;
-use std::any::Any;
+use dashmap::{
+    mapref::{entry::Entry, one::RefMut},
+    DashMap,
+};
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    fmt::{self, Debug, Formatter},
+    ops::Deref,
+    sync::Arc,
+};
+use tracing::{event, Level};
 
 pub type Context = DashMap<&'static str, Box<dyn Any + Send + Sync>>;
 
+/// Methods of the [`Context`] for cooperative composition of middlewares
+pub trait ContextExt {
+    /// Inserts the value only if the key is absent
+    /// # Returns
+    /// `true` if the value is inserted, `false` if the key is already present and the value is dropped
+    fn insert_if_absent(&self, key: &'static str, value: Box<dyn Any + Send + Sync>) -> bool;
+
+    /// Gets the value by the key or inserts the value returned by `f` if the key is absent
+    fn get_or_insert_with<F>(
+        &self,
+        key: &'static str,
+        f: F,
+    ) -> RefMut<'_, &'static str, Box<dyn Any + Send + Sync>>
+    where
+        F: FnOnce() -> Box<dyn Any + Send + Sync>;
+
+    /// Inserts the value like [`DashMap::insert`], but logs a warning if the key is already present
+    /// # Returns
+    /// The previous value of the key
+    fn insert_or_warn(
+        &self,
+        key: &'static str,
+        value: Box<dyn Any + Send + Sync>,
+    ) -> Option<Box<dyn Any + Send + Sync>>;
+}
+
+impl ContextExt for Context {
+    fn insert_if_absent(&self, key: &'static str, value: Box<dyn Any + Send + Sync>) -> bool {
+        match self.entry(key) {
+            Entry::Occupied(_) => false,
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+                true
+            }
+        }
+    }
+
+    fn get_or_insert_with<F>(
+        &self,
+        key: &'static str,
+        f: F,
+    ) -> RefMut<'_, &'static str, Box<dyn Any + Send + Sync>>
+    where
+        F: FnOnce() -> Box<dyn Any + Send + Sync>,
+    {
+        self.entry(key).or_insert_with(f)
+    }
+
+    fn insert_or_warn(
+        &self,
+        key: &'static str,
+        value: Box<dyn Any + Send + Sync>,
+    ) -> Option<Box<dyn Any + Send + Sync>> {
+        let previous = self.insert(key, value);
+        if previous.is_some() {
+            event!(Level::WARN, key, "Value in the context is overwritten");
+        }
+        previous
+    }
+}
+
+/// Key of the [`SharedState`] in the [`Context`], the state is set by the dispatcher at the start of the propagation
+pub const SHARED_STATE_KEY: &str = "shared_state";
+
+/// App-wide values, like database pools or config, stored by their types.
+/// Unlike [`Context`], the state is created once and shared between all updates.
+#[derive(Default, Clone)]
+pub struct SharedState {
+    values: HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
+}
+
+impl SharedState {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts the value, the previous value of the same type is replaced
+    pub fn insert<T: Send + Sync + 'static>(&mut self, val: T) {
+        self.values.insert(TypeId::of::<T>(), Arc::new(val));
+    }
+
+    /// Gets the value by its type
+    #[must_use]
+    pub fn get<T: Send + Sync + 'static>(&self) -> Option<Data<T>> {
+        self.values.get(&TypeId::of::<T>()).map(|value| {
+            Data(
+                Arc::clone(value)
+                    .downcast()
+                    .expect("Value type matches its key"),
+            )
+        })
+    }
+
+    #[must_use]
+    pub fn contains<T: 'static>(&self) -> bool {
+        self.values.contains_key(&TypeId::of::<T>())
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl Debug for SharedState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedState")
+            .field("len", &self.values.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Value from the [`SharedState`], which can be used as handler argument
+#[derive(Debug)]
+pub struct Data<T: ?Sized>(Arc<T>);
+
+/// Alias of [`Data`] for handlers, whose arguments are injected dependencies
+pub type Dep<T> = Data<T>;
+
+impl<T> Data<T> {
+    #[must_use]
+    pub fn new(val: T) -> Self {
+        Self(Arc::new(val))
+    }
+}
+
+impl<T: ?Sized> Data<T> {
+    #[must_use]
+    pub fn into_inner(self) -> Arc<T> {
+        self.0
+    }
+}
+
+impl<T: ?Sized> Clone for Data<T> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<T: ?Sized> Deref for Data<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: ?Sized> From<Arc<T>> for Data<T> {
+    fn from(val: Arc<T>) -> Self {
+        Self(val)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Context;
-    use crate::filters::command::CommandObject;
+    use super::{Context, ContextExt as _, SharedState};
+    use crate::{filters::command::CommandObject, test::recorder::Recorder};
+
+    use tracing::Level;
 
     #[test]
     fn test_context() {
@@ -118,4 +326,79 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_insert_if_absent() {
+        let context = Context::new();
+
+        assert!(context.insert_if_absent("key", Box::new(1)));
+        // Present value isn't overwritten
+        assert!(!context.insert_if_absent("key", Box::new(2)));
+        assert_eq!(
+            *context.get("key").unwrap().downcast_ref::<i32>().unwrap(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_get_or_insert_with() {
+        let context = Context::new();
+
+        assert_eq!(
+            *context
+                .get_or_insert_with("key", || Box::new(1))
+                .downcast_ref::<i32>()
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            *context
+                .get_or_insert_with("key", || unreachable!("Key is present"))
+                .downcast_ref::<i32>()
+                .unwrap(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_insert_or_warn() {
+        let recorder = Recorder::default();
+        let _guard = tracing::subscriber::set_default(recorder.clone());
+
+        let context = Context::new();
+
+        assert!(context.insert_or_warn("key", Box::new(1)).is_none());
+        assert!(recorder.events().is_empty());
+
+        let previous = context.insert_or_warn("key", Box::new(2)).unwrap();
+
+        let events = recorder.events();
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].0, Level::WARN);
+        assert_eq!(events[0].1["key"], r#""key""#);
+
+        assert_eq!(*previous.downcast_ref::<i32>().unwrap(), 1);
+        assert_eq!(
+            *context.get("key").unwrap().downcast_ref::<i32>().unwrap(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_shared_state() {
+        let mut state = SharedState::new();
+
+        assert!(state.get::<i32>().is_none());
+
+        state.insert(1_i32);
+        state.insert("test".to_owned());
+        state.insert(2_i32);
+
+        assert_eq!(state.len(), 2);
+        assert!(state.contains::<String>());
+        assert!(!state.contains::<i64>());
+        assert_eq!(*state.get::<i32>().unwrap(), 2);
+        assert_eq!(*state.get::<String>().unwrap(), "test");
+    }
 }
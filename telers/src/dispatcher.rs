@@ -15,50 +15,72 @@
 //! By default, it's 30 seconds, but you can change it with [`Builder::polling_timeout`] method.
 //! Polling sends [`GetUpdates`] request to the Telegram API and will wait for `polling_timeout` seconds.
 //! If there are no updates, it will send the same request again, so often as you set it in [`Builder::backoff`] method.
+//! * `Polling limit`: maximum number of updates received by one [`GetUpdates`] request (100 by default), see [`Builder::polling_limit`].
 //! * [`ExponentialBackoff`]:
 //! Backoff used for handling server-side errors and network errors (like connection reset or telegram server is down, etc.)
 //! and set timeout between requests to telegram server.
 //! * `Allowed updates`:
-//! List the types of updates you want your bot to receive.
-//! For example, specify `message`, `edited_channel_post`, `callback_query` to only receive updates of these types.
-//! See [`UpdateType`] for a complete list of available update types.
-//! By default, all update types except [`ChatMember`] are enabled.
+//!   List the types of updates you want your bot to receive.
+//!   For example, specify `message`, `edited_channel_post`, `callback_query` to only receive updates of these types.
+//!   See [`UpdateType`] for a complete list of available update types.
+//!   By default, all update types except [`UpdateType::opt_in`] ones are enabled, so specify them explicitly (for example, with [`UpdateType::all`]) to receive them.
+//!
+//!   When the dispatcher is converted to the service, update types that have handlers in the router but aren't allowed
+//!   are logged with `WARN` level, because their updates are never received.
+//!   Use [`Builder::strict_allowed_updates`] to return [`DispatcherInitError::DisallowedUpdateTypes`] in this case instead.
+//! * `Drop pending updates`: skip updates received while the bot was down on startup of the polling, see [`Builder::drop_pending_updates`].
+//! * `Update span`: tracing span with `update_id`, `update_type`, `chat_id` and `user_id` fields around each update processing, see [`Builder::update_span`].
+//! * [`OffsetStore`]: persists the polling offset, so restarted polling resumes from the next update, see [`Builder::offset_store`] and [`offset_store module`].
 //!
 //! Dispatcher supports startup and shutdown events.
 //! You can register handlers for these observers (startup and shutdown) in the main router and handle them (see [`router module`]).
 //! When you call long polling with [`Dispatcher::run_polling`] method, it will emit main router startup event
 //! and shutdown event when polling is stopped by signal (**SIGINT** and **SIGTERM** in Unix; **CTRL-C** and **CTRL-BREAK** in Windows).
+//! After these events, bot startup and bot shutdown events are emitted for each bot,
+//! so their handlers can make requests to the Telegram API (for example, set commands of the bot).
 //! Also, you can emit these events manually with [`Dispatcher::emit_startup`] and [`Dispatcher::emit_shutdown`] methods.
 //! See [`Dispatcher::run_polling_without_startup_and_shutdown`] method if you don't need emitting these events.
 //!
 //! Use [`Dispatcher::feed_update`] and [`Dispatcher::feed_update_with_context`] methods for feeding updates to the dispatcher manually.
 //! These methods are useful for testing or if you want to use your own update source.
+//! Also, you can implement [`UpdateStream`] for your update source (updates from a file or a channel, for example)
+//! and run it with [`Dispatcher::run_update_stream`] method. By default, polling uses [`Polling`] stream.
 //! Second method allows you to pass [`Context`] with own data, which will be used in the handlers, middlewares, etc. (see [`context module`] for more information).
 //!
 //! Check out the examples directory for usage examples.
 //!
 //! [`Router`]: crate::router::Router
-//! [`UpdateType`]: crate::enums::UpdateType
-//! [`ChatMember`]: crate::enums::UpdateType::ChatMember
 //! [`router module`]: crate::router
 //! [`context module`]: crate::context
 //! [`Dispatcher::new`]: Dispatcher#method.new
 //! [`Builder::polling_timeout`]: Builder#method.polling_timeout
+//! [`Builder::polling_limit`]: Builder#method.polling_limit
 //! [`Builder::backoff`]: Builder#method.backoff
+//! [`Builder::strict_allowed_updates`]: Builder#method.strict_allowed_updates
+//! [`Builder::drop_pending_updates`]: Builder#method.drop_pending_updates
+//! [`Builder::update_span`]: Builder#method.update_span
+//! [`Builder::offset_store`]: Builder#method.offset_store
+//! [`offset_store module`]: offset_store
+//! [`DispatcherInitError::DisallowedUpdateTypes`]: crate::errors::DispatcherInitError::DisallowedUpdateTypes
 //! [`Dispatcher::run_polling`]: Service#method.run_polling
 //! [`Dispatcher::emit_startup`]: Service#method.emit_startup
 //! [`Dispatcher::emit_shutdown`]: Service#method.emit_shutdown
 //! [`Dispatcher::run_polling_without_startup_and_shutdown`]: Service#method.run_polling_without_startup_and_shutdown
 //! [`Dispatcher::feed_update`]: Service#method.feed_update
 //! [`Dispatcher::feed_update_with_context`]: Service#method.feed_update_with_context
+//! [`Dispatcher::run_update_stream`]: Service#method.run_update_stream
+
+pub mod offset_store;
+
+pub use offset_store::{FileOffsetStore, MemoryOffsetStore, OffsetStore};
 
 use super::router::{PropagateEvent, Request, Response};
 
 use crate::{
     client::{Bot, Session},
-    context::Context,
+    context::{Context, SharedState, SHARED_STATE_KEY},
     enums::UpdateType,
-    errors::EventErrorKind,
+    errors::{DispatcherInitError, EventErrorKind, ExtractionError, SessionErrorKind},
     event::{
         service::{ServiceProvider, ToServiceProvider},
         simple::HandlerResult as SimpleHandlerResult,
@@ -67,16 +89,17 @@ use crate::{
     types::Update,
 };
 
+use async_trait::async_trait;
 use backoff::{backoff::Backoff, exponential::ExponentialBackoff, SystemClock};
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 use thiserror;
 use tokio::sync::mpsc::{channel as mspc_channel, error::SendError, Sender};
-use tracing::{event, field, instrument, Level, Span};
+use tracing::{event, info_span, instrument, Instrument as _, Level, Span};
 
-const GET_UPDATES_SIZE: i64 = 100;
 const CHANNEL_UPDATES_SIZE: usize = 100;
 
 pub const DEFAULT_POLLING_TIMEOUT: i64 = 30;
+pub const DEFAULT_POLLING_LIMIT: i64 = 100;
 
 #[derive(Debug, thiserror::Error)]
 enum ListenerError<T> {
@@ -90,13 +113,265 @@ enum PollingError {
     Aborted,
 }
 
+/// Source of updates for the dispatcher.
+/// By default, the dispatcher uses [`Polling`], but you can implement this trait to receive updates
+/// from another source (for example, a file or a channel) and pass it to [`Dispatcher::run_update_stream`].
+/// # Notes
+/// Implementation for [`Vec<Update>`] yields all updates at once and then is exhausted,
+/// so it's useful for testing and replaying recorded updates.
+///
+/// [`Dispatcher::run_update_stream`]: Service#method.run_update_stream
+#[async_trait]
+pub trait UpdateStream<Client>: Send {
+    /// Receives the next batch of updates for the bot
+    /// # Returns
+    /// `None` if the stream is exhausted
+    async fn next_updates(&mut self, bot: &Bot<Client>) -> Option<Vec<Update>>;
+}
+
+#[async_trait]
+impl<Client> UpdateStream<Client> for Vec<Update>
+where
+    Client: Sync,
+{
+    async fn next_updates(&mut self, _bot: &Bot<Client>) -> Option<Vec<Update>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(std::mem::take(self))
+        }
+    }
+}
+
+/// Update stream that receives updates from the Telegram API by [`GetUpdates`] requests.
+/// Server-side errors and network errors are handled with the backoff, so the stream is never exhausted.
+pub struct Polling<BackoffType = ExponentialBackoff<SystemClock>> {
+    method: GetUpdates,
+    backoff: BackoffType,
+    drop_pending_updates: bool,
+    offset_store: Option<Arc<dyn OffsetStore>>,
+    // Flag for loading the offset from the store only before the first request
+    offset_loaded: bool,
+    // Last offset saved to (or loaded from) the store to avoid saving the same offset after each request
+    saved_offset: Option<i64>,
+    // Flag for handling connection errors.
+    // If it's `true`, we will use backoff algorithm to next backoff.
+    // If it's `false`, we will use default backoff algorithm.
+    failed: bool,
+}
+
+impl<BackoffType> Polling<BackoffType> {
+    #[must_use]
+    pub fn new(backoff: BackoffType) -> Self {
+        Self {
+            method: GetUpdates::new().limit(DEFAULT_POLLING_LIMIT),
+            backoff,
+            drop_pending_updates: false,
+            offset_store: None,
+            offset_loaded: false,
+            saved_offset: None,
+            failed: false,
+        }
+    }
+
+    /// Timeout in seconds for long polling
+    #[must_use]
+    pub fn timeout(self, val: Option<i64>) -> Self {
+        Self {
+            method: self.method.timeout_option(val),
+            ..self
+        }
+    }
+
+    /// Maximum number of updates received by one request
+    #[must_use]
+    pub fn limit(self, val: i64) -> Self {
+        Self {
+            method: self.method.limit(val),
+            ..self
+        }
+    }
+
+    /// Update types you want your bot to receive
+    #[must_use]
+    pub fn allowed_updates(self, val: impl IntoIterator<Item = UpdateType>) -> Self {
+        Self {
+            method: self.method.allowed_updates(
+                val.into_iter()
+                    .map(|update_type| update_type.as_ref().to_owned()),
+            ),
+            ..self
+        }
+    }
+
+    /// Skip updates received while the bot was down on the first request
+    #[must_use]
+    pub fn drop_pending_updates(self, val: bool) -> Self {
+        Self {
+            drop_pending_updates: val,
+            ..self
+        }
+    }
+
+    /// Store to load the offset before the first request and save it each time it's confirmed by the Telegram server.
+    /// Dropping pending updates takes precedence over the loaded offset.
+    #[must_use]
+    pub fn offset_store(self, val: Arc<dyn OffsetStore>) -> Self {
+        Self {
+            offset_store: Some(val),
+            ..self
+        }
+    }
+
+    /// Store to load the offset before the first request and save it each time it's confirmed by the Telegram server.
+    /// Dropping pending updates takes precedence over the loaded offset.
+    #[must_use]
+    pub fn offset_store_option(self, val: Option<Arc<dyn OffsetStore>>) -> Self {
+        Self {
+            offset_store: val,
+            ..self
+        }
+    }
+}
+
+impl<BackoffType> Polling<BackoffType> {
+    /// Saves the offset of the last request to the store, if it's set and isn't saved yet
+    async fn save_confirmed_offset(&mut self, bot_id: i64) {
+        let (Some(store), Some(offset)) = (&self.offset_store, self.method.offset) else {
+            return;
+        };
+        if self.saved_offset == Some(offset) {
+            return;
+        }
+
+        match store.save(bot_id, offset).await {
+            Ok(()) => self.saved_offset = Some(offset),
+            Err(err) => {
+                event!(Level::ERROR, %err, "Failed to save offset to the store");
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<Client, BackoffType> UpdateStream<Client> for Polling<BackoffType>
+where
+    Client: Session,
+    BackoffType: Backoff + Send,
+{
+    async fn next_updates(&mut self, bot: &Bot<Client>) -> Option<Vec<Update>> {
+        if !self.offset_loaded {
+            self.offset_loaded = true;
+
+            if let Some(store) = &self.offset_store {
+                match store.load(bot.bot_id).await {
+                    Ok(Some(offset)) => {
+                        event!(Level::DEBUG, offset, "Offset is loaded from the store");
+
+                        self.method.offset = Some(offset);
+                        self.saved_offset = Some(offset);
+                    }
+                    Ok(None) => {}
+                    Err(err) => {
+                        event!(Level::ERROR, %err, "Failed to load offset from the store");
+                    }
+                }
+            }
+        }
+
+        if self.drop_pending_updates {
+            self.drop_pending_updates = false;
+
+            match pending_updates_offset(bot).await {
+                Ok(offset) => {
+                    event!(Level::DEBUG, ?offset, "Pending updates are dropped");
+
+                    self.method.offset = offset;
+                }
+                Err(err) => {
+                    event!(Level::ERROR, %err, "Failed to drop pending updates");
+                }
+            }
+        }
+
+        loop {
+            event!(
+                Level::TRACE,
+                "Send `getUpdates` request to the Telegram server",
+            );
+
+            match bot.send(&self.method).await {
+                Ok(updates) => {
+                    // If we successfully connected to the server, we will reset backoff config
+                    if self.failed {
+                        event!(Level::INFO, "Connection established successfully");
+
+                        self.backoff.reset();
+                        self.failed = false;
+                    }
+
+                    // Updates before the offset of the successful request are confirmed on the server,
+                    // so they won't be re-delivered and the offset can be persisted.
+                    // The offset of the received updates isn't saved until they are confirmed by the next request,
+                    // so updates, which weren't handled before restart, are re-delivered.
+                    self.save_confirmed_offset(bot.bot_id).await;
+
+                    // Get last update id to set offset or skip updates if it's empty
+                    let Some(Update { id, .. }) = updates.last() else {
+                        event!(Level::TRACE, "No updates received");
+
+                        continue;
+                    };
+
+                    event!(
+                        Level::TRACE,
+                        updates_len = updates.len(),
+                        last_update_id = id,
+                        "Received updates from the Telegram server",
+                    );
+
+                    // The `getUpdates` method returns the earliest 100 unconfirmed updates.
+                    // To confirm an update, use the offset parameter when calling `getUpdates`.
+                    // All updates with `update_id` less than or equal to `offset` will be marked.
+                    // as confirmed on the server and will no longer be returned.
+                    // So we need to set offset to the last update `id` + 1
+                    self.method.offset = Some(id + 1);
+
+                    return Some(updates);
+                }
+                Err(err) => {
+                    event!(Level::ERROR, %err, "Failed to fetch updates");
+
+                    // If we failed to fetch updates, we will sleep for a while and try again
+                    self.failed = true;
+
+                    if let Some(duration) = self.backoff.next_backoff() {
+                        event!(
+                            Level::WARN,
+                            "Sleep for {duration:?} seconds and try again..."
+                        );
+
+                        tokio::time::sleep(duration).await;
+                    }
+                }
+            }
+        }
+    }
+}
+
 /// Dispatcher using to dispatch incoming updates to the main router
 pub struct Dispatcher<Client, Propagator, BackoffType = ExponentialBackoff<SystemClock>> {
     main_router: Propagator,
     bots: Box<[Bot<Client>]>,
     polling_timeout: Option<i64>,
+    polling_limit: i64,
     backoff: BackoffType,
     allowed_updates: Box<[UpdateType]>,
+    strict_allowed_updates: bool,
+    drop_pending_updates: bool,
+    update_span: bool,
+    offset_store: Option<Arc<dyn OffsetStore>>,
+    shared_state: SharedState,
 }
 
 impl<Client, Propagator, BackoffType> Dispatcher<Client, Propagator, BackoffType> {
@@ -138,8 +413,14 @@ impl<Client, Propagator, BackoffType> Dispatcher<Client, Propagator, BackoffType
             main_router,
             bots: bots.into_iter().collect(),
             polling_timeout,
+            polling_limit: DEFAULT_POLLING_LIMIT,
             backoff,
             allowed_updates: allowed_updates.into_iter().collect(),
+            strict_allowed_updates: false,
+            drop_pending_updates: false,
+            update_span: true,
+            offset_store: None,
+            shared_state: SharedState::default(),
         }
     }
 }
@@ -168,8 +449,14 @@ pub struct Builder<Client, Propagator, BackoffType = ExponentialBackoff<SystemCl
     main_router: Propagator,
     bots: Vec<Bot<Client>>,
     polling_timeout: Option<i64>,
+    polling_limit: i64,
     backoff: BackoffType,
     allowed_updates: Vec<UpdateType>,
+    strict_allowed_updates: bool,
+    drop_pending_updates: bool,
+    update_span: bool,
+    offset_store: Option<Arc<dyn OffsetStore>>,
+    shared_state: SharedState,
 }
 
 impl<Client, Propagator> Default for Builder<Client, Propagator>
@@ -183,8 +470,14 @@ where
             main_router: Propagator::default(),
             bots: vec![],
             polling_timeout: Some(DEFAULT_POLLING_TIMEOUT),
+            polling_limit: DEFAULT_POLLING_LIMIT,
             backoff: ExponentialBackoff::default(),
             allowed_updates: vec![],
+            strict_allowed_updates: false,
+            drop_pending_updates: false,
+            update_span: true,
+            offset_store: None,
+            shared_state: SharedState::default(),
         }
     }
 }
@@ -199,8 +492,14 @@ where
             main_router: Propagator::default(),
             bots: vec![],
             polling_timeout: Some(DEFAULT_POLLING_TIMEOUT),
+            polling_limit: DEFAULT_POLLING_LIMIT,
             backoff,
             allowed_updates: vec![],
+            strict_allowed_updates: false,
+            drop_pending_updates: false,
+            update_span: true,
+            offset_store: None,
+            shared_state: SharedState::default(),
         }
     }
 }
@@ -256,6 +555,10 @@ impl<Client, Propagator, BackoffType> Builder<Client, Propagator, BackoffType> {
     /// All bots use the same dispatcher, but each bot has the own polling process.
     /// Polling process gets updates and propagates them to the main propagator.
     /// # Notes
+    /// Handlers, middlewares and shared state (see [`Builder::data`]) are shared between the bots,
+    /// use the bot argument of the handler to know which bot received the update.
+    /// FSM storage keys contain the bot identifier, so states of the bots don't mix.
+    ///
     /// You can add sinlge bot using [`Builder::bot`] method
     #[must_use]
     pub fn bots(self, val: impl IntoIterator<Item = Bot<Client>>) -> Self {
@@ -276,6 +579,17 @@ impl<Client, Propagator, BackoffType> Builder<Client, Propagator, BackoffType> {
         }
     }
 
+    /// Maximum number of updates received by one `getUpdates` request, values between 1-100 are accepted
+    /// # Default
+    /// [`DEFAULT_POLLING_LIMIT`]
+    #[must_use]
+    pub fn polling_limit(self, val: i64) -> Self {
+        Self {
+            polling_limit: val,
+            ..self
+        }
+    }
+
     /// Backoff used for handling server-side errors and network errors (like connection reset or telegram server is down, etc.)
     /// and set timeout between requests to telegram server
     #[must_use]
@@ -311,41 +625,187 @@ impl<Client, Propagator, BackoffType> Builder<Client, Propagator, BackoffType> {
         }
     }
 
+    /// Return [`DispatcherInitError::DisallowedUpdateTypes`] instead of logging a warning if some update types have handlers,
+    /// but aren't allowed to be received, when the dispatcher is converted to the service
+    /// # Default
+    /// `false`
+    #[must_use]
+    pub fn strict_allowed_updates(self, val: bool) -> Self {
+        Self {
+            strict_allowed_updates: val,
+            ..self
+        }
+    }
+
+    /// Skip pending updates on startup of the polling.
+    /// Pending updates are updates, which were received by the Telegram server while the bot was down.
+    /// # Default
+    /// `false`
+    #[must_use]
+    pub fn drop_pending_updates(self, val: bool) -> Self {
+        Self {
+            drop_pending_updates: val,
+            ..self
+        }
+    }
+
+    /// Wrap processing of each update in the tracing span with `update_id`, `update_type`, `chat_id` and `user_id` fields,
+    /// so all logs of handlers, middlewares and filters are correlated with the update
+    /// # Default
+    /// `true`
+    #[must_use]
+    pub fn update_span(self, val: bool) -> Self {
+        Self {
+            update_span: val,
+            ..self
+        }
+    }
+
+    /// Store to persist the polling offset of each bot, so restarted polling resumes from the next update
+    /// and updates confirmed on the server aren't re-delivered. See [`offset_store module`] for more details.
+    /// # Notes
+    /// Dropping pending updates (see [`Builder::drop_pending_updates`]) takes precedence over the stored offset
+    ///
+    /// [`offset_store module`]: offset_store
+    #[must_use]
+    pub fn offset_store(self, val: impl OffsetStore + 'static) -> Self {
+        Self {
+            offset_store: Some(Arc::new(val)),
+            ..self
+        }
+    }
+
+    /// App-wide value, like database pool or config, which can be extracted in handlers as [`Data<T>`](crate::context::Data).
+    /// The value of the same type is replaced.
+    /// # Notes
+    /// The state is set to the [`Context`] by [`SHARED_STATE_KEY`] key at the start of the propagation
+    #[must_use]
+    pub fn data<T: Send + Sync + 'static>(mut self, val: T) -> Self {
+        self.shared_state.insert(val);
+        self
+    }
+
+    /// App-wide values, which can be extracted in handlers as [`Data<T>`](crate::context::Data).
+    /// Previously added values are replaced.
+    #[must_use]
+    pub fn shared_state(self, val: SharedState) -> Self {
+        Self {
+            shared_state: val,
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn build(self) -> Dispatcher<Client, Propagator, BackoffType> {
         Dispatcher {
             main_router: self.main_router,
             bots: self.bots.into(),
             polling_timeout: self.polling_timeout,
+            polling_limit: self.polling_limit,
             backoff: self.backoff,
             allowed_updates: self.allowed_updates.into_iter().collect(),
+            strict_allowed_updates: self.strict_allowed_updates,
+            drop_pending_updates: self.drop_pending_updates,
+            update_span: self.update_span,
+            offset_store: self.offset_store,
+            shared_state: self.shared_state,
         }
     }
 }
 
+/// Gets update types that have handlers, but aren't allowed to be received
+fn disallowed_update_types(
+    allowed_updates: &[UpdateType],
+    used_update_types: &HashSet<UpdateType>,
+) -> Vec<UpdateType> {
+    UpdateType::all()
+        .into_iter()
+        .filter(|update_type| used_update_types.contains(update_type))
+        .filter(|update_type| {
+            if allowed_updates.is_empty() {
+                update_type.is_opt_in()
+            } else {
+                !allowed_updates.contains(update_type)
+            }
+        })
+        .collect()
+}
+
+/// Gets offset to skip pending updates.
+/// `getUpdates` request with `-1` offset returns only the last pending update and confirms all previous ones,
+/// so the offset is the last pending update `id` + 1.
+/// # Returns
+/// Offset to skip pending updates, if there are no pending updates, then `None` will be return
+/// # Errors
+/// If the request to the Telegram API fails
+async fn pending_updates_offset<Client>(bot: &Bot<Client>) -> Result<Option<i64>, SessionErrorKind>
+where
+    Client: Session,
+{
+    let updates = bot
+        .send(GetUpdates::new().offset(-1).limit(1).timeout(0))
+        .await?;
+
+    Ok(updates.last().map(|update| update.id + 1))
+}
+
 /// This converts all dependencies to [`ServiceProvider`] and creates [`Arc<Service>`]
 /// that contains converted [`ServiceProvider`]s.
+/// # Errors
+/// - If the main router can't be converted to the service
+/// - If [`Builder::strict_allowed_updates`] is enabled and some update types have handlers, but aren't allowed to be received
 impl<Client, BackoffType, PropagatorService, Propagator, Cfg, InitError> ToServiceProvider
     for Dispatcher<Client, Propagator, BackoffType>
 where
     Client: Send + Sync + 'static,
     Propagator:
         ToServiceProvider<Config = Cfg, ServiceProvider = PropagatorService, InitError = InitError>,
+    PropagatorService: PropagateEvent<Client>,
 {
     type Config = Cfg;
     type ServiceProvider = Arc<Service<Client, PropagatorService, BackoffType>>;
-    type InitError = InitError;
+    type InitError = DispatcherInitError<InitError>;
 
     fn to_service_provider(
         self,
         config: Self::Config,
     ) -> Result<Self::ServiceProvider, Self::InitError> {
+        let main_router = self
+            .main_router
+            .to_service_provider(config)
+            .map_err(DispatcherInitError::Propagator)?;
+
+        let disallowed_update_types = disallowed_update_types(
+            &self.allowed_updates,
+            &main_router.resolve_used_update_types(),
+        );
+        if !disallowed_update_types.is_empty() {
+            if self.strict_allowed_updates {
+                return Err(DispatcherInitError::DisallowedUpdateTypes(
+                    disallowed_update_types.into(),
+                ));
+            }
+
+            for update_type in disallowed_update_types {
+                event!(
+                    Level::WARN,
+                    %update_type,
+                    "Update type has handlers, but isn't allowed to be received, so its handlers are never called",
+                );
+            }
+        }
+
         Ok(Arc::new(Service {
-            main_router: self.main_router.to_service_provider(config)?,
+            main_router,
             bots: self.bots,
             polling_timeout: self.polling_timeout,
+            polling_limit: self.polling_limit,
             backoff: self.backoff,
             allowed_updates: self.allowed_updates,
+            drop_pending_updates: self.drop_pending_updates,
+            update_span: self.update_span,
+            offset_store: self.offset_store,
+            shared_state: Arc::new(self.shared_state),
         }))
     }
 }
@@ -354,8 +814,13 @@ pub struct Service<Client, PropagatorService, BackoffType> {
     main_router: PropagatorService,
     bots: Box<[Bot<Client>]>,
     polling_timeout: Option<i64>,
+    polling_limit: i64,
     backoff: BackoffType,
     allowed_updates: Box<[UpdateType]>,
+    drop_pending_updates: bool,
+    update_span: bool,
+    offset_store: Option<Arc<dyn OffsetStore>>,
+    shared_state: Arc<SharedState>,
 }
 
 impl<Client, PropagatorService, BackoffType> ServiceProvider
@@ -364,6 +829,12 @@ impl<Client, PropagatorService, BackoffType> ServiceProvider
 }
 
 impl<Client, PropagatorService, BackoffType> Service<Client, PropagatorService, BackoffType> {
+    /// Gets bots of the dispatcher
+    #[must_use]
+    pub fn bots(&self) -> &[Bot<Client>] {
+        &self.bots
+    }
+
     /// Main entry point for incoming updates.
     /// This method will propagate update to the main router.
     #[instrument(skip(self, bot, update))]
@@ -380,12 +851,30 @@ impl<Client, PropagatorService, BackoffType> Service<Client, PropagatorService,
             .await
     }
 
+    /// Entry point for raw incoming updates, for example, from the request body of a webhook.
+    /// This method will deserialize the update and propagate it to the main router.
+    /// # Errors
+    /// - If the update can't be deserialized
+    /// - If the propagation fails, see [`Service::feed_update`] for more details
+    pub async fn feed_raw_update(
+        self: Arc<Self>,
+        bot: Arc<Bot<Client>>,
+        update: serde_json::Value,
+    ) -> Result<Response<Client>, EventErrorKind>
+    where
+        Client: Send + Sync + 'static,
+        PropagatorService: PropagateEvent<Client>,
+    {
+        // `Update` deserializer needs the borrowed input to resolve the update kind
+        let update = serde_json::from_str::<Update>(&update.to_string())
+            .map_err(|err| ExtractionError::new(format!("Failed to deserialize update: {err}")))?;
+
+        self.feed_update(bot, Arc::new(update)).await
+    }
+
     /// Main entry point for incoming updates with user context.
     /// This method will propagate update to the main router.
-    #[instrument(
-        skip(self, bot, update, context),
-        fields(bot_id, update_id, update_type)
-    )]
+    /// If [`Builder::update_span`] is enabled, the propagation is wrapped in the `update` span.
     pub async fn feed_update_with_context(
         self: Arc<Self>,
         bot: Arc<Bot<Client>>,
@@ -398,111 +887,84 @@ impl<Client, PropagatorService, BackoffType> Service<Client, PropagatorService,
     {
         let update_type = UpdateType::from(update.as_ref());
 
-        Span::current()
-            .record("bot_id", bot.bot_id)
-            .record("update_id", update.id)
-            .record("update_type", field::debug(&update_type));
+        let span = if self.update_span {
+            info_span!(
+                "update",
+                bot_id = bot.bot_id,
+                update_id = update.id,
+                update_type = ?update_type,
+                chat_id = update.chat_id(),
+                user_id = update.from_id(),
+            )
+        } else {
+            Span::none()
+        };
+
+        context
+            .entry(SHARED_STATE_KEY)
+            .or_insert_with(|| Box::new(Arc::clone(&self.shared_state)));
 
         self.main_router
             .propagate_event(update_type, Request::new(bot, update, context))
+            .instrument(span)
             .await
     }
 
-    /// Start listening updates for the bot.
+    /// Start listening updates for the bot from the stream.
     /// [`Update`] is sent to the [`Sender`] channel.
     /// # Errors
     /// If sender channel is disconnected
-    #[instrument(skip(bot, polling_timeout, allowed_updates, update_sender, backoff))]
-    async fn listen_updates(
+    #[instrument(skip(bot, stream, update_sender))]
+    async fn listen_updates<Stream>(
         bot: Arc<Bot<Client>>,
-        polling_timeout: Option<i64>,
-        allowed_updates: Box<[UpdateType]>,
+        mut stream: Stream,
         update_sender: Sender<Update>,
-        mut backoff: BackoffType,
     ) -> Result<(), ListenerError<Update>>
     where
-        Client: Session,
-        BackoffType: Backoff,
+        Stream: UpdateStream<Client>,
     {
         event!(Level::TRACE, "Start listening updates");
 
-        let mut method = GetUpdates::new()
-            .limit(GET_UPDATES_SIZE)
-            .timeout_option(polling_timeout)
-            .allowed_updates(allowed_updates.iter().map(AsRef::as_ref));
-
-        // Flag for handling connection errors.
-        // If it's `true`, we will use backoff algorithm to next backoff.
-        // If it's `false`, we will use default backoff algorithm.
-        let mut failed = false;
-
-        loop {
-            event!(
-                Level::TRACE,
-                "Send `getUpdates` request to the Telegram server",
-            );
-
-            let updates = match bot.send(&method).await {
-                Ok(updates) => {
-                    // Get last update id to set offset or skip updates if it's empty
-                    let Some(Update { id, .. }) = updates.last() else {
-                        event!(Level::TRACE, "No updates received");
-
-                        continue;
-                    };
-
-                    event!(
-                        Level::TRACE,
-                        updates_len = updates.len(),
-                        last_update_id = id,
-                        "Received updates from the Telegram server",
-                    );
-
-                    // The `getUpdates` method returns the earliest 100 unconfirmed updates.
-                    // To confirm an update, use the offset parameter when calling `getUpdates`.
-                    // All updates with `update_id` less than or equal to `offset` will be marked.
-                    // as confirmed on the server and will no longer be returned.
-                    // So we need to set offset to the last update `id` + 1
-                    // `unwrap` is safe here, because we checked that updates isn't empty
-                    method.offset = Some(id + 1);
-
-                    updates
-                }
-                Err(err) => {
-                    event!(Level::ERROR, %err, "Failed to fetch updates");
-
-                    // If we failed to fetch updates, we will sleep for a while and try again
-                    failed = true;
-
-                    if let Some(duration) = backoff.next_backoff() {
-                        event!(
-                            Level::WARN,
-                            "Sleep for {duration:?} seconds and try again..."
-                        );
-
-                        tokio::time::sleep(duration).await;
-                    }
-                    continue;
-                }
-            };
-
+        while let Some(updates) = stream.next_updates(&bot).await {
             for update in updates {
-                event!(Level::TRACE, "Send update to the listener",);
+                event!(Level::TRACE, "Send update to the listener");
 
-                // `Box` is used to avoid stack overflow, because `Update` is a big struct
                 update_sender.send(update).await?;
             }
+        }
 
-            // If we successfully connected to the server, we will reset backoff config
-            if failed {
-                event!(Level::INFO, "Connection established successfully");
+        event!(Level::TRACE, "Update stream is exhausted");
 
-                backoff.reset();
+        Ok(())
+    }
 
-                // Reset failed flag, because we successfully connected to the server and don't need to use backoff algorithm
-                failed = false;
+    /// Propagates updates of the stream to the main router one by one until the stream is exhausted.
+    /// Unlike the polling, the next update is propagated only after the previous one is handled,
+    /// so it's useful for testing and replaying recorded updates.
+    /// # Errors
+    /// If any outer middleware, inner middleware or handler returns error
+    #[instrument(skip(self, bot, stream), fields(bot_id = bot.bot_id))]
+    pub async fn run_update_stream<Stream>(
+        self: Arc<Self>,
+        bot: Bot<Client>,
+        mut stream: Stream,
+    ) -> Result<(), EventErrorKind>
+    where
+        Client: Send + Sync + 'static,
+        PropagatorService: PropagateEvent<Client>,
+        Stream: UpdateStream<Client>,
+    {
+        let bot = Arc::new(bot);
+
+        while let Some(updates) = stream.next_updates(&bot).await {
+            for update in updates {
+                Arc::clone(&self)
+                    .feed_update(Arc::clone(&bot), Arc::new(update))
+                    .await?;
             }
         }
+
+        Ok(())
     }
 
     /// Internal polling process.
@@ -521,12 +983,17 @@ impl<Client, PropagatorService, BackoffType> Service<Client, PropagatorService,
 
         let (sender_update, mut receiver_update) = mspc_channel(CHANNEL_UPDATES_SIZE);
 
+        let stream = Polling::new(self.backoff.clone())
+            .timeout(self.polling_timeout)
+            .limit(self.polling_limit)
+            .allowed_updates(self.allowed_updates.iter().copied())
+            .drop_pending_updates(self.drop_pending_updates)
+            .offset_store_option(self.offset_store.clone());
+
         let listen_updates_handle = tokio::spawn(Self::listen_updates(
             Arc::clone(&bot),
-            self.polling_timeout,
-            self.allowed_updates.clone(),
+            stream,
             sender_update,
-            self.backoff.clone(),
         ));
 
         let receiver_updates_handle = tokio::spawn(async move {
@@ -617,7 +1084,7 @@ impl<Client, PropagatorService, BackoffType> Service<Client, PropagatorService,
     {
         event!(Level::TRACE, "Start emit startup observers");
 
-        if let Err(err) = self.main_router.emit_startup().await {
+        if let Err(err) = self.emit_startup().await {
             event!(Level::ERROR, error = %err, "Error while emit startup");
 
             return Err(err.into());
@@ -675,34 +1142,48 @@ impl<Client, PropagatorService, BackoffType> Service<Client, PropagatorService,
         }
     }
 
-    /// Emit startup events.
+    /// Emit startup events and then bot startup events for each bot.
     /// Use this method if you want to emit startup events manually
     /// # Notes
     /// This method is called automatically in `run_polling` method,
     /// but not in `run_polling_without_startup_and_shutdown` method
     /// # Errors
-    /// If any startup observer returns error
+    /// If any startup or bot startup observer returns error
     #[instrument(skip(self))]
     pub async fn emit_startup(&self) -> SimpleHandlerResult
     where
+        Client: Clone + Send + Sync + 'static,
         PropagatorService: PropagateEvent<Client>,
     {
-        self.main_router.emit_startup().await
+        self.main_router.emit_startup().await?;
+
+        for bot in &*self.bots {
+            self.main_router.emit_bot_startup(bot).await?;
+        }
+
+        Ok(())
     }
 
-    /// Emit shutdown events.
+    /// Emit shutdown events and then bot shutdown events for each bot.
     /// Use this method if you want to emit shutdown events manually
     /// # Notes
     /// This method is called automatically in `run_polling` method,
     /// but not in `run_polling_without_startup_and_shutdown` method
     /// # Errors
-    /// If any shutdown observer returns error
+    /// If any shutdown or bot shutdown observer returns error
     #[instrument(skip(self))]
     pub async fn emit_shutdown(&self) -> SimpleHandlerResult
     where
+        Client: Clone + Send + Sync + 'static,
         PropagatorService: PropagateEvent<Client>,
     {
-        self.main_router.emit_shutdown().await
+        self.main_router.emit_shutdown().await?;
+
+        for bot in &*self.bots {
+            self.main_router.emit_bot_shutdown(bot).await?;
+        }
+
+        Ok(())
     }
 }
 
@@ -711,12 +1192,56 @@ mod tests {
     use super::*;
     use crate::{
         client::Reqwest,
-        event::bases::{EventReturn, PropagateEventResult},
+        event::{
+            bases::{EventReturn, PropagateEventResult},
+            telegram::HandlerResult,
+        },
         router::Router,
     };
 
     use tokio;
 
+    #[tokio::test]
+    async fn test_emit_bot_startup() {
+        use crate::{
+            methods::SetMyCommands,
+            test::MockBot,
+            types::{BotCommand, BotCommandScopeAllPrivateChats},
+        };
+
+        async fn on_startup(bot: Bot<MockBot>) -> SimpleHandlerResult {
+            bot.send(
+                SetMyCommands::new([BotCommand::new("start", "Start the bot")])
+                    .scope(BotCommandScopeAllPrivateChats::new()),
+            )
+            .await?;
+
+            Ok(())
+        }
+
+        let mock = MockBot::new();
+        mock.push_result::<SetMyCommands>(true);
+
+        let mut router = Router::new("main");
+        router.bot_startup.register(on_startup);
+
+        let dispatcher = Dispatcher::builder()
+            .main_router(router)
+            .bot(mock.bot())
+            .build()
+            .to_service_provider_default()
+            .unwrap();
+
+        dispatcher.emit_startup().await.unwrap();
+        dispatcher.emit_shutdown().await.unwrap();
+
+        let calls = mock.calls();
+
+        assert_eq!(calls.len(), 1);
+        assert!(calls[0].is::<SetMyCommands>());
+        assert_eq!(calls[0].data["scope"]["type"], "all_private_chats");
+    }
+
     #[tokio::test]
     async fn test_feed_update() {
         let bot = Arc::new(Bot::<Reqwest>::default());
@@ -763,6 +1288,54 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_feed_raw_update() {
+        use crate::types::Message;
+
+        let bot = Arc::new(Bot::<Reqwest>::default());
+
+        let mut router = Router::new("main");
+        router.message.register(|message: Message| async move {
+            assert_eq!(message.text(), Some("text"));
+
+            Ok(EventReturn::Finish)
+        });
+
+        let dispatcher = Dispatcher::builder()
+            .main_router(router)
+            .build()
+            .to_service_provider_default()
+            .unwrap();
+
+        let response = Arc::clone(&dispatcher)
+            .feed_raw_update(
+                Arc::clone(&bot),
+                serde_json::json!({
+                    "update_id": 1,
+                    "message": {
+                        "message_id": 1,
+                        "date": 0,
+                        "chat": {"id": 1, "type": "private"},
+                        "text": "text",
+                    },
+                }),
+            )
+            .await
+            .unwrap();
+
+        match response.propagate_result {
+            PropagateEventResult::Handled(_) => {}
+            _ => panic!("Unexpected result"),
+        }
+
+        assert!(matches!(
+            dispatcher
+                .feed_raw_update(bot, serde_json::json!({"message": {}}))
+                .await,
+            Err(EventErrorKind::Extraction(_))
+        ));
+    }
+
     #[test]
     fn test_builder() {
         let bot = Bot::<Reqwest>::default();
@@ -772,12 +1345,524 @@ mod tests {
             .bot(bot.clone())
             .bots([bot])
             .polling_timeout(123)
+            .polling_limit(50)
             .allowed_update(UpdateType::Message)
             .allowed_updates([UpdateType::InlineQuery, UpdateType::ChosenInlineResult])
+            .drop_pending_updates(true)
             .build();
 
         assert_eq!(dispatcher.bots.len(), 2);
         assert_eq!(dispatcher.polling_timeout, Some(123));
+        assert_eq!(dispatcher.polling_limit, 50);
         assert_eq!(dispatcher.allowed_updates.len(), 3);
+        assert!(dispatcher.drop_pending_updates);
+        assert!(dispatcher.update_span);
+    }
+
+    #[test]
+    fn test_disallowed_update_types() {
+        let mut router = Router::<Reqwest>::new("main");
+        router
+            .message
+            .register(|| async { Ok(EventReturn::Finish) });
+        router
+            .callback_query
+            .register(|| async { Ok(EventReturn::Finish) });
+
+        let used_update_types = router
+            .to_service_provider_default()
+            .unwrap()
+            .resolve_used_update_types();
+
+        assert_eq!(
+            disallowed_update_types(&[UpdateType::Message], &used_update_types),
+            [UpdateType::CallbackQuery]
+        );
+        assert!(disallowed_update_types(
+            &[UpdateType::Message, UpdateType::CallbackQuery],
+            &used_update_types
+        )
+        .is_empty());
+        // All update types except some of them are allowed by default
+        assert!(disallowed_update_types(&[], &used_update_types).is_empty());
+        assert_eq!(
+            disallowed_update_types(&[], &HashSet::from([UpdateType::ChatMember])),
+            [UpdateType::ChatMember]
+        );
+    }
+
+    #[test]
+    fn test_strict_allowed_updates() {
+        let mut router = Router::<Reqwest>::new("main");
+        router
+            .callback_query
+            .register(|| async { Ok(EventReturn::Finish) });
+
+        let result = Dispatcher::builder()
+            .main_router(router)
+            .allowed_update(UpdateType::Message)
+            .strict_allowed_updates(true)
+            .build()
+            .to_service_provider_default();
+
+        assert!(matches!(
+            result,
+            Err(DispatcherInitError::DisallowedUpdateTypes(update_types))
+                if *update_types == [UpdateType::CallbackQuery]
+        ));
+    }
+
+    #[test]
+    fn test_disallowed_update_types_warning() {
+        use crate::test::recorder::Recorder;
+
+        let mut router = Router::<Reqwest>::new("main");
+        router
+            .callback_query
+            .register(|| async { Ok(EventReturn::Finish) });
+
+        let recorder = Recorder::default();
+        let _guard = tracing::subscriber::set_default(recorder.clone());
+
+        let result = Dispatcher::builder()
+            .main_router(router)
+            .allowed_update(UpdateType::Message)
+            .build()
+            .to_service_provider_default();
+
+        assert!(result.is_ok());
+
+        let warnings = recorder
+            .events()
+            .into_iter()
+            .filter(|(level, _)| *level == Level::WARN)
+            .collect::<Vec<_>>();
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].1["update_type"],
+            UpdateType::CallbackQuery.to_string()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pending_updates_offset() {
+        use crate::test::MockBot;
+
+        let mock = MockBot::new();
+        mock.push_result::<GetUpdates>(serde_json::json!([{
+            "update_id": 10,
+            "message": {"message_id": 1, "date": 0, "chat": {"id": 1, "type": "private"}, "text": "text"},
+        }]));
+        mock.push_result::<GetUpdates>(serde_json::json!([]));
+
+        let bot = mock.bot();
+
+        // Offset skips the last pending update and all previous ones
+        assert_eq!(pending_updates_offset(&bot).await.unwrap(), Some(11));
+        // No pending updates
+        assert_eq!(pending_updates_offset(&bot).await.unwrap(), None);
+
+        let calls = mock.calls();
+
+        assert!(calls[0].is::<GetUpdates>());
+        assert_eq!(calls[0].data["offset"], -1);
+        assert_eq!(calls[0].data["limit"], 1);
+        assert_eq!(calls[0].data["timeout"], 0);
+    }
+
+    #[tokio::test]
+    async fn test_polling_offset_store() {
+        use crate::test::MockBot;
+
+        let mock = MockBot::new();
+        mock.push_result::<GetUpdates>(serde_json::json!([{
+            "update_id": 42,
+            "message": {"message_id": 1, "date": 0, "chat": {"id": 1, "type": "private"}, "text": "text"},
+        }]));
+        mock.push_result::<GetUpdates>(serde_json::json!([{
+            "update_id": 43,
+            "message": {"message_id": 2, "date": 0, "chat": {"id": 1, "type": "private"}, "text": "text"},
+        }]));
+
+        let bot = mock.bot();
+
+        let store = Arc::new(MemoryOffsetStore::new());
+        store.save(bot.bot_id, 42).await.unwrap();
+
+        // Restarted polling resumes from the stored offset
+        let mut stream = Polling::new(ExponentialBackoff::<SystemClock>::default())
+            .offset_store(Arc::clone(&store) as _);
+
+        // Received updates aren't confirmed yet, so the offset isn't moved
+        assert_eq!(stream.next_updates(&bot).await.unwrap()[0].id, 42);
+        assert_eq!(store.load(bot.bot_id).await.unwrap(), Some(42));
+
+        // The next request confirms the previous updates
+        assert_eq!(stream.next_updates(&bot).await.unwrap()[0].id, 43);
+        assert_eq!(store.load(bot.bot_id).await.unwrap(), Some(43));
+
+        let calls = mock.calls();
+
+        assert_eq!(calls[0].data["offset"], 42);
+        assert_eq!(calls[1].data["offset"], 43);
+    }
+
+    #[tokio::test]
+    async fn test_polling_offset_store_restart() {
+        use crate::test::MockBot;
+
+        let mock = MockBot::new();
+        mock.push_result::<GetUpdates>(serde_json::json!([{
+            "update_id": 42,
+            "message": {"message_id": 1, "date": 0, "chat": {"id": 1, "type": "private"}, "text": "text"},
+        }]));
+        mock.push_result::<GetUpdates>(serde_json::json!([{
+            "update_id": 42,
+            "message": {"message_id": 1, "date": 0, "chat": {"id": 1, "type": "private"}, "text": "text"},
+        }]));
+
+        let bot = mock.bot();
+        let store = Arc::new(MemoryOffsetStore::new());
+
+        let mut stream = Polling::new(ExponentialBackoff::<SystemClock>::default())
+            .offset_store(Arc::clone(&store) as _);
+
+        // The batch is received, but the bot crashes before handling it
+        assert_eq!(stream.next_updates(&bot).await.unwrap()[0].id, 42);
+        drop(stream);
+
+        // Restarted polling doesn't skip the unhandled batch
+        let mut stream = Polling::new(ExponentialBackoff::<SystemClock>::default())
+            .offset_store(Arc::clone(&store) as _);
+
+        assert_eq!(stream.next_updates(&bot).await.unwrap()[0].id, 42);
+
+        let calls = mock.calls();
+
+        assert!(calls[1].data.get("offset").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_listen_updates_polling_options() {
+        use crate::test::MockBot;
+
+        let mock = MockBot::new();
+        mock.push_result::<GetUpdates>(serde_json::json!([{
+            "update_id": 10,
+            "message": {"message_id": 1, "date": 0, "chat": {"id": 1, "type": "private"}, "text": "text"},
+        }]));
+
+        let (sender, mut receiver) = mspc_channel(1);
+
+        let stream = Polling::new(ExponentialBackoff::<SystemClock>::default())
+            .timeout(Some(10))
+            .limit(50)
+            .allowed_updates([UpdateType::Message]);
+
+        let handle = tokio::spawn(
+            Service::<MockBot, (), ExponentialBackoff<SystemClock>>::listen_updates(
+                Arc::new(mock.bot()),
+                stream,
+                sender,
+            ),
+        );
+
+        assert_eq!(receiver.recv().await.unwrap().id, 10);
+
+        handle.abort();
+
+        let calls = mock.calls();
+
+        assert!(calls[0].is::<GetUpdates>());
+        assert_eq!(calls[0].data["timeout"], 10);
+        assert_eq!(calls[0].data["limit"], 50);
+        assert_eq!(
+            calls[0].data["allowed_updates"],
+            serde_json::json!(["message"])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_multiple_bots() {
+        use crate::{
+            context::Data,
+            methods::SendMessage,
+            test::{self, MockBot},
+            types::MessageText,
+        };
+
+        use std::sync::Mutex;
+
+        type Received = Mutex<Vec<(i64, Box<str>)>>;
+
+        async fn on_message(
+            bot: Bot<MockBot>,
+            message: MessageText,
+            received: Data<Received>,
+        ) -> HandlerResult {
+            received
+                .lock()
+                .unwrap()
+                .push((bot.bot_id, message.text.clone()));
+
+            bot.send(SendMessage::new(test::CHAT_ID, message.text))
+                .await?;
+
+            Ok(EventReturn::Finish)
+        }
+
+        let result = test::sent_message("text");
+
+        let first = MockBot::new();
+        first.push_result::<SendMessage>(&result);
+        let second = MockBot::new();
+        second.push_result::<SendMessage>(&result);
+        second.push_result::<SendMessage>(&result);
+
+        let mut router = Router::new("main");
+        router.message.register(on_message);
+
+        let dispatcher = Dispatcher::builder()
+            .main_router(router)
+            .bots([
+                first.bot_with_token("1:first"),
+                second.bot_with_token("2:second"),
+            ])
+            .data(Received::default())
+            .build()
+            .to_service_provider_default()
+            .unwrap();
+
+        let [first_bot, second_bot] = dispatcher.bots() else {
+            panic!("Unexpected number of bots");
+        };
+
+        let (first_result, second_result) = tokio::join!(
+            Arc::clone(&dispatcher)
+                .run_update_stream(first_bot.clone(), vec![test::message("first")]),
+            Arc::clone(&dispatcher).run_update_stream(
+                second_bot.clone(),
+                vec![test::message("second"), test::message("third")]
+            ),
+        );
+        first_result.unwrap();
+        second_result.unwrap();
+
+        // Each bot sends requests with own session
+        let first_calls = first.calls();
+        assert_eq!(first_calls.len(), 1);
+        assert_eq!(first_calls[0].data["text"], "first");
+
+        let second_calls = second.calls();
+        assert_eq!(second_calls.len(), 2);
+        assert_eq!(second_calls[0].data["text"], "second");
+        assert_eq!(second_calls[1].data["text"], "third");
+
+        // Shared state is the same for all bots
+        let mut received = dispatcher
+            .shared_state
+            .get::<Received>()
+            .unwrap()
+            .lock()
+            .unwrap()
+            .clone();
+        received.sort();
+
+        assert_eq!(
+            received,
+            [
+                (1, "first".into()),
+                (2, "second".into()),
+                (2, "third".into())
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_update_stream() {
+        use crate::{
+            methods::{AnswerCallbackQuery, SendMessage},
+            test::{self, MockBot},
+            types::{CallbackQuery, MessageText},
+        };
+
+        async fn on_message(bot: Bot<MockBot>, message: MessageText) -> HandlerResult {
+            bot.send(SendMessage::new(test::CHAT_ID, message.text))
+                .await?;
+
+            Ok(EventReturn::Finish)
+        }
+
+        async fn on_callback_query(bot: Bot<MockBot>, query: CallbackQuery) -> HandlerResult {
+            bot.send(AnswerCallbackQuery::new(query.id)).await?;
+
+            Ok(EventReturn::Finish)
+        }
+
+        let mock = MockBot::new();
+        mock.push_result::<SendMessage>(test::sent_message("hello"));
+        mock.push_result::<AnswerCallbackQuery>(true);
+
+        let mut router = Router::new("main");
+        router.message.register(on_message);
+        router.callback_query.register(on_callback_query);
+
+        let dispatcher = Dispatcher::builder()
+            .main_router(router)
+            .build()
+            .to_service_provider_default()
+            .unwrap();
+
+        dispatcher
+            .run_update_stream(
+                mock.bot(),
+                vec![test::message("hello"), test::callback_query("data")],
+            )
+            .await
+            .unwrap();
+
+        let calls = mock.calls();
+
+        assert_eq!(calls.len(), 2);
+        assert!(calls[0].is::<SendMessage>());
+        assert_eq!(calls[0].data["text"], "hello");
+        assert!(calls[1].is::<AnswerCallbackQuery>());
+    }
+
+    #[tokio::test]
+    async fn test_shared_state() {
+        use crate::{
+            context::{Data, SharedState},
+            test::{self, MockBot},
+            types::MessageText,
+        };
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        struct Config {
+            greeting: &'static str,
+        }
+
+        async fn on_message(
+            config: Data<Config>,
+            counter: Data<AtomicUsize>,
+            message: MessageText,
+        ) -> HandlerResult {
+            assert_eq!(config.greeting, "hello");
+            assert_eq!(&*message.text, "hello");
+
+            counter.fetch_add(1, Ordering::SeqCst);
+
+            Ok(EventReturn::Finish)
+        }
+
+        async fn on_callback_query(_: Data<i64>) -> HandlerResult {
+            Ok(EventReturn::Finish)
+        }
+
+        let mut state = SharedState::new();
+        state.insert(AtomicUsize::new(0));
+
+        let mut router = Router::new("main");
+        router.message.register(on_message);
+        router.callback_query.register(on_callback_query);
+
+        let dispatcher = Dispatcher::builder()
+            .main_router(router)
+            .shared_state(state.clone())
+            .data(Config { greeting: "hello" })
+            .build()
+            .to_service_provider_default()
+            .unwrap();
+
+        let bot = Arc::new(MockBot::new().bot());
+
+        for _ in 0..2 {
+            Arc::clone(&dispatcher)
+                .feed_update(Arc::clone(&bot), Arc::new(test::message("hello")))
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(
+            state.get::<AtomicUsize>().unwrap().load(Ordering::SeqCst),
+            2
+        );
+
+        // Not registered data can't be extracted
+        assert!(dispatcher
+            .feed_update(bot, Arc::new(test::callback_query("data")))
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_update_span() {
+        use crate::test::{self, MockBot};
+        use std::sync::Mutex;
+        use tracing::{
+            field::{Field, Visit},
+            span::{Attributes, Id, Record},
+            subscriber, Event, Metadata, Subscriber,
+        };
+
+        /// Subscriber that collects fields of the `update` spans
+        #[derive(Default)]
+        struct Spans(Arc<Mutex<Vec<String>>>);
+
+        impl Visit for Spans {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                self.0.lock().unwrap().push(format!("{field}={value:?}"));
+            }
+        }
+
+        impl Subscriber for Spans {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                if span.metadata().name() == "update" {
+                    span.record(&mut Spans(Arc::clone(&self.0)));
+                }
+                Id::from_u64(1)
+            }
+
+            fn record(&self, _: &Id, _: &Record<'_>) {}
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+            fn event(&self, _: &Event<'_>) {}
+            fn enter(&self, _: &Id) {}
+            fn exit(&self, _: &Id) {}
+        }
+
+        let fields = Arc::new(Mutex::new(vec![]));
+        let _guard = subscriber::set_default(Spans(Arc::clone(&fields)));
+
+        let mock = MockBot::new();
+
+        for update_span in [false, true] {
+            let dispatcher = Dispatcher::builder()
+                .main_router(Router::new("main"))
+                .update_span(update_span)
+                .build()
+                .to_service_provider_default()
+                .unwrap();
+
+            dispatcher
+                .feed_update(Arc::new(mock.bot()), Arc::new(test::message("text")))
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(
+            *fields.lock().unwrap(),
+            [
+                format!("bot_id={}", mock.bot().bot_id),
+                "update_id=0".to_owned(),
+                "update_type=Message".to_owned(),
+                format!("chat_id={}", test::CHAT_ID),
+                format!("user_id={}", test::USER_ID),
+            ]
+        );
     }
 }
@@ -0,0 +1,135 @@
+//! This module contains [`OffsetStore`] trait and its implementations to persist the polling offset across restarts.
+//!
+//! [`Polling`] loads the offset of the bot from the store before the first `getUpdates` request
+//! and saves the offset each time it's confirmed by the Telegram server (by the next `getUpdates` request),
+//! so restarted polling resumes from the first unconfirmed update.
+//! Updates received, but not confirmed before restart (for example, if the bot crashed while handling them), are re-delivered.
+//!
+//! Ready-made implementations:
+//! * [`FileOffsetStore`]: keeps the offset of each bot in the separate file in the directory.
+//! * [`MemoryOffsetStore`]: keeps offsets in memory, so they are lost after restart. Useful for testing.
+//!
+//! [`Polling`]: super::Polling
+
+use async_trait::async_trait;
+use dashmap::DashMap;
+use std::{
+    io::{Error, ErrorKind},
+    path::PathBuf,
+};
+
+/// Storage of the polling offset, which is the identifier of the next update to receive
+#[async_trait]
+pub trait OffsetStore: Send + Sync {
+    /// Load the offset of the bot
+    /// # Returns
+    /// `None` if the offset isn't saved yet
+    /// # Errors
+    /// If the offset can't be loaded
+    async fn load(&self, bot_id: i64) -> Result<Option<i64>, Error>;
+
+    /// Save the offset of the bot
+    /// # Errors
+    /// If the offset can't be saved
+    async fn save(&self, bot_id: i64, offset: i64) -> Result<(), Error>;
+}
+
+/// Keeps offsets in memory
+#[derive(Debug, Default)]
+pub struct MemoryOffsetStore {
+    offsets: DashMap<i64, i64>,
+}
+
+impl MemoryOffsetStore {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl OffsetStore for MemoryOffsetStore {
+    async fn load(&self, bot_id: i64) -> Result<Option<i64>, Error> {
+        Ok(self.offsets.get(&bot_id).map(|offset| *offset))
+    }
+
+    async fn save(&self, bot_id: i64, offset: i64) -> Result<(), Error> {
+        self.offsets.insert(bot_id, offset);
+        Ok(())
+    }
+}
+
+/// Keeps the offset of each bot in the `{bot_id}.offset` file in the directory.
+/// The directory is created on the first save if it doesn't exist.
+#[derive(Debug, Clone)]
+pub struct FileOffsetStore {
+    dir: PathBuf,
+}
+
+impl FileOffsetStore {
+    #[must_use]
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn path(&self, bot_id: i64) -> PathBuf {
+        self.dir.join(format!("{bot_id}.offset"))
+    }
+}
+
+#[async_trait]
+impl OffsetStore for FileOffsetStore {
+    async fn load(&self, bot_id: i64) -> Result<Option<i64>, Error> {
+        let content = match tokio::fs::read_to_string(self.path(bot_id)).await {
+            Ok(content) => content,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err),
+        };
+
+        content
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(|err| Error::new(ErrorKind::InvalidData, err))
+    }
+
+    async fn save(&self, bot_id: i64, offset: i64) -> Result<(), Error> {
+        let path = self.path(bot_id);
+        // Write to the temporary file first, so the offset isn't corrupted if the process is killed during writing
+        let temp_path = path.with_extension("offset.tmp");
+
+        tokio::fs::create_dir_all(&self.dir).await?;
+        tokio::fs::write(&temp_path, offset.to_string()).await?;
+        tokio::fs::rename(temp_path, path).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_file_offset_store() {
+        let dir = std::env::temp_dir().join(format!("telers-offset-{}", uuid::Uuid::new_v4()));
+        let store = FileOffsetStore::new(&dir);
+
+        assert_eq!(store.load(1).await.unwrap(), None);
+
+        store.save(1, 10).await.unwrap();
+        store.save(1, 11).await.unwrap();
+        store.save(2, 20).await.unwrap();
+
+        assert_eq!(store.load(1).await.unwrap(), Some(11));
+        assert_eq!(store.load(2).await.unwrap(), Some(20));
+
+        tokio::fs::write(dir.join("3.offset"), "invalid")
+            .await
+            .unwrap();
+        assert_eq!(
+            store.load(3).await.unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+
+        tokio::fs::remove_dir_all(dir).await.unwrap();
+    }
+}
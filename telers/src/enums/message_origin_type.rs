@@ -0,0 +1,59 @@
+use strum_macros::{AsRefStr, Display, EnumString, IntoStaticStr};
+
+use crate::types::MessageOrigin;
+
+/// This enum represents all possible types of the message origin
+/// # Documentation
+/// <https://core.telegram.org/bots/api#messageorigin>
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash, EnumString, AsRefStr, IntoStaticStr)]
+pub enum MessageOriginType {
+    #[strum(serialize = "user")]
+    User,
+    #[strum(serialize = "hidden_user")]
+    HiddenUser,
+    #[strum(serialize = "chat")]
+    Chat,
+    #[strum(serialize = "channel")]
+    Channel,
+}
+
+impl MessageOriginType {
+    #[must_use]
+    pub const fn all() -> [MessageOriginType; 4] {
+        [
+            MessageOriginType::User,
+            MessageOriginType::HiddenUser,
+            MessageOriginType::Chat,
+            MessageOriginType::Channel,
+        ]
+    }
+}
+
+impl From<MessageOriginType> for Box<str> {
+    fn from(origin_type: MessageOriginType) -> Self {
+        Into::<&'static str>::into(origin_type).into()
+    }
+}
+
+impl From<MessageOriginType> for String {
+    fn from(origin_type: MessageOriginType) -> Self {
+        origin_type.as_ref().to_owned()
+    }
+}
+
+impl<'a> PartialEq<&'a str> for MessageOriginType {
+    fn eq(&self, other: &&'a str) -> bool {
+        self.as_ref() == *other
+    }
+}
+
+impl<'a> From<&'a MessageOrigin> for MessageOriginType {
+    fn from(origin: &'a MessageOrigin) -> Self {
+        match origin {
+            MessageOrigin::User(_) => MessageOriginType::User,
+            MessageOrigin::HiddenUser(_) => MessageOriginType::HiddenUser,
+            MessageOrigin::Chat(_) => MessageOriginType::Chat,
+            MessageOrigin::Channel(_) => MessageOriginType::Channel,
+        }
+    }
+}
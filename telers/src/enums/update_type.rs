@@ -54,6 +54,8 @@ pub enum UpdateType {
 }
 
 impl UpdateType {
+    /// All update types, including the ones which require explicit opt-in (see [`UpdateType::opt_in`]).
+    /// Pass it to `allowed_updates` to receive every update type.
     #[must_use]
     pub const fn all() -> [Self; 22] {
         [
@@ -81,6 +83,33 @@ impl UpdateType {
             UpdateType::RemovedChatBoost,
         ]
     }
+
+    /// Update types, which Telegram sends only if they're explicitly specified in `allowed_updates`.
+    /// If `allowed_updates` isn't specified, handlers of these types are never called.
+    #[must_use]
+    pub const fn opt_in() -> [Self; 3] {
+        [
+            UpdateType::ChatMember,
+            UpdateType::MessageReaction,
+            UpdateType::MessageReactionCount,
+        ]
+    }
+
+    /// Update types, which Telegram sends if `allowed_updates` isn't specified,
+    /// so all update types except [`UpdateType::opt_in`]
+    #[must_use]
+    pub fn all_default() -> Vec<Self> {
+        Self::all()
+            .into_iter()
+            .filter(|update_type| !update_type.is_opt_in())
+            .collect()
+    }
+
+    /// Checks if the update type requires explicit opt-in in `allowed_updates` (see [`UpdateType::opt_in`])
+    #[must_use]
+    pub fn is_opt_in(self) -> bool {
+        Self::opt_in().contains(&self)
+    }
 }
 
 impl<'a> From<&'a UpdateKind> for UpdateType {
@@ -117,3 +146,26 @@ impl<'a> From<&'a Update> for UpdateType {
         UpdateType::from(update.kind())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_opt_in() {
+        assert!(UpdateType::all().contains(&UpdateType::ChatMember));
+        assert!(UpdateType::opt_in().contains(&UpdateType::ChatMember));
+        assert!(UpdateType::ChatMember.is_opt_in());
+        assert!(!UpdateType::MyChatMember.is_opt_in());
+        assert!(UpdateType::opt_in().into_iter().all(UpdateType::is_opt_in));
+
+        let all_default = UpdateType::all_default();
+
+        assert!(!all_default.contains(&UpdateType::ChatMember));
+        assert!(all_default.contains(&UpdateType::MyChatMember));
+        assert_eq!(
+            all_default.len() + UpdateType::opt_in().len(),
+            UpdateType::all().len()
+        );
+    }
+}
@@ -4,24 +4,30 @@
 //! - [`HandlerError`]
 //! - [`ExtractionError`]
 //! - [`SessionErrorKind`]
-//! - [`TelegramErrorKind`]
+//! - [`TelegramErrorKind`] (can be classified to [`TelegramApiError`])
 //! - [`ConvertToTypeError`]
+//! - [`DispatcherInitError`]
+//! - [`RouterInitError`]
 //! Check the documentation for each error to see what it means.
 
 #![allow(clippy::module_name_repetitions)]
 
 pub mod convert;
+pub mod dispatcher;
 pub mod event;
 pub mod extractor;
 pub mod handler;
 pub mod middleware;
+pub mod router;
 pub mod session;
 pub mod telegram;
 
 pub use convert::ConvertToType as ConvertToTypeError;
+pub use dispatcher::InitError as DispatcherInitError;
 pub use event::ErrorKind as EventErrorKind;
 pub use extractor::Error as ExtractionError;
 pub use handler::Error as HandlerError;
 pub use middleware::Error as MiddlewareError;
+pub use router::InitError as RouterInitError;
 pub use session::ErrorKind as SessionErrorKind;
-pub use telegram::ErrorKind as TelegramErrorKind;
+pub use telegram::{ApiError as TelegramApiError, ErrorKind as TelegramErrorKind};
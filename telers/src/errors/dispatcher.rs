@@ -0,0 +1,22 @@
+//! This module contains the error [`InitError`] that can occur when the dispatcher is converted to the service.
+//!
+//! See [`ToServiceProvider`] implementation for [`Dispatcher`] for more information.
+//!
+//! [`ToServiceProvider`]: crate::event::service::ToServiceProvider
+//! [`Dispatcher`]: crate::dispatcher::Dispatcher
+
+use crate::enums::UpdateType;
+
+/// Possible errors that can occur when the dispatcher is converted to the service:
+/// - [`InitError::Propagator`] - The main router (propagator) can't be converted to the service
+/// - [`InitError::DisallowedUpdateTypes`] - Some update types have handlers, but aren't allowed to be received
+///   and [`Builder::strict_allowed_updates`] is enabled
+///
+/// [`Builder::strict_allowed_updates`]: crate::dispatcher::Builder#method.strict_allowed_updates
+#[derive(Debug, thiserror::Error)]
+pub enum InitError<E> {
+    #[error("Main router can't be converted to the service: {0:?}")]
+    Propagator(E),
+    #[error("Update types {0:?} have handlers, but aren't allowed to be received")]
+    DisallowedUpdateTypes(Box<[UpdateType]>),
+}
@@ -7,6 +7,8 @@
 
 use super::{SessionErrorKind, TelegramErrorKind};
 
+use crate::utils::inline_answer::InlineAnswerError;
+
 use anyhow;
 use thiserror;
 
@@ -99,6 +101,13 @@ impl From<std::num::ParseFloatError> for Error {
     }
 }
 
+/// To possible to wrap [`InlineAnswerError`] error in [`Error`] struct without boilerplate code
+impl From<InlineAnswerError> for Error {
+    fn from(err: InlineAnswerError) -> Self {
+        Self::new(err)
+    }
+}
+
 impl<T> From<Box<T>> for Error
 where
     T: std::error::Error + Send + Sync + 'static,
@@ -0,0 +1,34 @@
+//! This module contains the error [`InitError`] that can occur when the router is converted to the service.
+//!
+//! See [`ToServiceProvider`] implementation for [`Router`] for more information.
+//!
+//! [`ToServiceProvider`]: crate::event::service::ToServiceProvider
+//! [`Router`]: crate::router::Router
+
+use crate::enums::TelegramObserverName;
+
+/// Possible errors that can occur when the router is converted to the service:
+/// - [`InitError::Observer`] - Observer of the router can't be converted to the service
+/// - [`InitError::InactiveObserver`] - Handlers are registered to the observer,
+///   which isn't active (see [`Router::with_update_types`])
+///
+/// [`Router::with_update_types`]: crate::router::Router::with_update_types
+#[derive(Debug, thiserror::Error)]
+pub enum InitError {
+    #[error("Observer can't be converted to the service")]
+    Observer,
+    #[error(
+        "Router `{router_name}` has handlers registered to the inactive observer `{observer}`"
+    )]
+    InactiveObserver {
+        router_name: &'static str,
+        observer: TelegramObserverName,
+    },
+}
+
+/// Observers return `()` as the error, so it's converted to [`InitError::Observer`]
+impl From<()> for InitError {
+    fn from((): ()) -> Self {
+        Self::Observer
+    }
+}
@@ -4,6 +4,9 @@
 //! defined by messages in the responses, but these messages can be changed in the future (frequent situation).
 //! So, many errors are represents as [`ErrorKind::BadRequest`], and we are not trying to distinguish them
 //! for stability. Thanks Telegram Bot API for this ^_^.
+//!
+//! If you need to distinguish the most common errors anyway (for example, the bot is blocked by the user),
+//! use [`ErrorKind::api_error`] method, which classifies the error to the [`ApiError`] enum.
 
 use anyhow;
 use thiserror;
@@ -58,3 +61,188 @@ pub enum ErrorKind {
     #[error(transparent)]
     UnknownError(#[from] anyhow::Error),
 }
+
+impl ErrorKind {
+    /// Classifies the error by its kind and message to the [`ApiError`], so it can be matched without string-sniffing
+    /// # Returns
+    /// `None` if the error isn't the Telegram Bot API error response (for example, unknown error)
+    #[must_use]
+    pub fn api_error(&self) -> Option<ApiError> {
+        let (error_code, message) = match self {
+            Self::RetryAfter { retry_after, .. } => {
+                return Some(ApiError::TooManyRequests {
+                    retry_after: *retry_after,
+                })
+            }
+            Self::MigrateToChat {
+                migrate_to_chat_id, ..
+            } => {
+                return Some(ApiError::Migrated {
+                    to_chat_id: *migrate_to_chat_id,
+                })
+            }
+            Self::BadRequest { message } => (400, message),
+            Self::Unauthorized { message } => (401, message),
+            Self::Forbidden { message } => (403, message),
+            Self::NotFound { message } => (404, message),
+            Self::ConflictError { message } => (409, message),
+            Self::EntityTooLarge { message, .. } => (413, message),
+            Self::ServerError { message } | Self::RestartingTelegram { message } => (500, message),
+            Self::NetworkError { .. } | Self::UnknownError(_) => return None,
+        };
+
+        Some(ApiError::parse(error_code, message))
+    }
+}
+
+/// Telegram Bot API error classified by the error code and the description.
+/// # Notes
+/// Descriptions of the errors aren't documented and can be changed by Telegram,
+/// so new or changed errors are classified as [`ApiError::Forbidden`] or [`ApiError::Other`] with the raw description.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApiError {
+    /// The bot was blocked by the user
+    BotBlocked,
+    /// The bot was kicked from the group or channel
+    BotKicked,
+    /// The user is deactivated (deleted account)
+    UserDeactivated,
+    /// The chat isn't found or the bot isn't a member of it
+    ChatNotFound,
+    /// New content of the message is the same as the current one
+    MessageNotModified,
+    /// The message to edit isn't found
+    MessageToEditNotFound,
+    /// The message to delete isn't found
+    MessageToDeleteNotFound,
+    /// Flood control is exceeded, the request can be repeated after `retry_after` seconds
+    TooManyRequests { retry_after: i64 },
+    /// The group is migrated to the supergroup with `to_chat_id` identifier
+    Migrated { to_chat_id: i64 },
+    /// The bot has no rights to do the action
+    Forbidden { description: Box<str> },
+    /// Other error with the raw description
+    Other {
+        error_code: u16,
+        description: Box<str>,
+    },
+}
+
+impl ApiError {
+    /// Classifies the error by the error code and the description
+    #[must_use]
+    pub fn parse(error_code: u16, description: &str) -> Self {
+        let lowercase = description.to_lowercase();
+
+        if lowercase.contains("bot was blocked by the user") {
+            Self::BotBlocked
+        } else if lowercase.contains("bot was kicked") {
+            Self::BotKicked
+        } else if lowercase.contains("user is deactivated") {
+            Self::UserDeactivated
+        } else if lowercase.contains("chat not found") {
+            Self::ChatNotFound
+        } else if lowercase.contains("message is not modified") {
+            Self::MessageNotModified
+        } else if lowercase.contains("message to edit not found") {
+            Self::MessageToEditNotFound
+        } else if lowercase.contains("message to delete not found") {
+            Self::MessageToDeleteNotFound
+        } else if error_code == 403 {
+            Self::Forbidden {
+                description: description.into(),
+            }
+        } else {
+            Self::Other {
+                error_code,
+                description: description.into(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::session::ClientResponse, errors::SessionErrorKind, methods::SendMessage,
+        test::MockBot,
+    };
+
+    async fn api_error(response: serde_json::Value) -> ApiError {
+        let mock = MockBot::new();
+        mock.push_response::<SendMessage>(ClientResponse::new(
+            u16::try_from(response["error_code"].as_u64().unwrap()).unwrap(),
+            response.to_string(),
+        ));
+
+        match mock.bot().send(SendMessage::new(1, "text")).await {
+            Err(SessionErrorKind::Telegram(err)) => err.api_error().unwrap(),
+            result => panic!("Unexpected result: {result:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_api_error() {
+        let cases = [
+            (403, "Forbidden: bot was blocked by the user", ApiError::BotBlocked),
+            (403, "Forbidden: bot was kicked from the group chat", ApiError::BotKicked),
+            (403, "Forbidden: user is deactivated", ApiError::UserDeactivated),
+            (400, "Bad Request: chat not found", ApiError::ChatNotFound),
+            (
+                400,
+                "Bad Request: message is not modified: specified new message content and reply markup are exactly the same",
+                ApiError::MessageNotModified,
+            ),
+            (400, "Bad Request: message to edit not found", ApiError::MessageToEditNotFound),
+            (400, "Bad Request: message to delete not found", ApiError::MessageToDeleteNotFound),
+            (
+                403,
+                "Forbidden: bot can't initiate conversation with a user",
+                ApiError::Forbidden {
+                    description: "Forbidden: bot can't initiate conversation with a user".into(),
+                },
+            ),
+            (
+                400,
+                "Bad Request: message text is empty",
+                ApiError::Other {
+                    error_code: 400,
+                    description: "Bad Request: message text is empty".into(),
+                },
+            ),
+        ];
+
+        for (error_code, description, expected) in cases {
+            let response = serde_json::json!({
+                "ok": false,
+                "error_code": error_code,
+                "description": description,
+            });
+
+            assert_eq!(api_error(response).await, expected);
+        }
+
+        let response = serde_json::json!({
+            "ok": false,
+            "error_code": 429,
+            "description": "Too Many Requests: retry after 5",
+            "parameters": {"retry_after": 5},
+        });
+        assert_eq!(
+            api_error(response).await,
+            ApiError::TooManyRequests { retry_after: 5 }
+        );
+
+        let response = serde_json::json!({
+            "ok": false,
+            "error_code": 400,
+            "description": "Bad Request: group chat was upgraded to a supergroup chat",
+            "parameters": {"migrate_to_chat_id": -100},
+        });
+        assert_eq!(
+            api_error(response).await,
+            ApiError::Migrated { to_chat_id: -100 }
+        );
+    }
+}
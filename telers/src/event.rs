@@ -1,6 +1,7 @@
 #![allow(clippy::module_name_repetitions)]
 
 pub mod bases;
+pub mod bot;
 pub mod service;
 pub mod simple;
 pub mod telegram;
@@ -0,0 +1,185 @@
+//! This module contains [`Observer`] for bot events, which isn't related with Telegram updates,
+//! but is related with the bot (for example, startup and shutdown of the bot).
+//!
+//! Unlike [`crate::event::simple::Observer`], handlers of this observer receive the [`Bot`] instance,
+//! so you can make requests to the Telegram Bot API in them (for example, set commands or delete webhook).
+//! If the dispatcher has multiple bots, then handlers are called for each bot.
+
+use crate::{
+    client::Bot,
+    enums::SimpleObserverName,
+    event::{
+        service::{BoxFuture, ServiceProvider, ToServiceProvider},
+        simple::HandlerResult,
+    },
+};
+
+use std::{
+    fmt::{self, Debug, Formatter},
+    future::Future,
+    sync::Arc,
+};
+use tracing::instrument;
+
+type BoxedHandler<Client> = Arc<dyn Fn(Bot<Client>) -> BoxFuture<HandlerResult> + Send + Sync>;
+
+/// Bot events observer
+/// Is used for managing events of the bot, which handlers receive the [`Bot`] (for example, startup/shutdown events)
+pub struct Observer<Client> {
+    pub event_name: SimpleObserverName,
+
+    handlers: Vec<BoxedHandler<Client>>,
+}
+
+impl<Client> Observer<Client> {
+    #[must_use]
+    pub fn new(event_name: SimpleObserverName) -> Self {
+        Self {
+            event_name,
+            handlers: vec![],
+        }
+    }
+
+    #[must_use]
+    pub fn handlers_len(&self) -> usize {
+        self.handlers.len()
+    }
+
+    /// Register event handler
+    pub fn register<H, Fut>(&mut self, handler: H)
+    where
+        H: Fn(Bot<Client>) -> Fut + Send + Sync + 'static,
+        Fut: Future + Send + 'static,
+        Fut::Output: Into<HandlerResult>,
+    {
+        self.handlers.push(Arc::new(move |bot| {
+            let fut = handler(bot);
+
+            Box::pin(async move { fut.await.into() })
+        }));
+    }
+
+    /// Alias to [`Observer::register`] method
+    pub fn on<H, Fut>(&mut self, handler: H)
+    where
+        H: Fn(Bot<Client>) -> Fut + Send + Sync + 'static,
+        Fut: Future + Send + 'static,
+        Fut::Output: Into<HandlerResult>,
+    {
+        self.register(handler);
+    }
+}
+
+impl<Client> Debug for Observer<Client> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Observer")
+            .field("event_name", &self.event_name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Client> AsRef<Observer<Client>> for Observer<Client> {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl<Client> ToServiceProvider for Observer<Client> {
+    type Config = ();
+    type ServiceProvider = Service<Client>;
+    type InitError = ();
+
+    fn to_service_provider(
+        self,
+        (): Self::Config,
+    ) -> Result<Self::ServiceProvider, Self::InitError> {
+        Ok(Service {
+            event_name: self.event_name,
+            handlers: self.handlers.into(),
+        })
+    }
+}
+
+pub struct Service<Client> {
+    event_name: SimpleObserverName,
+    handlers: Box<[BoxedHandler<Client>]>,
+}
+
+impl<Client> Debug for Service<Client> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Service")
+            .field("event_name", &self.event_name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Client> ServiceProvider for Service<Client> {}
+
+impl<Client> Service<Client> {
+    /// Propagate event to handlers
+    ///
+    /// If any handler returns error, then propagation will be stopped and error will be returned.
+    /// # Errors
+    /// If any handler returns error
+    #[instrument(skip(self, bot))]
+    pub async fn trigger(&self, bot: &Bot<Client>) -> HandlerResult
+    where
+        Client: Clone,
+    {
+        for handler in &*self.handlers {
+            handler(bot.clone()).await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{errors::HandlerError, methods::SetMyCommands, test::MockBot, types::BotCommand};
+
+    use anyhow::anyhow;
+
+    #[tokio::test]
+    async fn test_observer_trigger() {
+        async fn on_startup(bot: Bot<MockBot>) -> HandlerResult {
+            bot.send(SetMyCommands::new([BotCommand::new(
+                "start",
+                "Start the bot",
+            )]))
+            .await?;
+
+            Ok(())
+        }
+
+        async fn on_shutdown(_bot: Bot<MockBot>) -> HandlerResult {
+            unreachable!("Propagation must be stopped after error")
+        }
+
+        let mock = MockBot::new();
+        mock.push_result::<SetMyCommands>(true);
+
+        let mut observer = Observer::new(SimpleObserverName::Startup);
+        observer.register(on_startup);
+
+        let service = observer.to_service_provider_default().unwrap();
+        service.trigger(&mock.bot()).await.unwrap();
+
+        let calls = mock.calls();
+
+        assert_eq!(calls.len(), 1);
+        assert!(calls[0].is::<SetMyCommands>());
+        assert_eq!(calls[0].data["commands"][0]["command"], "start");
+
+        // Error of the request is propagated
+        service.trigger(&mock.bot()).await.unwrap_err();
+
+        let mut observer = Observer::<MockBot>::new(SimpleObserverName::Shutdown);
+        observer.register(|_| async { Err(HandlerError::new(anyhow!("test"))) });
+        observer.register(on_shutdown);
+
+        let service = observer.to_service_provider_default().unwrap();
+        service.trigger(&mock.bot()).await.unwrap_err();
+    }
+}
@@ -1,8 +1,9 @@
 pub mod handler;
 pub mod observer;
+pub mod trace;
 
 pub use handler::{
-    handler_service, BoxedHandlerService, Handler, Request as HandlerRequest,
+    handler_service, BoxedHandlerService, Handler, HandlerId, Request as HandlerRequest,
     Response as HandlerResponse, Result as HandlerResult,
 };
 pub use observer::Observer;
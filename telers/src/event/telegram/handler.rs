@@ -91,6 +91,11 @@ impl<Client> Debug for Response<Client> {
     }
 }
 
+/// Handler of the telegram events.
+///
+/// It's implemented for functions with up to 20 arguments, each of which implements [`FromEventAndContext`].
+/// If a function doesn't implement this trait, check that it doesn't have more arguments and all of them can be extracted.
+/// Tuples of extractors are extractors too, so more arguments can be passed by grouping them into tuples.
 pub trait Handler<Args> {
     type Output;
     type Future: Future<Output = Self::Output>;
@@ -98,9 +103,16 @@ pub trait Handler<Args> {
     fn call(&self, args: Args) -> Self::Future;
 }
 
+/// Identifier of the handler in the observer.
+/// It's stable, so removing other handlers doesn't change it.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct HandlerId(pub(crate) usize);
+
 #[allow(clippy::module_name_repetitions)]
 pub struct HandlerObject<Client> {
     service: BoxedHandlerServiceFactory<Client>,
+    pub(crate) id: HandlerId,
 
     pub filters: Vec<Arc<dyn Filter<Client>>>,
 }
@@ -119,12 +131,19 @@ where
     {
         Self {
             service: handler_service(handler),
+            id: HandlerId(0),
             filters: vec![],
         }
     }
 }
 
 impl<Client> HandlerObject<Client> {
+    /// Gets the identifier of the handler, which can be used to get or remove the handler from the observer later
+    #[must_use]
+    pub const fn id(&self) -> HandlerId {
+        self.id
+    }
+
     pub fn filter<T>(&mut self, val: T) -> &mut Self
     where
         T: Filter<Client> + 'static,
@@ -133,6 +152,16 @@ impl<Client> HandlerObject<Client> {
         self
     }
 
+    /// Register the filter marked as cheap, so it's checked before other filters of the handler.
+    /// Use it for sync filters, which only inspect the update, see [`Filter#ordering`] for more details.
+    pub fn filter_fast<T>(&mut self, val: T) -> &mut Self
+    where
+        T: Filter<Client> + 'static,
+        Client: Sync,
+    {
+        self.filter(val.cheap())
+    }
+
     pub fn filters<T, I>(&mut self, val: I) -> &mut Self
     where
         T: Filter<Client> + 'static,
@@ -154,9 +183,14 @@ impl<Client> ServiceFactory<Request<Client>> for HandlerObject<Client> {
     fn new_service(&self, config: Self::Config) -> StdResult<Self::Service, Self::InitError> {
         let service = self.service.new_service(config)?;
 
+        // Cheap filters are checked first, the stable sort keeps the order of registration in each group
+        let mut order = (0..self.filters.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&index| !self.filters[index].is_cheap());
+
         Ok(HandlerObjectService {
             service: Arc::new(service),
             filters: self.filters.clone().into(),
+            order: order.into(),
         })
     }
 }
@@ -165,6 +199,8 @@ impl<Client> ServiceFactory<Request<Client>> for HandlerObject<Client> {
 pub struct HandlerObjectService<Client> {
     pub(crate) service: Arc<BoxedHandlerService<Client>>,
     filters: Box<[Arc<dyn Filter<Client>>]>,
+    /// Indexes of the filters in order of checking
+    order: Box<[usize]>,
 }
 
 impl<Client> HandlerObjectService<Client>
@@ -175,15 +211,23 @@ where
     /// If the handler pass all them, it will be called.
     #[instrument(skip(self, request))]
     pub async fn check(&self, request: &Request<Client>) -> bool {
-        for filter in &*self.filters {
+        self.rejected_by(request).await.is_none()
+    }
+
+    /// Check the filters of the handler, cheap filters first and then others in order of registration
+    /// # Returns
+    /// Index (in order of registration) of the first checked filter that didn't pass or `None` if all filters passed
+    pub(crate) async fn rejected_by(&self, request: &Request<Client>) -> Option<usize> {
+        for &index in self.order.iter() {
+            let filter = &self.filters[index];
             if !filter
                 .check(&request.bot, &request.update, &request.context)
                 .await
             {
-                return false;
+                return Some(index);
             }
         }
-        true
+        None
     }
 }
 
@@ -289,21 +333,21 @@ mod factory_handlers {
     factory! { A B C D E F G H I J K }
     // To be able to use function with 12 arguments
     factory! { A B C D E F G H I J K L }
-    // To be able to extract tuple with 13 arguments
+    // To be able to use function with 13 arguments
     factory! { A B C D E F G H I J K L M}
-    // To be able to extract tuple with 14 arguments
+    // To be able to use function with 14 arguments
     factory! { A B C D E F G H I J K L M N }
-    // To be able to extract tuple with 15 arguments
+    // To be able to use function with 15 arguments
     factory! { A B C D E F G H I J K L M N O}
-    // To be able to extract tuple with 16 arguments
+    // To be able to use function with 16 arguments
     factory! { A B C D E F G H I J K L M N O P }
-    // To be able to extract tuple with 17 arguments
+    // To be able to use function with 17 arguments
     factory! { A B C D E F G H I J K L M N O P Q }
-    // To be able to extract tuple with 18 arguments
+    // To be able to use function with 18 arguments
     factory! { A B C D E F G H I J K L M N O P Q R }
-    // To be able to extract tuple with 19 arguments
+    // To be able to use function with 19 arguments
     factory! { A B C D E F G H I J K L M N O P Q R S }
-    // To be able to extract tuple with 20 arguments
+    // To be able to use function with 20 arguments
     factory! { A B C D E F G H I J K L M N O P Q R S T }
 }
 
@@ -385,4 +429,109 @@ mod tests {
             _ => panic!("Unexpected result"),
         }
     }
+
+    #[tokio::test]
+    async fn test_cheap_filters_first() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let expensive_calls = Arc::new(AtomicUsize::new(0));
+
+        let mut handler_object =
+            HandlerObject::<Reqwest>::new(|| async { Ok(EventReturn::Finish) });
+        handler_object
+            .filter({
+                let expensive_calls = Arc::clone(&expensive_calls);
+                move |_: &Bot, _: &Update, _: &Context| {
+                    let expensive_calls = Arc::clone(&expensive_calls);
+                    async move {
+                        tokio::task::yield_now().await;
+                        expensive_calls.fetch_add(1, Ordering::SeqCst);
+                        true
+                    }
+                }
+            })
+            .filter(|_: &Bot, _: &Update, _: &Context| async { true })
+            .filter_fast(|_: &Bot, update: &Update, _: &Context| {
+                let passed = update.id == 1;
+                async move { passed }
+            });
+        let handler_object_service = handler_object.new_service(()).unwrap();
+
+        let request = |id| {
+            Request::new(
+                Arc::new(Bot::<Reqwest>::default()),
+                Arc::new(Update {
+                    id,
+                    kind: UpdateKind::Message(Message::default()),
+                }),
+                Arc::new(Context::default()),
+            )
+        };
+
+        // Cheap filter registered last is checked first, so the expensive one is skipped
+        assert_eq!(
+            handler_object_service.rejected_by(&request(0)).await,
+            Some(2)
+        );
+        assert_eq!(expensive_calls.load(Ordering::SeqCst), 0);
+
+        assert_eq!(handler_object_service.rejected_by(&request(1)).await, None);
+        assert_eq!(expensive_calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_high_arity_handler() {
+        use crate::{
+            event::telegram::HandlerResult,
+            methods::SendMessage,
+            test::{self, MockBot},
+            Router,
+        };
+
+        #[allow(clippy::too_many_arguments)]
+        async fn handler(
+            bot: Bot<MockBot>,
+            message: Message,
+            update: Update,
+            _context: Arc<Context>,
+            _unit05: (),
+            _unit06: (),
+            _unit07: (),
+            _unit08: (),
+            _unit09: (),
+            _unit10: (),
+            _unit11: (),
+            _unit12: (),
+            _unit13: (),
+            _unit14: (),
+            _unit15: (),
+            _unit16: (),
+            _unit17: (),
+            _unit18: (),
+            _unit19: (),
+            // Nested tuple to pass more arguments
+            (nested_message, _, _): (Option<Message>, (), ()),
+        ) -> HandlerResult {
+            assert_eq!(message.text(), update.text());
+
+            bot.send(SendMessage::new(
+                test::CHAT_ID,
+                nested_message.unwrap().text().unwrap(),
+            ))
+            .await?;
+
+            Ok(EventReturn::Finish)
+        }
+
+        let mock = MockBot::new();
+        mock.push_result::<SendMessage>(test::sent_message("text"));
+
+        let mut router = Router::new("main");
+        router.message.register(handler);
+
+        let calls = mock.propagate(router, test::message("text")).await.unwrap();
+
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].data["text"], "text");
+    }
 }
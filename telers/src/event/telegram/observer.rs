@@ -7,9 +7,10 @@ use crate::{
         bases::{EventReturn, PropagateEventResult},
         service::{Service as _, ServiceFactory as _, ServiceProvider, ToServiceProvider},
         telegram::handler::{
-            Handler, HandlerObject, HandlerObjectService, Request as HandlerRequest,
+            Handler, HandlerId, HandlerObject, HandlerObjectService, Request as HandlerRequest,
             Result as HandlerResult,
         },
+        telegram::trace::{PropagationStep, PropagationTrace},
     },
     extractors::FromEventAndContext,
     filters::Filter,
@@ -99,7 +100,9 @@ pub struct Observer<Client> {
     pub event_name: TelegramObserverName,
 
     handlers: Vec<HandlerObject<Client>>,
+    next_handler_id: usize,
     common: Box<HandlerObject<Client>>,
+    default_event_return: EventReturn,
 
     pub inner_middlewares: InnerMiddlewareManager<Client>,
     pub outer_middlewares: OuterMiddlewareManager<Client>,
@@ -115,12 +118,14 @@ impl<Client> Observer<Client> {
         Self {
             event_name,
             handlers: vec![],
+            next_handler_id: 0,
             common: Box::new(HandlerObject::<Client>::new(|| async move {
                 // This handler never will be called, so we can use `unreachable!` macro
                 ({
                     unreachable!("This handler never will be used");
                 }) as Result<_, _>
             })),
+            default_event_return: EventReturn::Finish,
             inner_middlewares: InnerMiddlewareManager::<Client>::default(),
             outer_middlewares: OuterMiddlewareManager::<Client>::default(),
         }
@@ -141,11 +146,36 @@ impl<Client> Observer<Client> {
         Args: FromEventAndContext<Client> + Send,
         Args::Error: Send,
     {
-        self.handlers.push(HandlerObject::new(handler));
+        let mut handler = HandlerObject::new(handler);
+        handler.id = HandlerId(self.next_handler_id);
+
+        self.next_handler_id += 1;
+        self.handlers.push(handler);
         // `unwrap` is safe, because we just added element to the vector
         self.handlers.last_mut().unwrap()
     }
 
+    /// Gets the handler by its identifier to attach filters to it later
+    /// # Returns
+    /// `None` if the handler isn't registered in the observer or was removed
+    #[must_use]
+    pub fn handler_mut(&mut self, id: HandlerId) -> Option<&mut HandlerObject<Client>> {
+        self.handlers.iter_mut().find(|handler| handler.id() == id)
+    }
+
+    /// Removes the handler by its identifier.
+    /// Identifiers of other handlers aren't changed.
+    /// # Returns
+    /// `true` if the handler was removed, `false` if it isn't registered in the observer
+    pub fn remove(&mut self, id: HandlerId) -> bool {
+        if let Some(index) = self.handlers.iter().position(|handler| handler.id() == id) {
+            self.handlers.remove(index);
+            true
+        } else {
+            false
+        }
+    }
+
     /// Alias to [`Observer::register`] method
     pub fn on<H, Args>(&mut self, handler: H) -> &mut HandlerObject<Client>
     where
@@ -159,7 +189,9 @@ impl<Client> Observer<Client> {
         self.register(handler)
     }
 
-    /// Register filter for all handlers in the observer
+    /// Register filter for all handlers in the observer.
+    /// Observer filters are checked before filters of the handlers,
+    /// so if any of them doesn't pass, then the event is rejected and isn't propagated to the sub routers.
     pub fn filter<T>(&mut self, val: T) -> &mut Self
     where
         T: Filter<Client> + 'static,
@@ -168,6 +200,16 @@ impl<Client> Observer<Client> {
         self
     }
 
+    /// Register cheap filter for all handlers in the observer, see [`HandlerObject::filter_fast`] for more details
+    pub fn filter_fast<T>(&mut self, val: T) -> &mut Self
+    where
+        T: Filter<Client> + 'static,
+        Client: Sync,
+    {
+        self.common.filter_fast(val);
+        self
+    }
+
     /// Register filters for all handlers in the observer
     pub fn filters<T, I>(&mut self, val: I) -> &mut Self
     where
@@ -177,6 +219,16 @@ impl<Client> Observer<Client> {
         self.common.filters(val);
         self
     }
+
+    /// Set the variant, which is used instead of [`EventReturn::Finish`] (the default) returned by handlers of the observer.
+    /// For example, [`EventReturn::Skip`] makes all matching handlers run, like in logging or analytics routers,
+    /// without returning [`EventReturn::Skip`] from each handler.
+    /// # Notes
+    /// Other variants returned by handlers are processed as usual
+    pub fn default_event_return(&mut self, val: EventReturn) -> &mut Self {
+        self.default_event_return = val;
+        self
+    }
 }
 
 impl<Client> Debug for Observer<Client> {
@@ -221,6 +273,7 @@ impl<Client> ToServiceProvider for Observer<Client> {
                 .map(|handler| handler.new_service(config))
                 .collect::<Result<_, _>>()?,
             common: self.common.new_service(config)?,
+            default_event_return: self.default_event_return,
             inner_middlewares: self.inner_middlewares.middlewares.into(),
             outer_middlewares: self.outer_middlewares.middlewares.into(),
         })
@@ -232,6 +285,7 @@ pub struct Service<Client> {
 
     handlers: Box<[HandlerObjectService<Client>]>,
     common: HandlerObjectService<Client>,
+    default_event_return: EventReturn,
 
     inner_middlewares: Box<[Arc<dyn InnerMiddleware<Client>>]>,
     outer_middlewares: Box<[Arc<dyn OuterMiddleware<Client>>]>,
@@ -253,9 +307,18 @@ impl<Client> Service<Client> {
         Client: Send + Sync + 'static,
     {
         let handler_request: HandlerRequest<Client> = request.clone().into();
+        let trace = PropagationTrace::from_context(&request.context);
+
+        let filters_passed = self.common.check(&handler_request).await;
+        if let Some(trace) = &trace {
+            trace.push(PropagationStep::Observer {
+                name: self.event_name,
+                filters_passed,
+            });
+        }
 
         // Check observer filters
-        if !self.common.check(&handler_request).await {
+        if !filters_passed {
             event!(Level::TRACE, "Request are not pass observer filters");
 
             return Ok(Response {
@@ -265,8 +328,13 @@ impl<Client> Service<Client> {
         }
 
         // Check handlers filters
-        for handler in &*self.handlers {
-            if !handler.check(&handler_request).await {
+        for (index, handler) in self.handlers.iter().enumerate() {
+            let rejected_by = handler.rejected_by(&handler_request).await;
+            if let Some(trace) = &trace {
+                trace.push(PropagationStep::Handler { index, rejected_by });
+            }
+
+            if rejected_by.is_some() {
                 continue;
             }
 
@@ -286,7 +354,21 @@ impl<Client> Service<Client> {
                     .map_err(EventErrorKind::Extraction),
             }?;
 
-            return match response.handler_result {
+            let handler_result = match response.handler_result {
+                // If the handler or middleware returns the default, then we should use the default of the observer
+                Ok(EventReturn::Finish) => Ok(self.default_event_return.clone()),
+                Ok(ref event_return) => Ok(event_return.clone()),
+                Err(_) => Err(()),
+            };
+
+            if let Some(trace) = &trace {
+                trace.push(PropagationStep::HandlerReturn {
+                    index,
+                    event_return: handler_result.clone().ok(),
+                });
+            }
+
+            return match handler_result {
                 // If the handler or middleware returns skip, then we should skip it
                 Ok(EventReturn::Skip) => {
                     event!(Level::TRACE, "Handler returns skip");
@@ -334,6 +416,11 @@ impl<Client> Service<Client> {
         })
     }
 
+    #[must_use]
+    pub fn handlers_len(&self) -> usize {
+        self.handlers.len()
+    }
+
     #[must_use]
     pub fn inner_middlewares(&self) -> &[Arc<dyn InnerMiddleware<Client>>] {
         &self.inner_middlewares
@@ -481,4 +568,107 @@ mod tests {
             _ => panic!("Unexpected result"),
         }
     }
+
+    #[allow(unreachable_code)]
+    #[tokio::test]
+    async fn test_observer_default_event_return() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let mut observer = Observer::<Reqwest>::default();
+        observer.default_event_return(EventReturn::Skip);
+        for _ in 0..3 {
+            let calls = Arc::clone(&calls);
+
+            observer.register(move || {
+                let calls = Arc::clone(&calls);
+
+                async move {
+                    calls.fetch_add(1, Ordering::SeqCst);
+
+                    Ok(EventReturn::Finish)
+                }
+            });
+        }
+
+        let observer_service = observer.to_service_provider_default().unwrap();
+        let request = Request::new(
+            Arc::new(Bot::default()),
+            Arc::new(Update::default()),
+            Arc::new(Context::default()),
+        );
+        let response = observer_service.trigger(request.clone()).await.unwrap();
+
+        // All handlers are called and the event isn't handled by the observer
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        match response.propagate_result {
+            PropagateEventResult::Unhandled => {}
+            _ => panic!("Unexpected result"),
+        }
+
+        let mut observer = Observer::<Reqwest>::default();
+        observer.default_event_return(EventReturn::Skip);
+        observer.register(|| async { Ok(EventReturn::Cancel) });
+        observer.register(|| async {
+            unreachable!("The first handler cancels propagation");
+
+            Ok(EventReturn::Finish)
+        });
+
+        let observer_service = observer.to_service_provider_default().unwrap();
+        let response = observer_service.trigger(request).await.unwrap();
+
+        // Explicit variants aren't replaced by the default
+        match response.propagate_result {
+            PropagateEventResult::Rejected => {}
+            _ => panic!("Unexpected result"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_observer_remove_handler() {
+        use crate::{
+            filters::Text,
+            methods::SendMessage,
+            test::{self, MockBot},
+            Router,
+        };
+
+        async fn reply(bot: Bot<MockBot>, text: &str) -> HandlerResult {
+            bot.send(SendMessage::new(test::CHAT_ID, text)).await?;
+
+            Ok(EventReturn::Finish)
+        }
+
+        let mut router = Router::new("main");
+
+        let hello = router.message.register(|bot| reply(bot, "hello")).id();
+        let any = router.message.register(|bot| reply(bot, "any")).id();
+
+        assert_ne!(hello, any);
+
+        router
+            .message
+            .handler_mut(hello)
+            .unwrap()
+            .filter(Text::one("hello"));
+
+        assert!(router.message.remove(hello));
+        assert!(!router.message.remove(hello));
+        assert!(router.message.handler_mut(hello).is_none());
+        // Identifier of other handler isn't changed after removing
+        assert_eq!(router.message.handlers()[0].id(), any);
+
+        let mock = MockBot::new();
+        mock.push_result::<SendMessage>(test::sent_message("any"));
+
+        let calls = mock
+            .propagate(router, test::message("hello"))
+            .await
+            .unwrap();
+
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].data["text"], "any");
+    }
 }
@@ -0,0 +1,88 @@
+//! This module contains [`PropagationTrace`], which records the path of the update through the routers for debugging.
+//!
+//! The trace is opt-in: it's recorded only if [`PropagationTrace`] is in the context by [`TRACE_KEY`] key.
+//! Register [`crate::middlewares::outer::Trace`] middleware to the update observer of the main router or
+//! insert the trace to the context manually before feeding the update.
+//! After the propagation, the recorded steps can be received from the context of the response request
+//! by [`PropagationTrace::from_context`].
+
+use crate::{context::Context, enums::TelegramObserverName, event::EventReturn};
+
+use std::sync::{Arc, Mutex};
+
+/// Key of [`PropagationTrace`] in the context
+pub const TRACE_KEY: &str = "event_trace";
+
+/// Step of the update propagation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PropagationStep {
+    /// The update entered the router
+    Router { name: &'static str },
+    /// The update was cancelled by an outer middleware of the observer
+    OuterMiddlewareCancel { observer: TelegramObserverName },
+    /// The observer was triggered
+    Observer {
+        name: TelegramObserverName,
+        filters_passed: bool,
+    },
+    /// Filters of the handler with the index in the observer were checked.
+    /// `rejected_by` is the index of the first filter that didn't pass.
+    Handler {
+        index: usize,
+        rejected_by: Option<usize>,
+    },
+    /// The handler with the index in the observer was called.
+    /// `event_return` is `None`, if the handler returned an error.
+    HandlerReturn {
+        index: usize,
+        event_return: Option<EventReturn>,
+    },
+}
+
+/// Recorded steps of the update propagation
+#[derive(Debug, Default)]
+pub struct PropagationTrace {
+    steps: Mutex<Vec<PropagationStep>>,
+}
+
+impl PropagationTrace {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enable recording of the trace for the update with the context.
+    /// If the trace is already enabled, returns the existing one.
+    #[must_use]
+    pub fn enable(context: &Context) -> Arc<Self> {
+        context
+            .entry(TRACE_KEY)
+            .or_insert_with(|| Box::new(Arc::new(Self::new())))
+            .downcast_ref::<Arc<Self>>()
+            .map(Arc::clone)
+            .expect("`event_trace` key in the context is reserved for `PropagationTrace`")
+    }
+
+    /// Get the trace from the context, if recording is enabled
+    #[must_use]
+    pub fn from_context(context: &Context) -> Option<Arc<Self>> {
+        context
+            .get(TRACE_KEY)
+            .and_then(|trace| trace.downcast_ref::<Arc<Self>>().map(Arc::clone))
+    }
+
+    /// Get recorded steps
+    /// # Panics
+    /// If the steps mutex is poisoned
+    #[must_use]
+    pub fn steps(&self) -> Vec<PropagationStep> {
+        self.steps.lock().unwrap().clone()
+    }
+
+    /// Record the step
+    /// # Panics
+    /// If the steps mutex is poisoned
+    pub fn push(&self, step: PropagationStep) {
+        self.steps.lock().unwrap().push(step);
+    }
+}
@@ -246,12 +246,12 @@ pub use crate::{FromContext, FromEvent};
 
 use crate::{
     client::{Bot, Reqwest},
-    context::Context,
+    context::{Context, Data, SharedState, SHARED_STATE_KEY},
     errors::ExtractionError,
     types::Update,
 };
 
-use std::{convert::Infallible, sync::Arc};
+use std::{any::type_name, convert::Infallible, sync::Arc};
 
 /// Trait for extracting data from [`Update`] and [`Context`] to handlers arguments
 pub trait FromEventAndContext<Client = Reqwest>: Sized {
@@ -390,6 +390,34 @@ impl<Client> FromEventAndContext<Client> for Arc<Context> {
     }
 }
 
+/// To be able to use values of the [`SharedState`] as handler arguments.
+/// The state is set to the context by the dispatcher, see [`DispatcherBuilder::data`].
+///
+/// [`DispatcherBuilder::data`]: crate::dispatcher::Builder::data
+impl<Client, T: Send + Sync + 'static> FromEventAndContext<Client> for Data<T> {
+    type Error = ExtractionError;
+
+    fn extract(
+        _bot: Arc<Bot<Client>>,
+        _update: Arc<Update>,
+        context: Arc<Context>,
+    ) -> Result<Self, Self::Error> {
+        context
+            .get(SHARED_STATE_KEY)
+            .and_then(|state| {
+                state
+                    .downcast_ref::<Arc<SharedState>>()
+                    .and_then(|state| state.get())
+            })
+            .ok_or_else(|| {
+                ExtractionError::new(format!(
+                    "No shared data of type `{}`. Did you forget to register it with `DispatcherBuilder::data`?",
+                    type_name::<T>(),
+                ))
+            })
+    }
+}
+
 #[allow(non_snake_case)]
 mod factory_from_event_and_context {
     //! This module is used to implement [`FromEventAndContext`] for tuple arguments, each of which implements it
@@ -457,19 +485,20 @@ mod tests {
         errors::ConvertToTypeError,
         types::{
             CallbackQuery, ChatBoostRemoved, ChatBoostUpdated, ChatJoinRequest, ChatMemberUpdated,
-            ChosenInlineResult, InlineQuery, Message, MessageAnimation, MessageAudio,
-            MessageChannelChatCreated, MessageChatShared, MessageConnectedWebsite, MessageContact,
-            MessageDeleteChatPhoto, MessageDice, MessageDocument, MessageForumTopicClosed,
-            MessageForumTopicCreated, MessageForumTopicEdited, MessageForumTopicReopened,
-            MessageGame, MessageGeneralForumTopicHidden, MessageGeneralForumTopicUnhidden,
-            MessageGiveaway, MessageGiveawayCompleted, MessageGiveawayCreated,
-            MessageGiveawayWinners, MessageGroupChatCreated, MessageInvoice, MessageLeftChatMember,
-            MessageLocation, MessageMessageAutoDeleteTimerChanged, MessageMigrateFromChat,
-            MessageMigrateToChat, MessageNewChatMembers, MessageNewChatPhoto, MessageNewChatTitle,
-            MessagePassportData, MessagePhoto, MessagePinned, MessagePoll,
-            MessageProximityAlertTriggered, MessageReactionCountUpdated, MessageReactionUpdated,
-            MessageSticker, MessageStory, MessageSuccessfulPayment, MessageSupergroupChatCreated,
-            MessageText, MessageUsersShared, MessageVenue, MessageVideo, MessageVideoChatEnded,
+            ChosenInlineResult, InlineQuery, MaybeInaccessibleMessage, Message, MessageAnimation,
+            MessageAudio, MessageChannelChatCreated, MessageChatShared, MessageConnectedWebsite,
+            MessageContact, MessageDeleteChatPhoto, MessageDice, MessageDocument,
+            MessageForumTopicClosed, MessageForumTopicCreated, MessageForumTopicEdited,
+            MessageForumTopicReopened, MessageGame, MessageGeneralForumTopicHidden,
+            MessageGeneralForumTopicUnhidden, MessageGiveaway, MessageGiveawayCompleted,
+            MessageGiveawayCreated, MessageGiveawayWinners, MessageGroupChatCreated,
+            MessageInvoice, MessageLeftChatMember, MessageLocation,
+            MessageMessageAutoDeleteTimerChanged, MessageMigrateFromChat, MessageMigrateToChat,
+            MessageNewChatMembers, MessageNewChatPhoto, MessageNewChatTitle, MessagePassportData,
+            MessagePhoto, MessagePinned, MessagePoll, MessageProximityAlertTriggered,
+            MessageReactionCountUpdated, MessageReactionUpdated, MessageSticker, MessageStory,
+            MessageSuccessfulPayment, MessageSupergroupChatCreated, MessageText,
+            MessageUsersShared, MessageVenue, MessageVideo, MessageVideoChatEnded,
             MessageVideoChatParticipantsInvited, MessageVideoChatScheduled,
             MessageVideoChatStarted, MessageVideoNote, MessageVoice, MessageWebAppData,
             MessageWriteAccessAllowed, Poll, PollAnswer, PollQuiz, PollRegular, PreCheckoutQuery,
@@ -520,6 +549,99 @@ mod tests {
             FromEventAndContext::extract(bot.clone(), update.clone(), context.clone()).unwrap();
     }
 
+    #[tokio::test]
+    async fn test_option_extract_in_handler() {
+        use crate::{
+            event::{telegram::HandlerResult, EventReturn},
+            methods::SendMessage,
+            test::{self, MockBot},
+            Router,
+        };
+
+        async fn handler(
+            bot: Bot<MockBot>,
+            message: Option<MessageText>,
+            query: Option<CallbackQuery>,
+        ) -> HandlerResult {
+            let text = match (message, query) {
+                (Some(message), None) => message.text,
+                (None, Some(query)) => query.data.unwrap(),
+                _ => unreachable!("Only one of the extractors can succeed"),
+            };
+
+            bot.send(SendMessage::new(test::CHAT_ID, text)).await?;
+
+            Ok(EventReturn::Finish)
+        }
+
+        let mock = MockBot::new();
+        mock.push_result::<SendMessage>(test::sent_message("text"));
+        mock.push_result::<SendMessage>(test::sent_message("text"));
+
+        let mut router = Router::new("main");
+        router.message.register(handler);
+
+        // Message extractor succeeds and callback query extractor fails, but the handler is called
+        let calls = mock
+            .propagate(router, test::message("Hello"))
+            .await
+            .unwrap();
+
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].data["text"], "Hello");
+
+        let mut router = Router::new("main");
+        router.callback_query.register(handler);
+
+        let calls = mock
+            .propagate(router, test::callback_query("data"))
+            .await
+            .unwrap();
+
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].data["text"], "data");
+    }
+
+    #[tokio::test]
+    async fn test_result_extract_in_handler() {
+        use crate::{
+            errors::ExtractionError,
+            event::{telegram::HandlerResult, EventReturn},
+            methods::SendMessage,
+            test::{self, MockBot},
+            Router,
+        };
+
+        async fn handler(
+            bot: Bot<MockBot>,
+            query: Result<CallbackQuery, ExtractionError>,
+        ) -> HandlerResult {
+            let text = match query {
+                Ok(query) => query.data.unwrap(),
+                Err(_) => "I couldn't read that".into(),
+            };
+
+            bot.send(SendMessage::new(test::CHAT_ID, text)).await?;
+
+            Ok(EventReturn::Finish)
+        }
+
+        let mock = MockBot::new();
+        mock.push_result::<SendMessage>(test::sent_message("text"));
+
+        let mut router = Router::new("main");
+        router.message.register(handler);
+
+        // Callback query can't be extracted from the message, so the handler receives the error
+        let calls = mock
+            .propagate(router, test::message("Hello"))
+            .await
+            .unwrap();
+
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].data["text"], "I couldn't read that");
+    }
+
     #[allow(unreachable_code)]
     fn _check_bounds<Client, T: FromEventAndContext<Client>>() {
         unimplemented!("This function is only used for checking bounds");
@@ -591,6 +713,7 @@ mod tests {
         _check_bounds::<Client, MessageReactionUpdated>();
         _check_bounds::<Client, MessageReactionCountUpdated>();
         _check_bounds::<Client, CallbackQuery>();
+        _check_bounds::<Client, MaybeInaccessibleMessage>();
         _check_bounds::<Client, ChosenInlineResult>();
         _check_bounds::<Client, ShippingQuery>();
         _check_bounds::<Client, PreCheckoutQuery>();
@@ -783,4 +906,40 @@ mod tests {
         _check_bounds::<Client, Result<ChatBoostUpdated, ConvertToTypeError>>();
         _check_bounds::<Client, Result<ChatBoostRemoved, ConvertToTypeError>>();
     }
+
+    #[test]
+    fn test_extract_data() {
+        use crate::context::{Dep, SharedState};
+
+        struct Pool(&'static str);
+        struct Config(i64);
+
+        let mut state = SharedState::new();
+        state.insert(Pool("postgres://localhost"));
+        state.insert(Config(1));
+
+        let bot = Arc::new(Bot::<Reqwest>::default());
+        let update = Arc::new(Update::default());
+        let context = Arc::new(Context::new());
+        context.insert(SHARED_STATE_KEY, Box::new(Arc::new(state)));
+
+        let (pool, config) = <(Data<Pool>, Dep<Config>)>::extract(
+            Arc::clone(&bot),
+            Arc::clone(&update),
+            Arc::clone(&context),
+        )
+        .unwrap();
+
+        assert_eq!(pool.0, "postgres://localhost");
+        assert_eq!(config.0, 1);
+
+        let err = Data::<i64>::extract(Arc::clone(&bot), Arc::clone(&update), context)
+            .unwrap_err()
+            .to_string();
+
+        assert!(err.contains("No shared data of type `i64`"), "{err}");
+
+        // Context without the state
+        assert!(Data::<Pool>::extract(bot, update, Arc::new(Context::new())).is_err());
+    }
 }
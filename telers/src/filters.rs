@@ -9,7 +9,11 @@
 //! Filters can be combined with logical operators [`And`] and [`Or`] and inverted with [`Invert`].
 //! Each filter has a method [`Filter::invert`], [`Filter::and`] and [`Filter::or`] to create [`Invert`], [`And`] and [`Or`] filters respectively.
 //!
+//! Cheap filters, which only inspect the update, are checked before other filters, see [`Filter#ordering`] for more details.
+//! Ready-made filters without requests and storage access are cheap, other filters can be marked as cheap with [`Filter::cheap`] method.
+//!
 //! Ready-made implementations:
+//! * [`CallbackQuery`]: filter for checking the data of the callback query, creates with `eq`, `many`, `starts_with` or `starts_with_many` methods.
 //! * [`ChatType`]:
 //! Filter for checking the type of chat.
 //! Usually used with [`ChatTypeEnum`] (or its string representation) to check the type of chat.
@@ -21,9 +25,18 @@
 //! You can create a filter with `new` method with transferring all necessary data at once, or use [`CommandBuilder`] to create a filter step by step.
 //! Instead of [`CommandBuilder`] you can use [`Command`] `one`, `one_with_prefix`, `many`, `many_with_prefix` methods.
 //! * [`ContentType`]:
-//! Filter for checking the type of the message content.
-//! Usually used with [`ContentTypeEnum`] (or its string representation) to check the type of content.
-//! Creates with `one` or `many` methods.
+//!   Filter for checking the type of the message content.
+//!   Usually used with [`ContentTypeEnum`] (or its string representation) to check the type of content.
+//!   Creates with `one`, `many`, `media` or `text_only` methods, the caption can be required with `with_caption` and `without_caption` methods.
+//! * [`Forwarded`]: filter for checking that the message is forwarded with the origin of specified types, creates with `any`, `one` or `many` methods.
+//! * [`HasMediaSpoiler`]: filter for checking that the media of the message is covered with a spoiler.
+//! * [`HasProtectedContent`]: filter for checking that the message has protected content, which can't be forwarded and saved.
+//! * [`MessageEntity`]:
+//!   Filter for checking the kinds of entities in the message text or caption.
+//!   Usually used with [`MessageEntityKind`] to check that the message contains, for example, a URL, mention or hashtag.
+//!   Creates with `one` or `many` methods.
+//! * [`PreCheckoutQuery`] and [`SuccessfulPayment`]: filters for checking the invoice payload prefix and the currency of the pre-checkout query and the successful payment, create with `any` method.
+//! * [`StartPayload`]: filter for checking the payload of the `/start` command from deep links, creates with `any` or `eq` methods.
 //! * [`State`]:
 //! Filter for checking the state of the user/chat/etc.
 //! Filter accepts [`StateType`] that represents a state type for verification,
@@ -51,6 +64,7 @@
 //! [`Regex`]: regex::Regex
 //! [`ChatTypeEnum`]: crate::enums::ChatType
 //! [`ContentTypeEnum`]: crate::enums::ContentType
+//! [`MessageEntityKind`]: crate::types::MessageEntityKind
 //! [`BotCommand`]: crate::types::BotCommand
 //! [`Regex`]: regex::Regex
 //! [`context`]: crate::context::Context
@@ -60,19 +74,33 @@
 //! [`update`]: crate::types::Update
 
 pub mod base;
+pub mod callback_query;
 pub mod chat_type;
 pub mod command;
 pub mod content_type;
+pub mod forwarded;
+pub mod has_media_spoiler;
+pub mod has_protected_content;
 pub mod logical;
+pub mod message_entity;
+pub mod payment;
+pub mod start_payload;
 pub mod state;
 pub mod text;
 pub mod user;
 
-pub use base::Filter;
+pub use base::{Cheap, Filter};
+pub use callback_query::CallbackQuery;
 pub use chat_type::ChatType;
-pub use command::{Builder as CommandBuilder, Command, CommandObject};
+pub use command::{ArgsMode as CommandArgsMode, Builder as CommandBuilder, Command, CommandObject};
 pub use content_type::ContentType;
+pub use forwarded::Forwarded;
+pub use has_media_spoiler::HasMediaSpoiler;
+pub use has_protected_content::HasProtectedContent;
 pub use logical::{And, Invert, Or};
+pub use message_entity::MessageEntity;
+pub use payment::{PreCheckoutQuery, SuccessfulPayment};
+pub use start_payload::{StartPayload, StartPayloadObject};
 pub use state::{State, StateType};
 pub use text::{Builder as TextBuilder, Text};
 pub use user::{Builder as UserBuilder, User};
@@ -3,7 +3,7 @@ use super::{And, Invert, Or};
 use crate::{
     client::{Bot, Reqwest},
     context::Context,
-    types::Update,
+    types::{BotCommand, Update},
 };
 
 use async_trait::async_trait;
@@ -15,6 +15,10 @@ use std::{future::Future, sync::Arc};
 /// # Notes
 /// Check out the examples to see how to create your own filters and check ready-made implementations of filters
 /// to avoid writing your own filters which are already implemented.
+/// # Ordering
+/// Filters of a handler (and filters combined with [`And`] and [`Or`]) are checked in the following order:
+/// first cheap filters (see [`Filter::is_cheap`]), then the others, each group in order of registration.
+/// Checking stops at the first filter that decides the result, so the remaining (possibly expensive) filters aren't called.
 #[async_trait]
 pub trait Filter<Client = Reqwest>: Send + Sync {
     /// Check if the filter passes
@@ -22,6 +26,31 @@ pub trait Filter<Client = Reqwest>: Send + Sync {
     /// `true` if the filter passes, otherwise `false`
     async fn check(&self, bot: &Bot<Client>, update: &Update, context: &Context) -> bool;
 
+    /// Get bot commands which are handled by the filter
+    /// # Notes
+    /// This method is used by [`crate::Router::collect_commands`] to collect commands of the bot from the registered handlers.
+    /// By default, it returns an empty list.
+    fn bot_commands(&self) -> Vec<BotCommand> {
+        vec![]
+    }
+
+    /// Check if the filter is cheap to check, so it's checked before other filters.
+    /// Cheap filters should only inspect the update without requests, storage access and other slow operations.
+    /// By default, it returns `false`.
+    fn is_cheap(&self) -> bool {
+        false
+    }
+
+    /// Mark the filter as cheap, so it's checked before other filters
+    /// # Notes
+    /// This method is used to create [`Cheap`] filter
+    fn cheap(self) -> Cheap<Self>
+    where
+        Self: Sized,
+    {
+        Cheap(self)
+    }
+
     /// Invert result of the filter
     /// # Notes
     /// This method is used to create [`Invert`] filter
@@ -62,6 +91,37 @@ where
     async fn check(&self, bot: &Bot<Client>, update: &Update, context: &Context) -> bool {
         T::check(self, bot, update, context).await
     }
+
+    fn bot_commands(&self) -> Vec<BotCommand> {
+        T::bot_commands(self)
+    }
+
+    fn is_cheap(&self) -> bool {
+        T::is_cheap(self)
+    }
+}
+
+/// Wrapper to mark the filter as cheap, see [`Filter::is_cheap`] for more details
+#[derive(Debug, Clone)]
+pub struct Cheap<F>(pub F);
+
+#[async_trait]
+impl<Client, F> Filter<Client> for Cheap<F>
+where
+    F: Filter<Client>,
+    Client: Sync,
+{
+    async fn check(&self, bot: &Bot<Client>, update: &Update, context: &Context) -> bool {
+        self.0.check(bot, update, context).await
+    }
+
+    fn bot_commands(&self) -> Vec<BotCommand> {
+        self.0.bot_commands()
+    }
+
+    fn is_cheap(&self) -> bool {
+        true
+    }
 }
 
 /// To possible use function-like as filters
@@ -0,0 +1,153 @@
+use super::base::Filter;
+
+use crate::{
+    client::Bot,
+    context::Context,
+    types::{Update, UpdateKind},
+};
+
+use async_trait::async_trait;
+
+/// Filter for checking the data of the callback query.
+/// It's a lightweight alternative to the typed callback data, if the data is just a string.
+/// # Notes
+/// The filter doesn't pass callback queries without data and other update types
+#[derive(Debug, Default, Clone)]
+pub struct CallbackQuery {
+    /// List of data that must be equal to the callback data
+    data: Box<[Box<str>]>,
+    /// List of prefixes that must be at the beginning of the callback data
+    prefixes: Box<[Box<str>]>,
+}
+
+impl CallbackQuery {
+    /// Creates a new [`CallbackQuery`] filter with data that must be equal to the callback data
+    #[must_use]
+    pub fn eq(data: impl Into<Box<str>>) -> Self {
+        Self {
+            data: [data.into()].into(),
+            prefixes: [].into(),
+        }
+    }
+
+    /// Creates a new [`CallbackQuery`] filter with list of data, one of which must be equal to the callback data
+    #[must_use]
+    pub fn many<T, I>(data: I) -> Self
+    where
+        T: Into<Box<str>>,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            data: data.into_iter().map(Into::into).collect(),
+            prefixes: [].into(),
+        }
+    }
+
+    /// Creates a new [`CallbackQuery`] filter with prefix that must be at the beginning of the callback data
+    #[must_use]
+    pub fn starts_with(prefix: impl Into<Box<str>>) -> Self {
+        Self {
+            data: [].into(),
+            prefixes: [prefix.into()].into(),
+        }
+    }
+
+    /// Creates a new [`CallbackQuery`] filter with list of prefixes, one of which must be at the beginning of the callback data
+    #[must_use]
+    pub fn starts_with_many<T, I>(prefixes: I) -> Self
+    where
+        T: Into<Box<str>>,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            data: [].into(),
+            prefixes: prefixes.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl CallbackQuery {
+    #[must_use]
+    pub fn validate_data(&self, data: &str) -> bool {
+        self.data.iter().any(|allowed_data| &**allowed_data == data)
+            || self
+                .prefixes
+                .iter()
+                .any(|prefix| data.starts_with(&**prefix))
+    }
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for CallbackQuery {
+    async fn check(&self, _bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
+        match &update.kind {
+            UpdateKind::CallbackQuery(query) => query
+                .data
+                .as_deref()
+                .map_or(false, |data| self.validate_data(data)),
+            _ => false,
+        }
+    }
+
+    fn is_cheap(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::Reqwest, test};
+
+    #[test]
+    fn test_validate_data() {
+        let filter = CallbackQuery::starts_with("page:");
+
+        assert!(filter.validate_data("page:1"));
+        assert!(filter.validate_data("page:"));
+        assert!(!filter.validate_data("pages"));
+        assert!(!filter.validate_data("delete:1"));
+
+        let filter = CallbackQuery::eq("cancel");
+
+        assert!(filter.validate_data("cancel"));
+        assert!(!filter.validate_data("cancel:1"));
+
+        let filter = CallbackQuery::many(["yes", "no"]);
+
+        assert!(filter.validate_data("yes"));
+        assert!(filter.validate_data("no"));
+        assert!(!filter.validate_data("maybe"));
+
+        let filter = CallbackQuery::starts_with_many(["page:", "delete:"]);
+
+        assert!(filter.validate_data("delete:1"));
+        assert!(!filter.validate_data("cancel"));
+    }
+
+    #[tokio::test]
+    async fn test_check() {
+        let bot = Bot::<Reqwest>::default();
+        let context = Context::new();
+        let filter = CallbackQuery::starts_with("page:");
+
+        assert!(
+            filter
+                .check(&bot, &test::callback_query("page:1"), &context)
+                .await
+        );
+        assert!(
+            !filter
+                .check(&bot, &test::callback_query("cancel"), &context)
+                .await
+        );
+        // Callback query without data
+        let mut update = test::callback_query("page:1");
+        if let UpdateKind::CallbackQuery(query) = &mut update.kind {
+            query.data = None;
+        }
+        assert!(!filter.check(&bot, &update, &context).await);
+        // Other update types
+        assert!(!filter.check(&bot, &test::message("page:1"), &context).await);
+    }
+}
@@ -51,6 +51,10 @@ impl<Client> Filter<Client> for ChatType {
             None => false,
         }
     }
+
+    fn is_cheap(&self) -> bool {
+        true
+    }
 }
 
 #[cfg(test)]
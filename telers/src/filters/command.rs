@@ -5,7 +5,6 @@ use crate::{
     context::Context,
     errors::SessionErrorKind,
     extractors::FromContext,
-    methods::GetMe,
     types::{BotCommand, Update},
 };
 
@@ -54,6 +53,17 @@ impl From<Regex> for PatternType<'_> {
     }
 }
 
+/// Represents a mode of splitting command arguments
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum ArgsMode {
+    /// Arguments are split by spaces as is
+    #[default]
+    Split,
+    /// Arguments are split by whitespaces like in the shell, so `/ban "John Doe" spam` has `John Doe` and `spam` arguments.
+    /// Quotes inside arguments can be escaped by `\"`. If a quote isn't closed, then the rest of the text is one argument.
+    Quoted,
+}
+
 /// This filter checks if the message is a command.
 ///
 /// Filter accepts [`PatternType`] that represents a command pattern type for verification,
@@ -62,16 +72,27 @@ impl From<Regex> for PatternType<'_> {
 /// # Notes
 /// You can use parsed command using [`CommandObject`] struct in handler arguments,
 /// or get it from [`Context`] by `command` key.
+///
+/// Commands with descriptions (set by [`Command::description`] or passed as [`BotCommand`])
+/// can be collected by [`crate::Router::collect_commands`] to set them as the list of the bot commands.
 #[derive(Debug, Clone)]
 pub struct Command<'a> {
     /// List of commands ([`Cow`], [`BotCommand`] or compiled [`Regex`] patterns)
     commands: Box<[PatternType<'a>]>,
+    /// Commands passed as [`BotCommand`] objects, which are used to collect commands with own descriptions
+    bot_commands: Box<[BotCommand]>,
+    /// Description of the text commands, which is used to collect commands
+    description: Option<Cow<'a, str>>,
     /// Command prefix
     prefix: char,
     /// Ignore case sensitive
     ignore_case: bool,
     /// Ignore bot mention
     ignore_mention: bool,
+    /// Require bot mention
+    require_mention: bool,
+    /// Mode of splitting command arguments
+    args_mode: ArgsMode,
 }
 
 impl<'a> Command<'a> {
@@ -93,10 +114,20 @@ impl<'a> Command<'a> {
         CommandType: Into<PatternType<'a>>,
         Commands: IntoIterator<Item = CommandType>,
     {
+        let mut bot_commands = vec![];
+
+        let commands = commands.into_iter().map(|command| match command.into() {
+            PatternType::Object(command) => {
+                bot_commands.push(command.clone());
+
+                PatternType::Object(command)
+            }
+            pattern => pattern,
+        });
+
         let commands = if ignore_case {
             commands
-                .into_iter()
-                .map(|command| match command.into() {
+                .map(|command| match command {
                     PatternType::Text(text) => PatternType::Text(text.to_lowercase().into()),
                     // We convert object to text, because this pattern type is just a shortcut for text
                     PatternType::Object(command) => {
@@ -113,8 +144,7 @@ impl<'a> Command<'a> {
                 .collect()
         } else {
             commands
-                .into_iter()
-                .map(|command| match command.into() {
+                .map(|command| match command {
                     PatternType::Text(text) => PatternType::Text(text),
                     // We convert object to text, because this pattern type is just a shortcut for text
                     PatternType::Object(command) => PatternType::Text(command.command.into()),
@@ -131,9 +161,45 @@ impl<'a> Command<'a> {
 
         Self {
             commands,
+            bot_commands: bot_commands.into(),
+            description: None,
             prefix,
             ignore_case,
             ignore_mention,
+            require_mention: false,
+            args_mode: ArgsMode::default(),
+        }
+    }
+
+    /// Sets mode of splitting command arguments, see [`ArgsMode`]
+    #[must_use]
+    pub fn args_mode(self, val: ArgsMode) -> Self {
+        Self {
+            args_mode: val,
+            ..self
+        }
+    }
+
+    /// Sets whether the command must contain the bot mention, for example, `/help@bot_username`.
+    /// By default, commands without mention and with the mention of this bot are passed,
+    /// commands with mentions of other bots are never passed.
+    /// # Notes
+    /// This flag is ignored if the filter is created with `ignore_mention` flag
+    #[must_use]
+    pub fn require_mention(self, val: bool) -> Self {
+        Self {
+            require_mention: val,
+            ..self
+        }
+    }
+
+    /// Sets description of the text commands, which is used to collect commands by [`crate::Router::collect_commands`].
+    /// [`BotCommand`] patterns use their own descriptions.
+    #[must_use]
+    pub fn description(self, val: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            description: Some(val.into()),
+            ..self
         }
     }
 
@@ -192,9 +258,13 @@ impl Default for Command<'_> {
     fn default() -> Self {
         Self {
             commands: Box::new([]),
+            bot_commands: Box::new([]),
+            description: None,
             prefix: '/',
             ignore_case: false,
             ignore_mention: false,
+            require_mention: false,
+            args_mode: ArgsMode::default(),
         }
     }
 }
@@ -202,9 +272,12 @@ impl Default for Command<'_> {
 #[derive(Debug, Clone)]
 pub struct Builder<'a> {
     commands: Vec<PatternType<'a>>,
+    description: Option<Cow<'a, str>>,
     prefix: char,
     ignore_case: bool,
     ignore_mention: bool,
+    require_mention: bool,
+    args_mode: ArgsMode,
 }
 
 impl<'a> Builder<'a> {
@@ -237,6 +310,15 @@ impl<'a> Builder<'a> {
         }
     }
 
+    /// Description of the text commands, see [`Command::description`]
+    #[must_use]
+    pub fn description(self, val: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            description: Some(val.into()),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn prefix(self, val: char) -> Self {
         Self {
@@ -261,14 +343,39 @@ impl<'a> Builder<'a> {
         }
     }
 
+    /// Require bot mention, see [`Command::require_mention`]
+    #[must_use]
+    pub fn require_mention(self, val: bool) -> Self {
+        Self {
+            require_mention: val,
+            ..self
+        }
+    }
+
+    /// Mode of splitting command arguments, see [`ArgsMode`]
+    #[must_use]
+    pub fn args_mode(self, val: ArgsMode) -> Self {
+        Self {
+            args_mode: val,
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn build(self) -> Command<'a> {
-        Command::new(
+        let command = Command::new(
             self.commands,
             self.prefix,
             self.ignore_case,
             self.ignore_mention,
         )
+        .require_mention(self.require_mention)
+        .args_mode(self.args_mode);
+
+        match self.description {
+            Some(description) => command.description(description),
+            None => command,
+        }
     }
 }
 
@@ -277,22 +384,74 @@ impl Default for Builder<'_> {
     fn default() -> Self {
         Self {
             commands: vec![],
+            description: None,
             prefix: '/',
             ignore_case: false,
             ignore_mention: false,
+            require_mention: false,
+            args_mode: ArgsMode::default(),
         }
     }
 }
 
 impl Command<'_> {
+    /// Gets bot commands handled by the filter.
+    /// # Notes
+    /// Only commands with `/` prefix are returned, because Telegram supports only them in the list of the bot commands.
+    /// Text commands are returned only if the description is set, regex patterns are skipped.
+    #[must_use]
+    pub fn bot_commands(&self) -> Vec<BotCommand> {
+        if self.prefix != '/' {
+            return vec![];
+        }
+
+        let mut bot_commands: Vec<BotCommand> = vec![];
+
+        for pattern in &*self.commands {
+            let PatternType::Text(command) = pattern else {
+                continue;
+            };
+
+            if bot_commands
+                .iter()
+                .any(|bot_command| bot_command.command == *command)
+            {
+                continue;
+            }
+
+            // Text command can be converted from `BotCommand` object, so we use its own description
+            let object = self.bot_commands.iter().find(|bot_command| {
+                if self.ignore_case {
+                    bot_command.command.to_lowercase() == *command
+                } else {
+                    bot_command.command == *command
+                }
+            });
+
+            if let Some(object) = object {
+                bot_commands.push(BotCommand::new(
+                    command.as_ref(),
+                    object.description.as_str(),
+                ));
+            } else if let Some(ref description) = self.description {
+                bot_commands.push(BotCommand::new(command.as_ref(), description.as_ref()));
+            }
+        }
+
+        bot_commands
+    }
+
     #[must_use]
     pub fn validate_prefix(&self, command: &CommandObject) -> bool {
         command.prefix == self.prefix
     }
 
+    /// Checks that the command is addressed to this bot:
+    /// commands with mentions of other bots aren't passed, commands without mention are passed only if mention isn't required.
     /// # Errors
     /// If error occurred in the process of sending request to the Telegram API or parsing response
-    #[allow(clippy::missing_panics_doc)]
+    /// # Notes
+    /// Bot username is cached in the bot, so `getMe` request is sent only once, check [`Bot::me`]
     pub async fn validate_mention(
         &self,
         command: &CommandObject,
@@ -301,12 +460,12 @@ impl Command<'_> {
         if self.ignore_mention {
             Ok(true)
         } else if let Some(ref mention) = command.mention {
-            bot.send(GetMe {}).await.map(|user| {
-                // `unwrap` is safe here, because bot always has username
-                user.username.unwrap().eq(mention)
-            })
+            // Usernames are case-insensitive
+            bot.username()
+                .await
+                .map(|username| username.eq_ignore_ascii_case(mention))
         } else {
-            Ok(true)
+            Ok(!self.require_mention)
         }
     }
 
@@ -373,14 +532,34 @@ pub struct CommandObject {
 
 impl CommandObject {
     /// Extracts [`CommandObject`] from text
+    /// # Notes
+    /// Arguments are split by spaces as is, use [`CommandObject::extract_with_args_mode`] to change it
     #[must_use]
     pub fn extract(text: &str) -> Option<Self> {
-        let result: Box<[&str]> = text.trim().split(' ').collect();
-        let full_command = result[0];
-        let args = result[1..]
-            .iter()
-            .map(|arg| (*arg).to_owned().into_boxed_str())
-            .collect();
+        Self::extract_with_args_mode(text, ArgsMode::Split)
+    }
+
+    /// Extracts [`CommandObject`] from text with the mode of splitting arguments
+    #[must_use]
+    pub fn extract_with_args_mode(text: &str, args_mode: ArgsMode) -> Option<Self> {
+        let text = text.trim();
+        let (full_command, args) = match args_mode {
+            ArgsMode::Split => {
+                let result: Box<[&str]> = text.split(' ').collect();
+
+                (
+                    result[0],
+                    result[1..]
+                        .iter()
+                        .map(|arg| (*arg).to_owned().into_boxed_str())
+                        .collect(),
+                )
+            }
+            ArgsMode::Quoted => match text.split_once(char::is_whitespace) {
+                Some((full_command, args)) => (full_command, split_quoted_args(args)),
+                None => (text, Box::default()),
+            },
+        };
 
         let mut full_command_chars = full_command.chars();
 
@@ -421,6 +600,47 @@ impl CommandObject {
     }
 }
 
+/// Splits text to arguments by whitespaces like in the shell, see [`ArgsMode::Quoted`]
+#[must_use]
+pub fn split_quoted_args(text: &str) -> Box<[Box<str>]> {
+    let mut args = vec![];
+    let mut arg = String::new();
+    // Flag for arguments in quotes, which can be empty
+    let mut has_arg = false;
+    let mut in_quotes = false;
+
+    let mut chars = text.chars();
+    while let Some(char) = chars.next() {
+        match char {
+            '\\' => match chars.next() {
+                Some(escaped @ ('"' | '\\')) => arg.push(escaped),
+                Some(other) => {
+                    arg.push('\\');
+                    arg.push(other);
+                }
+                None => arg.push('\\'),
+            },
+            '"' => in_quotes = !in_quotes,
+            char if char.is_whitespace() && !in_quotes => {
+                if has_arg {
+                    args.push(std::mem::take(&mut arg).into_boxed_str());
+                }
+                has_arg = false;
+                continue;
+            }
+            char => arg.push(char),
+        }
+        has_arg = true;
+    }
+
+    // If a quote isn't closed, then the rest of the text is one argument
+    if has_arg {
+        args.push(arg.into_boxed_str());
+    }
+
+    args.into()
+}
+
 #[async_trait]
 impl<Client> Filter<Client> for Command<'_>
 where
@@ -434,7 +654,7 @@ where
         let Some(text) = message.text_or_caption() else {
             return false;
         };
-        let Some(command) = CommandObject::extract(text) else {
+        let Some(command) = CommandObject::extract_with_args_mode(text, self.args_mode) else {
             return false;
         };
 
@@ -455,6 +675,10 @@ where
             }
         }
     }
+
+    fn bot_commands(&self) -> Vec<BotCommand> {
+        Command::bot_commands(self)
+    }
 }
 
 #[cfg(test)]
@@ -536,6 +760,50 @@ mod tests {
         assert!(command_obj.args == Box::new(["два".into()]) as Box<_>);
     }
 
+    #[test]
+    fn test_command_extract_quoted_args() {
+        let extract = |text| {
+            CommandObject::extract_with_args_mode(text, ArgsMode::Quoted)
+                .unwrap()
+                .args
+        };
+
+        assert_eq!(
+            &*extract(r#"/ban "John Doe" spam"#),
+            [Box::from("John Doe"), Box::from("spam")]
+        );
+        assert_eq!(
+            &*extract("/ban   John\tDoe  "),
+            [Box::from("John"), Box::from("Doe")]
+        );
+        assert_eq!(
+            &*extract(r#"/say "He said \"hi\"" C:\dir"#),
+            [Box::from(r#"He said "hi""#), Box::from(r"C:\dir")]
+        );
+        assert_eq!(&*extract(r#"/say "" x"#), [Box::from(""), Box::from("x")]);
+        // Unterminated quote
+        assert_eq!(
+            &*extract(r#"/ban spam "John Doe"#),
+            [Box::from("spam"), Box::from("John Doe")]
+        );
+        assert!(extract("/start").is_empty());
+
+        // Raw splitting is used by default
+        assert_eq!(
+            &*CommandObject::extract(r#"/ban "John Doe""#).unwrap().args,
+            [Box::from("\"John"), Box::from("Doe\"")]
+        );
+
+        let command_obj = CommandObject::extract_with_args_mode(
+            "/ban@bot_username \"John Doe\"",
+            ArgsMode::Quoted,
+        )
+        .unwrap();
+        assert_eq!(command_obj.command.as_ref(), "ban");
+        assert_eq!(command_obj.mention.as_deref(), Some("bot_username"));
+        assert_eq!(&*command_obj.args, [Box::from("John Doe")]);
+    }
+
     #[test]
     #[should_panic]
     fn test_command_extract_panic() {
@@ -620,5 +888,133 @@ mod tests {
         assert!(!command.validate_command(&command_obj));
     }
 
-    // TODO: Add tests for `validate_mention` method
+    #[test]
+    fn test_bot_commands() {
+        let command = Command::many(["start", "help"]).description("Start the bot");
+        assert_eq!(
+            command.bot_commands(),
+            [
+                BotCommand::new("start", "Start the bot"),
+                BotCommand::new("help", "Start the bot"),
+            ]
+        );
+
+        let command = Command::builder()
+            .command(BotCommand::new("Start", "Start the bot"))
+            .command("help")
+            .command(Regex::new(r"item_\d+").unwrap())
+            .description("Show help")
+            .ignore_case(true)
+            .build();
+        assert_eq!(
+            command.bot_commands(),
+            [
+                BotCommand::new("start", "Start the bot"),
+                BotCommand::new("help", "Show help"),
+            ]
+        );
+
+        // Text commands without description aren't returned
+        assert!(Command::one("start").bot_commands().is_empty());
+        // Commands with custom prefix aren't supported by Telegram
+        assert!(Command::one_with_prefix("start", '!')
+            .description("Start the bot")
+            .bot_commands()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_validate_mention() {
+        use crate::{methods::GetMe, test::MockBot};
+
+        let mock = MockBot::new();
+        mock.push_result::<GetMe>(serde_json::json!({
+            "id": 1,
+            "is_bot": true,
+            "first_name": "Bot",
+            "username": "bot_username",
+        }));
+
+        let bot = mock.bot();
+        let command = Command::one("start");
+
+        let command_obj = CommandObject::extract("/start@bot_username").unwrap();
+        assert!(command.validate_mention(&command_obj, &bot).await.unwrap());
+
+        let command_obj = CommandObject::extract("/start@other_bot").unwrap();
+        assert!(!command.validate_mention(&command_obj, &bot).await.unwrap());
+
+        let command_obj = CommandObject::extract("/start").unwrap();
+        assert!(command.validate_mention(&command_obj, &bot).await.unwrap());
+
+        let command = Command::builder()
+            .command("start")
+            .ignore_mention(true)
+            .build();
+        let command_obj = CommandObject::extract("/start@other_bot").unwrap();
+        assert!(command.validate_mention(&command_obj, &bot).await.unwrap());
+
+        // Bot username is cached, so `getMe` is called only once
+        assert_eq!(mock.calls().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_check_mention_in_group() {
+        use crate::{
+            methods::GetMe,
+            test::MockBot,
+            types::{Chat, Group, Message, MessageText, UpdateKind},
+        };
+
+        fn group_message(text: &str) -> Update {
+            Update {
+                kind: UpdateKind::Message(Message::Text(Box::new(MessageText {
+                    chat: Chat::Group(Box::new(Group {
+                        id: -1,
+                        title: "Group".into(),
+                    })),
+                    text: text.into(),
+                    ..Default::default()
+                }))),
+                ..Default::default()
+            }
+        }
+
+        let mock = MockBot::new();
+        mock.push_result::<GetMe>(serde_json::json!({
+            "id": 1,
+            "is_bot": true,
+            "first_name": "Bot",
+            "username": "MyBot",
+        }));
+
+        let bot = mock.bot();
+        let context = Context::new();
+
+        let command = Command::one("help");
+        assert!(command.check(&bot, &group_message("/help"), &context).await);
+        assert!(
+            command
+                .check(&bot, &group_message("/help@mybot"), &context)
+                .await
+        );
+        assert!(
+            !command
+                .check(&bot, &group_message("/help@otherbot"), &context)
+                .await
+        );
+
+        let command = Command::one("help").require_mention(true);
+        assert!(!command.check(&bot, &group_message("/help"), &context).await);
+        assert!(
+            command
+                .check(&bot, &group_message("/help@MyBot"), &context)
+                .await
+        );
+        assert!(
+            !command
+                .check(&bot, &group_message("/help@otherbot"), &context)
+                .await
+        );
+    }
 }
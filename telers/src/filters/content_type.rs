@@ -1,13 +1,36 @@
 use super::base::Filter;
 
-use crate::{client::Bot, context::Context, enums::ContentType as ContentTypeEnum, types::Update};
+use crate::{
+    client::Bot,
+    context::Context,
+    enums::ContentType as ContentTypeEnum,
+    types::{Message, Update},
+};
 
 use async_trait::async_trait;
 
-/// Filter for checking the type of content
+/// Content types of messages with media
+pub const MEDIA_CONTENT_TYPES: [ContentTypeEnum; 9] = [
+    ContentTypeEnum::Animation,
+    ContentTypeEnum::Audio,
+    ContentTypeEnum::Document,
+    ContentTypeEnum::PaidMedia,
+    ContentTypeEnum::Photo,
+    ContentTypeEnum::Sticker,
+    ContentTypeEnum::Video,
+    ContentTypeEnum::VideoNote,
+    ContentTypeEnum::Voice,
+];
+
+/// Filter for checking the type of content.
+/// # Notes
+/// Each message has exactly one content type, so the allowed content types are checked with "any" semantics.
+/// To require additional conditions for the content, for example, photo with caption,
+/// use [`ContentType::with_caption`] and [`ContentType::without_caption`] or combine filters with [`Filter::and`].
 #[derive(Debug, Clone)]
 pub struct ContentType {
     content_types: Box<[ContentTypeEnum]>,
+    caption: Option<bool>,
 }
 
 impl ContentType {
@@ -18,6 +41,7 @@ impl ContentType {
     pub fn one(content_type: impl Into<ContentTypeEnum>) -> Self {
         Self {
             content_types: [content_type.into()].into(),
+            caption: None,
         }
     }
 
@@ -32,6 +56,37 @@ impl ContentType {
     {
         Self {
             content_types: content_types.into_iter().map(Into::into).collect(),
+            caption: None,
+        }
+    }
+
+    /// Creates a new [`ContentType`] filter, which allows messages with media, see [`MEDIA_CONTENT_TYPES`]
+    #[must_use]
+    pub fn media() -> Self {
+        Self::many(MEDIA_CONTENT_TYPES)
+    }
+
+    /// Creates a new [`ContentType`] filter, which allows only text messages
+    #[must_use]
+    pub fn text_only() -> Self {
+        Self::one(ContentTypeEnum::Text)
+    }
+
+    /// Requires the message to have a caption in addition to one of the allowed content types
+    #[must_use]
+    pub fn with_caption(self) -> Self {
+        Self {
+            caption: Some(true),
+            ..self
+        }
+    }
+
+    /// Requires the message to have no caption in addition to one of the allowed content types
+    #[must_use]
+    pub fn without_caption(self) -> Self {
+        Self {
+            caption: Some(false),
+            ..self
         }
     }
 }
@@ -43,6 +98,14 @@ impl ContentType {
             .iter()
             .any(|allowed_content_type| allowed_content_type == &content_type)
     }
+
+    #[must_use]
+    pub fn validate_message(&self, message: &Message) -> bool {
+        self.validate_content_type(ContentTypeEnum::from(message))
+            && self
+                .caption
+                .map_or(true, |caption| message.caption().is_some() == caption)
+    }
 }
 
 #[async_trait]
@@ -52,7 +115,11 @@ impl<Client> Filter<Client> for ContentType {
             return false;
         };
 
-        self.validate_content_type(ContentTypeEnum::from(message))
+        self.validate_message(message)
+    }
+
+    fn is_cheap(&self) -> bool {
+        true
     }
 }
 
@@ -60,6 +127,8 @@ impl<Client> Filter<Client> for ContentType {
 mod tests {
     use super::*;
 
+    use crate::types::Message;
+
     #[tokio::test]
     async fn test_content_type() {
         let filter = ContentType::many([ContentTypeEnum::Text, ContentTypeEnum::Photo]);
@@ -68,4 +137,139 @@ mod tests {
         assert!(filter.validate_content_type(ContentTypeEnum::Photo));
         assert!(!filter.validate_content_type(ContentTypeEnum::Audio));
     }
+
+    #[test]
+    fn test_caption() {
+        let message = |json: serde_json::Value| -> Message {
+            let mut json = json;
+            let fields = json.as_object_mut().unwrap();
+            fields.insert("message_id".to_owned(), 1.into());
+            fields.insert("date".to_owned(), 0.into());
+            fields.insert(
+                "chat".to_owned(),
+                serde_json::json!({"id": 1, "type": "private"}),
+            );
+
+            serde_json::from_value(json).unwrap()
+        };
+        let photo = serde_json::json!([{
+            "file_id": "id",
+            "file_unique_id": "unique_id",
+            "width": 1,
+            "height": 1,
+        }]);
+
+        let plain_photo = message(serde_json::json!({"photo": photo}));
+        let photo_with_caption = message(serde_json::json!({"photo": photo, "caption": "text"}));
+        let text = message(serde_json::json!({"text": "text"}));
+
+        let filter = ContentType::one(ContentTypeEnum::Photo);
+        assert!(filter.validate_message(&plain_photo));
+        assert!(filter.validate_message(&photo_with_caption));
+
+        let filter = ContentType::one(ContentTypeEnum::Photo).with_caption();
+        assert!(!filter.validate_message(&plain_photo));
+        assert!(filter.validate_message(&photo_with_caption));
+
+        let filter = ContentType::one(ContentTypeEnum::Photo).without_caption();
+        assert!(filter.validate_message(&plain_photo));
+        assert!(!filter.validate_message(&photo_with_caption));
+
+        let filter = ContentType::media();
+        assert!(filter.validate_message(&plain_photo));
+        assert!(!filter.validate_message(&text));
+
+        let filter = ContentType::text_only();
+        assert!(!filter.validate_message(&plain_photo));
+        assert!(filter.validate_message(&text));
+    }
+
+    #[test]
+    fn test_content_type_from_message() {
+        let chat = serde_json::json!({
+            "id": -1,
+            "title": "test",
+            "type": "channel",
+        });
+        let cases = [
+            (
+                ContentTypeEnum::PaidMedia,
+                serde_json::json!({
+                    "paid_media": {
+                        "type": "preview",
+                    },
+                }),
+            ),
+            (
+                ContentTypeEnum::Story,
+                serde_json::json!({
+                    "story": {
+                        "chat": chat,
+                        "id": 1,
+                    },
+                }),
+            ),
+            (
+                ContentTypeEnum::GiveawayCreated,
+                serde_json::json!({
+                    "giveaway_created": {},
+                }),
+            ),
+            (
+                ContentTypeEnum::Giveaway,
+                serde_json::json!({
+                    "giveaway": {
+                        "chats": [chat],
+                        "winners_selection_date": 0,
+                        "winner_count": 1,
+                    },
+                }),
+            ),
+            (
+                ContentTypeEnum::GiveawayWinners,
+                serde_json::json!({
+                    "giveaway_winners": {
+                        "chat": chat,
+                        "giveaway_message_id": 1,
+                        "winners_selection_date": 0,
+                        "winner_count": 1,
+                        "winners": [{
+                            "id": 1,
+                            "is_bot": false,
+                            "first_name": "test",
+                        }],
+                    },
+                }),
+            ),
+            (
+                ContentTypeEnum::GiveawayCompleted,
+                serde_json::json!({
+                    "giveaway_completed": {
+                        "winner_count": 1,
+                    },
+                }),
+            ),
+            (
+                ContentTypeEnum::ChatBoostAdded,
+                serde_json::json!({
+                    "boost_added": {
+                        "boost_count": 1,
+                    },
+                }),
+            ),
+        ];
+
+        for (content_type, mut json) in cases {
+            let fields = json.as_object_mut().unwrap();
+            fields.insert("message_id".to_owned(), 1.into());
+            fields.insert("date".to_owned(), 0.into());
+            fields.insert("chat".to_owned(), chat.clone());
+
+            let message: Message = serde_json::from_value(json).unwrap();
+
+            assert_eq!(ContentTypeEnum::from(&message), content_type);
+            assert!(ContentType::one(content_type)
+                .validate_content_type(ContentTypeEnum::from(&message)));
+        }
+    }
 }
@@ -0,0 +1,134 @@
+use super::base::Filter;
+
+use crate::{
+    client::Bot,
+    context::Context,
+    enums::MessageOriginType,
+    types::{Message, Update},
+};
+
+use async_trait::async_trait;
+
+/// Filter for checking that the message is forwarded.
+/// Passes if the message has origin of one of the specified types.
+#[derive(Debug, Clone)]
+pub struct Forwarded {
+    origin_types: Box<[MessageOriginType]>,
+}
+
+impl Forwarded {
+    /// Creates a new [`Forwarded`] filter, which passes forwarded messages with any origin
+    #[must_use]
+    pub fn any() -> Self {
+        Self::many(MessageOriginType::all())
+    }
+
+    /// Creates a new [`Forwarded`] filter with one allowed origin type.
+    /// # Notes
+    /// You can use [`MessageOriginType`] or its string representation.
+    #[must_use]
+    pub fn one(origin_type: impl Into<MessageOriginType>) -> Self {
+        Self {
+            origin_types: [origin_type.into()].into(),
+        }
+    }
+
+    /// Creates a new [`Forwarded`] filter with many allowed origin types.
+    /// # Notes
+    /// You can use [`MessageOriginType`] or its string representation.
+    #[must_use]
+    pub fn many<T, I>(origin_types: I) -> Self
+    where
+        T: Into<MessageOriginType>,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            origin_types: origin_types.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Forwarded {
+    #[must_use]
+    pub fn validate_origin_type(&self, origin_type: MessageOriginType) -> bool {
+        self.origin_types.contains(&origin_type)
+    }
+
+    #[must_use]
+    pub fn validate_message(&self, message: &Message) -> bool {
+        message
+            .forward_origin()
+            .map_or(false, |origin| self.validate_origin_type(origin.into()))
+    }
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for Forwarded {
+    async fn check(&self, _bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
+        let Some(message) = update.message() else {
+            return false;
+        };
+
+        self.validate_message(message)
+    }
+
+    fn is_cheap(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::types::{message::Text, MessageOrigin};
+
+    use serde_json::json;
+
+    fn message(forward_origin: Option<MessageOrigin>) -> Message {
+        Message::Text(Box::new(Text {
+            forward_origin,
+            ..Default::default()
+        }))
+    }
+
+    #[test]
+    fn test_forwarded() {
+        let channel = message(Some(
+            serde_json::from_value(json!({
+                "type": "channel",
+                "date": 0,
+                "chat": {"id": -100, "type": "channel", "title": "Channel"},
+                "message_id": 1,
+            }))
+            .unwrap(),
+        ));
+        let hidden_user = message(Some(
+            serde_json::from_value(json!({
+                "type": "hidden_user",
+                "date": 0,
+                "sender_user_name": "User",
+            }))
+            .unwrap(),
+        ));
+        let not_forwarded = message(None);
+
+        let filter = Forwarded::any();
+
+        assert!(filter.validate_message(&channel));
+        assert!(filter.validate_message(&hidden_user));
+        assert!(!filter.validate_message(&not_forwarded));
+
+        let filter = Forwarded::one(MessageOriginType::Channel);
+
+        assert!(filter.validate_message(&channel));
+        assert!(!filter.validate_message(&hidden_user));
+        assert!(!filter.validate_message(&not_forwarded));
+
+        let filter = Forwarded::many([MessageOriginType::User, MessageOriginType::HiddenUser]);
+
+        assert!(!filter.validate_message(&channel));
+        assert!(filter.validate_message(&hidden_user));
+        assert!(!filter.validate_message(&not_forwarded));
+    }
+}
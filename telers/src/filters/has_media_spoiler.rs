@@ -0,0 +1,87 @@
+use super::base::Filter;
+
+use crate::{
+    client::Bot,
+    context::Context,
+    types::{Message, Update},
+};
+
+use async_trait::async_trait;
+
+/// Filter for checking that the media of the message is covered with a spoiler animation.
+/// Passes for photo, video and animation messages with the spoiler, otherwise doesn't pass.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HasMediaSpoiler;
+
+impl HasMediaSpoiler {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl HasMediaSpoiler {
+    #[must_use]
+    pub fn validate_message(&self, message: &Message) -> bool {
+        message.has_media_spoiler().unwrap_or(false)
+    }
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for HasMediaSpoiler {
+    async fn check(&self, _bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
+        let Some(message) = update.message() else {
+            return false;
+        };
+
+        self.validate_message(message)
+    }
+
+    fn is_cheap(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    fn message(value: serde_json::Value) -> Message {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_has_media_spoiler() {
+        let photo =
+            json!([{"file_id": "id", "file_unique_id": "unique_id", "width": 1, "height": 1}]);
+
+        let spoilered = message(json!({
+            "message_id": 1,
+            "date": 0,
+            "chat": {"id": 1, "type": "private"},
+            "photo": photo,
+            "has_media_spoiler": true,
+        }));
+        let not_spoilered = message(json!({
+            "message_id": 1,
+            "date": 0,
+            "chat": {"id": 1, "type": "private"},
+            "photo": photo,
+        }));
+        let text = message(json!({
+            "message_id": 1,
+            "date": 0,
+            "chat": {"id": 1, "type": "private"},
+            "text": "text",
+        }));
+
+        let filter = HasMediaSpoiler::new();
+
+        assert!(matches!(spoilered, Message::Photo(_)));
+        assert!(filter.validate_message(&spoilered));
+        assert!(!filter.validate_message(&not_spoilered));
+        assert!(!filter.validate_message(&text));
+    }
+}
@@ -0,0 +1,67 @@
+use super::base::Filter;
+
+use crate::{
+    client::Bot,
+    context::Context,
+    types::{Message, Update},
+};
+
+use async_trait::async_trait;
+
+/// Filter for checking that the message can't be forwarded and saved.
+/// Passes if the message has protected content, otherwise doesn't pass.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HasProtectedContent;
+
+impl HasProtectedContent {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl HasProtectedContent {
+    #[must_use]
+    pub fn validate_message(&self, message: &Message) -> bool {
+        message.has_protected_content().unwrap_or(false)
+    }
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for HasProtectedContent {
+    async fn check(&self, _bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
+        let Some(message) = update.message() else {
+            return false;
+        };
+
+        self.validate_message(message)
+    }
+
+    fn is_cheap(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::message::Text;
+
+    #[test]
+    fn test_has_protected_content() {
+        let filter = HasProtectedContent::new();
+
+        let protected = Message::Text(Box::new(Text {
+            has_protected_content: Some(true),
+            ..Default::default()
+        }));
+        let not_protected = Message::Text(Box::new(Text {
+            has_protected_content: Some(false),
+            ..Default::default()
+        }));
+
+        assert!(filter.validate_message(&protected));
+        assert!(!filter.validate_message(&not_protected));
+        assert!(!filter.validate_message(&Message::Text(Box::default())));
+    }
+}
@@ -11,7 +11,11 @@
 
 use super::base::Filter;
 
-use crate::{client::Bot, context::Context, types::Update};
+use crate::{
+    client::Bot,
+    context::Context,
+    types::{BotCommand, Update},
+};
 
 use async_trait::async_trait;
 use std::sync::Arc;
@@ -65,12 +69,22 @@ impl<Client> Invert<Client> {
     }
 }
 
+/// Iterate over the filters, cheap filters first, see [`Filter#ordering`] for more details
+fn ordered<Client>(
+    filters: &[Arc<dyn Filter<Client>>],
+) -> impl Iterator<Item = &Arc<dyn Filter<Client>>> {
+    filters
+        .iter()
+        .filter(|filter| filter.is_cheap())
+        .chain(filters.iter().filter(|filter| !filter.is_cheap()))
+}
+
 impl<Client> And<Client>
 where
     Client: Sync,
 {
     pub async fn validate(&self, bot: &Bot<Client>, update: &Update, context: &Context) -> bool {
-        for filter in &self.filters {
+        for filter in ordered(&self.filters) {
             if !filter.check(bot, update, context).await {
                 return false;
             }
@@ -85,7 +99,7 @@ where
     Client: Sync,
 {
     pub async fn validate(&self, bot: &Bot<Client>, update: &Update, context: &Context) -> bool {
-        for filter in &self.filters {
+        for filter in ordered(&self.filters) {
             if filter.check(bot, update, context).await {
                 return true;
             }
@@ -115,12 +129,39 @@ macro_rules! impl_filter {
             async fn check(&self, bot: &Bot<Client>, update: &Update, context: &Context) -> bool {
                 self.validate(bot, update, context).await
             }
+
+            fn is_cheap(&self) -> bool {
+                self.filter.is_cheap()
+            }
+        }
+    };
+    // Bot commands of the combined filters are handled by the filter too
+    ($name:ident, with_bot_commands) => {
+        #[async_trait]
+        impl<Client> Filter<Client> for $name<Client>
+        where
+            Client: Sync,
+        {
+            async fn check(&self, bot: &Bot<Client>, update: &Update, context: &Context) -> bool {
+                self.validate(bot, update, context).await
+            }
+
+            fn bot_commands(&self) -> Vec<BotCommand> {
+                self.filters
+                    .iter()
+                    .flat_map(|filter| filter.bot_commands())
+                    .collect()
+            }
+
+            fn is_cheap(&self) -> bool {
+                self.filters.iter().all(|filter| filter.is_cheap())
+            }
         }
     };
 }
 
-impl_filter!(And);
-impl_filter!(Or);
+impl_filter!(And, with_bot_commands);
+impl_filter!(Or, with_bot_commands);
 impl_filter!(Invert);
 
 #[cfg(test)]
@@ -130,6 +171,8 @@ mod tests {
     use crate::{
         client::{Bot, Reqwest},
         context::Context,
+        enums::ChatType as ChatTypeEnum,
+        filters::ChatType as ChatTypeFilter,
         types::Update,
     };
 
@@ -169,6 +212,34 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_and_cheap_first() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let bot = Bot::<Reqwest>::default();
+        let context = Context::new();
+        let update = Update::default();
+
+        let expensive_called = Arc::new(AtomicBool::new(false));
+        let filter = And::new({
+            let expensive_called = Arc::clone(&expensive_called);
+            move |_: &Bot, _: &Update, _: &Context| {
+                let expensive_called = Arc::clone(&expensive_called);
+                async move {
+                    expensive_called.store(true, Ordering::SeqCst);
+                    true
+                }
+            }
+        })
+        .and((|_: &Bot, _: &Update, _: &Context| async { false }).cheap());
+
+        assert!(!filter.is_cheap());
+        assert!(!filter.validate(&bot, &update, &context).await);
+        assert!(!expensive_called.load(Ordering::SeqCst));
+
+        assert!(Invert::<Reqwest>::new(ChatTypeFilter::one(ChatTypeEnum::Private)).is_cheap());
+    }
+
     #[tokio::test]
     async fn test_or() {
         let bot = Bot::<Reqwest>::default();
@@ -0,0 +1,167 @@
+use super::base::Filter;
+
+use crate::{
+    client::Bot,
+    context::Context,
+    types::{Message, MessageEntityKind, Update},
+};
+
+use async_trait::async_trait;
+use std::mem::discriminant;
+
+/// Filter for checking the kinds of message entities.
+/// Passes if the message text or caption contains at least one entity of the specified kinds.
+/// # Notes
+/// Entities are compared only by their kind, so data of entities like [`MessageEntityKind::TextLink`] (URL)
+/// or [`MessageEntityKind::Pre`] (language) is ignored.
+#[derive(Debug, Clone)]
+pub struct MessageEntity {
+    kinds: Box<[MessageEntityKind]>,
+}
+
+impl MessageEntity {
+    /// Creates a new [`MessageEntity`] filter with one allowed entity kind
+    #[must_use]
+    pub fn one(kind: impl Into<MessageEntityKind>) -> Self {
+        Self {
+            kinds: [kind.into()].into(),
+        }
+    }
+
+    /// Creates a new [`MessageEntity`] filter with many allowed entity kinds
+    #[must_use]
+    pub fn many<T, I>(kinds: I) -> Self
+    where
+        T: Into<MessageEntityKind>,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            kinds: kinds.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl MessageEntity {
+    /// Checks the entity kind is one of the allowed kinds, data of the entity is ignored
+    #[must_use]
+    pub fn validate_kind(&self, kind: &MessageEntityKind) -> bool {
+        self.kinds
+            .iter()
+            .any(|allowed_kind| discriminant(allowed_kind) == discriminant(kind))
+    }
+
+    /// Checks entities of the message text or caption
+    #[must_use]
+    pub fn validate_message(&self, message: &Message) -> bool {
+        let Some(entities) = message.entities() else {
+            return false;
+        };
+
+        entities
+            .iter()
+            .any(|entity| self.validate_kind(&entity.kind))
+    }
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for MessageEntity {
+    async fn check(&self, _bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
+        let Some(message) = update.message() else {
+            return false;
+        };
+
+        self.validate_message(message)
+    }
+
+    fn is_cheap(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::types::{message::Text, MessageEntity as MessageEntityType, TextLinkMessageEntity};
+
+    #[test]
+    fn test_validate_kind() {
+        let filter = MessageEntity::many([
+            MessageEntityKind::Url,
+            MessageEntityKind::TextLink(TextLinkMessageEntity::new("https://example.com")),
+        ]);
+
+        assert!(filter.validate_kind(&MessageEntityKind::Url));
+        assert!(
+            filter.validate_kind(&MessageEntityKind::TextLink(TextLinkMessageEntity::new(
+                "https://telegram.org"
+            )))
+        );
+        assert!(!filter.validate_kind(&MessageEntityKind::Mention));
+    }
+
+    #[test]
+    fn test_validate_message_url() {
+        let filter = MessageEntity::one(MessageEntityKind::Url);
+
+        let message = Message::Text(Box::new(Text {
+            text: "https://example.com".into(),
+            entities: Some([MessageEntityType::new_url(0, 19)].into()),
+            ..Default::default()
+        }));
+        assert!(filter.validate_message(&message));
+
+        let message = Message::Text(Box::new(Text {
+            text: "@username".into(),
+            entities: Some([MessageEntityType::new_mention(0, 9)].into()),
+            ..Default::default()
+        }));
+        assert!(!filter.validate_message(&message));
+    }
+
+    #[test]
+    fn test_validate_message_mention() {
+        let filter = MessageEntity::many([MessageEntityKind::Mention, MessageEntityKind::Hashtag]);
+
+        let message = Message::Text(Box::new(Text {
+            text: "Hello, @username".into(),
+            entities: Some([MessageEntityType::new_mention(7, 9)].into()),
+            ..Default::default()
+        }));
+        assert!(filter.validate_message(&message));
+
+        let message: Message = serde_json::from_value(serde_json::json!({
+            "message_id": 1,
+            "date": 0,
+            "chat": {
+                "id": 1,
+                "type": "private",
+                "first_name": "test",
+            },
+            "photo": [],
+            "caption": "Hello, @username",
+            "caption_entities": [{"type": "mention", "offset": 7, "length": 9}],
+        }))
+        .unwrap();
+        assert!(matches!(message, Message::Photo(_)));
+        assert!(filter.validate_message(&message));
+    }
+
+    #[test]
+    fn test_validate_message_without_entities() {
+        let filter = MessageEntity::many([MessageEntityKind::Url, MessageEntityKind::Mention]);
+
+        let message = Message::Text(Box::new(Text {
+            text: "test".into(),
+            ..Default::default()
+        }));
+        assert!(!filter.validate_message(&message));
+
+        let message = Message::Text(Box::new(Text {
+            text: "test".into(),
+            entities: Some([].into()),
+            ..Default::default()
+        }));
+        assert!(!filter.validate_message(&message));
+    }
+}
@@ -0,0 +1,217 @@
+use super::base::Filter;
+
+use crate::{
+    client::Bot,
+    context::Context,
+    types::{Message, Update, UpdateKind},
+};
+
+use async_trait::async_trait;
+
+/// Conditions on the invoice payload and the currency, which are shared by payment filters
+#[derive(Debug, Default, Clone)]
+struct Conditions {
+    payload_prefix: Option<Box<str>>,
+    currency: Option<Box<str>>,
+}
+
+impl Conditions {
+    fn validate(&self, invoice_payload: &str, currency: &str) -> bool {
+        self.payload_prefix
+            .as_deref()
+            .map_or(true, |prefix| invoice_payload.starts_with(prefix))
+            && self
+                .currency
+                .as_deref()
+                .map_or(true, |expected| expected == currency)
+    }
+}
+
+/// Filter for checking the service message about the successful payment.
+/// Passes if the message has `successful_payment` with the invoice payload and the currency, which match the conditions.
+/// # Notes
+/// Without conditions the filter passes all successful payments
+#[derive(Debug, Default, Clone)]
+pub struct SuccessfulPayment {
+    conditions: Conditions,
+}
+
+/// Filter for checking the incoming pre-checkout query.
+/// Passes if the query has the invoice payload and the currency, which match the conditions.
+/// # Notes
+/// Without conditions the filter passes all pre-checkout queries
+#[derive(Debug, Default, Clone)]
+pub struct PreCheckoutQuery {
+    conditions: Conditions,
+}
+
+macro_rules! impl_conditions {
+    ($filter:ident) => {
+        impl $filter {
+            /// Creates a new filter, which passes without conditions
+            #[must_use]
+            pub fn any() -> Self {
+                Self::default()
+            }
+
+            /// Set prefix that must be at the beginning of the invoice payload
+            #[must_use]
+            pub fn payload_prefix(self, val: impl Into<Box<str>>) -> Self {
+                Self {
+                    conditions: Conditions {
+                        payload_prefix: Some(val.into()),
+                        ..self.conditions
+                    },
+                }
+            }
+
+            /// Set three-letter ISO 4217 currency code, for example, `XTR` for Telegram Stars
+            #[must_use]
+            pub fn currency(self, val: impl Into<Box<str>>) -> Self {
+                Self {
+                    conditions: Conditions {
+                        currency: Some(val.into()),
+                        ..self.conditions
+                    },
+                }
+            }
+
+            #[must_use]
+            pub fn validate(&self, invoice_payload: &str, currency: &str) -> bool {
+                self.conditions.validate(invoice_payload, currency)
+            }
+        }
+    };
+}
+
+impl_conditions!(SuccessfulPayment);
+impl_conditions!(PreCheckoutQuery);
+
+#[async_trait]
+impl<Client> Filter<Client> for SuccessfulPayment {
+    async fn check(&self, _bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
+        match update.message() {
+            Some(Message::SuccessfulPayment(message)) => {
+                self.validate(&message.payment.invoice_payload, &message.payment.currency)
+            }
+            _ => false,
+        }
+    }
+
+    fn is_cheap(&self) -> bool {
+        true
+    }
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for PreCheckoutQuery {
+    async fn check(&self, _bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
+        match &update.kind {
+            UpdateKind::PreCheckoutQuery(query) => {
+                self.validate(&query.invoice_payload, &query.currency)
+            }
+            _ => false,
+        }
+    }
+
+    fn is_cheap(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::Reqwest, test};
+
+    use serde_json::json;
+
+    fn update(value: serde_json::Value) -> Update {
+        serde_json::from_str(&value.to_string()).unwrap()
+    }
+
+    fn successful_payment(invoice_payload: &str, currency: &str) -> Update {
+        update(json!({
+            "update_id": 1,
+            "message": {
+                "message_id": 1,
+                "date": 0,
+                "chat": {"id": 1, "type": "private"},
+                "successful_payment": {
+                    "currency": currency,
+                    "total_amount": 100,
+                    "invoice_payload": invoice_payload,
+                    "telegram_payment_charge_id": "telegram",
+                    "provider_payment_charge_id": "provider",
+                },
+            },
+        }))
+    }
+
+    fn pre_checkout_query(invoice_payload: &str, currency: &str) -> Update {
+        update(json!({
+            "update_id": 1,
+            "pre_checkout_query": {
+                "id": "id",
+                "from": {"id": 1, "is_bot": false, "first_name": "User"},
+                "currency": currency,
+                "total_amount": 100,
+                "invoice_payload": invoice_payload,
+            },
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_successful_payment() {
+        let bot = Bot::<Reqwest>::default();
+        let context = Context::new();
+        let filter = SuccessfulPayment::any()
+            .payload_prefix("premium:")
+            .currency("XTR");
+
+        assert!(
+            filter
+                .check(&bot, &successful_payment("premium:1", "XTR"), &context)
+                .await
+        );
+        assert!(
+            !filter
+                .check(&bot, &successful_payment("donation:1", "XTR"), &context)
+                .await
+        );
+        assert!(
+            !filter
+                .check(&bot, &successful_payment("premium:1", "USD"), &context)
+                .await
+        );
+        assert!(!filter.check(&bot, &test::message("text"), &context).await);
+        assert!(
+            SuccessfulPayment::any()
+                .check(&bot, &successful_payment("donation:1", "USD"), &context)
+                .await
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pre_checkout_query() {
+        let bot = Bot::<Reqwest>::default();
+        let context = Context::new();
+        let filter = PreCheckoutQuery::any().payload_prefix("premium:");
+
+        assert!(
+            filter
+                .check(&bot, &pre_checkout_query("premium:1", "XTR"), &context)
+                .await
+        );
+        assert!(
+            !filter
+                .check(&bot, &pre_checkout_query("donation:1", "XTR"), &context)
+                .await
+        );
+        assert!(
+            !filter
+                .check(&bot, &successful_payment("premium:1", "XTR"), &context)
+                .await
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use super::base::Filter;
+
+use crate::{client::Bot, context::Context, extractors::FromContext, types::Update};
+
+use async_trait::async_trait;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+
+/// Filter for checking the payload of the `/start` command from [deep links](https://core.telegram.org/bots/features#deep-linking).
+/// Passes if the message is the `/start` command with a non-empty payload (`/start ref123`),
+/// and inserts [`StartPayloadObject`] with the payload to the context.
+/// # Notes
+/// Telegram allows only `A-Z`, `a-z`, `0-9`, `_` and `-` characters in the payload,
+/// so it's recommended to encode other data with base64url. Use [`StartPayload::base64`] to decode the payload.
+#[derive(Debug, Clone, Default)]
+pub struct StartPayload {
+    expected: Option<Box<str>>,
+    base64: bool,
+}
+
+impl StartPayload {
+    /// Creates a new [`StartPayload`] filter, which passes with any non-empty payload
+    #[must_use]
+    pub fn any() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`StartPayload`] filter, which passes if the (decoded) payload is equal to the value
+    #[must_use]
+    pub fn eq(val: impl Into<Box<str>>) -> Self {
+        Self {
+            expected: Some(val.into()),
+            base64: false,
+        }
+    }
+
+    /// Decode the payload from base64url (without padding).
+    /// If the payload can't be decoded to the UTF-8 string, then the filter doesn't pass.
+    #[must_use]
+    pub fn base64(self, val: bool) -> Self {
+        Self {
+            base64: val,
+            ..self
+        }
+    }
+}
+
+impl StartPayload {
+    /// Extracts the payload from the text of the `/start` command
+    #[must_use]
+    pub fn extract(&self, text: &str) -> Option<Box<str>> {
+        let (command, payload) = text.trim().split_once(' ')?;
+
+        if command != "/start" && !command.starts_with("/start@") {
+            return None;
+        }
+
+        let payload = payload.trim();
+
+        if payload.is_empty() {
+            return None;
+        }
+
+        if self.base64 {
+            String::from_utf8(URL_SAFE_NO_PAD.decode(payload).ok()?)
+                .ok()
+                .map(Into::into)
+        } else {
+            Some(payload.into())
+        }
+    }
+
+    /// Checks the text of the `/start` command
+    #[must_use]
+    pub fn validate(&self, text: &str) -> Option<StartPayloadObject> {
+        let payload = self.extract(text)?;
+
+        match self.expected {
+            Some(ref expected) if *expected != payload => None,
+            _ => Some(StartPayloadObject { payload }),
+        }
+    }
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for StartPayload {
+    async fn check(&self, _bot: &Bot<Client>, update: &Update, context: &Context) -> bool {
+        let Some(text) = update.text() else {
+            return false;
+        };
+
+        match self.validate(text) {
+            Some(payload) => {
+                context.insert("start_payload", Box::new(payload));
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn is_cheap(&self) -> bool {
+        true
+    }
+}
+
+/// Payload of the `/start` command, which is decoded if the filter is configured to do so
+#[derive(Debug, Clone, Hash, PartialEq, Eq, FromContext)]
+#[context(
+    key = "start_payload",
+    description = "Payload of the `/start` command. This type is available only if the start payload filter is used and filter is passed."
+)]
+pub struct StartPayloadObject {
+    pub payload: Box<str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        event::{telegram::HandlerResult, EventReturn},
+        methods::SendMessage,
+        test::{self, MockBot},
+        Router,
+    };
+
+    #[test]
+    fn test_validate() {
+        let filter = StartPayload::any();
+
+        assert_eq!(
+            filter.validate("/start ref123").unwrap().payload.as_ref(),
+            "ref123"
+        );
+        assert_eq!(
+            filter
+                .validate("/start@bot ref123")
+                .unwrap()
+                .payload
+                .as_ref(),
+            "ref123"
+        );
+        assert!(filter.validate("/start").is_none());
+        assert!(filter.validate("/start  ").is_none());
+        assert!(filter.validate("/started ref123").is_none());
+        assert!(filter.validate("/help ref123").is_none());
+
+        let filter = StartPayload::eq("ref123");
+
+        assert!(filter.validate("/start ref123").is_some());
+        assert!(filter.validate("/start ref321").is_none());
+
+        // "user:42" encoded with base64url without padding
+        let filter = StartPayload::eq("user:42").base64(true);
+
+        assert!(filter.validate("/start dXNlcjo0Mg").is_some());
+        assert!(filter.validate("/start user:42").is_none());
+        assert!(StartPayload::any()
+            .base64(true)
+            .validate("/start !!!")
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_payload_in_handler() {
+        async fn handler(bot: Bot<MockBot>, payload: StartPayloadObject) -> HandlerResult {
+            bot.send(SendMessage::new(test::CHAT_ID, payload.payload))
+                .await?;
+
+            Ok(EventReturn::Finish)
+        }
+
+        let mock = MockBot::new();
+        mock.push_result::<SendMessage>(test::sent_message("ref123"));
+
+        for text in ["/start", "/start ref123"] {
+            let mut router = Router::new("main");
+            router.message.register(handler).filter(StartPayload::any());
+
+            mock.propagate(router, test::message(text)).await.unwrap();
+        }
+
+        let calls = mock.calls();
+
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].data["text"], "ref123");
+    }
+}
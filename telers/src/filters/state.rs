@@ -210,4 +210,71 @@ mod tests {
         assert!(!filter.check(Some("another_state")));
         assert!(filter.check(None));
     }
+
+    #[cfg(feature = "memory-storage")]
+    #[tokio::test]
+    async fn test_filter_in_router() {
+        use crate::{
+            event::{telegram::HandlerResult, EventReturn},
+            fsm::{Context as FSMContext, MemoryStorage},
+            methods::SendMessage,
+            middlewares::outer::FSMContext as FSMContextMiddleware,
+            test::{self, MockBot},
+            types::MessageText,
+            Router,
+        };
+
+        async fn set_state(fsm: FSMContext<MemoryStorage>, message: MessageText) -> HandlerResult {
+            match message.text.as_ref() {
+                "none" => fsm.remove_states().await?,
+                state => fsm.set_state(state.to_owned()).await?,
+            }
+
+            Ok(EventReturn::Finish)
+        }
+
+        async fn reply(bot: Bot<MockBot>, text: &str) -> HandlerResult {
+            bot.send(SendMessage::new(test::CHAT_ID, text)).await?;
+
+            Ok(EventReturn::Finish)
+        }
+
+        let storage = MemoryStorage::new();
+        let mock = MockBot::new();
+
+        for (state, expected) in [("form", "form"), ("other", "any"), ("none", "none")] {
+            let mut router = Router::new("set");
+            router
+                .message
+                .outer_middlewares
+                .register(FSMContextMiddleware::new(storage.clone()));
+            router.message.register(set_state);
+            mock.propagate(router, test::message(state)).await.unwrap();
+
+            let mut router = Router::new("main");
+            router
+                .message
+                .outer_middlewares
+                .register(FSMContextMiddleware::new(storage.clone()));
+            router
+                .message
+                .register(|bot| reply(bot, "form"))
+                .filter(State::one("form"));
+            router
+                .message
+                .register(|bot| reply(bot, "any"))
+                .filter(State::any());
+            router
+                .message
+                .register(|bot| reply(bot, "none"))
+                .filter(State::none());
+
+            mock.push_result::<SendMessage>(test::sent_message(expected));
+
+            let calls = mock.propagate(router, test::message("text")).await.unwrap();
+
+            assert_eq!(calls.len(), 1);
+            assert_eq!(calls[0].data["text"], expected);
+        }
+    }
 }
@@ -390,6 +390,10 @@ impl<Client> Filter<Client> for Text<'_> {
             .text_or_caption()
             .map_or(false, |text| self.validate_text(text))
     }
+
+    fn is_cheap(&self) -> bool {
+        true
+    }
 }
 
 #[cfg(test)]
@@ -380,6 +380,10 @@ impl<Client> Filter<Client> for User<'_> {
             None => false,
         }
     }
+
+    fn is_cheap(&self) -> bool {
+        true
+    }
 }
 
 #[cfg(test)]
@@ -24,6 +24,8 @@
 //! But we don't want to use [`StorageKey`] directly in the code for the sake of convenience,
 //! so we create [`StorageKey`] in the [`FSMContext middleware`] and pass it to the [`Context`]
 //! that wrap the [`StorageKey`] and [`Storage`] to provide more convenient API to work with the storage.
+//! * [`StorageKeyBuilder`]: builds [`StorageKey`] of the update in the [`FSMContext middleware`] by the [`Strategy`] by default, implement it to use another layout of the key.
+//! * [`Data`]: state and data of the user loaded by the [`FSMData middleware`], so handlers don't need to request the storage.
 //!
 //! You can check example of using FSM in the [`examples/fsm`](https://github.com/Desiders/telers/tree/dev-1.x/examples/fsm).
 //!
@@ -33,12 +35,17 @@
 //! [`UserInThread`]: Strategy::UserInThread
 //! [`storage module`]: storage
 //! [`FSMContext middleware`]: crate::middlewares::outer::fsm_context::FSMContext
+//! [`FSMData middleware`]: crate::middlewares::outer::fsm_data::FSMData
 
 pub mod context;
+pub mod data;
+pub mod key_builder;
 pub mod storage;
 pub mod strategy;
 
 pub use context::Context;
+pub use data::Data;
+pub use key_builder::{StorageKeyBuilder, StorageKeyBuilderImpl};
 pub use storage::{Storage, StorageKey};
 pub use strategy::Strategy;
 
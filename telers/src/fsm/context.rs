@@ -62,6 +62,18 @@ where
         self.storage.set_previous_state(&self.key).await
     }
 
+    /// Remove current state and return it, so the previous state becomes current
+    /// # Errors
+    /// If storage error occurs, when pop state
+    /// # Returns
+    /// Removed state, if state is no exists, then `None` will be return
+    pub async fn pop_state(&self) -> Result<Option<Box<str>>, S::Error>
+    where
+        S: Sync,
+    {
+        self.storage.pop_state(&self.key).await
+    }
+
     /// Get current state
     /// # Errors
     /// If storage error occurs, when get state
@@ -149,6 +161,21 @@ where
         self.storage.get_value(&self.key, value_key).await
     }
 
+    /// Get value from data for value key as JSON string, without deserializing it
+    /// # Arguments
+    /// * `value_key` - Specified value key to get value from data
+    /// # Errors
+    /// If storage error occurs, when get value from data
+    /// # Returns
+    /// JSON string of the value, if value is no exists, then `None` will be return
+    pub async fn get_value_raw<Key>(&self, value_key: Key) -> Result<Option<Box<str>>, S::Error>
+    where
+        S: Sync,
+        Key: Into<Cow<'static, str>> + Send,
+    {
+        self.storage.get_value_raw(&self.key, value_key).await
+    }
+
     /// Remove current data
     /// # Errors
     /// If storage error occurs, when remove data
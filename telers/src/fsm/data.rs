@@ -0,0 +1,132 @@
+use crate::extractors::FromContext;
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+#[derive(Debug, Default)]
+struct Inner {
+    values: HashMap<Box<str>, Value>,
+    changed: bool,
+}
+
+/// Current state and data of the user, which are loaded from the storage before handlers run.
+/// Data is shared between clones, so changes made in the handler are visible to the middleware
+/// that writes them back to the storage.
+#[derive(Debug, Default, Clone, FromContext)]
+#[context(
+    key = "fsm_data",
+    description = "State and data of the user. \
+    This data is available only if `FSMContext` and `FSMData` middlewares are used and `user_id` in context is not empty."
+)]
+pub struct Data {
+    state: Option<Box<str>>,
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl Data {
+    #[must_use]
+    pub fn new(state: Option<Box<str>>, values: HashMap<Box<str>, Value>) -> Self {
+        Self {
+            state,
+            inner: Arc::new(Mutex::new(Inner {
+                values,
+                changed: false,
+            })),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap()
+    }
+
+    /// Get state loaded before handlers run
+    #[must_use]
+    pub fn state(&self) -> Option<&str> {
+        self.state.as_deref()
+    }
+
+    /// Get value for value key
+    /// # Returns
+    /// Value, if value is no exists or can't be deserialized, then `None` will be return
+    #[must_use]
+    pub fn get_value<T>(&self, value_key: &str) -> Option<T>
+    where
+        T: DeserializeOwned,
+    {
+        self.lock()
+            .values
+            .get(value_key)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+    }
+
+    /// Set value for value key
+    /// # Errors
+    /// If value can't be serialized
+    pub fn set_value<T>(
+        &self,
+        value_key: impl Into<Box<str>>,
+        value: T,
+    ) -> Result<(), serde_json::Error>
+    where
+        T: Serialize,
+    {
+        let value = serde_json::to_value(value)?;
+
+        let mut inner = self.lock();
+        inner.values.insert(value_key.into(), value);
+        inner.changed = true;
+
+        Ok(())
+    }
+
+    /// Remove value for value key
+    pub fn remove_value(&self, value_key: &str) {
+        let mut inner = self.lock();
+
+        if inner.values.remove(value_key).is_some() {
+            inner.changed = true;
+        }
+    }
+
+    /// Get copy of all values
+    #[must_use]
+    pub fn values(&self) -> HashMap<Box<str>, Value> {
+        self.lock().values.clone()
+    }
+
+    /// Get copy of all values if they were changed after loading
+    #[must_use]
+    pub fn changed_values(&self) -> Option<HashMap<Box<str>, Value>> {
+        let inner = self.lock();
+
+        inner.changed.then(|| inner.values.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_data() {
+        let data = Data::new(
+            Some("state".into()),
+            HashMap::from([("name".into(), Value::from("Bob"))]),
+        );
+
+        assert_eq!(data.state(), Some("state"));
+        assert_eq!(data.get_value::<String>("name").as_deref(), Some("Bob"));
+        assert_eq!(data.get_value::<i64>("name"), None);
+        assert!(data.changed_values().is_none());
+
+        // Changes are shared between clones
+        data.clone().set_value("age", 18).unwrap();
+
+        assert_eq!(data.get_value::<i64>("age"), Some(18));
+        assert_eq!(data.changed_values().unwrap().len(), 2);
+    }
+}
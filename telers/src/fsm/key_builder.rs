@@ -0,0 +1,129 @@
+//! This module contains [`StorageKeyBuilder`] trait, which is used by the [`FSMContext middleware`]
+//! to build [`StorageKey`] of the update.
+//!
+//! By default, [`StorageKeyBuilderImpl`] is used, which builds the key by the [`Strategy`] and destiny.
+//! Implement the trait to use another layout of the key, for example, to share the state of the user across all bots.
+//!
+//! # Examples
+//! ```rust
+//! use telers::{
+//!     context::Context,
+//!     fsm::{key_builder::StorageKeyBuilder, MemoryStorage, StorageKey},
+//!     middlewares::outer::FSMContext,
+//!     types::Update,
+//! };
+//!
+//! /// Key only by the user, so the state of the user is global across chats
+//! struct UserKeyBuilder;
+//!
+//! impl StorageKeyBuilder for UserKeyBuilder {
+//!     fn build(&self, bot_id: i64, update: &Update, _context: &Context) -> Option<StorageKey> {
+//!         update
+//!             .from_id()
+//!             .map(|user_id| StorageKey::new(bot_id, user_id, user_id, None, None))
+//!     }
+//! }
+//!
+//! let middleware = FSMContext::new(MemoryStorage::new()).key_builder(UserKeyBuilder);
+//! ```
+//!
+//! [`FSMContext middleware`]: crate::middlewares::outer::FSMContext
+
+use super::{
+    storage::base::{StorageKey, DEFAULT_DESTINY},
+    Strategy,
+};
+
+use crate::{
+    context::Context,
+    types::{Chat, Update, User},
+};
+
+/// Builder of the [`StorageKey`] of the update
+pub trait StorageKeyBuilder: Send + Sync {
+    /// Build storage key of the update
+    /// # Arguments
+    /// * `bot_id` - Identifier of the bot, which received the update
+    /// * `update` - Update to build key for
+    /// * `context` - Context of the update with data of the outer middlewares, like event user and chat
+    /// # Returns
+    /// Storage key of the update, if `None`, then FSM context isn't created for the update
+    #[must_use]
+    fn build(&self, bot_id: i64, update: &Update, context: &Context) -> Option<StorageKey>;
+}
+
+/// This is a default storage key builder implementation
+/// # Notes
+/// It uses event user, chat, message thread and business connection from the context
+/// (see [`UserContext`] middleware) and applies [`Strategy`] to them.
+/// If the event has no user, then the key isn't built.
+///
+/// [`UserContext`]: crate::middlewares::outer::UserContext
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageKeyBuilderImpl {
+    strategy: Strategy,
+    destiny: &'static str,
+}
+
+impl StorageKeyBuilderImpl {
+    #[must_use]
+    pub const fn new(strategy: Strategy, destiny: &'static str) -> Self {
+        Self { strategy, destiny }
+    }
+
+    #[must_use]
+    pub fn strategy(self, val: Strategy) -> Self {
+        Self {
+            strategy: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn destiny(self, val: &'static str) -> Self {
+        Self {
+            destiny: val,
+            ..self
+        }
+    }
+}
+
+impl Default for StorageKeyBuilderImpl {
+    fn default() -> Self {
+        Self::new(Strategy::default(), DEFAULT_DESTINY)
+    }
+}
+
+impl StorageKeyBuilder for StorageKeyBuilderImpl {
+    fn build(&self, bot_id: i64, _update: &Update, context: &Context) -> Option<StorageKey> {
+        let user = context.get("event_user");
+        let chat = context.get("event_chat");
+        let message_thread_id = context.get("event_message_thread_id");
+        let business_connection_id = context.get("event_business_connection_id");
+
+        let user_id = user.and_then(|user| user.downcast_ref().map(|user: &User| user.id))?;
+        let chat_id = chat.and_then(|chat| chat.downcast_ref().map(|chat: &Chat| chat.id()));
+        let message_thread_id = message_thread_id
+            .and_then(|message_thread_id| message_thread_id.downcast_ref().copied());
+        let business_connection_id = business_connection_id
+            .and_then(|business_connection_id| business_connection_id.downcast_ref().cloned());
+
+        let id_pair = self.strategy.apply(
+            chat_id.unwrap_or(user_id),
+            user_id,
+            message_thread_id,
+            business_connection_id,
+        );
+
+        Some(
+            StorageKey::new(
+                bot_id,
+                id_pair.chat_id,
+                id_pair.user_id,
+                id_pair.message_thread_id,
+                id_pair.business_connection_id,
+            )
+            .destiny(self.destiny),
+        )
+    }
+}
@@ -12,15 +12,20 @@
 //! Redis storage implementation.
 //! This is a thread-safe Redis storage implementation that persists data between restarts.
 //! This is the recommended storage implementation for production use.
+//! * Sled (feature: `sled-storage`): embedded storage implementation based on [`sled`](https://docs.rs/sled) database, which persists data between restarts in the local files without external database.
 
 pub mod base;
 #[cfg(feature = "memory-storage")]
 pub mod memory;
 #[cfg(feature = "redis-storage")]
 pub mod redis;
+#[cfg(feature = "sled-storage")]
+pub mod sled;
 
 #[cfg(feature = "redis-storage")]
 pub use self::redis::{KeyBuilderImpl, Redis};
+#[cfg(feature = "sled-storage")]
+pub use self::sled::Sled;
 #[allow(clippy::module_name_repetitions)]
 pub use base::{Error, Storage, StorageKey};
 #[cfg(feature = "memory-storage")]
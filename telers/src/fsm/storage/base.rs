@@ -104,6 +104,27 @@ pub trait Storage: Clone {
     /// so you can use this method to back to the previous state
     async fn set_previous_state(&self, key: &StorageKey) -> Result<(), Self::Error>;
 
+    /// Remove current state from the states stack and return it, so the previous state becomes current
+    /// # Arguments
+    /// * `key` - Specified key to pop state
+    /// # Errors
+    /// If storage error occurs, when pop state
+    /// # Returns
+    /// Removed state, if state is no exists, then `None` will be return
+    /// # Notes
+    /// By default, this method gets the state and then sets the previous one, so it isn't atomic.
+    /// Storage implementations can override it to pop the state atomically.
+    async fn pop_state(&self, key: &StorageKey) -> Result<Option<Box<str>>, Self::Error>
+    where
+        Self: Sync,
+    {
+        let state = self.get_state(key).await?;
+        if state.is_some() {
+            self.set_previous_state(key).await?;
+        }
+        Ok(state)
+    }
+
     /// Get state for specified key
     /// # Arguments
     /// * `key` - Specified key to get state
@@ -186,6 +207,30 @@ pub trait Storage: Clone {
         Value: DeserializeOwned,
         Key: Into<Cow<'static, str>> + Send;
 
+    /// Get value from the data for specified key and value key as JSON string, without deserializing it.
+    /// It's useful for large values, which can be deserialized lazily or into borrowed types.
+    ///
+    /// By default, the value is deserialized by [`Storage::get_value`] and serialized back,
+    /// so storages that keep values as JSON should override it to avoid this.
+    /// # Arguments
+    /// * `key` - Specified key to get data
+    /// * `value_key` - Specified value key to get value from data
+    /// # Returns
+    /// JSON string of the value for specified key and value key, if value is no exists, then `None` will be return
+    async fn get_value_raw<Key>(
+        &self,
+        key: &StorageKey,
+        value_key: Key,
+    ) -> Result<Option<Box<str>>, Self::Error>
+    where
+        Key: Into<Cow<'static, str>> + Send,
+    {
+        Ok(self
+            .get_value::<_, serde_json::Value>(key, value_key)
+            .await?
+            .map(|value| value.to_string().into()))
+    }
+
     /// Remove data for specified key
     /// # Arguments
     /// * `key` - Specified key to remove data
@@ -210,6 +255,10 @@ where
         S::set_previous_state(self, key).await
     }
 
+    async fn pop_state(&self, key: &StorageKey) -> Result<Option<Box<str>>, Self::Error> {
+        S::pop_state(self, key).await
+    }
+
     async fn get_state(&self, key: &StorageKey) -> Result<Option<Box<str>>, Self::Error> {
         S::get_state(self, key).await
     }
@@ -269,6 +318,17 @@ where
         S::get_value(self, key, value_key).await
     }
 
+    async fn get_value_raw<Key>(
+        &self,
+        key: &StorageKey,
+        value_key: Key,
+    ) -> Result<Option<Box<str>>, Self::Error>
+    where
+        Key: Into<Cow<'static, str>> + Send,
+    {
+        S::get_value_raw(self, key, value_key).await
+    }
+
     async fn remove_data(&self, key: &StorageKey) -> Result<(), Self::Error> {
         S::remove_data(self, key).await
     }
@@ -292,6 +352,10 @@ where
         S::set_previous_state(self, key).await
     }
 
+    async fn pop_state(&self, key: &StorageKey) -> Result<Option<Box<str>>, Self::Error> {
+        S::pop_state(self, key).await
+    }
+
     async fn get_state(&self, key: &StorageKey) -> Result<Option<Box<str>>, Self::Error> {
         S::get_state(self, key).await
     }
@@ -351,6 +415,17 @@ where
         S::get_value(self, key, value_key).await
     }
 
+    async fn get_value_raw<Key>(
+        &self,
+        key: &StorageKey,
+        value_key: Key,
+    ) -> Result<Option<Box<str>>, Self::Error>
+    where
+        Key: Into<Cow<'static, str>> + Send,
+    {
+        S::get_value_raw(self, key, value_key).await
+    }
+
     async fn remove_data(&self, key: &StorageKey) -> Result<(), Self::Error> {
         S::remove_data(self, key).await
     }
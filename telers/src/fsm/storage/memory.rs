@@ -23,6 +23,7 @@ struct Record {
 #[derive(Debug, Default, Clone)]
 pub struct Memory {
     storage: Arc<Mutex<HashMap<StorageKey, Record>>>,
+    max_states: Option<usize>,
 }
 
 impl PartialEq for Memory {
@@ -36,6 +37,18 @@ impl Memory {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Set maximum depth of the states stack, the oldest states are dropped when the stack is full.
+    /// By default, the stack is unbounded.
+    /// # Notes
+    /// The depth is at least 1
+    #[must_use]
+    pub fn max_states(self, val: usize) -> Self {
+        Self {
+            max_states: Some(val.max(1)),
+            ..self
+        }
+    }
 }
 
 #[async_trait]
@@ -52,7 +65,12 @@ impl Storage for Memory {
     {
         match self.storage.lock().await.entry(key.clone()) {
             Entry::Occupied(mut entry) => {
-                entry.get_mut().states.push(state.into());
+                let states = &mut entry.get_mut().states;
+                states.push(state.into());
+
+                if let Some(max_states) = self.max_states {
+                    states.drain(..states.len().saturating_sub(max_states));
+                }
             }
             Entry::Vacant(entry) => {
                 entry.insert(Record {
@@ -81,6 +99,21 @@ impl Storage for Memory {
         Ok(())
     }
 
+    /// Remove current state from the states stack and return it
+    /// # Arguments
+    /// * `key` - Specified key to pop state
+    /// # Returns
+    /// Removed state, if state is no exists, then `None` will be return
+    async fn pop_state(&self, key: &StorageKey) -> Result<Option<Box<str>>, Self::Error> {
+        Ok(self
+            .storage
+            .lock()
+            .await
+            .get_mut(key)
+            .and_then(|record| record.states.pop())
+            .map(|state| state.as_ref().into()))
+    }
+
     /// Get state for specified key
     /// # Arguments
     /// * `key` - Specified key to get state
@@ -355,6 +388,33 @@ impl Storage for Memory {
         }
     }
 
+    /// Get value from the data for specified key and value key as JSON string
+    /// # Arguments
+    /// * `key` - Specified key to get data
+    /// * `value_key` - Specified value key to get value from the data
+    /// # Returns
+    /// JSON string of the value for specified key and value key, if value is no exists, then `None` will be return
+    #[instrument(skip(self, value_key), fields(value_key))]
+    async fn get_value_raw<Key>(
+        &self,
+        key: &StorageKey,
+        value_key: Key,
+    ) -> Result<Option<Box<str>>, Self::Error>
+    where
+        Key: Into<Cow<'static, str>> + Send,
+    {
+        let value_key = value_key.into();
+
+        Span::current().record("value_key", value_key.as_ref());
+
+        Ok(self
+            .storage
+            .lock()
+            .await
+            .get(key)
+            .and_then(|record| record.data.get(&value_key).cloned()))
+    }
+
     /// Remove data for specified key
     /// # Arguments
     /// * `key` - Specified key to remove data
@@ -425,6 +485,69 @@ mod tests {
         assert_eq!(storage.get_states(&key1).await.unwrap(), [].into());
     }
 
+    #[tokio::test]
+    async fn test_pop_state() {
+        let storage = Memory::default();
+
+        let key = StorageKey::new(0, 1, 2, None, None);
+
+        assert_eq!(storage.pop_state(&key).await.unwrap(), None);
+
+        storage.set_state(&key, "state1").await.unwrap();
+        storage.set_state(&key, "state2").await.unwrap();
+
+        assert_eq!(
+            storage.pop_state(&key).await.unwrap(),
+            Some("state2".into())
+        );
+        assert_eq!(
+            storage.get_state(&key).await.unwrap(),
+            Some("state1".into())
+        );
+        assert_eq!(
+            storage.pop_state(&key).await.unwrap(),
+            Some("state1".into())
+        );
+        assert_eq!(storage.pop_state(&key).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_max_states() {
+        let storage = Memory::new().max_states(2);
+
+        let key = StorageKey::new(0, 1, 2, None, None);
+
+        for state in ["state1", "state2", "state3"] {
+            storage.set_state(&key, state).await.unwrap();
+        }
+
+        // The oldest state is dropped
+        assert_eq!(
+            storage.get_states(&key).await.unwrap(),
+            ["state2".into(), "state3".into()].into()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_value_raw() {
+        let storage = Memory::default();
+
+        let key = StorageKey::new(0, 1, 2, None, None);
+
+        assert_eq!(storage.get_value_raw(&key, "key").await.unwrap(), None);
+
+        storage
+            .set_value(&key, "key", serde_json::json!({"a": 1, "b": [true, null]}))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            storage.get_value_raw(&key, "key").await.unwrap().as_deref(),
+            Some(r#"{"a":1,"b":[true,null]}"#)
+        );
+        assert_eq!(storage.get_value_raw(&key, "other").await.unwrap(), None);
+    }
+
     #[tokio::test]
     async fn test_data() {
         let storage = Memory::default();
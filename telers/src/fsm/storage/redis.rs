@@ -1,16 +1,20 @@
 use super::{Error, Storage, StorageKey};
 
 use async_trait::async_trait;
-use deadpool_redis::{Config, ConfigError, Connection, CreatePoolError, Pool, PoolError, Runtime};
-use redis::{IntoConnectionInfo, RedisError};
+use deadpool_redis::{Config, ConfigError, Connection, CreatePoolError, PoolError, Runtime};
+use redis::{IntoConnectionInfo, Pipeline, RedisError};
 use serde::{de::DeserializeOwned, Serialize};
+use serde_json::value::RawValue;
 use std::{
     borrow::Cow,
     collections::HashMap,
     fmt::{self, Debug, Display, Formatter},
+    time::Duration,
 };
 use tracing::{event, field, instrument, Level, Span};
 
+pub use deadpool_redis::{Pool, PoolConfig, Timeouts};
+
 const DEFAULT_PREFIX: &str = "fsm";
 const DEFAULT_SEPARATOR: &str = ":";
 
@@ -144,11 +148,15 @@ impl KeyBuilder for KeyBuilderImpl {
     }
 }
 
-/// This is a thread-safe storage implementation for redis
+/// This is a thread-safe storage implementation for redis.
+/// Connections are taken from the pool, so requests of different handlers don't wait for each other,
+/// and commands of one operation (for example, push of the state and update of its TTL) are sent in one pipeline.
 #[derive(Clone)]
 pub struct Redis<K = KeyBuilderImpl> {
     pool: Pool,
     key_builder: K,
+    max_states: Option<usize>,
+    ttl: Option<Duration>,
 }
 
 impl<K: KeyBuilder> Redis<K> {
@@ -161,25 +169,38 @@ impl<K: KeyBuilder> Redis<K> {
     where
         T: IntoConnectionInfo,
     {
-        let config = Config::from_connection_info(connection_info.into_connection_info()?);
-        let pool = match config.create_pool(Some(Runtime::Tokio1)) {
-            Ok(pool) => pool,
-            Err(err) => match err {
-                CreatePoolError::Config(err) => match err {
-                    ConfigError::UrlAndConnectionSpecified => unreachable!(
-                        "This error should not be occurred because we use `IntoConnectionInfo` where it will use only one of them.\
-                        If you see this error, then report it to the library maintainer."
-                    ),
-                    ConfigError::Redis(err) => return Err(err),
-                },
-                CreatePoolError::Build(_) => unreachable!(
-                    "This error should not be occurred because we specify runtime in `create_pool` method.\
-                    If you see this error, then report it to the library maintainer."
-                ),
-            },
-        };
+        Ok(Self::from_pool_with_key_builder(
+            create_pool(connection_info, None)?,
+            key_builder,
+        ))
+    }
 
-        Ok(Self { pool, key_builder })
+    /// Creates storage with the configured connection pool, for example, with custom maximum size and timeouts
+    /// # Errors
+    /// This method will return error if config is invalid
+    pub fn new_with_pool_config<T>(
+        connection_info: T,
+        pool_config: PoolConfig,
+        key_builder: K,
+    ) -> Result<Self, RedisError>
+    where
+        T: IntoConnectionInfo,
+    {
+        Ok(Self::from_pool_with_key_builder(
+            create_pool(connection_info, Some(pool_config))?,
+            key_builder,
+        ))
+    }
+
+    /// Creates storage with the existing connection pool, so it can be shared with other parts of the application
+    #[must_use]
+    pub fn from_pool_with_key_builder(pool: Pool, key_builder: K) -> Self {
+        Self {
+            pool,
+            key_builder,
+            max_states: None,
+            ttl: None,
+        }
     }
 
     #[must_use]
@@ -189,6 +210,56 @@ impl<K: KeyBuilder> Redis<K> {
             ..self
         }
     }
+
+    /// Set maximum depth of the states stack, the oldest states are dropped when the stack is full.
+    /// By default, the stack is unbounded.
+    /// # Notes
+    /// The depth is at least 1
+    #[must_use]
+    pub fn max_states(self, val: usize) -> Self {
+        Self {
+            max_states: Some(val.max(1)),
+            ..self
+        }
+    }
+
+    /// Set time to live of the states stack and the data, it's refreshed on each write.
+    /// By default, states and data never expire.
+    #[must_use]
+    pub fn ttl(self, val: Duration) -> Self {
+        Self {
+            ttl: Some(val),
+            ..self
+        }
+    }
+}
+
+/// Creates the connection pool
+/// # Errors
+/// If config is invalid
+fn create_pool<T>(connection_info: T, pool_config: Option<PoolConfig>) -> Result<Pool, RedisError>
+where
+    T: IntoConnectionInfo,
+{
+    let mut config = Config::from_connection_info(connection_info.into_connection_info()?);
+    config.pool = pool_config;
+
+    match config.create_pool(Some(Runtime::Tokio1)) {
+        Ok(pool) => Ok(pool),
+        Err(err) => match err {
+            CreatePoolError::Config(err) => match err {
+                ConfigError::UrlAndConnectionSpecified => unreachable!(
+                    "This error should not be occurred because we use `IntoConnectionInfo` where it will use only one of them.\
+                    If you see this error, then report it to the library maintainer."
+                ),
+                ConfigError::Redis(err) => Err(err),
+            },
+            CreatePoolError::Build(_) => unreachable!(
+                "This error should not be occurred because we specify runtime in `create_pool` method.\
+                If you see this error, then report it to the library maintainer."
+            ),
+        },
+    }
 }
 
 impl Redis {
@@ -204,12 +275,71 @@ impl Redis {
     {
         Self::new_with_key_builder(connection_info, KeyBuilderImpl::default())
     }
+
+    /// Creates storage with the existing connection pool and [`KeyBuilderImpl`] as key builder
+    #[must_use]
+    pub fn from_pool(pool: Pool) -> Self {
+        Self::from_pool_with_key_builder(pool, KeyBuilderImpl::default())
+    }
 }
 
 impl<K> Redis<K> {
     async fn get_connection(&self) -> Result<Connection, PoolError> {
         self.pool.get().await
     }
+
+    /// Add command to refresh time to live of the key, if it's set
+    fn expire(&self, pipe: &mut Pipeline, key: &str) {
+        if let Some(ttl) = self.ttl {
+            pipe.cmd("PEXPIRE")
+                .arg(key)
+                .arg(u64::try_from(ttl.as_millis()).unwrap_or(u64::MAX))
+                .ignore();
+        }
+    }
+
+    /// Build atomic pipeline to push the state to the states stack
+    fn set_state_pipeline(&self, key: &str, state: &str) -> Pipeline {
+        let mut pipe = redis::pipe();
+        pipe.atomic().cmd("RPUSH").arg(key).arg(state).ignore();
+
+        if let Some(max_states) = self.max_states {
+            // Keep only the last `max_states` states
+            pipe.cmd("LTRIM")
+                .arg(key)
+                .arg(-i64::try_from(max_states).unwrap_or(i64::MAX))
+                .arg(-1)
+                .ignore();
+        }
+
+        self.expire(&mut pipe, key);
+        pipe
+    }
+
+    /// Build atomic pipeline to replace the data
+    fn set_data_pipeline(&self, key: &str, plain_json: &str) -> Pipeline {
+        let mut pipe = redis::pipe();
+        pipe.atomic().cmd("SET").arg(key).arg(plain_json).ignore();
+
+        self.expire(&mut pipe, key);
+        pipe
+    }
+}
+
+/// Maximum number of attempts to set the value, if the data is changed concurrently by other clients
+const SET_VALUE_MAX_ATTEMPTS: u32 = 16;
+
+/// Random delay up to 10 milliseconds before the next attempt to set the value,
+/// so concurrent clients don't retry at the same time
+fn retry_jitter() -> Duration {
+    Duration::from_micros(u64::from(uuid::Uuid::new_v4().as_fields().0) % 10_000)
+}
+
+/// Discard `WATCH` of the connection, so the connection can be returned to the pool
+async fn unwatch(connection: &mut Connection) {
+    if let Err(err) = redis::cmd("UNWATCH").query_async::<_, ()>(connection).await {
+        event!(Level::WARN, error = %err, "Failed to unwatch keys");
+    }
 }
 
 #[async_trait]
@@ -243,9 +373,7 @@ impl<K: KeyBuilder + Clone> Storage for Redis<K> {
             )
         })?;
 
-        redis::cmd("RPUSH")
-            .arg(key_ref)
-            .arg(state_ref)
+        self.set_state_pipeline(key_ref, state_ref)
             .query_async(&mut connection)
             .await
             .map_err(|err| {
@@ -289,6 +417,39 @@ impl<K: KeyBuilder + Clone> Storage for Redis<K> {
             })
     }
 
+    /// Remove current state from the states stack and return it
+    /// # Arguments
+    /// * `key` - Specified key to pop state
+    /// # Returns
+    /// Removed state, if state is no exists, then `None` will be return
+    #[instrument(skip(self, key), fields(key))]
+    async fn pop_state(&self, key: &StorageKey) -> Result<Option<Box<str>>, Self::Error> {
+        let key = self.key_builder.build(key, Part::States);
+        let key_ref = key.as_ref();
+
+        Span::current().record("key", key_ref);
+
+        let mut connection = self.get_connection().await.map_err(|err| {
+            event!(Level::ERROR, error = %err, "Failed to get redis connection");
+
+            Error::new(
+                format!("Failed to get redis connection. Storage key: {key}"),
+                err,
+            )
+        })?;
+
+        redis::cmd("RPOP")
+            .arg(key_ref)
+            .query_async::<_, Option<String>>(&mut connection)
+            .await
+            .map(|state| state.map(Into::into))
+            .map_err(|err| {
+                event!(Level::ERROR, error = %err, "Failed to pop state");
+
+                Error::new(format!("Failed to pop state. Storage key: {key}"), err)
+            })
+    }
+
     /// Get state for specified key
     /// # Arguments
     /// * `key` - Specified key to get state
@@ -429,9 +590,7 @@ impl<K: KeyBuilder + Clone> Storage for Redis<K> {
             )
         })?;
 
-        redis::cmd("SET")
-            .arg(key_ref)
-            .arg(plain_json.as_str())
+        self.set_data_pipeline(key_ref, &plain_json)
             .query_async(&mut connection)
             .await
             .map_err(|err| {
@@ -471,64 +630,131 @@ impl<K: KeyBuilder + Clone> Storage for Redis<K> {
             )
         })?;
 
-        let plain_json: Option<String> = redis::cmd("GET")
-            .arg(key_ref)
-            .query_async(&mut connection)
-            .await
-            .map_err(|err| {
-                event!(Level::ERROR, error = %err, "Failed to get data");
+        let value_key = value_key.into();
+        let value = serde_json::to_value(value).map_err(|err| {
+            event!(Level::ERROR, error = %err, "Failed to convert value to `serde_json::Value`");
 
-                Error::new(format!("Failed to get data. Storage key: {key}"), err)
-            })?;
+            Error::new(
+                format!("Failed to convert value to `serde_json::Value`. Storage key: {key}"),
+                err,
+            )
+        })?;
 
-        let mut data = match plain_json {
-            Some(plain_json) => serde_json::from_str(plain_json.as_str()).map_err(|err| {
-                event!(
-                    Level::ERROR,
-                    error = %err,
-                    json = %plain_json,
-                    "Failed to deserialize data",
-                );
+        Span::current().record("value_key", value_key.as_ref());
 
-                Error::new(
-                    format!("Failed to deserialize data. Storage key: {key}"),
-                    err,
-                )
-            })?,
-            None => HashMap::with_capacity(1),
-        };
+        // Optimistic locking: if the data is changed by another client between `GET` and `SET`,
+        // then the transaction is discarded and the data is read again, so concurrent updates aren't lost
+        for attempt in 1..=SET_VALUE_MAX_ATTEMPTS {
+            redis::cmd("WATCH")
+                .arg(key_ref)
+                .query_async::<_, ()>(&mut connection)
+                .await
+                .map_err(|err| {
+                    event!(Level::ERROR, error = %err, "Failed to watch data");
+
+                    Error::new(format!("Failed to watch data. Storage key: {key}"), err)
+                })?;
+
+            let plain_json: Option<String> = match redis::cmd("GET")
+                .arg(key_ref)
+                .query_async(&mut connection)
+                .await
+            {
+                Ok(plain_json) => plain_json,
+                Err(err) => {
+                    event!(Level::ERROR, error = %err, "Failed to get data");
+
+                    unwatch(&mut connection).await;
+
+                    return Err(Error::new(
+                        format!("Failed to get data. Storage key: {key}"),
+                        err,
+                    ));
+                }
+            };
 
-        let value_key = value_key.into();
+            let mut data: HashMap<Cow<'static, str>, serde_json::Value> = match plain_json {
+                Some(plain_json) => match serde_json::from_str(plain_json.as_str()) {
+                    Ok(data) => data,
+                    Err(err) => {
+                        event!(
+                            Level::ERROR,
+                            error = %err,
+                            json = %plain_json,
+                            "Failed to deserialize data",
+                        );
 
-        Span::current().record("value_key", value_key.as_ref());
+                        unwatch(&mut connection).await;
+
+                        return Err(Error::new(
+                            format!("Failed to deserialize data. Storage key: {key}"),
+                            err,
+                        ));
+                    }
+                },
+                None => HashMap::with_capacity(1),
+            };
 
-        data.insert(
-            value_key,
-            serde_json::to_value(value).map_err(|err| {
-                event!(Level::ERROR, error = %err, "Failed to convert value to `serde_json::Value`");
+            data.insert(value_key.clone(), value.clone());
 
-                Error::new(format!("Failed to convert value to `serde_json::Value`. Storage key: {key}"), err)
-            })?,
-        );
+            Span::current().record("data", field::debug(&data));
 
-        Span::current().record("data", field::debug(&data));
+            let plain_json = match serde_json::to_string(&data) {
+                Ok(plain_json) => plain_json,
+                Err(err) => {
+                    event!(Level::ERROR, error = %err, "Failed to serialize data");
 
-        let plain_json = serde_json::to_string(&data).map_err(|err| {
-            event!(Level::ERROR, error = %err, "Failed to serialize data");
+                    unwatch(&mut connection).await;
 
-            Error::new(format!("Failed to serialize data. Storage key: {key}"), err)
-        })?;
+                    return Err(Error::new(
+                        format!("Failed to serialize data. Storage key: {key}"),
+                        err,
+                    ));
+                }
+            };
 
-        redis::cmd("SET")
-            .arg(key_ref)
-            .arg(plain_json.as_str())
-            .query_async(&mut connection)
-            .await
-            .map_err(|err| {
-                event!(Level::ERROR, error = %err, "Failed to set data");
+            // `EXEC` returns nil if the watched key was changed
+            let result: Option<()> = self
+                .set_data_pipeline(key_ref, &plain_json)
+                .query_async(&mut connection)
+                .await
+                .map_err(|err| {
+                    event!(Level::ERROR, error = %err, "Failed to set data");
 
-                Error::new(format!("Failed to set data. Storage key: {key}"), err)
-            })
+                    Error::new(format!("Failed to set data. Storage key: {key}"), err)
+                })?;
+
+            if result.is_some() {
+                return Ok(());
+            }
+
+            event!(
+                Level::TRACE,
+                attempt,
+                "Data was changed concurrently, retrying"
+            );
+
+            if attempt < SET_VALUE_MAX_ATTEMPTS {
+                tokio::time::sleep(retry_jitter()).await;
+            }
+        }
+
+        event!(
+            Level::ERROR,
+            attempts = SET_VALUE_MAX_ATTEMPTS,
+            "Failed to set data, because it was changed concurrently on each attempt",
+        );
+
+        Err(Error::new(
+            format!(
+                "Failed to set data, because it was changed concurrently on each of {SET_VALUE_MAX_ATTEMPTS} attempts. \
+                 Storage key: {key}"
+            ),
+            RedisError::from((
+                redis::ErrorKind::ExecAbortError,
+                "Watched key was changed concurrently",
+            )),
+        ))
     }
 
     /// Get data for specified key
@@ -663,6 +889,69 @@ impl<K: KeyBuilder + Clone> Storage for Redis<K> {
         }
     }
 
+    /// Get value from the data for specified key and value key as JSON string
+    /// # Arguments
+    /// * `key` - Specified key to get data
+    /// * `value_key` - Specified value key to get value from the data
+    /// # Returns
+    /// JSON string of the value for specified key and value key, if value is no exists, then `None` will be return
+    #[instrument(skip(self, key, value_key), fields(key))]
+    async fn get_value_raw<Key>(
+        &self,
+        key: &StorageKey,
+        value_key: Key,
+    ) -> Result<Option<Box<str>>, Self::Error>
+    where
+        Key: Into<Cow<'static, str>> + Send,
+    {
+        let key = self.key_builder.build(key, Part::Data);
+        let key_ref = key.as_ref();
+
+        Span::current().record("key", key_ref);
+
+        let mut connection = self.get_connection().await.map_err(|err| {
+            event!(Level::ERROR, error = %err, "Failed to get redis connection");
+
+            Error::new(
+                format!("Failed to get redis connection. Storage key: {key}"),
+                err,
+            )
+        })?;
+
+        let plain_json: Option<String> = redis::cmd("GET")
+            .arg(key_ref)
+            .query_async(&mut connection)
+            .await
+            .map_err(|err| {
+                event!(Level::ERROR, error = %err, "Failed to get data");
+
+                Error::new(format!("Failed to get data. Storage key: {key}"), err)
+            })?;
+
+        match plain_json {
+            Some(plain_json) => {
+                // Values are kept as raw JSON, so they aren't parsed
+                let mut data: HashMap<Box<str>, Box<RawValue>> =
+                    serde_json::from_str(plain_json.as_str()).map_err(|err| {
+                        event!(
+                            Level::ERROR,
+                            error = %err,
+                            json = %plain_json,
+                            "Failed to deserialize data",
+                        );
+
+                        Error::new(
+                            format!("Failed to deserialize data. Storage key: {key}"),
+                            err,
+                        )
+                    })?;
+
+                Ok(data.remove(value_key.into().as_ref()).map(Into::into))
+            }
+            None => Ok(None),
+        }
+    }
+
     /// Remove data for specified key
     /// # Arguments
     /// * `key` - Specified key to remove data
@@ -693,3 +982,158 @@ impl<K: KeyBuilder + Clone> Storage for Redis<K> {
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+
+    fn storage() -> Redis {
+        let url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1/".to_owned());
+
+        Redis::new_with_pool_config(url, PoolConfig::new(4), KeyBuilderImpl::default()).unwrap()
+    }
+
+    #[test]
+    fn test_pipelines() {
+        let capped = storage().max_states(2).ttl(Duration::from_secs(60));
+
+        let packed = String::from_utf8(
+            capped
+                .set_state_pipeline("key", "state")
+                .get_packed_pipeline(),
+        )
+        .unwrap();
+
+        assert!(packed.contains("MULTI"));
+        assert!(packed.contains("RPUSH"));
+        assert!(packed.contains("LTRIM"));
+        assert!(packed.contains("PEXPIRE"));
+        assert!(packed.contains("60000"));
+
+        let packed = String::from_utf8(
+            storage()
+                .set_data_pipeline("key", "{}")
+                .get_packed_pipeline(),
+        )
+        .unwrap();
+
+        assert!(packed.contains("SET"));
+        assert!(!packed.contains("PEXPIRE"));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running redis, set `REDIS_URL` to use non-default one"]
+    async fn test_concurrency() {
+        let storage = Arc::new(storage());
+        let key = StorageKey::new(
+            i64::from(uuid::Uuid::new_v4().as_fields().0),
+            1,
+            2,
+            None,
+            None,
+        );
+
+        let tasks = (0..32)
+            .map(|index| {
+                let storage = Arc::clone(&storage);
+                let key = key.clone();
+
+                tokio::spawn(async move {
+                    storage
+                        .set_value(&key, format!("key{index}"), index)
+                        .await
+                        .unwrap();
+                    storage
+                        .set_state(&key, format!("state{index}"))
+                        .await
+                        .unwrap();
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        let data: HashMap<Box<str>, i32> = storage.get_data(&key).await.unwrap();
+
+        assert_eq!(data.len(), 32);
+        for index in 0..32 {
+            assert_eq!(data[format!("key{index}").as_str()], index);
+        }
+        assert_eq!(storage.get_states(&key).await.unwrap().len(), 32);
+
+        storage.remove_data(&key).await.unwrap();
+        storage.remove_states(&key).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running redis, set `REDIS_URL` to use non-default one"]
+    async fn test_max_states_and_pop_state() {
+        let storage = storage().max_states(2);
+        let key = StorageKey::new(
+            i64::from(uuid::Uuid::new_v4().as_fields().0),
+            1,
+            2,
+            None,
+            None,
+        );
+
+        for state in ["state1", "state2", "state3"] {
+            storage.set_state(&key, state).await.unwrap();
+        }
+
+        // Only the last `max_states` states are kept
+        assert_eq!(
+            storage.get_states(&key).await.unwrap(),
+            ["state2".into(), "state3".into()].into()
+        );
+
+        assert_eq!(
+            storage.pop_state(&key).await.unwrap().as_deref(),
+            Some("state3")
+        );
+        assert_eq!(
+            storage.get_state(&key).await.unwrap().as_deref(),
+            Some("state2")
+        );
+        assert_eq!(
+            storage.pop_state(&key).await.unwrap().as_deref(),
+            Some("state2")
+        );
+        assert_eq!(storage.pop_state(&key).await.unwrap(), None);
+        assert_eq!(storage.get_state(&key).await.unwrap(), None);
+
+        storage.remove_states(&key).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "requires running redis, set `REDIS_URL` to use non-default one"]
+    async fn test_get_value_raw() {
+        let storage = storage();
+        let key = StorageKey::new(
+            i64::from(uuid::Uuid::new_v4().as_fields().0),
+            1,
+            2,
+            None,
+            None,
+        );
+
+        assert_eq!(storage.get_value_raw(&key, "key").await.unwrap(), None);
+
+        storage
+            .set_value(&key, "key", serde_json::json!({"a": 1, "b": [true, null]}))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            storage.get_value_raw(&key, "key").await.unwrap().as_deref(),
+            Some(r#"{"a":1,"b":[true,null]}"#)
+        );
+        assert_eq!(storage.get_value_raw(&key, "other").await.unwrap(), None);
+
+        storage.remove_data(&key).await.unwrap();
+    }
+}
@@ -0,0 +1,671 @@
+use super::{Error, Storage, StorageKey};
+
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Serialize};
+use sled::{CompareAndSwapError, Db, IVec, Tree};
+use std::{borrow::Cow, collections::HashMap, path::Path};
+use tracing::{event, instrument, Level, Span};
+
+const STATES_TREE: &str = "fsm_states";
+const DATA_TREE: &str = "fsm_data";
+const SEPARATOR: &str = ":";
+
+/// This is an embedded storage implementation based on [`sled`] database,
+/// which persists data between restarts without any external database.
+/// # Notes
+/// States stacks and data are stored in the separate trees of the database by the key built from [`StorageKey`].
+/// Stacks and data are serialized to JSON and updated by compare-and-swap,
+/// so concurrent updates of the same key aren't lost.
+#[derive(Debug, Clone)]
+pub struct Sled {
+    db: Db,
+    states: Tree,
+    data: Tree,
+    max_states: Option<usize>,
+}
+
+impl Sled {
+    /// Creates a new storage in the database
+    /// # Errors
+    /// If the trees of the storage can't be opened
+    pub fn new(db: Db) -> Result<Self, Error> {
+        let states = db
+            .open_tree(STATES_TREE)
+            .map_err(|err| Error::new("Failed to open states tree", err))?;
+        let data = db
+            .open_tree(DATA_TREE)
+            .map_err(|err| Error::new("Failed to open data tree", err))?;
+
+        Ok(Self {
+            db,
+            states,
+            data,
+            max_states: None,
+        })
+    }
+
+    /// Opens the database by the path and creates a new storage in it
+    /// # Errors
+    /// If the database or the trees of the storage can't be opened
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::new(sled::open(path).map_err(|err| Error::new("Failed to open database", err))?)
+    }
+
+    /// Set maximum depth of the states stack, the oldest states are dropped when the stack is full.
+    /// By default, the stack is unbounded.
+    /// # Notes
+    /// The depth is at least 1
+    #[must_use]
+    pub fn max_states(self, val: usize) -> Self {
+        Self {
+            max_states: Some(val.max(1)),
+            ..self
+        }
+    }
+
+    /// Gets the database of the storage
+    #[must_use]
+    pub const fn db(&self) -> &Db {
+        &self.db
+    }
+
+    fn build_key(key: &StorageKey) -> String {
+        let mut parts = vec![
+            Cow::Borrowed(key.destiny),
+            key.bot_id.to_string().into(),
+            key.chat_id.to_string().into(),
+        ];
+
+        if let Some(message_thread_id) = key.message_thread_id {
+            parts.push(message_thread_id.to_string().into());
+        }
+        if let Some(business_connection_id) = &key.business_connection_id {
+            parts.push(business_connection_id.as_str().into());
+        }
+
+        parts.push(key.user_id.to_string().into());
+        parts.join(SEPARATOR)
+    }
+}
+
+/// Atomically updates the value of the key by compare-and-swap, the update is retried if the value is changed concurrently
+/// # Arguments
+/// * `f` - Function, which returns the new value by the current one, `None` removes the value
+fn update<F>(tree: &Tree, key: &str, mut f: F) -> Result<(), Error>
+where
+    F: FnMut(Option<&IVec>) -> Result<Option<Vec<u8>>, Error>,
+{
+    let mut current = tree
+        .get(key)
+        .map_err(|err| Error::new(format!("Failed to get value by key `{key}`"), err))?;
+
+    loop {
+        let new = f(current.as_ref())?;
+
+        match tree
+            .compare_and_swap(key, current, new)
+            .map_err(|err| Error::new(format!("Failed to update value by key `{key}`"), err))?
+        {
+            Ok(()) => return Ok(()),
+            Err(CompareAndSwapError {
+                current: actual, ..
+            }) => {
+                current = actual;
+            }
+        }
+    }
+}
+
+fn deserialize<T: DeserializeOwned>(value: &[u8], key: &str) -> Result<T, Error> {
+    serde_json::from_slice(value).map_err(|err| {
+        event!(Level::ERROR, "Failed to deserialize value");
+
+        Error::new(
+            format!("Failed to deserialize value. Storage key: `{key}`"),
+            err,
+        )
+    })
+}
+
+fn serialize<T: Serialize + ?Sized>(value: &T, key: &str) -> Result<Vec<u8>, Error> {
+    serde_json::to_vec(value).map_err(|err| {
+        event!(Level::ERROR, "Failed to serialize value");
+
+        Error::new(
+            format!("Failed to serialize value. Storage key: `{key}`"),
+            err,
+        )
+    })
+}
+
+impl Sled {
+    fn get_states_raw(&self, key: &str) -> Result<Vec<Box<str>>, Error> {
+        self.states
+            .get(key)
+            .map_err(|err| Error::new(format!("Failed to get states. Storage key: `{key}`"), err))?
+            .map_or_else(|| Ok(vec![]), |states| deserialize(&states, key))
+    }
+
+    fn get_data_raw(&self, key: &str) -> Result<HashMap<Box<str>, Box<str>>, Error> {
+        self.data
+            .get(key)
+            .map_err(|err| Error::new(format!("Failed to get data. Storage key: `{key}`"), err))?
+            .map_or_else(|| Ok(HashMap::new()), |data| deserialize(&data, key))
+    }
+}
+
+#[async_trait]
+impl Storage for Sled {
+    type Error = Error;
+
+    /// Set state for specified key
+    /// # Arguments
+    /// * `key` - Specified key to set state
+    /// * `state` - State for specified key
+    #[instrument(skip(self, state))]
+    async fn set_state<State>(&self, key: &StorageKey, state: State) -> Result<(), Self::Error>
+    where
+        State: Into<Cow<'static, str>> + Send,
+    {
+        let key = Self::build_key(key);
+        let state = state.into();
+
+        update(&self.states, &key, |states| {
+            let mut states: Vec<Cow<'_, str>> =
+                states.map_or_else(|| Ok(vec![]), |states| deserialize(states, &key))?;
+            states.push(Cow::Borrowed(&state));
+
+            if let Some(max_states) = self.max_states {
+                states.drain(..states.len().saturating_sub(max_states));
+            }
+
+            serialize(&states, &key).map(Some)
+        })
+    }
+
+    /// Set previous state as current state
+    /// # Arguments
+    /// * `key` - Specified key to set previous state
+    /// # Notes
+    /// States stack is used to store states history,
+    /// when user set new state, then current state will be push to the states stack,
+    /// so you can use this method to back to the previous state
+    #[instrument(skip(self))]
+    async fn set_previous_state(&self, key: &StorageKey) -> Result<(), Self::Error> {
+        let key = Self::build_key(key);
+
+        update(&self.states, &key, |states| {
+            let Some(states) = states else {
+                return Ok(None);
+            };
+
+            let mut states: Vec<Box<str>> = deserialize(states, &key)?;
+            states.pop();
+
+            if states.is_empty() {
+                Ok(None)
+            } else {
+                serialize(&states, &key).map(Some)
+            }
+        })
+    }
+
+    /// Remove current state from the states stack and return it
+    /// # Arguments
+    /// * `key` - Specified key to pop state
+    /// # Returns
+    /// Removed state, if state is no exists, then `None` will be return
+    #[instrument(skip(self))]
+    async fn pop_state(&self, key: &StorageKey) -> Result<Option<Box<str>>, Self::Error> {
+        let key = Self::build_key(key);
+        let mut popped = None;
+
+        update(&self.states, &key, |states| {
+            let Some(states) = states else {
+                popped = None;
+                return Ok(None);
+            };
+
+            let mut states: Vec<Box<str>> = deserialize(states, &key)?;
+            popped = states.pop();
+
+            if states.is_empty() {
+                Ok(None)
+            } else {
+                serialize(&states, &key).map(Some)
+            }
+        })?;
+
+        Ok(popped)
+    }
+
+    /// Get state for specified key
+    /// # Arguments
+    /// * `key` - Specified key to get state
+    /// # Returns
+    /// State for specified key, if state is no exists, then `None` will be return
+    #[instrument(skip(self))]
+    async fn get_state(&self, key: &StorageKey) -> Result<Option<Box<str>>, Self::Error> {
+        self.get_states_raw(&Self::build_key(key))
+            .map(|mut states| states.pop())
+    }
+
+    /// Get states stack for specified key
+    /// # Arguments
+    /// * `key` - Specified key to get states stack
+    /// # Notes
+    /// States stack is used to store states history,
+    /// when user set new state, then current state will be push to the states stack,
+    /// so you can use this method to get states history or back to the previous state
+    /// # Returns
+    /// States stack for specified key, if states stack is no exists, then empty slice will be return
+    #[instrument(skip(self))]
+    async fn get_states(&self, key: &StorageKey) -> Result<Box<[Box<str>]>, Self::Error> {
+        self.get_states_raw(&Self::build_key(key))
+            .map(Vec::into_boxed_slice)
+    }
+
+    /// Remove states stack for specified key
+    /// # Arguments
+    /// * `key` - Specified key to remove states stack
+    /// # Notes
+    /// States stack is used to store states history,
+    /// when user set new state, then current state will be push to the states stack,
+    /// so you can use this method to clear states history
+    #[instrument(skip(self))]
+    async fn remove_states(&self, key: &StorageKey) -> Result<(), Self::Error> {
+        let key = Self::build_key(key);
+
+        self.states.remove(&key).map(|_| ()).map_err(|err| {
+            Error::new(
+                format!("Failed to remove states. Storage key: `{key}`"),
+                err,
+            )
+        })
+    }
+
+    /// Set data for specified key
+    /// # Arguments
+    /// * `key` - Specified key to set data
+    /// * `data` - Data for specified key, if empty, then data will be clear
+    #[instrument(skip(self, data))]
+    async fn set_data<Key, Value>(
+        &self,
+        key: &StorageKey,
+        data: HashMap<Key, Value>,
+    ) -> Result<(), Self::Error>
+    where
+        Value: Serialize + Send,
+        Key: Serialize + Into<Cow<'static, str>> + Send,
+    {
+        let key = Self::build_key(key);
+
+        if data.is_empty() {
+            return self.data.remove(&key).map(|_| ()).map_err(|err| {
+                Error::new(format!("Failed to remove data. Storage key: `{key}`"), err)
+            });
+        }
+
+        let mut new_data = HashMap::with_capacity(data.len());
+        for (value_key, value) in data {
+            new_data.insert(
+                value_key.into(),
+                serde_json::to_string(&value).map_err(|err| {
+                    event!(Level::ERROR, "Failed to serialize value");
+
+                    Error::new(
+                        format!("Failed to serialize value. Storage key: `{key}`"),
+                        err,
+                    )
+                })?,
+            );
+        }
+
+        self.data
+            .insert(&key, serialize(&new_data, &key)?)
+            .map(|_| ())
+            .map_err(|err| Error::new(format!("Failed to set data. Storage key: `{key}`"), err))
+    }
+
+    /// Set value to the data for specified key and value key
+    /// # Arguments
+    /// * `key` - Specified key to set data
+    /// * `value_key` - Specified value key to set value to the data
+    /// * `value` - Value for specified key and value key
+    #[instrument(skip(self, value_key, value), fields(value_key))]
+    async fn set_value<Key, Value>(
+        &self,
+        key: &StorageKey,
+        value_key: Key,
+        value: Value,
+    ) -> Result<(), Self::Error>
+    where
+        Value: Serialize + Send,
+        Key: Serialize + Into<Cow<'static, str>> + Send,
+    {
+        let key = Self::build_key(key);
+        let value_key = value_key.into();
+
+        Span::current().record("value_key", value_key.as_ref());
+
+        let value: Box<str> = serde_json::to_string(&value)
+            .map_err(|err| {
+                event!(Level::ERROR, "Failed to serialize value");
+
+                Error::new(
+                    format!("Failed to serialize value. Storage key: `{key}`"),
+                    err,
+                )
+            })?
+            .into();
+
+        update(&self.data, &key, |data| {
+            let mut data: HashMap<Box<str>, Box<str>> =
+                data.map_or_else(|| Ok(HashMap::new()), |data| deserialize(data, &key))?;
+            data.insert(value_key.as_ref().into(), value.clone());
+
+            serialize(&data, &key).map(Some)
+        })
+    }
+
+    /// Get data for specified key
+    /// # Arguments
+    /// * `key` - Specified key to get data
+    /// # Returns
+    /// Data for specified key, if data is no exists, then empty [`HashMap`] will be return
+    #[instrument(skip(self))]
+    async fn get_data<Value>(
+        &self,
+        key: &StorageKey,
+    ) -> Result<HashMap<Box<str>, Value>, Self::Error>
+    where
+        Value: DeserializeOwned,
+    {
+        let key = Self::build_key(key);
+
+        self.get_data_raw(&key)?
+            .into_iter()
+            .map(|(value_key, value)| Ok((value_key, deserialize(value.as_bytes(), &key)?)))
+            .collect()
+    }
+
+    /// Get value from the data for specified key and value key
+    /// # Arguments
+    /// * `key` - Specified key to get data
+    /// * `value_key` - Specified value key to get value from the data
+    /// # Returns
+    /// Value for specified key and value key, if value is no exists, then `None` will be return
+    #[instrument(skip(self, value_key), fields(value_key))]
+    async fn get_value<Key, Value>(
+        &self,
+        key: &StorageKey,
+        value_key: Key,
+    ) -> Result<Option<Value>, Self::Error>
+    where
+        Value: DeserializeOwned,
+        Key: Into<Cow<'static, str>> + Send,
+    {
+        let key = Self::build_key(key);
+        let value_key = value_key.into();
+
+        Span::current().record("value_key", value_key.as_ref());
+
+        self.get_data_raw(&key)?
+            .get(value_key.as_ref())
+            .map(|value| deserialize(value.as_bytes(), &key))
+            .transpose()
+    }
+
+    /// Get value from the data for specified key and value key as JSON string
+    /// # Arguments
+    /// * `key` - Specified key to get data
+    /// * `value_key` - Specified value key to get value from the data
+    /// # Returns
+    /// JSON string of the value for specified key and value key, if value is no exists, then `None` will be return
+    #[instrument(skip(self, value_key), fields(value_key))]
+    async fn get_value_raw<Key>(
+        &self,
+        key: &StorageKey,
+        value_key: Key,
+    ) -> Result<Option<Box<str>>, Self::Error>
+    where
+        Key: Into<Cow<'static, str>> + Send,
+    {
+        let key = Self::build_key(key);
+        let value_key = value_key.into();
+
+        Span::current().record("value_key", value_key.as_ref());
+
+        Ok(self.get_data_raw(&key)?.remove(value_key.as_ref()))
+    }
+
+    /// Remove data for specified key
+    /// # Arguments
+    /// * `key` - Specified key to remove data
+    #[instrument(skip(self))]
+    async fn remove_data(&self, key: &StorageKey) -> Result<(), Self::Error> {
+        let key = Self::build_key(key);
+
+        self.data
+            .remove(&key)
+            .map(|_| ())
+            .map_err(|err| Error::new(format!("Failed to remove data. Storage key: `{key}`"), err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+
+    fn storage() -> Sled {
+        Sled::new(sled::Config::new().temporary(true).open().unwrap()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_state() {
+        let storage = storage();
+
+        let key1 = StorageKey::new(0, 1, 2, None, None);
+        let key2 = StorageKey::new(2, 1, 0, Some(3), Some("business".to_owned()));
+
+        assert_eq!(storage.get_state(&key1).await.unwrap(), None);
+        assert_eq!(storage.get_states(&key2).await.unwrap(), [].into());
+
+        storage.set_state(&key1, "state1").await.unwrap();
+        storage.set_state(&key1, "state2").await.unwrap();
+        storage.set_state(&key2, "state3").await.unwrap();
+
+        assert_eq!(
+            storage.get_state(&key1).await.unwrap(),
+            Some("state2".into())
+        );
+        assert_eq!(
+            storage.get_states(&key1).await.unwrap(),
+            ["state1".into(), "state2".into()].into()
+        );
+        assert_eq!(
+            storage.get_state(&key2).await.unwrap(),
+            Some("state3".into())
+        );
+
+        storage.set_previous_state(&key1).await.unwrap();
+
+        assert_eq!(
+            storage.get_state(&key1).await.unwrap(),
+            Some("state1".into())
+        );
+
+        storage.set_previous_state(&key1).await.unwrap();
+        storage.set_previous_state(&key1).await.unwrap();
+
+        assert_eq!(storage.get_state(&key1).await.unwrap(), None);
+
+        storage.remove_states(&key2).await.unwrap();
+
+        assert_eq!(storage.get_states(&key2).await.unwrap(), [].into());
+    }
+
+    #[tokio::test]
+    async fn test_pop_state() {
+        let storage = storage().max_states(2);
+
+        let key = StorageKey::new(0, 1, 2, None, None);
+
+        assert_eq!(storage.pop_state(&key).await.unwrap(), None);
+
+        for state in ["state1", "state2", "state3"] {
+            storage.set_state(&key, state).await.unwrap();
+        }
+
+        // The oldest state is dropped
+        assert_eq!(
+            storage.get_states(&key).await.unwrap(),
+            ["state2".into(), "state3".into()].into()
+        );
+        assert_eq!(
+            storage.pop_state(&key).await.unwrap(),
+            Some("state3".into())
+        );
+        assert_eq!(
+            storage.pop_state(&key).await.unwrap(),
+            Some("state2".into())
+        );
+        assert_eq!(storage.pop_state(&key).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_value_raw() {
+        let storage = storage();
+
+        let key = StorageKey::new(0, 1, 2, None, None);
+
+        assert_eq!(storage.get_value_raw(&key, "key").await.unwrap(), None);
+
+        storage
+            .set_value(&key, "key", serde_json::json!({"a": 1, "b": [true, null]}))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            storage.get_value_raw(&key, "key").await.unwrap().as_deref(),
+            Some(r#"{"a":1,"b":[true,null]}"#)
+        );
+        assert_eq!(storage.get_value_raw(&key, "other").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_data() {
+        let storage = storage();
+
+        let key1 = StorageKey::new(0, 1, 2, None, None);
+        let key2 = StorageKey::new(0, 1, 2, None, None).destiny("other");
+
+        assert!(storage
+            .get_data::<Box<str>>(&key1)
+            .await
+            .unwrap()
+            .is_empty());
+
+        storage
+            .set_data(
+                &key1,
+                HashMap::from([("key1", "value1"), ("key2", "value2")]),
+            )
+            .await
+            .unwrap();
+        storage.set_value(&key1, "key1", "value11").await.unwrap();
+        storage.set_value(&key2, "key3", 3).await.unwrap();
+
+        let data = storage.get_data::<Box<str>>(&key1).await.unwrap();
+
+        assert_eq!(data.len(), 2);
+        assert_eq!(data["key1"].as_ref(), "value11");
+        assert_eq!(data["key2"].as_ref(), "value2");
+        assert_eq!(
+            storage.get_value::<_, i32>(&key2, "key3").await.unwrap(),
+            Some(3)
+        );
+        assert_eq!(
+            storage.get_value::<_, i32>(&key1, "key3").await.unwrap(),
+            None
+        );
+
+        storage.remove_data(&key1).await.unwrap();
+
+        assert!(storage
+            .get_data::<Box<str>>(&key1)
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(storage.get_data::<i32>(&key2).await.unwrap().len(), 1);
+
+        storage
+            .set_data::<&str, i32>(&key2, HashMap::new())
+            .await
+            .unwrap();
+
+        assert!(storage.get_data::<i32>(&key2).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_persist() {
+        let db = sled::Config::new().temporary(true).open().unwrap();
+        let key = StorageKey::new(0, 1, 2, None, None);
+
+        let storage = Sled::new(db.clone()).unwrap();
+        storage.set_state(&key, "state").await.unwrap();
+        storage.set_value(&key, "key", "value").await.unwrap();
+
+        // New storage in the same database sees the same states and data
+        let storage = Sled::new(db).unwrap();
+
+        assert_eq!(storage.get_state(&key).await.unwrap(), Some("state".into()));
+        assert_eq!(
+            storage
+                .get_value::<_, Box<str>>(&key, "key")
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("value")
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_set_value() {
+        const TASKS: usize = 64;
+
+        let storage = Arc::new(storage());
+        let key = Arc::new(StorageKey::new(0, 1, 2, None, None));
+
+        let handles = (0..TASKS)
+            .map(|index| {
+                let storage = Arc::clone(&storage);
+                let key = Arc::clone(&key);
+
+                tokio::spawn(async move {
+                    storage
+                        .set_value(&key, format!("key{index}"), index)
+                        .await
+                        .unwrap();
+                    storage
+                        .set_state(&key, format!("state{index}"))
+                        .await
+                        .unwrap();
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        let data = storage.get_data::<usize>(&key).await.unwrap();
+
+        // No update is lost
+        assert_eq!(data.len(), TASKS);
+        assert!((0..TASKS).all(|index| data[format!("key{index}").as_str()] == index));
+        assert_eq!(storage.get_states(&key).await.unwrap().len(), TASKS);
+    }
+}
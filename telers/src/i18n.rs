@@ -0,0 +1,37 @@
+//! This module contains the implementation of the internationalization (i18n) of the bot.
+//!
+//! Components of the i18n:
+//! * [`Backend`]:
+//!   Backend is used to get translations of messages by their keys for the specified locale.
+//!   Backend is a trait, so you can implement it for any localization system or use one of the ready-made implementations.
+//!   Ready-made implementation is [`Fluent`] (feature: `fluent`) based on [Project Fluent](https://projectfluent.org/) with `.ftl` files.
+//! * [`LocaleResolver`]:
+//!   Locale resolver is used to determine the locale of the user by the update and context.
+//!   You can implement it for your own types or use function-like resolvers (`async fn(&Bot, &Update, &Context) -> Option<Box<str>>`).
+//!   Ready-made implementation is [`FSMLocale`] that gets the locale from the FSM data of the user,
+//!   so the user can choose the locale in the bot and override the locale of their Telegram client.
+//! * [`Translator`]:
+//!   Translator is a type that wraps the [`Backend`] and the resolved locale to provide convenient API to translate messages.
+//!   It's created in the [`I18n middleware`] and can be used as handler argument.
+//!
+//! [`I18n middleware`] resolves the locale in the following order:
+//! 1. Locale resolvers in the order of their registration
+//! 2. `language_code` of the user from the update
+//! 3. Default locale of the middleware
+//!
+//! If the backend doesn't support the resolved locale, then the next candidate is used.
+//! For example, if the user locale is `en-US` and the backend supports only `en`, then `en` will be used.
+//!
+//! [`I18n middleware`]: crate::middlewares::outer::I18n
+
+pub mod backend;
+#[cfg(feature = "fluent")]
+pub mod fluent;
+pub mod locale;
+pub mod translator;
+
+pub use backend::{Args, Backend, Value};
+#[cfg(feature = "fluent")]
+pub use fluent::{Error as FluentError, Fluent};
+pub use locale::{FSMLocale, LocaleResolver};
+pub use translator::Translator;
@@ -0,0 +1,133 @@
+use std::{borrow::Cow, sync::Arc};
+
+/// Value of the argument which can be passed to the message
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value<'a> {
+    String(Cow<'a, str>),
+    Number(f64),
+}
+
+impl<'a> From<&'a str> for Value<'a> {
+    fn from(value: &'a str) -> Self {
+        Self::String(Cow::Borrowed(value))
+    }
+}
+
+impl From<String> for Value<'_> {
+    fn from(value: String) -> Self {
+        Self::String(Cow::Owned(value))
+    }
+}
+
+impl<'a> From<Cow<'a, str>> for Value<'a> {
+    fn from(value: Cow<'a, str>) -> Self {
+        Self::String(value)
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Value<'_> {
+                fn from(value: $ty) -> Self {
+                    Self::Number(f64::from(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(i8, i16, i32, u8, u16, u32, f32, f64);
+
+impl From<i64> for Value<'_> {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: i64) -> Self {
+        Self::Number(value as f64)
+    }
+}
+
+impl From<u64> for Value<'_> {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: u64) -> Self {
+        Self::Number(value as f64)
+    }
+}
+
+impl From<usize> for Value<'_> {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: usize) -> Self {
+        Self::Number(value as f64)
+    }
+}
+
+/// Arguments which can be passed to the message
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Args<'a> {
+    args: Vec<(Cow<'a, str>, Value<'a>)>,
+}
+
+impl<'a> Args<'a> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { args: vec![] }
+    }
+
+    /// Adds an argument with the specified key and value
+    #[must_use]
+    pub fn arg(mut self, key: impl Into<Cow<'a, str>>, value: impl Into<Value<'a>>) -> Self {
+        self.args.push((key.into(), value.into()));
+        self
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Value<'a>)> {
+        self.args.iter().map(|(key, value)| (key.as_ref(), value))
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.args.is_empty()
+    }
+}
+
+impl<'a, K, V> FromIterator<(K, V)> for Args<'a>
+where
+    K: Into<Cow<'a, str>>,
+    V: Into<Value<'a>>,
+{
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        Self {
+            args: iter
+                .into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+        }
+    }
+}
+
+/// Backend is used to get translations of messages by their keys for the specified locale
+pub trait Backend: Send + Sync {
+    /// Check if the backend supports the locale
+    fn has_locale(&self, locale: &str) -> bool;
+
+    /// Get translation of the message
+    /// # Arguments
+    /// * `locale` - Locale of the translation
+    /// * `key` - Key of the message
+    /// * `args` - Arguments of the message
+    /// # Returns
+    /// Translation of the message, if the locale or the message is not found, then `None` will be return
+    fn translate(&self, locale: &str, key: &str, args: Option<&Args>) -> Option<String>;
+}
+
+impl<T: ?Sized> Backend for Arc<T>
+where
+    T: Backend,
+{
+    fn has_locale(&self, locale: &str) -> bool {
+        T::has_locale(self, locale)
+    }
+
+    fn translate(&self, locale: &str, key: &str, args: Option<&Args>) -> Option<String> {
+        T::translate(self, locale, key, args)
+    }
+}
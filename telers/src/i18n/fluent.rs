@@ -0,0 +1,268 @@
+use super::{Args, Backend, Value};
+
+use fluent_bundle::{
+    concurrent::FluentBundle, FluentArgs, FluentError, FluentResource, FluentValue,
+};
+use std::{
+    collections::HashMap,
+    fmt::{self, Debug, Formatter},
+    fs, io,
+    path::Path,
+};
+use thiserror;
+use tracing::{event, Level};
+use unic_langid::{LanguageIdentifier, LanguageIdentifierError};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Invalid locale `{locale}`: {source}")]
+    Locale {
+        locale: Box<str>,
+        source: LanguageIdentifierError,
+    },
+    #[error("Failed to parse resource for locale `{locale}`: {errors:?}")]
+    Parse {
+        locale: Box<str>,
+        errors: Vec<FluentError>,
+    },
+    #[error("Failed to add resource for locale `{locale}`: {errors:?}")]
+    Resource {
+        locale: Box<str>,
+        errors: Vec<FluentError>,
+    },
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+/// Backend based on [Project Fluent](https://projectfluent.org/).
+/// Each locale has its own bundle with resources in the FTL syntax.
+/// # Notes
+/// Unicode isolation marks around placeables are disabled, because Telegram clients display them as is.
+#[derive(Default)]
+pub struct Fluent {
+    bundles: HashMap<Box<str>, FluentBundle<FluentResource>>,
+}
+
+impl Debug for Fluent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Fluent")
+            .field("locales", &self.bundles.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl Fluent {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the resource in the FTL syntax to the bundle of the locale
+    /// # Arguments
+    /// * `locale` - Locale of the resource, for example, `en` or `en-US`
+    /// * `source` - Resource in the FTL syntax
+    /// # Errors
+    /// - If the locale is invalid
+    /// - If the resource has syntax errors
+    /// - If the resource has messages which are already added to the bundle
+    pub fn resource(
+        mut self,
+        locale: impl Into<Box<str>>,
+        source: impl Into<String>,
+    ) -> Result<Self, Error> {
+        let locale = locale.into();
+
+        let resource =
+            FluentResource::try_new(source.into()).map_err(|(_, errors)| Error::Parse {
+                locale: locale.clone(),
+                errors: errors.into_iter().map(FluentError::ParserError).collect(),
+            })?;
+
+        if !self.bundles.contains_key(&locale) {
+            let language_identifier: LanguageIdentifier =
+                locale.parse().map_err(|source| Error::Locale {
+                    locale: locale.clone(),
+                    source,
+                })?;
+
+            let mut bundle = FluentBundle::new_concurrent(vec![language_identifier]);
+            bundle.set_use_isolating(false);
+
+            self.bundles.insert(locale.clone(), bundle);
+        }
+
+        self.bundles
+            .get_mut(&locale)
+            .expect("Bundle should be inserted above")
+            .add_resource(resource)
+            .map_err(|errors| Error::Resource { locale, errors })?;
+
+        Ok(self)
+    }
+
+    /// Adds the resource from the file in the FTL syntax to the bundle of the locale
+    /// # Errors
+    /// - If the file can't be read
+    /// - Errors of [`Fluent::resource`]
+    pub fn file(self, locale: impl Into<Box<str>>, path: impl AsRef<Path>) -> Result<Self, Error> {
+        self.resource(locale, fs::read_to_string(path)?)
+    }
+
+    /// Adds resources from the directory, where each subdirectory is a locale with `.ftl` files, for example:
+    /// ```text
+    /// locales/
+    /// ├── en/
+    /// │   ├── main.ftl
+    /// │   └── errors.ftl
+    /// └── ru/
+    ///     ├── main.ftl
+    ///     └── errors.ftl
+    /// ```
+    /// # Errors
+    /// - If the directory can't be read
+    /// - Errors of [`Fluent::file`]
+    pub fn dir(mut self, path: impl AsRef<Path>) -> Result<Self, Error> {
+        for locale_entry in fs::read_dir(path)? {
+            let locale_entry = locale_entry?;
+
+            if !locale_entry.file_type()?.is_dir() {
+                continue;
+            }
+
+            let locale = locale_entry.file_name().to_string_lossy().into_owned();
+
+            for file_entry in fs::read_dir(locale_entry.path())? {
+                let file_path = file_entry?.path();
+
+                if file_path
+                    .extension()
+                    .map_or(true, |extension| extension != "ftl")
+                {
+                    continue;
+                }
+
+                self = self.file(locale.as_str(), file_path)?;
+            }
+        }
+
+        Ok(self)
+    }
+
+    pub fn locales(&self) -> impl Iterator<Item = &str> {
+        self.bundles.keys().map(AsRef::as_ref)
+    }
+}
+
+impl Backend for Fluent {
+    fn has_locale(&self, locale: &str) -> bool {
+        self.bundles.contains_key(locale)
+    }
+
+    /// Get translation of the message
+    /// # Notes
+    /// You can get an attribute of the message by the key in the format `message.attribute`
+    fn translate(&self, locale: &str, key: &str, args: Option<&Args>) -> Option<String> {
+        let bundle = self.bundles.get(locale)?;
+
+        let (message_key, attribute_key) = match key.split_once('.') {
+            Some((message_key, attribute_key)) => (message_key, Some(attribute_key)),
+            None => (key, None),
+        };
+
+        let message = bundle.get_message(message_key)?;
+        let pattern = match attribute_key {
+            Some(attribute_key) => message.get_attribute(attribute_key)?.value(),
+            None => message.value()?,
+        };
+
+        let args = args.map(|args| {
+            args.iter()
+                .map(|(key, value)| {
+                    let value = match value {
+                        Value::String(value) => FluentValue::from(value.as_ref()),
+                        Value::Number(value) => FluentValue::from(*value),
+                    };
+
+                    (key, value)
+                })
+                .collect::<FluentArgs>()
+        });
+
+        let mut errors = vec![];
+        let text = bundle.format_pattern(pattern, args.as_ref(), &mut errors);
+
+        if !errors.is_empty() {
+            event!(
+                Level::WARN,
+                locale,
+                key,
+                ?errors,
+                "Errors occurred while formatting message",
+            );
+        }
+
+        Some(text.into_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resource_errors() {
+        assert!(matches!(
+            Fluent::new().resource("en", "hello = Hello\n-"),
+            Err(Error::Parse { .. })
+        ));
+        assert!(matches!(
+            Fluent::new().resource("-", "hello = Hello"),
+            Err(Error::Locale { .. })
+        ));
+        assert!(matches!(
+            Fluent::new()
+                .resource("en", "hello = Hello")
+                .unwrap()
+                .resource("en", "hello = Hi"),
+            Err(Error::Resource { .. })
+        ));
+    }
+
+    #[test]
+    fn test_translate() {
+        let backend = Fluent::new()
+            .resource(
+                "en",
+                "hello = Hello, { $name }!\n    .title = Greeting\n\
+                 apples = { $count ->\n    [one] One apple\n   *[other] { $count } apples\n}",
+            )
+            .unwrap();
+
+        assert!(backend.has_locale("en"));
+        assert!(!backend.has_locale("ru"));
+
+        let args = Args::new().arg("name", "Alice");
+        assert_eq!(
+            backend.translate("en", "hello", Some(&args)).as_deref(),
+            Some("Hello, Alice!")
+        );
+        assert_eq!(
+            backend.translate("en", "hello.title", None).as_deref(),
+            Some("Greeting")
+        );
+        assert_eq!(
+            backend
+                .translate("en", "apples", Some(&Args::new().arg("count", 1)))
+                .as_deref(),
+            Some("One apple")
+        );
+        assert_eq!(
+            backend
+                .translate("en", "apples", Some(&Args::new().arg("count", 5)))
+                .as_deref(),
+            Some("5 apples")
+        );
+        assert_eq!(backend.translate("en", "unknown", None), None);
+        assert_eq!(backend.translate("ru", "hello", None), None);
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{
+    client::{Bot, Reqwest},
+    context::Context,
+    fsm::{storage::Error as StorageError, Context as FSMContext, Storage},
+    types::Update,
+};
+
+use async_trait::async_trait;
+use std::{future::Future, marker::PhantomData};
+use tracing::{event, Level};
+
+/// Locale resolver is used to determine the locale of the user by the update and context
+#[async_trait]
+pub trait LocaleResolver<Client = Reqwest>: Send + Sync {
+    /// Resolve the locale
+    /// # Returns
+    /// Locale of the user, if the locale can't be resolved, then `None` will be return
+    /// and the next resolver will be used
+    async fn resolve(
+        &self,
+        bot: &Bot<Client>,
+        update: &Update,
+        context: &Context,
+    ) -> Option<Box<str>>;
+}
+
+/// To possible use function-like as locale resolvers
+#[async_trait]
+impl<Client, Func, Fut> LocaleResolver<Client> for Func
+where
+    Client: Sync,
+    Func: Fn(&Bot<Client>, &Update, &Context) -> Fut + Send + Sync,
+    Fut: Future<Output = Option<Box<str>>> + Send,
+{
+    async fn resolve(
+        &self,
+        bot: &Bot<Client>,
+        update: &Update,
+        context: &Context,
+    ) -> Option<Box<str>> {
+        self(bot, update, context).await
+    }
+}
+
+/// Locale resolver that gets the locale from the FSM data of the user by the key
+/// # Notes
+/// This resolver requires `FSMContext` middleware to be registered before the `I18n` middleware,
+/// otherwise FSM context isn't available and the locale isn't resolved
+pub struct FSMLocale<S> {
+    key: &'static str,
+    _storage: PhantomData<fn() -> S>,
+}
+
+impl<S> FSMLocale<S> {
+    /// Creates a new [`FSMLocale`] resolver with the default key `locale`
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            key: "locale",
+            _storage: PhantomData,
+        }
+    }
+
+    #[must_use]
+    pub const fn key(self, val: &'static str) -> Self {
+        Self { key: val, ..self }
+    }
+}
+
+impl<S> Default for FSMLocale<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Clone for FSMLocale<S> {
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key,
+            _storage: PhantomData,
+        }
+    }
+}
+
+impl<S> std::fmt::Debug for FSMLocale<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FSMLocale").field("key", &self.key).finish()
+    }
+}
+
+#[async_trait]
+impl<Client, S> LocaleResolver<Client> for FSMLocale<S>
+where
+    Client: Sync,
+    S: Storage + Clone + Send + Sync + 'static,
+{
+    async fn resolve(
+        &self,
+        _bot: &Bot<Client>,
+        _update: &Update,
+        context: &Context,
+    ) -> Option<Box<str>> {
+        let fsm_context = context
+            .get("fsm_context")
+            .and_then(|fsm_context| fsm_context.downcast_ref::<FSMContext<S>>().cloned())?;
+
+        match fsm_context.get_value(self.key).await {
+            Ok(locale) => locale,
+            Err(err) => {
+                let err: StorageError = err.into();
+
+                event!(Level::ERROR, error = %err, "Failed to get locale from FSM data");
+
+                None
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "memory-storage"))]
+mod tests {
+    use super::*;
+    use crate::{
+        fsm::{MemoryStorage, StorageKey},
+        test,
+    };
+
+    #[tokio::test]
+    async fn test_fsm_locale() {
+        let bot = Bot::<Reqwest>::default();
+        let update = test::message("text");
+        let context = Context::new();
+        let resolver = FSMLocale::<MemoryStorage>::new();
+
+        // FSM context isn't available
+        assert_eq!(resolver.resolve(&bot, &update, &context).await, None);
+
+        let fsm_context = FSMContext::new(
+            MemoryStorage::new(),
+            StorageKey::new(bot.id(), test::CHAT_ID, test::USER_ID, None, None),
+        );
+        context.insert("fsm_context", Box::new(fsm_context.clone()));
+
+        // Locale isn't stored
+        assert_eq!(resolver.resolve(&bot, &update, &context).await, None);
+
+        fsm_context.set_value("locale", "uk").await.unwrap();
+
+        assert_eq!(
+            resolver.resolve(&bot, &update, &context).await.as_deref(),
+            Some("uk")
+        );
+        assert_eq!(
+            resolver
+                .clone()
+                .key("language")
+                .resolve(&bot, &update, &context)
+                .await,
+            None
+        );
+    }
+}
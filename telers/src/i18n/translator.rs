@@ -0,0 +1,75 @@
+use super::{Args, Backend};
+
+use crate::extractors::FromContext;
+
+use std::{
+    fmt::{self, Debug, Formatter},
+    sync::Arc,
+};
+
+/// Translator is used to translate messages to the locale of the user
+#[derive(Clone, FromContext)]
+#[context(
+    key = "i18n_translator",
+    description = "Translator is used to translate messages to the locale of the user. \
+    This context is available only if `I18n` middleware is used."
+)]
+pub struct Translator {
+    backend: Arc<dyn Backend>,
+    locale: Box<str>,
+}
+
+impl Translator {
+    #[must_use]
+    pub fn new(backend: Arc<dyn Backend>, locale: impl Into<Box<str>>) -> Self {
+        Self {
+            backend,
+            locale: locale.into(),
+        }
+    }
+
+    /// Gets the locale which is used to translate messages
+    #[must_use]
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// Creates a new [`Translator`] with the same backend, but with another locale
+    #[must_use]
+    pub fn with_locale(&self, locale: impl Into<Box<str>>) -> Self {
+        Self {
+            backend: Arc::clone(&self.backend),
+            locale: locale.into(),
+        }
+    }
+
+    /// Translates the message by the key
+    /// # Arguments
+    /// * `key` - Key of the message
+    /// * `args` - Arguments of the message, you can pass `None` if the message doesn't have arguments
+    /// # Returns
+    /// Translation of the message, if the message is not found, then the key will be return
+    #[must_use]
+    pub fn t<'a>(&self, key: &str, args: impl Into<Option<&'a Args<'a>>>) -> String {
+        self.try_t(key, args).unwrap_or_else(|| key.to_owned())
+    }
+
+    /// Translates the message by the key
+    /// # Arguments
+    /// * `key` - Key of the message
+    /// * `args` - Arguments of the message, you can pass `None` if the message doesn't have arguments
+    /// # Returns
+    /// Translation of the message, if the message is not found, then `None` will be return
+    #[must_use]
+    pub fn try_t<'a>(&self, key: &str, args: impl Into<Option<&'a Args<'a>>>) -> Option<String> {
+        self.backend.translate(&self.locale, key, args.into())
+    }
+}
+
+impl Debug for Translator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Translator")
+            .field("locale", &self.locale)
+            .finish_non_exhaustive()
+    }
+}
@@ -11,13 +11,15 @@ pub mod event;
 pub mod extractors;
 pub mod filters;
 pub mod fsm;
+pub mod i18n;
 pub mod methods;
 pub mod middlewares;
 pub mod router;
+pub mod test;
 pub mod types;
 pub mod utils;
 
-pub use telers_macros::{FromContext, FromEvent};
+pub use telers_macros::{md, FromContext, FromEvent};
 
 pub use client::Bot;
 pub use context::Context;
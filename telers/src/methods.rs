@@ -22,12 +22,106 @@
 //!
 //! You can check more examples of usage methods in the [`examples`] directory.
 //!
+//! # Feature flags
+//! Some groups of methods can be excluded from the build by disabling the `all-methods` default feature
+//! and enabling only the needed groups:
+//! - `stickers` - methods for creating and managing sticker sets
+//! - `payments` - methods for sending invoices and answering shipping and pre-checkout queries
+//! - `stars` - methods for Telegram Stars transactions and refunds
+//! - `games` - methods for games and their scores
+//! - `passport` - method for reporting errors in Telegram Passport data and its error types
+//!
 //! [`examples`]: https://github.com/Desiders/telers/tree/dev-1.x/examples
 
+/// Implements shortcuts for the send methods, which build [`ReplyParameters`](crate::types::ReplyParameters) under the hood.
+/// Methods with `message_thread_id` field also get shortcuts to send the message to the forum topic,
+/// methods without it are marked with `without_thread`.
+macro_rules! impl_reply_shortcuts {
+    ($method:ident $(<$lt:lifetime>)?) => {
+        impl_reply_shortcuts!(@reply $method $(<$lt>)?);
+
+        impl$(<$lt>)? $method$(<$lt>)? {
+            /// Replies to the message from the same chat.
+            /// If the message is sent to a forum topic, then the reply is sent to the same topic
+            #[must_use]
+            pub fn reply_to_message(self, message: &$crate::types::Message) -> Self {
+                let reply = self.reply_to(message.id());
+
+                if message.is_topic_message() == Some(true) {
+                    return Self {
+                        message_thread_id: message.thread_id(),
+                        ..reply
+                    };
+                }
+
+                reply
+            }
+
+            #[doc = concat!(
+                "Sends the message to the forum topic.\n",
+                "Alias to [`", stringify!($method), "::message_thread_id`] method",
+            )]
+            #[must_use]
+            pub fn message_thread(self, thread_id: i64) -> Self {
+                self.message_thread_id(thread_id)
+            }
+        }
+    };
+    ($method:ident $(<$lt:lifetime>)?, without_thread) => {
+        impl_reply_shortcuts!(@reply $method $(<$lt>)?);
+
+        impl$(<$lt>)? $method$(<$lt>)? {
+            /// Replies to the message from the same chat
+            #[must_use]
+            pub fn reply_to_message(self, message: &$crate::types::Message) -> Self {
+                self.reply_to(message.id())
+            }
+        }
+    };
+    (@reply $method:ident $(<$lt:lifetime>)?) => {
+        impl$(<$lt>)? $method$(<$lt>)? {
+            /// Replies to the message with the identifier in the current chat
+            #[must_use]
+            pub fn reply_to(self, message_id: i64) -> Self {
+                self.reply_parameters($crate::types::ReplyParameters::new(message_id))
+            }
+
+            /// Replies to the message with the identifier in another chat
+            #[must_use]
+            pub fn reply_to_in_chat(
+                self,
+                chat_id: impl Into<$crate::types::ChatIdKind>,
+                message_id: i64,
+            ) -> Self {
+                self.reply_parameters(
+                    $crate::types::ReplyParameters::new(message_id).chat_id(chat_id),
+                )
+            }
+
+            #[doc = concat!(
+                "Sends the message even if the message to be replied to is not found.\n",
+                "Has effect only if the reply is set, for example, by [`", stringify!($method), "::reply_to`]",
+            )]
+            #[must_use]
+            pub fn allow_sending_without_reply(self) -> Self {
+                Self {
+                    reply_parameters: self
+                        .reply_parameters
+                        .map(|val| val.allow_sending_without_reply(true)),
+                    ..self
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "stickers")]
 pub mod add_sticker_to_set;
 pub mod answer_callback_query;
 pub mod answer_inline_query;
+#[cfg(feature = "payments")]
 pub mod answer_pre_checkout_query;
+#[cfg(feature = "payments")]
 pub mod answer_shipping_query;
 pub mod answer_web_app_query;
 pub mod approve_chat_join_request;
@@ -41,7 +135,9 @@ pub mod copy_messages;
 pub mod create_chat_invite_link;
 pub mod create_chat_subscription_invite_link;
 pub mod create_forum_topic;
+#[cfg(feature = "payments")]
 pub mod create_invoice_link;
+#[cfg(feature = "stickers")]
 pub mod create_new_sticker_set;
 pub mod decline_chat_join_request;
 pub mod delete_chat_photo;
@@ -50,8 +146,11 @@ pub mod delete_forum_topic;
 pub mod delete_message;
 pub mod delete_messages;
 pub mod delete_my_commands;
+#[cfg(feature = "stickers")]
 pub mod delete_sticker_from_set;
+#[cfg(feature = "stickers")]
 pub mod delete_sticker_set;
+pub mod delete_webhook;
 pub mod edit_chat_invite_link;
 pub mod edit_chat_subscription_invite_link;
 pub mod edit_forum_topic;
@@ -70,9 +169,11 @@ pub mod get_chat_administrators;
 pub mod get_chat_member;
 pub mod get_chat_member_count;
 pub mod get_chat_menu_button;
+#[cfg(feature = "stickers")]
 pub mod get_custom_emoji_stickers;
 pub mod get_file;
 pub mod get_forum_topic_icon_stickers;
+#[cfg(feature = "games")]
 pub mod get_game_high_scores;
 pub mod get_me;
 pub mod get_my_commands;
@@ -80,7 +181,9 @@ pub mod get_my_default_administrator_rights;
 pub mod get_my_description;
 pub mod get_my_name;
 pub mod get_my_short_description;
+#[cfg(feature = "stars")]
 pub mod get_star_transactions;
+#[cfg(feature = "stickers")]
 pub mod get_sticker_set;
 pub mod get_updates;
 pub mod get_user_chat_boosts;
@@ -90,9 +193,11 @@ pub mod leave_chat;
 pub mod log_out;
 pub mod pin_chat_message;
 pub mod promote_chat_member;
+#[cfg(feature = "stars")]
 pub mod refund_star_payment;
 pub mod reopen_forum_topic;
 pub mod reopen_general_forum_topic;
+#[cfg(feature = "stickers")]
 pub mod replace_sticker_in_set;
 pub mod restrict_chat_member;
 pub mod revoke_chat_invite_link;
@@ -102,7 +207,9 @@ pub mod send_chat_action;
 pub mod send_contact;
 pub mod send_dice;
 pub mod send_document;
+#[cfg(feature = "games")]
 pub mod send_game;
+#[cfg(feature = "payments")]
 pub mod send_invoice;
 pub mod send_location;
 pub mod send_media_group;
@@ -121,7 +228,9 @@ pub mod set_chat_menu_button;
 pub mod set_chat_permissions;
 pub mod set_chat_sticker_set;
 pub mod set_chat_title;
+#[cfg(feature = "stickers")]
 pub mod set_custom_emoji_sticker_set_thumbnail;
+#[cfg(feature = "games")]
 pub mod set_game_score;
 pub mod set_message_reaction;
 pub mod set_my_commands;
@@ -129,13 +238,21 @@ pub mod set_my_default_administrator_rights;
 pub mod set_my_description;
 pub mod set_my_name;
 pub mod set_my_short_description;
+#[cfg(feature = "passport")]
 pub mod set_passport_data_errors;
+#[cfg(feature = "stickers")]
 pub mod set_sticker_emoji_list;
+#[cfg(feature = "stickers")]
 pub mod set_sticker_keywords;
+#[cfg(feature = "stickers")]
 pub mod set_sticker_mask_position;
+#[cfg(feature = "stickers")]
 pub mod set_sticker_position_in_set;
+#[cfg(feature = "stickers")]
 pub mod set_sticker_set_thumbnail;
+#[cfg(feature = "stickers")]
 pub mod set_sticker_set_title;
+pub mod set_webhook;
 pub mod stop_message_live_location;
 pub mod stop_poll;
 pub mod unban_chat_member;
@@ -145,18 +262,22 @@ pub mod unpin_all_chat_messages;
 pub mod unpin_all_forum_topic_messages;
 pub mod unpin_all_general_forum_topic_messages;
 pub mod unpin_chat_message;
+#[cfg(feature = "stickers")]
 pub mod upload_sticker_file;
 
+#[cfg(feature = "stickers")]
 pub use add_sticker_to_set::AddStickerToSet;
 pub use answer_callback_query::AnswerCallbackQuery;
 pub use answer_inline_query::AnswerInlineQuery;
-pub use answer_pre_checkout_query::AnswerPreCheckoutQuery;
-pub use answer_shipping_query::AnswerShippingQuery;
+#[cfg(feature = "payments")]
+pub use answer_pre_checkout_query::{AnswerPreCheckoutQuery, InvalidAnswerPreCheckoutQuery};
+#[cfg(feature = "payments")]
+pub use answer_shipping_query::{AnswerShippingQuery, InvalidAnswerShippingQuery};
 pub use answer_web_app_query::AnswerWebAppQuery;
 pub use approve_chat_join_request::ApproveChatJoinRequest;
 pub use ban_chat_member::BanChatMember;
 pub use ban_chat_sender_chat::BanChatSenderChat;
-pub use base::{Request, Response, TelegramMethod};
+pub use base::{PaginatedMethod, Request, Response, TelegramMethod};
 pub use close_forum_topic::CloseForumTopic;
 pub use close_general_forum_topic::CloseGeneralForumTopic;
 pub use copy_message::CopyMessage;
@@ -164,7 +285,9 @@ pub use copy_messages::CopyMessages;
 pub use create_chat_invite_link::CreateChatInviteLink;
 pub use create_chat_subscription_invite_link::CreateChatSubscriptionInviteLink;
 pub use create_forum_topic::CreateForumTopic;
+#[cfg(feature = "payments")]
 pub use create_invoice_link::CreateInvoiceLink;
+#[cfg(feature = "stickers")]
 pub use create_new_sticker_set::CreateNewStickerSet;
 pub use decline_chat_join_request::DeclineChatJoinRequest;
 pub use delete_chat_photo::DeleteChatPhoto;
@@ -173,8 +296,11 @@ pub use delete_forum_topic::DeleteForumTopic;
 pub use delete_message::DeleteMessage;
 pub use delete_messages::DeleteMessages;
 pub use delete_my_commands::DeleteMyCommands;
+#[cfg(feature = "stickers")]
 pub use delete_sticker_from_set::DeleteStickerFromSet;
+#[cfg(feature = "stickers")]
 pub use delete_sticker_set::DeleteStickerSet;
+pub use delete_webhook::DeleteWebhook;
 pub use edit_chat_invite_link::EditChatInviteLink;
 pub use edit_chat_subscription_invite_link::EditChatSubscriptionInviteLink;
 pub use edit_forum_topic::EditForumTopic;
@@ -193,9 +319,11 @@ pub use get_chat_administrators::GetChatAdministrators;
 pub use get_chat_member::GetChatMember;
 pub use get_chat_member_count::GetChatMemberCount;
 pub use get_chat_menu_button::GetChatMenuButton;
+#[cfg(feature = "stickers")]
 pub use get_custom_emoji_stickers::GetCustomEmojiStickers;
 pub use get_file::GetFile;
 pub use get_forum_topic_icon_stickers::GetForumTopicIconStickers;
+#[cfg(feature = "games")]
 pub use get_game_high_scores::GetGameHighScores;
 pub use get_me::GetMe;
 pub use get_my_commands::GetMyCommands;
@@ -203,7 +331,9 @@ pub use get_my_default_administrator_rights::GetMyDefaultAdministratorRights;
 pub use get_my_description::GetMyDescription;
 pub use get_my_name::GetMyName;
 pub use get_my_short_description::GetMyShortDescription;
+#[cfg(feature = "stars")]
 pub use get_star_transactions::GetStarTransactions;
+#[cfg(feature = "stickers")]
 pub use get_sticker_set::GetStickerSet;
 pub use get_updates::GetUpdates;
 pub use get_user_chat_boosts::GetUserChatBoosts;
@@ -213,9 +343,11 @@ pub use leave_chat::LeaveChat;
 pub use log_out::LogOut;
 pub use pin_chat_message::PinChatMessage;
 pub use promote_chat_member::PromoteChatMember;
+#[cfg(feature = "stars")]
 pub use refund_star_payment::RefundStarPayment;
 pub use reopen_forum_topic::ReopenForumTopic;
 pub use reopen_general_forum_topic::ReopenGeneralForumTopic;
+#[cfg(feature = "stickers")]
 pub use replace_sticker_in_set::ReplaceStickerInSet;
 pub use restrict_chat_member::RestrictChatMember;
 pub use revoke_chat_invite_link::RevokeChatInviteLink;
@@ -225,14 +357,16 @@ pub use send_chat_action::SendChatAction;
 pub use send_contact::SendContact;
 pub use send_dice::SendDice;
 pub use send_document::SendDocument;
+#[cfg(feature = "games")]
 pub use send_game::SendGame;
+#[cfg(feature = "payments")]
 pub use send_invoice::SendInvoice;
 pub use send_location::SendLocation;
 pub use send_media_group::SendMediaGroup;
 pub use send_message::SendMessage;
 pub use send_paid_media::SendPaidMedia;
 pub use send_photo::SendPhoto;
-pub use send_poll::SendPoll;
+pub use send_poll::{InvalidCorrectOption, SendPoll};
 pub use send_sticker::SendSticker;
 pub use send_venue::SendVenue;
 pub use send_video::SendVideo;
@@ -244,7 +378,9 @@ pub use set_chat_menu_button::SetChatMenuButton;
 pub use set_chat_permissions::SetChatPermissions;
 pub use set_chat_sticker_set::SetChatStickerSet;
 pub use set_chat_title::SetChatTitle;
+#[cfg(feature = "stickers")]
 pub use set_custom_emoji_sticker_set_thumbnail::SetCustomEmojiStickerSetThumbnail;
+#[cfg(feature = "games")]
 pub use set_game_score::SetGameScore;
 pub use set_message_reaction::SetMessageReaction;
 pub use set_my_commands::SetMyCommands;
@@ -252,13 +388,21 @@ pub use set_my_default_administrator_rights::SetMyDefaultAdministratorRights;
 pub use set_my_description::SetMyDescription;
 pub use set_my_name::SetMyName;
 pub use set_my_short_description::SetMyShortDescription;
+#[cfg(feature = "passport")]
 pub use set_passport_data_errors::SetPassportDataErrors;
+#[cfg(feature = "stickers")]
 pub use set_sticker_emoji_list::SetStickerEmojiList;
+#[cfg(feature = "stickers")]
 pub use set_sticker_keywords::SetStickerKeywords;
+#[cfg(feature = "stickers")]
 pub use set_sticker_mask_position::SetStickerMaskPosition;
+#[cfg(feature = "stickers")]
 pub use set_sticker_position_in_set::SetStickerPositionInSet;
+#[cfg(feature = "stickers")]
 pub use set_sticker_set_thumbnail::SetStickerSetThumbnail;
+#[cfg(feature = "stickers")]
 pub use set_sticker_set_title::SetStickerSetTitle;
+pub use set_webhook::SetWebhook;
 pub use stop_message_live_location::StopMessageLiveLocation;
 pub use stop_poll::StopPoll;
 pub use unban_chat_member::UnbanChatMember;
@@ -268,4 +412,241 @@ pub use unpin_all_chat_messages::UnpinAllChatMessages;
 pub use unpin_all_forum_topic_messages::UnpinAllForumTopicMessages;
 pub use unpin_all_general_forum_topic_messages::UnpinAllGeneralForumTopicMessages;
 pub use unpin_chat_message::UnpinChatMessage;
+#[cfg(feature = "stickers")]
 pub use upload_sticker_file::UploadStickerFile;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "stickers")]
+    use crate::types::InputSticker;
+    #[cfg(feature = "payments")]
+    use crate::types::LabeledPrice;
+    use crate::types::{
+        BotCommand, ChatPermissions, InlineKeyboardButton, InlineKeyboardMarkup,
+        InlineQueryResultArticle, InputFile, InputMediaPhoto, InputPaidMediaPhoto,
+        InputTextMessageContent, KeyboardButton, LinkPreviewOptions, ReplyKeyboardMarkup,
+        ReplyParameters,
+    };
+    #[cfg(feature = "passport")]
+    use crate::types::{PassportElementErrorDataField, PassportElementErrorDataFieldType};
+
+    use serde_json::Value;
+
+    fn contains_null(value: &Value) -> bool {
+        match value {
+            Value::Null => true,
+            Value::Array(values) => values.iter().any(contains_null),
+            Value::Object(values) => values.values().any(contains_null),
+            _ => false,
+        }
+    }
+
+    /// Checks that `None` fields of the methods are omitted instead of serializing as `null`,
+    /// because Telegram rejects `null` for some fields
+    macro_rules! assert_skip_none {
+        ($($method:expr),+ $(,)?) => {
+            $(
+                let value = serde_json::to_value(&$method).unwrap();
+
+                assert!(
+                    !contains_null(&value),
+                    "`{}` is serialized with `null`: {value}",
+                    stringify!($method),
+                );
+            )+
+        };
+    }
+
+    #[test]
+    fn test_skip_serializing_none() {
+        let file = || InputFile::id("file_id");
+
+        assert_skip_none!(
+            AnswerCallbackQuery::new("id"),
+            AnswerInlineQuery::new(
+                "id",
+                [InlineQueryResultArticle::new(
+                    "id",
+                    "title",
+                    InputTextMessageContent::new("text"),
+                )],
+            ),
+            AnswerWebAppQuery::new(
+                "id",
+                InlineQueryResultArticle::new("id", "title", InputTextMessageContent::new("text")),
+            ),
+            ApproveChatJoinRequest::new(1, 1),
+            BanChatMember::new(1, 1),
+            BanChatSenderChat::new(1, 1),
+            CloseForumTopic::new(1, 1),
+            CloseGeneralForumTopic::new(1),
+            CopyMessage::new(1, 1, 1),
+            CopyMessages::new(1, 1, [1]),
+            CreateChatInviteLink::new(1),
+            CreateChatSubscriptionInviteLink::new(1, 1, 1),
+            CreateForumTopic::new(1, "title"),
+            DeclineChatJoinRequest::new(1, 1),
+            DeleteChatPhoto::new(1),
+            DeleteChatStickerSet::new(1),
+            DeleteForumTopic::new(1, 1),
+            DeleteMessage::new(1, 1),
+            DeleteMessages::new(1, [1]),
+            DeleteMyCommands::new(),
+            DeleteWebhook::new(),
+            EditChatInviteLink::new(1, "link"),
+            EditChatSubscriptionInviteLink::new(1, "link"),
+            EditForumTopic::new(1, 1),
+            EditGeneralForumTopic::new(1, "name"),
+            EditMessageCaption::new("caption"),
+            EditMessageLiveLocation::new(0.0, 0.0),
+            EditMessageMedia::new(InputMediaPhoto::new(file())),
+            EditMessageReplyMarkup::new(),
+            EditMessageText::new("text"),
+            ExportChatInviteLink::new(1),
+            ForwardMessage::new(1, 1, 1),
+            ForwardMessages::new(1, 1, [1]),
+            GetBusinessConnection::new("id"),
+            GetChat::new(1),
+            GetChatAdministrators::new(1),
+            GetChatMember::new(1, 1),
+            GetChatMemberCount::new(1),
+            GetChatMenuButton::new(),
+            GetFile::new("id"),
+            GetForumTopicIconStickers::new(),
+            GetMe::new(),
+            GetMyCommands::new(),
+            GetMyDefaultAdministratorRights::new(),
+            GetMyDescription::new(),
+            GetMyName::new(),
+            GetMyShortDescription::new(),
+            GetUpdates::new(),
+            GetUserChatBoosts::new(1, 1),
+            GetUserProfilePhotos::new(1),
+            HideGeneralForumTopic::new(1),
+            LeaveChat::new(1),
+            LogOut::new(),
+            PinChatMessage::new(1, 1),
+            PromoteChatMember::new(1, 1),
+            ReopenForumTopic::new(1, 1),
+            ReopenGeneralForumTopic::new(1),
+            RestrictChatMember::new(1, 1, ChatPermissions::default()),
+            RevokeChatInviteLink::new(1, "link"),
+            SendAnimation::new(1, file()),
+            SendAudio::new(1, file()),
+            SendChatAction::new(1, "typing"),
+            SendContact::new(1, "phone", "name"),
+            SendDice::new(1),
+            SendDocument::new(1, file()),
+            SendLocation::new(1, 0.0, 0.0),
+            SendMediaGroup::new(1, [InputMediaPhoto::new(file())]),
+            SendMessage::new(1, "text"),
+            SendPaidMedia::new(1, 1, [InputPaidMediaPhoto::new(file())]),
+            SendPhoto::new(1, file()),
+            SendPoll::new(1, "question", ["first", "second"]),
+            SendSticker::new(1, file()),
+            SendVenue::new(1, 0.0, 0.0, "title", "address"),
+            SendVideo::new(1, file()),
+            SendVideoNote::new(1, file()),
+            SendVoice::new(1, file()),
+            SetChatAdministratorCustomTitle::new(1, 1, "title"),
+            SetChatDescription::new(1, "description"),
+            SetChatMenuButton::new(1),
+            SetChatPermissions::new(1, ChatPermissions::default()),
+            SetChatStickerSet::new(1, "name"),
+            SetChatTitle::new(1, "title"),
+            SetMessageReaction::new(1, 1),
+            SetMyCommands::new([BotCommand::new("start", "description")]),
+            SetMyDefaultAdministratorRights::new(),
+            SetMyDescription::new(),
+            SetMyName::new(),
+            SetMyShortDescription::new(),
+            SetWebhook::new("https://example.com"),
+            StopMessageLiveLocation::new(),
+            StopPoll::new(1, 1),
+            UnbanChatMember::new(1, 1),
+            UnbanChatSenderChat::new(1, 1),
+            UnhideGeneralForumTopic::new(1),
+            UnpinAllChatMessages::new(1),
+            UnpinAllForumTopicMessages::new(1, 1),
+            UnpinAllGeneralForumTopicMessages::new(1),
+            UnpinChatMessage::new(1, 1),
+        );
+
+        #[cfg(feature = "stickers")]
+        assert_skip_none!(
+            AddStickerToSet::new(1, "name", InputSticker::new(file(), "static")),
+            CreateNewStickerSet::new(1, "name", "title", [InputSticker::new(file(), "static")]),
+            DeleteStickerFromSet::new("sticker"),
+            DeleteStickerSet::new("name"),
+            GetCustomEmojiStickers::new(["id"]),
+            GetStickerSet::new("name"),
+            ReplaceStickerInSet::new(1, "name", "old", InputSticker::new(file(), "static")),
+            SetCustomEmojiStickerSetThumbnail::new("name"),
+            SetStickerEmojiList::new("sticker"),
+            SetStickerKeywords::new("sticker"),
+            SetStickerMaskPosition::new("sticker"),
+            SetStickerPositionInSet::new("sticker", 1),
+            SetStickerSetThumbnail::new("name", 1, "static"),
+            SetStickerSetTitle::new("name", "title"),
+            UploadStickerFile::new(1, file()),
+        );
+
+        #[cfg(feature = "payments")]
+        assert_skip_none!(
+            AnswerPreCheckoutQuery::new("id", true),
+            AnswerShippingQuery::new("id", true),
+            CreateInvoiceLink::new(
+                "title",
+                "description",
+                "payload",
+                "token",
+                "XTR",
+                [LabeledPrice::new("label", 1)]
+            ),
+            SendInvoice::new(
+                1,
+                "title",
+                "description",
+                "payload",
+                "token",
+                "XTR",
+                [LabeledPrice::new("label", 1)]
+            ),
+        );
+
+        #[cfg(feature = "stars")]
+        assert_skip_none!(GetStarTransactions::new(), RefundStarPayment::new(1, "id"),);
+
+        #[cfg(feature = "games")]
+        assert_skip_none!(
+            GetGameHighScores::new(1),
+            SendGame::new(1, "game"),
+            SetGameScore::new(1, 1),
+        );
+
+        #[cfg(feature = "passport")]
+        assert_skip_none!(SetPassportDataErrors::new(
+            1,
+            [PassportElementErrorDataField::new(
+                PassportElementErrorDataFieldType::Passport,
+                "field",
+                "hash",
+                "message",
+            )],
+        ),);
+
+        // Nested types with `None` fields
+        assert_skip_none!(
+            SendMessage::new(1, "text")
+                .link_preview_options(LinkPreviewOptions::new())
+                .reply_parameters(ReplyParameters::new(1))
+                .reply_markup(InlineKeyboardMarkup::new([[InlineKeyboardButton::new(
+                    "text"
+                )
+                .callback_data("data")]])),
+            SendMessage::new(1, "text")
+                .reply_markup(ReplyKeyboardMarkup::new([[KeyboardButton::new("text")]])),
+        );
+    }
+}
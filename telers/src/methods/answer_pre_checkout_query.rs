@@ -5,6 +5,11 @@ use crate::client::Bot;
 use serde::Serialize;
 use serde_with::skip_serializing_none;
 
+/// This error occurs when the answer to the pre-checkout query is invalid, see [`AnswerPreCheckoutQuery::is_valid`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Answer to the pre-checkout query `{0}` is invalid: the error answer must have non-empty error message")]
+pub struct InvalidAnswerPreCheckoutQuery(pub Box<str>);
+
 /// Once the user has confirmed their payment and shipping details, the Bot API sends the final confirmation in the form of an [`Update`](crate::types::Update) with the field `pre_checkout_query`. Use this method to respond to such pre-checkout queries. On success, `true` is returned. Note: The Bot API must receive an answer within 10 seconds after the pre-checkout query was sent. Use this method to respond to such pre-checkout queries.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#answerprecheckoutquery>
@@ -64,6 +69,53 @@ impl AnswerPreCheckoutQuery {
     }
 }
 
+impl AnswerPreCheckoutQuery {
+    /// Creates answer, which confirms that the bot is ready to proceed with the order
+    #[must_use]
+    pub fn new_ok(pre_checkout_query_id: impl Into<String>) -> Self {
+        Self::new(pre_checkout_query_id, true)
+    }
+
+    /// Creates answer, which explains to the user the reason for failure to proceed with the checkout
+    #[must_use]
+    pub fn new_error(
+        pre_checkout_query_id: impl Into<String>,
+        error_message: impl Into<String>,
+    ) -> Self {
+        Self::new(pre_checkout_query_id, false).error_message(error_message)
+    }
+
+    /// Creates answer like [`AnswerPreCheckoutQuery::new_error`], but checks that the error message isn't empty
+    /// # Errors
+    /// If the error message is empty
+    pub fn try_new_error(
+        pre_checkout_query_id: impl Into<String>,
+        error_message: impl Into<String>,
+    ) -> Result<Self, InvalidAnswerPreCheckoutQuery> {
+        let answer = Self::new_error(pre_checkout_query_id, error_message);
+
+        if answer.is_valid() {
+            Ok(answer)
+        } else {
+            Err(InvalidAnswerPreCheckoutQuery(
+                answer.pre_checkout_query_id.into(),
+            ))
+        }
+    }
+
+    /// Checks that the error answer has non-empty error message and the successful answer doesn't have it
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        if self.ok {
+            self.error_message.is_none()
+        } else {
+            self.error_message
+                .as_deref()
+                .map_or(false, |message| !message.is_empty())
+        }
+    }
+}
+
 impl TelegramMethod for AnswerPreCheckoutQuery {
     type Method = Self;
     type Return = bool;
@@ -78,3 +130,39 @@ impl AsRef<AnswerPreCheckoutQuery> for AnswerPreCheckoutQuery {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers() {
+        let answer = AnswerPreCheckoutQuery::new_ok("id");
+        let value = serde_json::to_value(&answer).unwrap();
+
+        assert!(answer.is_valid());
+        assert_eq!(
+            value,
+            serde_json::json!({"pre_checkout_query_id": "id", "ok": true})
+        );
+
+        let answer = AnswerPreCheckoutQuery::new_error("id", "Out of stock");
+        let value = serde_json::to_value(&answer).unwrap();
+
+        assert!(answer.is_valid());
+        assert_eq!(value["ok"], false);
+        assert_eq!(value["error_message"], "Out of stock");
+
+        assert!(!AnswerPreCheckoutQuery::new("id", false).is_valid());
+        assert!(!AnswerPreCheckoutQuery::new_error("id", "").is_valid());
+        assert!(!AnswerPreCheckoutQuery::new_ok("id")
+            .error_message("error")
+            .is_valid());
+
+        assert!(AnswerPreCheckoutQuery::try_new_error("id", "Out of stock").is_ok());
+        assert_eq!(
+            AnswerPreCheckoutQuery::try_new_error("id", ""),
+            Err(InvalidAnswerPreCheckoutQuery("id".into()))
+        );
+    }
+}
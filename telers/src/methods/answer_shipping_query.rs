@@ -5,6 +5,11 @@ use crate::{client::Bot, types::ShippingOption};
 use serde::Serialize;
 use serde_with::skip_serializing_none;
 
+/// This error occurs when the answer to the shipping query is invalid, see [`AnswerShippingQuery::is_valid`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Answer to the shipping query `{0}` is invalid: the successful answer must have shipping options and the error answer must have non-empty error message")]
+pub struct InvalidAnswerShippingQuery(pub Box<str>);
+
 /// If you sent an invoice requesting a shipping address and the parameter `is_flexible` was specified, the Bot API will send an [`Update`](crate::types::Update) with a `shipping_query` field to the bot. Use this method to reply to shipping queries.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#answershippingquery>
@@ -111,6 +116,73 @@ impl AnswerShippingQuery {
     }
 }
 
+impl AnswerShippingQuery {
+    /// Creates answer, which confirms that delivery to the address is possible with the shipping options
+    #[must_use]
+    pub fn new_ok(
+        shipping_query_id: impl Into<String>,
+        shipping_options: impl IntoIterator<Item = ShippingOption>,
+    ) -> Self {
+        Self::new(shipping_query_id, true).shipping_options(shipping_options)
+    }
+
+    /// Creates answer, which explains to the user why it's impossible to complete the order
+    #[must_use]
+    pub fn new_error(
+        shipping_query_id: impl Into<String>,
+        error_message: impl Into<String>,
+    ) -> Self {
+        Self::new(shipping_query_id, false).error_message(error_message)
+    }
+
+    /// Creates answer like [`AnswerShippingQuery::new_ok`], but checks that the shipping options aren't empty
+    /// # Errors
+    /// If the shipping options are empty
+    pub fn try_new_ok(
+        shipping_query_id: impl Into<String>,
+        shipping_options: impl IntoIterator<Item = ShippingOption>,
+    ) -> Result<Self, InvalidAnswerShippingQuery> {
+        Self::new_ok(shipping_query_id, shipping_options).validated()
+    }
+
+    /// Creates answer like [`AnswerShippingQuery::new_error`], but checks that the error message isn't empty
+    /// # Errors
+    /// If the error message is empty
+    pub fn try_new_error(
+        shipping_query_id: impl Into<String>,
+        error_message: impl Into<String>,
+    ) -> Result<Self, InvalidAnswerShippingQuery> {
+        Self::new_error(shipping_query_id, error_message).validated()
+    }
+
+    fn validated(self) -> Result<Self, InvalidAnswerShippingQuery> {
+        if self.is_valid() {
+            Ok(self)
+        } else {
+            Err(InvalidAnswerShippingQuery(self.shipping_query_id.into()))
+        }
+    }
+
+    /// Checks that the successful answer has shipping options without error message
+    /// and the error answer has non-empty error message without shipping options
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        if self.ok {
+            self.error_message.is_none()
+                && self
+                    .shipping_options
+                    .as_ref()
+                    .map_or(false, |options| !options.is_empty())
+        } else {
+            self.shipping_options.is_none()
+                && self
+                    .error_message
+                    .as_deref()
+                    .map_or(false, |message| !message.is_empty())
+        }
+    }
+}
+
 impl TelegramMethod for AnswerShippingQuery {
     type Method = Self;
     type Return = bool;
@@ -125,3 +197,44 @@ impl AsRef<AnswerShippingQuery> for AnswerShippingQuery {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::LabeledPrice;
+
+    #[test]
+    fn test_answers() {
+        let option =
+            ShippingOption::new("standard", "Standard", [LabeledPrice::new("Delivery", 500)]);
+
+        let answer = AnswerShippingQuery::new_ok("id", [option]);
+        let value = serde_json::to_value(&answer).unwrap();
+
+        assert!(answer.is_valid());
+        assert_eq!(value["ok"], true);
+        assert_eq!(value["shipping_options"][0]["id"], "standard");
+        assert!(value.get("error_message").is_none());
+
+        let answer = AnswerShippingQuery::new_error("id", "Delivery is unavailable");
+        let value = serde_json::to_value(&answer).unwrap();
+
+        assert!(answer.is_valid());
+        assert_eq!(value["ok"], false);
+        assert_eq!(value["error_message"], "Delivery is unavailable");
+        assert!(value.get("shipping_options").is_none());
+
+        assert!(!AnswerShippingQuery::new_ok("id", []).is_valid());
+        assert!(!AnswerShippingQuery::new("id", false).is_valid());
+
+        assert_eq!(
+            AnswerShippingQuery::try_new_ok("id", []),
+            Err(InvalidAnswerShippingQuery("id".into()))
+        );
+        assert_eq!(
+            AnswerShippingQuery::try_new_error("id", ""),
+            Err(InvalidAnswerShippingQuery("id".into()))
+        );
+        assert!(AnswerShippingQuery::try_new_error("id", "Delivery is unavailable").is_ok());
+    }
+}
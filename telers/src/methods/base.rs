@@ -1,6 +1,8 @@
+#[cfg(feature = "stickers")]
+use crate::types::InputSticker;
 use crate::{
     client::Bot,
-    types::{InputFile, InputMedia, InputPaidMedia, InputSticker, ResponseParameters},
+    types::{InputFile, InputMedia, InputPaidMedia, ResponseParameters},
 };
 
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
@@ -63,6 +65,43 @@ pub trait TelegramMethod {
     #[must_use]
     fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<Self::Method>;
 
+    /// This method is called before sending the request, if the bot has the default parse mode.
+    /// It returns the method with the passed parse mode, if the method has `parse_mode` field and
+    /// neither parse mode nor entities are set explicitly, otherwise `None` (by default).
+    #[must_use]
+    fn with_default_parse_mode(&self, _parse_mode: &str) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// This method is called before sending the request, if the bot has the default business connection.
+    /// It returns the method with the passed business connection identifier, if the method has `business_connection_id` field
+    /// and it isn't set explicitly, otherwise `None` (by default).
+    #[must_use]
+    fn with_default_business_connection_id(&self, _business_connection_id: &str) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// This method is called before sending the request by [`ReplyTo`], which targets the chat of the current update.
+    /// It returns the method with the passed chat identifier, if the method has `chat_id` field
+    /// and it isn't set explicitly (it's [`ChatIdKind::UNSET`]), otherwise `None` (by default).
+    /// The message thread identifier is set only if the method has `message_thread_id` field and it isn't set explicitly.
+    ///
+    /// [`ReplyTo`]: crate::client::ReplyTo
+    /// [`ChatIdKind::UNSET`]: crate::types::ChatIdKind::UNSET
+    #[must_use]
+    fn with_chat(&self, _chat_id: i64, _message_thread_id: Option<i64>) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
     /// This method is called when a response is received from Telegram API.
     /// It's need for parsing a response from Telegram API.
     /// # Errors
@@ -72,6 +111,29 @@ pub trait TelegramMethod {
     }
 }
 
+/// This trait represents a Telegram API method, which result is paginated by offset and limit
+/// # Notes
+/// This trait is used by [`Bot::paginate`] to get all items of the method page by page
+pub trait PaginatedMethod: TelegramMethod + Clone {
+    /// This type represents an item of the page
+    type Item;
+
+    /// Sets offset and limit of the page
+    #[must_use]
+    fn page(self, offset: i64, limit: i64) -> Self;
+
+    /// Gets items of the page from the result of the method
+    #[must_use]
+    fn page_items(result: Self::Return) -> Vec<Self::Item>;
+
+    /// Gets offset of the next page.
+    /// By default, it's the offset of the current page plus the number of its items.
+    #[must_use]
+    fn next_offset(offset: i64, items: &[Self::Item]) -> i64 {
+        offset + items.len() as i64
+    }
+}
+
 pub(super) fn prepare_file<'a>(files: &mut Vec<&'a InputFile<'a>>, file: &'a InputFile<'a>) {
     match file {
         InputFile::FS(_) | InputFile::Buffered(_) | InputFile::Stream(_) => {
@@ -88,22 +150,32 @@ pub(super) fn prepare_input_media<'a>(
     files: &mut Vec<&'a InputFile<'a>>,
     input_media: &'a InputMedia<'a>,
 ) {
-    match input_media {
+    // Thumbnails can be uploaded only as a new file, so they are referenced by `attach://` too
+    let thumbnail = match input_media {
         InputMedia::Animation(inner) => {
             prepare_file(files, &inner.media);
+            inner.thumbnail.as_ref()
         }
         InputMedia::Audio(inner) => {
             prepare_file(files, &inner.media);
+            inner.thumbnail.as_ref()
         }
         InputMedia::Document(inner) => {
             prepare_file(files, &inner.media);
+            inner.thumbnail.as_ref()
         }
         InputMedia::Photo(inner) => {
             prepare_file(files, &inner.media);
+            None
         }
         InputMedia::Video(inner) => {
             prepare_file(files, &inner.media);
+            inner.thumbnail.as_ref()
         }
+    };
+
+    if let Some(thumbnail) = thumbnail {
+        prepare_file(files, thumbnail);
     }
 }
 
@@ -116,6 +188,7 @@ pub(super) fn prepare_input_media_group<'a>(
     }
 }
 
+#[cfg(feature = "stickers")]
 pub(super) fn prepare_input_sticker<'a>(
     files: &mut Vec<&'a InputFile<'a>>,
     input_sticker: &'a InputSticker<'a>,
@@ -123,6 +196,7 @@ pub(super) fn prepare_input_sticker<'a>(
     prepare_file(files, &input_sticker.sticker);
 }
 
+#[cfg(feature = "stickers")]
 pub(super) fn prepare_input_stickers<'a>(
     files: &mut Vec<&'a InputFile<'a>>,
     input_stickers: &'a [InputSticker<'a>],
@@ -142,6 +216,10 @@ pub(super) fn prepare_input_paid_media<'a>(
         }
         InputPaidMedia::Video(inner) => {
             prepare_file(files, &inner.media);
+
+            if let Some(thumbnail) = &inner.thumbnail {
+                prepare_file(files, thumbnail);
+            }
         }
     }
 }
@@ -154,3 +232,136 @@ pub(super) fn prepare_input_paid_media_group<'a>(
         prepare_input_paid_media(files, input_paid_media);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        client::{Bot, Reqwest},
+        methods::{EditMessageMedia, SendMediaGroup, SendPaidMedia, TelegramMethod as _},
+        types::{
+            InputFile, InputMedia, InputMediaDocument, InputMediaPhoto, InputMediaVideo,
+            InputPaidMedia, InputPaidMediaVideo,
+        },
+    };
+
+    use std::collections::HashSet;
+
+    /// Gets `attach://` references of the files, which are sent in `multipart/form-data`
+    fn attached_files(files: &[&InputFile]) -> HashSet<String> {
+        files
+            .iter()
+            .map(|file| {
+                assert!(file.is_require_multipart());
+
+                file.str_to_file().to_owned()
+            })
+            .collect()
+    }
+
+    /// Gets `attach://` references in the JSON data
+    fn attach_references(value: &serde_json::Value) -> HashSet<String> {
+        match value {
+            serde_json::Value::String(value) if value.starts_with("attach://") => {
+                HashSet::from([value.clone()])
+            }
+            serde_json::Value::Array(values) => values.iter().flat_map(attach_references).collect(),
+            serde_json::Value::Object(values) => {
+                values.values().flat_map(attach_references).collect()
+            }
+            _ => HashSet::new(),
+        }
+    }
+
+    #[test]
+    fn test_send_media_group_mixed_files() {
+        let bot = Bot::<Reqwest>::default();
+        let method = SendMediaGroup::new::<InputMedia, _>(
+            1,
+            [
+                InputMediaPhoto::new(InputFile::fs("photo.jpg")).into(),
+                InputMediaPhoto::new(InputFile::url("https://example.com/photo.jpg")).into(),
+                InputMediaVideo::new(InputFile::buffered(vec![0; 8]))
+                    .thumbnail(InputFile::buffered(vec![1; 8]))
+                    .into(),
+                InputMediaDocument::new(InputFile::id("file_id"))
+                    .thumbnail(InputFile::fs("thumbnail.jpg"))
+                    .into(),
+            ],
+        );
+
+        let request = method.build_request(&bot);
+        let files = attached_files(request.files.as_deref().unwrap());
+        let data = serde_json::to_value(request.data).unwrap();
+
+        assert_eq!(files.len(), 4);
+        assert_eq!(files, attach_references(&data));
+
+        // URLs and file ids are passed as is
+        assert_eq!(data["media"][1]["media"], "https://example.com/photo.jpg");
+        assert_eq!(data["media"][3]["media"], "file_id");
+    }
+
+    #[test]
+    fn test_edit_message_media_mixed_files() {
+        let bot = Bot::<Reqwest>::default();
+        let method = EditMessageMedia::new(
+            InputMediaVideo::new(InputFile::url("https://example.com/video.mp4"))
+                .thumbnail(InputFile::buffered(vec![1; 8])),
+        )
+        .chat_id(1)
+        .message_id(1);
+
+        let request = method.build_request(&bot);
+        let files = attached_files(request.files.as_deref().unwrap());
+        let data = serde_json::to_value(request.data).unwrap();
+
+        assert_eq!(files.len(), 1);
+        assert_eq!(files, attach_references(&data));
+        assert_eq!(data["media"]["media"], "https://example.com/video.mp4");
+    }
+
+    #[test]
+    fn test_send_paid_media_mixed_files() {
+        let bot = Bot::<Reqwest>::default();
+        let method = SendPaidMedia::new::<InputPaidMedia, _>(
+            1,
+            25,
+            [
+                InputPaidMedia::photo(InputFile::fs("photo.jpg")),
+                InputPaidMediaVideo::new(InputFile::id("file_id"))
+                    .thumbnail(InputFile::buffered(vec![1; 8]))
+                    .supports_streaming(true)
+                    .into(),
+            ],
+        )
+        .caption("caption")
+        .payload("payload");
+
+        let request = method.build_request(&bot);
+        let files = attached_files(request.files.as_deref().unwrap());
+        let data = serde_json::to_value(request.data).unwrap();
+
+        assert_eq!(request.method_name, "sendPaidMedia");
+        assert_eq!(files.len(), 2);
+        assert_eq!(files, attach_references(&data));
+
+        assert_eq!(data["chat_id"], 1);
+        assert_eq!(data["star_count"], 25);
+        assert_eq!(data["caption"], "caption");
+        assert_eq!(data["payload"], "payload");
+        assert_eq!(data["media"][0]["type"], "photo");
+        assert!(data["media"][0]["media"]
+            .as_str()
+            .unwrap()
+            .starts_with("attach://"));
+        assert_eq!(data["media"][1]["type"], "video");
+        assert_eq!(data["media"][1]["media"], "file_id");
+        assert_eq!(data["media"][1]["supports_streaming"], true);
+        assert!(data["media"][1]["thumbnail"]
+            .as_str()
+            .unwrap()
+            .starts_with("attach://"));
+        assert_eq!(data["media"].as_array().unwrap().len(), 2);
+        assert_eq!(data.get("show_caption_above_media"), None);
+    }
+}
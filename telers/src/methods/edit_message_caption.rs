@@ -227,6 +227,18 @@ impl TelegramMethod for EditMessageCaption {
     fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
         Request::new("editMessageCaption", self, None)
     }
+
+    fn with_default_parse_mode(&self, parse_mode: &str) -> Option<Self> {
+        (self.parse_mode.is_none() && self.caption_entities.is_none())
+            .then(|| self.clone().parse_mode(parse_mode))
+    }
+
+    fn with_default_business_connection_id(&self, business_connection_id: &str) -> Option<Self> {
+        self.business_connection_id.is_none().then(|| {
+            self.clone()
+                .business_connection_id(business_connection_id.to_owned())
+        })
+    }
 }
 
 impl AsRef<EditMessageCaption> for EditMessageCaption {
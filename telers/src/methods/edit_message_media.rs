@@ -144,6 +144,13 @@ impl TelegramMethod for EditMessageMedia<'_> {
 
         Request::new("editMessageMedia", self, Some(files.into()))
     }
+
+    fn with_default_business_connection_id(&self, business_connection_id: &str) -> Option<Self> {
+        self.business_connection_id.is_none().then(|| {
+            self.clone()
+                .business_connection_id(business_connection_id.to_owned())
+        })
+    }
 }
 
 impl<'a> AsRef<EditMessageMedia<'a>> for EditMessageMedia<'a> {
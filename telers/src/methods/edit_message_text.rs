@@ -203,6 +203,52 @@ impl EditMessageText {
     }
 }
 
+impl EditMessageText {
+    /// Disables link preview generation for the message
+    #[must_use]
+    pub fn disable_link_preview(self) -> Self {
+        self.map_link_preview_options(|options| options.is_disabled(true))
+    }
+
+    /// Shrinks the media in the link preview.
+    /// Ignored by Telegram if the URL isn't explicitly specified by [`EditMessageText::preview_url`]
+    #[must_use]
+    pub fn prefer_small_media(self) -> Self {
+        self.map_link_preview_options(|options| {
+            options
+                .prefer_small_media(true)
+                .prefer_large_media_option(None)
+        })
+    }
+
+    /// Enlarges the media in the link preview.
+    /// Ignored by Telegram if the URL isn't explicitly specified by [`EditMessageText::preview_url`]
+    #[must_use]
+    pub fn prefer_large_media(self) -> Self {
+        self.map_link_preview_options(|options| {
+            options
+                .prefer_large_media(true)
+                .prefer_small_media_option(None)
+        })
+    }
+
+    /// URL to use for the link preview instead of the first URL found in the message text
+    #[must_use]
+    pub fn preview_url(self, val: impl Into<String>) -> Self {
+        self.map_link_preview_options(|options| options.url(val))
+    }
+
+    fn map_link_preview_options(
+        self,
+        f: impl FnOnce(LinkPreviewOptions) -> LinkPreviewOptions,
+    ) -> Self {
+        Self {
+            link_preview_options: Some(f(self.link_preview_options.unwrap_or_default())),
+            ..self
+        }
+    }
+}
+
 impl TelegramMethod for EditMessageText {
     type Method = Self;
     type Return = MessageOrTrue;
@@ -210,6 +256,18 @@ impl TelegramMethod for EditMessageText {
     fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
         Request::new("editMessageText", self, None)
     }
+
+    fn with_default_parse_mode(&self, parse_mode: &str) -> Option<Self> {
+        (self.parse_mode.is_none() && self.entities.is_none())
+            .then(|| self.clone().parse_mode(parse_mode))
+    }
+
+    fn with_default_business_connection_id(&self, business_connection_id: &str) -> Option<Self> {
+        self.business_connection_id.is_none().then(|| {
+            self.clone()
+                .business_connection_id(business_connection_id.to_owned())
+        })
+    }
 }
 
 impl AsRef<EditMessageText> for EditMessageText {
@@ -129,6 +129,18 @@ impl TelegramMethod for ForwardMessage {
     fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
         Request::new("forwardMessage", self, None)
     }
+
+    fn with_chat(&self, chat_id: i64, message_thread_id: Option<i64>) -> Option<Self> {
+        if !self.chat_id.is_unset() {
+            return None;
+        }
+
+        Some(Self {
+            chat_id: chat_id.into(),
+            message_thread_id: self.message_thread_id.or(message_thread_id),
+            ..self.clone()
+        })
+    }
 }
 
 impl AsRef<ForwardMessage> for ForwardMessage {
@@ -1,4 +1,4 @@
-use super::{Request, TelegramMethod};
+use super::{PaginatedMethod, Request, TelegramMethod};
 
 use crate::{client::Bot, types::Update};
 
@@ -121,6 +121,23 @@ impl TelegramMethod for GetUpdates {
     }
 }
 
+impl PaginatedMethod for GetUpdates {
+    type Item = Update;
+
+    fn page(self, offset: i64, limit: i64) -> Self {
+        self.offset(offset).limit(limit)
+    }
+
+    fn page_items(result: Self::Return) -> Vec<Self::Item> {
+        result
+    }
+
+    /// Offset of the next page is greater by one than the highest identifier of the received updates
+    fn next_offset(offset: i64, items: &[Self::Item]) -> i64 {
+        items.last().map_or(offset, |update| update.id + 1)
+    }
+}
+
 impl AsRef<GetUpdates> for GetUpdates {
     fn as_ref(&self) -> &Self {
         self
@@ -1,6 +1,9 @@
-use super::base::{Request, TelegramMethod};
+use super::base::{PaginatedMethod, Request, TelegramMethod};
 
-use crate::{client::Bot, types::UserProfilePhotos};
+use crate::{
+    client::Bot,
+    types::{PhotoSize, UserProfilePhotos},
+};
 
 use serde::Serialize;
 use serde_with::skip_serializing_none;
@@ -80,6 +83,18 @@ impl TelegramMethod for GetUserProfilePhotos {
     }
 }
 
+impl PaginatedMethod for GetUserProfilePhotos {
+    type Item = Box<[PhotoSize]>;
+
+    fn page(self, offset: i64, limit: i64) -> Self {
+        self.offset(offset).limit(limit)
+    }
+
+    fn page_items(result: Self::Return) -> Vec<Self::Item> {
+        result.photos.into_vec()
+    }
+}
+
 impl AsRef<GetUserProfilePhotos> for GetUserProfilePhotos {
     fn as_ref(&self) -> &Self {
         self
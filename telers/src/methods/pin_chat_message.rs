@@ -92,6 +92,13 @@ impl TelegramMethod for PinChatMessage {
     fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
         Request::new("pinChatMessage", self, None)
     }
+
+    fn with_default_business_connection_id(&self, business_connection_id: &str) -> Option<Self> {
+        self.business_connection_id.is_none().then(|| {
+            self.clone()
+                .business_connection_id(business_connection_id.to_owned())
+        })
+    }
 }
 
 impl AsRef<PinChatMessage> for PinChatMessage {
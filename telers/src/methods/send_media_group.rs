@@ -28,6 +28,8 @@ pub struct SendMediaGroup<'a> {
     pub disable_notification: Option<bool>,
     /// Protects the contents of the sent message from forwarding and saving
     pub protect_content: Option<bool>,
+    /// Pass `true` to allow up to 1000 messages per second, ignoring [broadcasting limits](https://core.telegram.org/bots/faq#how-can-i-message-all-of-my-bot-39s-subscribers-at-once) for a fee of 0.1 Telegram Stars per message. The relevant Stars will be withdrawn from the bot's balance
+    pub allow_paid_broadcast: Option<bool>,
     /// Unique identifier of the message effect to be added to the message; for private chats only
     pub message_effect_id: Option<String>,
     /// Description of the message to reply to
@@ -48,6 +50,7 @@ impl<'a> SendMediaGroup<'a> {
             media: media.into_iter().map(Into::into).collect(),
             disable_notification: None,
             protect_content: None,
+            allow_paid_broadcast: None,
             message_effect_id: None,
             reply_parameters: None,
         }
@@ -117,6 +120,14 @@ impl<'a> SendMediaGroup<'a> {
         }
     }
 
+    #[must_use]
+    pub fn allow_paid_broadcast(self, val: bool) -> Self {
+        Self {
+            allow_paid_broadcast: Some(val),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn message_effect_id(self, val: impl Into<String>) -> Self {
         Self {
@@ -167,6 +178,14 @@ impl<'a> SendMediaGroup<'a> {
         }
     }
 
+    #[must_use]
+    pub fn allow_paid_broadcast_option(self, val: Option<bool>) -> Self {
+        Self {
+            allow_paid_broadcast: val,
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn message_effect_id_option(self, val: Option<impl Into<String>>) -> Self {
         Self {
@@ -184,6 +203,8 @@ impl<'a> SendMediaGroup<'a> {
     }
 }
 
+impl_reply_shortcuts!(SendMediaGroup<'a>);
+
 impl<'a> TelegramMethod for SendMediaGroup<'a> {
     type Method = Self;
     type Return = Vec<Message>;
@@ -194,6 +215,25 @@ impl<'a> TelegramMethod for SendMediaGroup<'a> {
 
         Request::new("sendMediaGroup", self, Some(files.into()))
     }
+
+    fn with_default_business_connection_id(&self, business_connection_id: &str) -> Option<Self> {
+        self.business_connection_id.is_none().then(|| {
+            self.clone()
+                .business_connection_id(business_connection_id.to_owned())
+        })
+    }
+
+    fn with_chat(&self, chat_id: i64, message_thread_id: Option<i64>) -> Option<Self> {
+        if !self.chat_id.is_unset() {
+            return None;
+        }
+
+        Some(Self {
+            chat_id: chat_id.into(),
+            message_thread_id: self.message_thread_id.or(message_thread_id),
+            ..self.clone()
+        })
+    }
 }
 
 impl<'a> AsRef<SendMediaGroup<'a>> for SendMediaGroup<'a> {
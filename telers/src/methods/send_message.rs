@@ -34,6 +34,8 @@ pub struct SendMessage {
     pub disable_notification: Option<bool>,
     /// Protects the contents of the sent message from forwarding and saving
     pub protect_content: Option<bool>,
+    /// Pass `true` to allow up to 1000 messages per second, ignoring [broadcasting limits](https://core.telegram.org/bots/faq#how-can-i-message-all-of-my-bot-39s-subscribers-at-once) for a fee of 0.1 Telegram Stars per message. The relevant Stars will be withdrawn from the bot's balance
+    pub allow_paid_broadcast: Option<bool>,
     /// Unique identifier of the message effect to be added to the message; for private chats only
     pub message_effect_id: Option<String>,
     /// Description of the message to reply to
@@ -55,6 +57,7 @@ impl SendMessage {
             link_preview_options: None,
             disable_notification: None,
             protect_content: None,
+            allow_paid_broadcast: None,
             message_effect_id: None,
             reply_parameters: None,
             reply_markup: None,
@@ -153,6 +156,14 @@ impl SendMessage {
         }
     }
 
+    #[must_use]
+    pub fn allow_paid_broadcast(self, val: bool) -> Self {
+        Self {
+            allow_paid_broadcast: Some(val),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn message_effect_id(self, val: impl Into<String>) -> Self {
         Self {
@@ -235,6 +246,14 @@ impl SendMessage {
         }
     }
 
+    #[must_use]
+    pub fn allow_paid_broadcast_option(self, val: Option<bool>) -> Self {
+        Self {
+            allow_paid_broadcast: val,
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn message_effect_id_option(self, val: Option<impl Into<String>>) -> Self {
         Self {
@@ -260,6 +279,54 @@ impl SendMessage {
     }
 }
 
+impl SendMessage {
+    /// Disables link preview generation for the message
+    #[must_use]
+    pub fn disable_link_preview(self) -> Self {
+        self.map_link_preview_options(|options| options.is_disabled(true))
+    }
+
+    /// Shrinks the media in the link preview.
+    /// Ignored by Telegram if the URL isn't explicitly specified by [`SendMessage::preview_url`]
+    #[must_use]
+    pub fn prefer_small_media(self) -> Self {
+        self.map_link_preview_options(|options| {
+            options
+                .prefer_small_media(true)
+                .prefer_large_media_option(None)
+        })
+    }
+
+    /// Enlarges the media in the link preview.
+    /// Ignored by Telegram if the URL isn't explicitly specified by [`SendMessage::preview_url`]
+    #[must_use]
+    pub fn prefer_large_media(self) -> Self {
+        self.map_link_preview_options(|options| {
+            options
+                .prefer_large_media(true)
+                .prefer_small_media_option(None)
+        })
+    }
+
+    /// URL to use for the link preview instead of the first URL found in the message text
+    #[must_use]
+    pub fn preview_url(self, val: impl Into<String>) -> Self {
+        self.map_link_preview_options(|options| options.url(val))
+    }
+
+    fn map_link_preview_options(
+        self,
+        f: impl FnOnce(LinkPreviewOptions) -> LinkPreviewOptions,
+    ) -> Self {
+        Self {
+            link_preview_options: Some(f(self.link_preview_options.unwrap_or_default())),
+            ..self
+        }
+    }
+}
+
+impl_reply_shortcuts!(SendMessage);
+
 impl TelegramMethod for SendMessage {
     type Method = Self;
     type Return = Message;
@@ -267,6 +334,30 @@ impl TelegramMethod for SendMessage {
     fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
         Request::new("sendMessage", self, None)
     }
+
+    fn with_default_parse_mode(&self, parse_mode: &str) -> Option<Self> {
+        (self.parse_mode.is_none() && self.entities.is_none())
+            .then(|| self.clone().parse_mode(parse_mode))
+    }
+
+    fn with_default_business_connection_id(&self, business_connection_id: &str) -> Option<Self> {
+        self.business_connection_id.is_none().then(|| {
+            self.clone()
+                .business_connection_id(business_connection_id.to_owned())
+        })
+    }
+
+    fn with_chat(&self, chat_id: i64, message_thread_id: Option<i64>) -> Option<Self> {
+        if !self.chat_id.is_unset() {
+            return None;
+        }
+
+        Some(Self {
+            chat_id: chat_id.into(),
+            message_thread_id: self.message_thread_id.or(message_thread_id),
+            ..self.clone()
+        })
+    }
 }
 
 impl AsRef<SendMessage> for SendMessage {
@@ -274,3 +365,137 @@ impl AsRef<SendMessage> for SendMessage {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    fn link_preview_options(method: &SendMessage) -> serde_json::Value {
+        serde_json::to_value(method).unwrap()["link_preview_options"].clone()
+    }
+
+    #[test]
+    fn test_link_preview_shortcuts() {
+        let method = SendMessage::new(1, "text");
+
+        assert_eq!(
+            link_preview_options(&method.clone().disable_link_preview()),
+            json!({"is_disabled": true})
+        );
+        assert_eq!(
+            link_preview_options(&method.clone().preview_url("https://example.com")),
+            json!({"url": "https://example.com"})
+        );
+        assert_eq!(
+            link_preview_options(&method.clone().prefer_small_media()),
+            json!({"prefer_small_media": true})
+        );
+        assert_eq!(
+            link_preview_options(&method.clone().prefer_large_media()),
+            json!({"prefer_large_media": true})
+        );
+        assert_eq!(
+            link_preview_options(
+                &method
+                    .clone()
+                    .preview_url("https://example.com")
+                    .prefer_small_media()
+                    .prefer_large_media()
+            ),
+            json!({"url": "https://example.com", "prefer_large_media": true})
+        );
+        assert_eq!(
+            link_preview_options(
+                &method
+                    .clone()
+                    .link_preview_options(LinkPreviewOptions::new().show_above_text(true))
+                    .preview_url("https://example.com")
+            ),
+            json!({"url": "https://example.com", "show_above_text": true})
+        );
+        assert!(serde_json::to_value(&method)
+            .unwrap()
+            .get("link_preview_options")
+            .is_none());
+    }
+
+    #[test]
+    fn test_reply_shortcuts() {
+        let method = SendMessage::new(1, "text");
+
+        let value = serde_json::to_value(method.clone().reply_to(2)).unwrap();
+        assert_eq!(value["reply_parameters"], json!({"message_id": 2}));
+
+        let value = serde_json::to_value(method.clone().reply_to_in_chat("@channel", 2)).unwrap();
+        assert_eq!(
+            value["reply_parameters"],
+            json!({"message_id": 2, "chat_id": "@channel"})
+        );
+
+        let value =
+            serde_json::to_value(method.clone().reply_to(2).allow_sending_without_reply()).unwrap();
+        assert_eq!(
+            value["reply_parameters"],
+            json!({"message_id": 2, "allow_sending_without_reply": true})
+        );
+
+        // Without reply there is nothing to allow
+        let value = serde_json::to_value(method.clone().allow_sending_without_reply()).unwrap();
+        assert!(value.get("reply_parameters").is_none());
+
+        let value = serde_json::to_value(method.clone().message_thread(3).reply_to(2)).unwrap();
+        assert_eq!(value["message_thread_id"], 3);
+        assert_eq!(value["reply_parameters"], json!({"message_id": 2}));
+    }
+
+    #[test]
+    fn test_reply_to_message() {
+        let method = SendMessage::new(-1, "text");
+
+        let topic_message: Message = serde_json::from_value(json!({
+            "message_id": 2,
+            "message_thread_id": 3,
+            "is_topic_message": true,
+            "date": 0,
+            "chat": {"id": -1, "type": "supergroup", "title": "Forum", "is_forum": true},
+            "text": "text",
+        }))
+        .unwrap();
+
+        let value = serde_json::to_value(method.clone().reply_to_message(&topic_message)).unwrap();
+        assert_eq!(value["message_thread_id"], 3);
+        assert_eq!(value["reply_parameters"], json!({"message_id": 2}));
+
+        // Message thread of the reply chain in non-forum chat isn't a topic
+        let thread_message: Message = serde_json::from_value(json!({
+            "message_id": 2,
+            "message_thread_id": 3,
+            "date": 0,
+            "chat": {"id": -1, "type": "supergroup", "title": "Group"},
+            "text": "text",
+        }))
+        .unwrap();
+
+        let value = serde_json::to_value(method.reply_to_message(&thread_message)).unwrap();
+        assert!(value.get("message_thread_id").is_none());
+        assert_eq!(value["reply_parameters"], json!({"message_id": 2}));
+    }
+
+    #[test]
+    fn test_recent_fields_serialization() {
+        let value = serde_json::to_value(
+            SendMessage::new(1, "text")
+                .message_effect_id("effect")
+                .allow_paid_broadcast(true),
+        )
+        .unwrap();
+        assert_eq!(value["message_effect_id"], "effect");
+        assert_eq!(value["allow_paid_broadcast"], true);
+
+        let value = serde_json::to_value(SendMessage::new(1, "text")).unwrap();
+        assert!(value.get("message_effect_id").is_none());
+        assert!(value.get("allow_paid_broadcast").is_none());
+    }
+}
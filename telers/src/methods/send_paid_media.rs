@@ -24,6 +24,8 @@ pub struct SendPaidMedia<'a> {
     pub star_count: i64,
     /// A JSON-serialized array describing the media to be sent; up to 10 items
     pub media: Vec<InputPaidMedia<'a>>,
+    /// Bot-defined paid media payload, 0-128 bytes. This will not be displayed to the user, use it for your internal processes.
+    pub payload: Option<String>,
     /// Media caption, 0-1024 characters after entities parsing
     pub caption: Option<String>,
     /// Mode for parsing entities in the media caption. See [`formatting options`](https://core.telegram.org/bots/api#formatting-options) for more details.
@@ -36,6 +38,8 @@ pub struct SendPaidMedia<'a> {
     pub disable_notification: Option<bool>,
     /// Protects the contents of the sent message from forwarding and saving
     pub protect_content: Option<bool>,
+    /// Pass `true` to allow up to 1000 messages per second, ignoring [broadcasting limits](https://core.telegram.org/bots/faq#how-can-i-message-all-of-my-bot-39s-subscribers-at-once) for a fee of 0.1 Telegram Stars per message. The relevant Stars will be withdrawn from the bot's balance
+    pub allow_paid_broadcast: Option<bool>,
     /// Description of the message to reply to
     pub reply_parameters: Option<ReplyParameters>,
     /// Additional interface options. A JSON-serialized object for an [inline keyboard](https://core.telegram.org/bots/features#inline-keyboards), [custom reply keyboard](https://core.telegram.org/bots/features#keyboards), instructions to remove reply keyboard or to force a reply from the user.
@@ -54,12 +58,14 @@ impl<'a> SendPaidMedia<'a> {
             chat_id: chat_id.into(),
             star_count,
             media: media.into_iter().map(Into::into).collect(),
+            payload: None,
             caption: None,
             parse_mode: None,
             caption_entities: None,
             show_caption_above_media: None,
             disable_notification: None,
             protect_content: None,
+            allow_paid_broadcast: None,
             reply_parameters: None,
             reply_markup: None,
         }
@@ -113,6 +119,14 @@ impl<'a> SendPaidMedia<'a> {
         }
     }
 
+    #[must_use]
+    pub fn payload(self, val: impl Into<String>) -> Self {
+        Self {
+            payload: Some(val.into()),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn caption(self, val: impl Into<String>) -> Self {
         Self {
@@ -181,6 +195,14 @@ impl<'a> SendPaidMedia<'a> {
         }
     }
 
+    #[must_use]
+    pub fn allow_paid_broadcast(self, val: bool) -> Self {
+        Self {
+            allow_paid_broadcast: Some(val),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn reply_parameters(self, val: ReplyParameters) -> Self {
         Self {
@@ -207,6 +229,14 @@ impl<'a> SendPaidMedia<'a> {
         }
     }
 
+    #[must_use]
+    pub fn payload_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            payload: val.map(Into::into),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn caption_option(self, val: Option<impl Into<String>>) -> Self {
         Self {
@@ -264,6 +294,14 @@ impl<'a> SendPaidMedia<'a> {
         }
     }
 
+    #[must_use]
+    pub fn allow_paid_broadcast_option(self, val: Option<bool>) -> Self {
+        Self {
+            allow_paid_broadcast: val,
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn reply_parameters_option(self, val: Option<ReplyParameters>) -> Self {
         Self {
@@ -281,6 +319,8 @@ impl<'a> SendPaidMedia<'a> {
     }
 }
 
+impl_reply_shortcuts!(SendPaidMedia<'a>, without_thread);
+
 impl<'a> TelegramMethod for SendPaidMedia<'a> {
     type Method = Self;
     type Return = Message;
@@ -291,6 +331,29 @@ impl<'a> TelegramMethod for SendPaidMedia<'a> {
 
         Request::new("sendPaidMedia", self, Some(files.into()))
     }
+
+    fn with_default_parse_mode(&self, parse_mode: &str) -> Option<Self> {
+        (self.parse_mode.is_none() && self.caption_entities.is_none())
+            .then(|| self.clone().parse_mode(parse_mode))
+    }
+
+    fn with_default_business_connection_id(&self, business_connection_id: &str) -> Option<Self> {
+        self.business_connection_id.is_none().then(|| {
+            self.clone()
+                .business_connection_id(business_connection_id.to_owned())
+        })
+    }
+
+    fn with_chat(&self, chat_id: i64, _message_thread_id: Option<i64>) -> Option<Self> {
+        if !self.chat_id.is_unset() {
+            return None;
+        }
+
+        Some(Self {
+            chat_id: chat_id.into(),
+            ..self.clone()
+        })
+    }
 }
 
 impl<'a> AsRef<SendPaidMedia<'a>> for SendPaidMedia<'a> {
@@ -38,6 +38,8 @@ pub struct SendPhoto<'a> {
     pub disable_notification: Option<bool>,
     /// Protects the contents of the sent message from forwarding and saving
     pub protect_content: Option<bool>,
+    /// Pass `true` to allow up to 1000 messages per second, ignoring [broadcasting limits](https://core.telegram.org/bots/faq#how-can-i-message-all-of-my-bot-39s-subscribers-at-once) for a fee of 0.1 Telegram Stars per message. The relevant Stars will be withdrawn from the bot's balance
+    pub allow_paid_broadcast: Option<bool>,
     /// Unique identifier of the message effect to be added to the message; for private chats only
     pub message_effect_id: Option<String>,
     /// Description of the message to reply to
@@ -61,6 +63,7 @@ impl<'a> SendPhoto<'a> {
             has_spoiler: None,
             disable_notification: None,
             protect_content: None,
+            allow_paid_broadcast: None,
             message_effect_id: None,
             reply_parameters: None,
             reply_markup: None,
@@ -175,6 +178,14 @@ impl<'a> SendPhoto<'a> {
         }
     }
 
+    #[must_use]
+    pub fn allow_paid_broadcast(self, val: bool) -> Self {
+        Self {
+            allow_paid_broadcast: Some(val),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn message_effect_id(self, val: impl Into<String>) -> Self {
         Self {
@@ -282,6 +293,14 @@ impl<'a> SendPhoto<'a> {
         }
     }
 
+    #[must_use]
+    pub fn allow_paid_broadcast_option(self, val: Option<bool>) -> Self {
+        Self {
+            allow_paid_broadcast: val,
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn message_effect_id_option(self, val: Option<impl Into<String>>) -> Self {
         Self {
@@ -307,6 +326,8 @@ impl<'a> SendPhoto<'a> {
     }
 }
 
+impl_reply_shortcuts!(SendPhoto<'a>);
+
 impl<'a> TelegramMethod for SendPhoto<'a> {
     type Method = Self;
     type Return = Message;
@@ -317,6 +338,30 @@ impl<'a> TelegramMethod for SendPhoto<'a> {
 
         Request::new("sendPhoto", self, Some(files.into()))
     }
+
+    fn with_default_parse_mode(&self, parse_mode: &str) -> Option<Self> {
+        (self.parse_mode.is_none() && self.caption_entities.is_none())
+            .then(|| self.clone().parse_mode(parse_mode))
+    }
+
+    fn with_default_business_connection_id(&self, business_connection_id: &str) -> Option<Self> {
+        self.business_connection_id.is_none().then(|| {
+            self.clone()
+                .business_connection_id(business_connection_id.to_owned())
+        })
+    }
+
+    fn with_chat(&self, chat_id: i64, message_thread_id: Option<i64>) -> Option<Self> {
+        if !self.chat_id.is_unset() {
+            return None;
+        }
+
+        Some(Self {
+            chat_id: chat_id.into(),
+            message_thread_id: self.message_thread_id.or(message_thread_id),
+            ..self.clone()
+        })
+    }
 }
 
 impl<'a> AsRef<SendPhoto<'a>> for SendPhoto<'a> {
@@ -324,3 +369,22 @@ impl<'a> AsRef<SendPhoto<'a>> for SendPhoto<'a> {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recent_fields_serialization() {
+        let value = serde_json::to_value(
+            SendPhoto::new(1, InputFile::id("file_id"))
+                .show_caption_above_media(true)
+                .message_effect_id("effect")
+                .allow_paid_broadcast(true),
+        )
+        .unwrap();
+        assert_eq!(value["show_caption_above_media"], true);
+        assert_eq!(value["message_effect_id"], "effect");
+        assert_eq!(value["allow_paid_broadcast"], true);
+    }
+}
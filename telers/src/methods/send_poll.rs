@@ -2,12 +2,21 @@ use super::base::{Request, TelegramMethod};
 
 use crate::{
     client::Bot,
+    enums::PollType,
     types::{ChatIdKind, InputPollOption, Message, MessageEntity, ReplyMarkup, ReplyParameters},
 };
 
 use serde::Serialize;
 use serde_with::skip_serializing_none;
 
+/// This error occurs when the correct option of the quiz is out of range of the poll options
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Correct option identifier {correct_option_id} is out of range of {options_len} options")]
+pub struct InvalidCorrectOption {
+    pub correct_option_id: usize,
+    pub options_len: usize,
+}
+
 /// Use this method to send a native poll.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#sendpoll>
@@ -55,6 +64,8 @@ pub struct SendPoll {
     pub disable_notification: Option<bool>,
     /// Protects the contents of the sent message from forwarding and saving
     pub protect_content: Option<bool>,
+    /// Pass `true` to allow up to 1000 messages per second, ignoring [broadcasting limits](https://core.telegram.org/bots/faq#how-can-i-message-all-of-my-bot-39s-subscribers-at-once) for a fee of 0.1 Telegram Stars per message. The relevant Stars will be withdrawn from the bot's balance
+    pub allow_paid_broadcast: Option<bool>,
     /// Unique identifier of the message effect to be added to the message; for private chats only
     pub message_effect_id: Option<String>,
     /// Description of the message to reply to
@@ -94,6 +105,7 @@ impl SendPoll {
             is_closed: None,
             disable_notification: None,
             protect_content: None,
+            allow_paid_broadcast: None,
             message_effect_id: None,
             reply_parameters: None,
             reply_markup: None,
@@ -308,6 +320,14 @@ impl SendPoll {
         }
     }
 
+    #[must_use]
+    pub fn allow_paid_broadcast(self, val: bool) -> Self {
+        Self {
+            allow_paid_broadcast: Some(val),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn message_effect_id(self, val: impl Into<String>) -> Self {
         Self {
@@ -465,6 +485,14 @@ impl SendPoll {
         }
     }
 
+    #[must_use]
+    pub fn allow_paid_broadcast_option(self, val: Option<bool>) -> Self {
+        Self {
+            allow_paid_broadcast: val,
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn message_effect_id_option(self, val: Option<impl Into<String>>) -> Self {
         Self {
@@ -490,6 +518,60 @@ impl SendPoll {
     }
 }
 
+impl SendPoll {
+    /// Creates a quiz with the correct option, see [`SendPoll::correct_option`] for more details
+    /// # Errors
+    /// If `correct_option_id` is out of range of the options
+    pub fn quiz<T, I>(
+        chat_id: impl Into<ChatIdKind>,
+        question: impl Into<String>,
+        options: I,
+        correct_option_id: usize,
+    ) -> Result<Self, InvalidCorrectOption>
+    where
+        T: Into<InputPollOption>,
+        I: IntoIterator<Item = T>,
+    {
+        Self::new(chat_id, question, options).correct_option(correct_option_id)
+    }
+
+    /// Sets 0-based identifier of the correct option and switches the poll to the `quiz` mode
+    /// # Errors
+    /// If `correct_option_id` is out of range of the options, so options must be set before
+    pub fn correct_option(self, correct_option_id: usize) -> Result<Self, InvalidCorrectOption> {
+        if correct_option_id >= self.options.len() {
+            return Err(InvalidCorrectOption {
+                correct_option_id,
+                options_len: self.options.len(),
+            });
+        }
+
+        Ok(Self {
+            poll_type: Some(PollType::Quiz.into()),
+            correct_option_id: Some(i64::try_from(correct_option_id).unwrap_or(i64::MAX)),
+            ..self
+        })
+    }
+
+    /// Sets explanation of the quiz with the mode for parsing entities in it.
+    /// Explanation entities are removed, because they can't be used together with the parse mode
+    #[must_use]
+    pub fn explanation_with_parse_mode(
+        self,
+        text: impl Into<String>,
+        parse_mode: impl Into<String>,
+    ) -> Self {
+        Self {
+            explanation: Some(text.into()),
+            explanation_parse_mode: Some(parse_mode.into()),
+            explanation_entities: None,
+            ..self
+        }
+    }
+}
+
+impl_reply_shortcuts!(SendPoll);
+
 impl TelegramMethod for SendPoll {
     type Method = Self;
     type Return = Message;
@@ -497,6 +579,25 @@ impl TelegramMethod for SendPoll {
     fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
         Request::new("sendPoll", self, None)
     }
+
+    fn with_default_business_connection_id(&self, business_connection_id: &str) -> Option<Self> {
+        self.business_connection_id.is_none().then(|| {
+            self.clone()
+                .business_connection_id(business_connection_id.to_owned())
+        })
+    }
+
+    fn with_chat(&self, chat_id: i64, message_thread_id: Option<i64>) -> Option<Self> {
+        if !self.chat_id.is_unset() {
+            return None;
+        }
+
+        Some(Self {
+            chat_id: chat_id.into(),
+            message_thread_id: self.message_thread_id.or(message_thread_id),
+            ..self.clone()
+        })
+    }
 }
 
 impl AsRef<SendPoll> for SendPoll {
@@ -504,3 +605,40 @@ impl AsRef<SendPoll> for SendPoll {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::ParseMode;
+
+    #[test]
+    fn test_quiz_serialization() {
+        let value = serde_json::to_value(
+            SendPoll::quiz(1, "2 + 2?", ["3", "4", "5"], 1)
+                .unwrap()
+                .explanation_with_parse_mode("<b>4</b>", ParseMode::HTML),
+        )
+        .unwrap();
+
+        assert_eq!(value["type"], "quiz");
+        assert_eq!(value["correct_option_id"], 1);
+        assert_eq!(value["explanation"], "<b>4</b>");
+        assert_eq!(value["explanation_parse_mode"], "HTML");
+        assert!(value.get("explanation_entities").is_none());
+        assert_eq!(value["options"].as_array().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_quiz_correct_option_out_of_range() {
+        assert_eq!(
+            SendPoll::quiz(1, "2 + 2?", ["3", "4"], 2),
+            Err(InvalidCorrectOption {
+                correct_option_id: 2,
+                options_len: 2,
+            })
+        );
+        assert!(SendPoll::new(1, "2 + 2?", ["3", "4"])
+            .correct_option(5)
+            .is_err());
+    }
+}
@@ -85,6 +85,24 @@ impl SetMessageReaction {
         }
     }
 
+    /// Adds the emoji reaction
+    #[must_use]
+    pub fn emoji(self, val: impl Into<String>) -> Self {
+        self.reaction(ReactionType::emoji(val))
+    }
+
+    /// Adds the custom emoji reaction by the custom emoji identifier
+    #[must_use]
+    pub fn custom_emoji(self, val: impl Into<String>) -> Self {
+        self.reaction(ReactionType::custom_emoji(val))
+    }
+
+    /// Adds the paid reaction
+    #[must_use]
+    pub fn paid(self) -> Self {
+        self.reaction(ReactionType::paid())
+    }
+
     #[must_use]
     pub fn is_big(self, val: bool) -> Self {
         Self {
@@ -150,3 +168,62 @@ impl AsRef<SetMessageReaction> for SetMessageReaction {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    #[test]
+    fn serialize_single_emoji() {
+        let method = SetMessageReaction::new(1, 2).reaction("👍");
+
+        assert_eq!(
+            serde_json::to_value(&method).unwrap(),
+            json!({
+                "chat_id": 1,
+                "message_id": 2,
+                "reaction": [{"type": "emoji", "emoji": "👍"}],
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_multiple() {
+        let method = SetMessageReaction::new("@channel", 2)
+            .emoji("👍")
+            .reactions(["🔥", "🎉"])
+            .paid()
+            .is_big(true);
+
+        assert_eq!(
+            serde_json::to_value(&method).unwrap(),
+            json!({
+                "chat_id": "@channel",
+                "message_id": 2,
+                "reaction": [
+                    {"type": "emoji", "emoji": "👍"},
+                    {"type": "emoji", "emoji": "🔥"},
+                    {"type": "emoji", "emoji": "🎉"},
+                    {"type": "paid"},
+                ],
+                "is_big": true,
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_custom_emoji() {
+        let method = SetMessageReaction::new(1, 2).custom_emoji("5368324170671202286");
+
+        assert_eq!(
+            serde_json::to_value(&method).unwrap(),
+            json!({
+                "chat_id": 1,
+                "message_id": 2,
+                "reaction": [{"type": "custom_emoji", "custom_emoji": "5368324170671202286"}],
+            })
+        );
+    }
+}
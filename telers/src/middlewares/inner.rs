@@ -16,9 +16,15 @@
 //! [`context`]: crate::context::Context
 
 pub mod base;
+pub mod delete_trigger_message;
 pub mod logging;
 pub mod manager;
+pub mod timeout;
+pub mod transaction;
 
 pub use base::{wrap_handler_and_middlewares_to_next, Middleware, Next};
+pub use delete_trigger_message::DeleteTriggerMessage;
 pub use logging::Logging;
 pub use manager::Manager;
+pub use timeout::Timeout;
+pub use transaction::{Transaction, TransactionPool, Tx};
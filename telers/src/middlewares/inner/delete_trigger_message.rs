@@ -0,0 +1,261 @@
+//! This module contains [`DeleteTriggerMessage`] middleware, which deletes the message that triggered the handler.
+//!
+//! It's useful for command bots in groups to keep the chat clean: the user's `/command` message is deleted
+//! after the handler processes it successfully (returns [`EventReturn::Finish`]).
+//! Errors of the deletion are logged and don't affect the handler result,
+//! for example, if the message is already deleted or the bot has no rights to delete it.
+//!
+//! Only messages of [`UpdateKind::Message`], [`UpdateKind::EditedMessage`] and [`UpdateKind::ChannelPost`] updates are deleted,
+//! so the bot's own message of the callback query isn't deleted and business messages are skipped,
+//! because they can't be deleted by [`DeleteMessage`].
+//!
+//! The deletion can be delayed by [`DeleteTriggerMessage::delay`], in this case it's done in the background,
+//! so the middleware doesn't wait for it.
+//!
+//! # Examples
+//! ```rust
+//! use std::time::Duration;
+//! use telers::{client::Reqwest, middlewares::inner::DeleteTriggerMessage, Router};
+//!
+//! let mut router = Router::<Reqwest>::new("main");
+//! router
+//!     .message
+//!     .inner_middlewares
+//!     .register(DeleteTriggerMessage::new().delay(Duration::from_secs(5)));
+//! ```
+
+use super::base::{Middleware, Next};
+
+use crate::{
+    client::{Bot, Session},
+    errors::{EventErrorKind, SessionErrorKind, TelegramApiError},
+    event::{
+        telegram::{HandlerRequest, HandlerResponse},
+        EventReturn,
+    },
+    methods::DeleteMessage,
+    types::UpdateKind,
+};
+
+use async_trait::async_trait;
+use std::{sync::Arc, time::Duration};
+use tracing::{event, instrument, Level};
+
+/// Middleware, which deletes the message that triggered the handler after the successful handling
+#[derive(Debug, Default, Clone)]
+pub struct DeleteTriggerMessage {
+    delay: Option<Duration>,
+}
+
+impl DeleteTriggerMessage {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set delay before the deletion, by default, the message is deleted immediately
+    #[must_use]
+    pub fn delay(self, val: Duration) -> Self {
+        Self { delay: Some(val) }
+    }
+
+    #[must_use]
+    pub fn delay_option(self, val: Option<Duration>) -> Self {
+        Self { delay: val }
+    }
+}
+
+async fn delete_message<Client: Session>(bot: &Bot<Client>, method: DeleteMessage) {
+    match bot.send(method).await {
+        Ok(_) => {}
+        Err(SessionErrorKind::Telegram(err))
+            if err.api_error() == Some(TelegramApiError::MessageToDeleteNotFound) =>
+        {
+            event!(Level::DEBUG, "Trigger message is already deleted");
+        }
+        Err(err) => {
+            event!(Level::WARN, error = %err, "Failed to delete trigger message");
+        }
+    }
+}
+
+#[async_trait]
+impl<Client> Middleware<Client> for DeleteTriggerMessage
+where
+    Client: Session + Send + Sync + 'static,
+{
+    #[instrument(skip(self, request, next))]
+    async fn call(
+        &self,
+        request: HandlerRequest<Client>,
+        next: Next<Client>,
+    ) -> Result<HandlerResponse<Client>, EventErrorKind> {
+        let response = next(request.clone()).await?;
+
+        if !matches!(response.handler_result, Ok(EventReturn::Finish)) {
+            return Ok(response);
+        }
+
+        let (UpdateKind::Message(message)
+        | UpdateKind::EditedMessage(message)
+        | UpdateKind::ChannelPost(message)) = &request.update.kind
+        else {
+            return Ok(response);
+        };
+        let method = DeleteMessage::new(message.chat().id(), message.id());
+
+        match self.delay {
+            Some(delay) => {
+                let bot = Arc::clone(&request.bot);
+
+                tokio::spawn(async move {
+                    tokio::time::sleep(delay).await;
+                    delete_message(&bot, method).await;
+                });
+            }
+            None => delete_message(&request.bot, method).await,
+        }
+
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        errors::HandlerError,
+        event::telegram::HandlerResult,
+        test::{self, MockBot},
+        types::MaybeInaccessibleMessage,
+        Router,
+    };
+
+    fn router(handler_result: fn() -> HandlerResult) -> Router<MockBot> {
+        let mut router = Router::new("main");
+        router
+            .message
+            .inner_middlewares
+            .register(DeleteTriggerMessage::new());
+        router
+            .message
+            .register(move || async move { handler_result() });
+        router
+    }
+
+    #[tokio::test]
+    async fn test_delete_trigger_message() {
+        let mock = MockBot::new();
+        mock.push_result::<DeleteMessage>(true);
+
+        let calls = mock
+            .propagate(router(|| Ok(EventReturn::Finish)), test::message("/start"))
+            .await
+            .unwrap();
+
+        assert_eq!(calls.len(), 1);
+        assert!(calls[0].is::<DeleteMessage>());
+        assert_eq!(calls[0].data["chat_id"], test::CHAT_ID);
+        assert_eq!(calls[0].data["message_id"], 1);
+
+        // Errors of the deletion are swallowed
+        mock.push_error::<DeleteMessage>(400, "Bad Request: message to delete not found");
+
+        let calls = mock
+            .propagate(router(|| Ok(EventReturn::Finish)), test::message("/start"))
+            .await
+            .unwrap();
+
+        assert_eq!(calls.len(), 1);
+
+        // No response is queued, so the mock returns client error
+        let calls = mock
+            .propagate(router(|| Ok(EventReturn::Finish)), test::message("/start"))
+            .await
+            .unwrap();
+
+        assert_eq!(calls.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_not_deleted_without_finish() {
+        let mock = MockBot::new();
+
+        let calls = mock
+            .propagate(router(|| Ok(EventReturn::Skip)), test::message("/start"))
+            .await
+            .unwrap();
+
+        assert!(calls.is_empty());
+
+        assert!(mock
+            .propagate(
+                router(|| Err(HandlerError::from_display("error"))),
+                test::message("/start"),
+            )
+            .await
+            .is_err());
+        assert!(mock.calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_not_deleted_callback_query_message() {
+        let mock = MockBot::new();
+
+        let mut router = Router::new("main");
+        router
+            .callback_query
+            .inner_middlewares
+            .register(DeleteTriggerMessage::new());
+        router
+            .callback_query
+            .register(|| async { Ok(EventReturn::Finish) });
+
+        let mut update = test::callback_query("data");
+        let UpdateKind::Message(message) = test::message("text").kind else {
+            unreachable!("`test::message` builds message update");
+        };
+        if let UpdateKind::CallbackQuery(query) = &mut update.kind {
+            query.message = Some(MaybeInaccessibleMessage::Message(message));
+        }
+
+        // The message of the callback query is the bot's message, so it isn't deleted
+        let calls = mock.propagate(router, update).await.unwrap();
+
+        assert!(calls.is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_delete_trigger_message_delay() {
+        let mock = MockBot::new();
+        mock.push_result::<DeleteMessage>(true);
+
+        let mut router = Router::new("main");
+        router
+            .message
+            .inner_middlewares
+            .register(DeleteTriggerMessage::new().delay(Duration::from_secs(5)));
+        router
+            .message
+            .register(|| async { Ok(EventReturn::Finish) });
+
+        // The deletion is done in the background after the delay
+        let calls = mock
+            .propagate(router, test::message("/start"))
+            .await
+            .unwrap();
+
+        assert!(calls.is_empty());
+
+        tokio::time::sleep(Duration::from_secs(4)).await;
+
+        assert!(mock.calls().is_empty());
+
+        tokio::time::sleep(Duration::from_secs(2)).await;
+
+        let calls = mock.calls();
+
+        assert_eq!(calls.len(), 1);
+        assert!(calls[0].is::<DeleteMessage>());
+    }
+}
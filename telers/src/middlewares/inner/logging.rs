@@ -1,3 +1,22 @@
+//! This module contains [`Logging`] middleware, which logs the result of the handler.
+//!
+//! Each log event has structured fields `update_id`, `chat_id`, `user_id` and `elapsed`,
+//! so logs can be filtered and aggregated by them.
+//! By default, results are logged with [`Level::DEBUG`] level, errors are always logged with [`Level::ERROR`] level,
+//! and the text of the update isn't logged for privacy, use [`Logging::include_text`] to log it.
+//!
+//! # Examples
+//! ```rust
+//! use telers::{client::Reqwest, middlewares::inner::Logging, Router};
+//! use tracing::Level;
+//!
+//! let mut router = Router::<Reqwest>::new("main");
+//! router
+//!     .message
+//!     .inner_middlewares
+//!     .register(Logging::new().level(Level::INFO).include_text(true));
+//! ```
+
 use super::base::{Middleware, Next};
 
 use crate::{
@@ -15,13 +34,57 @@ use std::{
 };
 use tracing::{event, instrument, Level};
 
-#[derive(Debug, Default, Clone, Copy)]
-pub struct Logging;
+/// Emits the event with the level, which is known only at runtime
+macro_rules! event_with_level {
+    ($level:expr, $($args:tt)+) => {
+        match $level {
+            Level::TRACE => event!(Level::TRACE, $($args)+),
+            Level::DEBUG => event!(Level::DEBUG, $($args)+),
+            Level::INFO => event!(Level::INFO, $($args)+),
+            Level::WARN => event!(Level::WARN, $($args)+),
+            _ => event!(Level::ERROR, $($args)+),
+        }
+    };
+}
+
+/// Middleware, which logs the result of the handler with the update identifiers and the elapsed time.
+/// Use [`Logging::new`] or [`Logging::default`] to create it.
+#[derive(Debug, Clone, Copy)]
+pub struct Logging {
+    level: Level,
+    include_text: bool,
+}
 
 impl Logging {
+    /// Creates the middleware, which logs results with [`Level::DEBUG`] level and without the text of the update
     #[must_use]
     pub const fn new() -> Self {
-        Self {}
+        Self {
+            level: Level::DEBUG,
+            include_text: false,
+        }
+    }
+
+    /// Set level of the handler result logs, by default, [`Level::DEBUG`].
+    /// Errors are always logged with [`Level::ERROR`] level.
+    #[must_use]
+    pub const fn level(self, val: Level) -> Self {
+        Self { level: val, ..self }
+    }
+
+    /// Set whether to log the text or the caption of the update in the `text` field, by default, `false`
+    #[must_use]
+    pub const fn include_text(self, val: bool) -> Self {
+        Self {
+            include_text: val,
+            ..self
+        }
+    }
+}
+
+impl Default for Logging {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -42,56 +105,75 @@ where
         request: HandlerRequest<Client>,
         next: Next<Client>,
     ) -> Result<HandlerResponse<Client>, EventErrorKind> {
+        let update = request.update.clone();
+        let update_id = update.id;
+        let chat_id = update.chat_id();
+        let user_id = update.from_id();
+        let text = if self.include_text {
+            update.text_or_caption()
+        } else {
+            None
+        };
+
         let now = Instant::now();
         let result = next(request).await;
         let elapsed = now.elapsed();
 
         match result {
-            // `unwrap` is safe because handler error is wrapped to event error by next function
-            Ok(ref response) => match response.handler_result.as_ref().unwrap() {
-                EventReturn::Finish => {
-                    event!(
-                        Level::DEBUG,
-                        "Handler finished. Execution time: {elapsed:.2?}",
-                    );
-                }
-                EventReturn::Skip => {
-                    event!(
-                        Level::DEBUG,
-                        "Handler skipped. Execution time: {elapsed:.2?}",
-                    );
-                }
-                EventReturn::Cancel => {
-                    event!(
-                        Level::DEBUG,
-                        "Handler canceled. Execution time: {elapsed:.2?}",
-                    );
-                }
-            },
-            Err(ref err_kind) => match err_kind {
-                EventErrorKind::Extraction(err) => {
-                    event!(
-                        Level::ERROR,
-                        error = %err,
-                        "Extraction returns error. Execution time: {elapsed:.2?}",
-
-                    );
-                }
-                EventErrorKind::Handler(err) => {
-                    event!(
-                        Level::ERROR,
-                        error = %err,
-                        "Handler returns error. Execution time: {elapsed:.2?}",
-                    );
-                }
-                EventErrorKind::Middleware(err) => {
-                    event!(
-                        Level::ERROR,
-                        error = %err,
-                        "Middleware returns error. Execution time: {elapsed:.2?}",
-                    );
-                }
-            },
+            Ok(HandlerResponse {
+                handler_result: Ok(ref event_return),
+                ..
+            }) => {
+                let message = match event_return {
+                    EventReturn::Finish => "Handler finished",
+                    EventReturn::Skip => "Handler skipped",
+                    EventReturn::Cancel => "Handler canceled",
+                };
+
+                event_with_level!(
+                    self.level,
+                    update_id,
+                    chat_id,
+                    user_id,
+                    text,
+                    ?elapsed,
+                    "{message}",
+                );
+            }
+            // Handler error can be returned in the response by next middlewares, for example, by `Timeout`
+            Ok(HandlerResponse {
+                handler_result: Err(ref err),
+                ..
+            }) => {
+                event!(
+                    Level::ERROR,
+                    update_id,
+                    chat_id,
+                    user_id,
+                    text,
+                    ?elapsed,
+                    error = %err,
+                    "Handler returns error",
+                );
+            }
+            Err(ref err_kind) => {
+                let message = match err_kind {
+                    EventErrorKind::Extraction(_) => "Extraction returns error",
+                    EventErrorKind::Handler(_) => "Handler returns error",
+                    EventErrorKind::Middleware(_) => "Middleware returns error",
+                };
+
+                event!(
+                    Level::ERROR,
+                    update_id,
+                    chat_id,
+                    user_id,
+                    text,
+                    ?elapsed,
+                    error = %err_kind,
+                    "{message}",
+                );
+            }
         }
 
         result
@@ -106,26 +188,29 @@ mod tests {
         context::Context,
         event::{service::ServiceFactory as _, telegram::handler_service},
         middlewares::inner::wrap_handler_and_middlewares_to_next,
+        test::{
+            self,
+            recorder::{Fields, Recorder},
+        },
         types::{Message, Update, UpdateKind},
     };
 
     use std::sync::Arc;
 
-    #[tokio::test]
-    async fn test_logging() {
+    async fn call(logging: Logging, update: Update) -> Vec<(Level, Fields)> {
         let handler_service_factory =
             handler_service(|| async { Ok(EventReturn::Finish) }).new_service(());
         let handler_service = Arc::new(handler_service_factory.unwrap());
 
+        let recorder = Recorder::default();
+        let _guard = tracing::subscriber::set_default(recorder.clone());
+
         let request = HandlerRequest::new(
             Arc::new(Bot::<Reqwest>::default()),
-            Arc::new(Update {
-                id: 0,
-                kind: UpdateKind::Message(Message::default()),
-            }),
+            Arc::new(update),
             Arc::new(Context::default()),
         );
-        let response = Logging
+        let response = logging
             .call(
                 request,
                 wrap_handler_and_middlewares_to_next(handler_service, [].into()),
@@ -133,5 +218,48 @@ mod tests {
             .await;
 
         assert!(response.is_ok());
+
+        recorder.events()
+    }
+
+    #[tokio::test]
+    async fn test_logging() {
+        let events = call(
+            Logging::default(),
+            Update {
+                id: 0,
+                kind: UpdateKind::Message(Message::default()),
+            },
+        )
+        .await;
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].0, Level::DEBUG);
+    }
+
+    #[tokio::test]
+    async fn test_fields() {
+        let events = call(Logging::new(), test::message("secret")).await;
+
+        let (level, fields) = &events[0];
+
+        assert_eq!(*level, Level::DEBUG);
+        assert_eq!(fields["update_id"], "0");
+        assert_eq!(fields["chat_id"], test::CHAT_ID.to_string());
+        assert_eq!(fields["user_id"], test::USER_ID.to_string());
+        assert_eq!(fields["message"], "Handler finished");
+        assert!(fields.contains_key("elapsed"));
+        assert!(!fields.contains_key("text"));
+
+        let events = call(
+            Logging::new().level(Level::INFO).include_text(true),
+            test::message("secret"),
+        )
+        .await;
+
+        let (level, fields) = &events[0];
+
+        assert_eq!(*level, Level::INFO);
+        assert_eq!(fields["text"], "\"secret\"");
     }
 }
@@ -0,0 +1,174 @@
+//! This module contains [`Timeout`] middleware, which limits the execution time of the handler.
+//!
+//! A handler that hangs forever blocks the processing of the update, so the middleware races the handler (and next middlewares)
+//! against a timer. If the timer fires first, the handler future is dropped, the timeout is logged and
+//! the middleware returns [`Elapsed`] as the handler error (like other handler errors) or [`EventReturn`], configured by [`Timeout::event_return`].
+//!
+//! The middleware is registered per observer, so each observer can have its own timeout.
+//!
+//! # Examples
+//! ```rust
+//! use std::time::Duration;
+//! use telers::{client::Reqwest, event::EventReturn, middlewares::inner::Timeout, Router};
+//!
+//! let mut router = Router::<Reqwest>::new("main");
+//! router
+//!     .message
+//!     .inner_middlewares
+//!     .register(Timeout::new(Duration::from_secs(10)));
+//! router
+//!     .callback_query
+//!     .inner_middlewares
+//!     .register(Timeout::new(Duration::from_secs(3)).event_return(EventReturn::Skip));
+//! ```
+
+use super::base::{Middleware, Next};
+
+use crate::{
+    errors::{EventErrorKind, HandlerError},
+    event::{
+        telegram::{HandlerRequest, HandlerResponse},
+        EventReturn,
+    },
+};
+
+use async_trait::async_trait;
+use std::time::Duration;
+use tracing::{event, instrument, Level};
+
+pub use tokio::time::error::Elapsed;
+
+/// Middleware, which limits the execution time of the handler
+#[derive(Debug, Clone)]
+pub struct Timeout {
+    duration: Duration,
+    event_return: Option<EventReturn>,
+}
+
+impl Timeout {
+    /// # Arguments
+    /// * `duration` - Maximum execution time of the handler
+    #[must_use]
+    pub const fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            event_return: None,
+        }
+    }
+
+    /// Set [`EventReturn`], which is returned on timeout instead of [`Elapsed`] error.
+    /// For example, [`EventReturn::Skip`] can be used to continue the propagation to the next handlers.
+    #[must_use]
+    pub fn event_return(self, val: EventReturn) -> Self {
+        Self {
+            event_return: Some(val),
+            ..self
+        }
+    }
+}
+
+#[async_trait]
+impl<Client> Middleware<Client> for Timeout
+where
+    Client: Send + Sync + 'static,
+{
+    #[instrument(skip(self, request, next))]
+    async fn call(
+        &self,
+        request: HandlerRequest<Client>,
+        next: Next<Client>,
+    ) -> Result<HandlerResponse<Client>, EventErrorKind> {
+        match tokio::time::timeout(self.duration, next(request.clone())).await {
+            Ok(result) => result,
+            Err(err) => {
+                event!(
+                    Level::WARN,
+                    timeout = ?self.duration,
+                    "Handler execution time exceeded the timeout",
+                );
+
+                // The timeout is returned as the handler result like other handler errors,
+                // so previous middlewares and the router handle it the same way
+                Ok(HandlerResponse {
+                    request,
+                    handler_result: match &self.event_return {
+                        Some(event_return) => Ok(event_return.clone()),
+                        None => Err(HandlerError::new(err)),
+                    },
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::Bot,
+        event::telegram::HandlerResult,
+        methods::SendMessage,
+        test::{self, MockBot},
+        Router,
+    };
+
+    async fn sleeping_handler() -> HandlerResult {
+        tokio::time::sleep(Duration::from_secs(60)).await;
+
+        Ok(EventReturn::Finish)
+    }
+
+    #[tokio::test]
+    async fn test_timeout_error() {
+        let mut router = Router::new("main");
+        // Previous middlewares receive the timeout as the handler result
+        router.message.inner_middlewares.register(
+            |request: HandlerRequest<MockBot>, next: Next<MockBot>| async move {
+                let response = next(request).await?;
+
+                assert!(response
+                    .handler_result
+                    .as_ref()
+                    .is_err_and(|err| err.to_string().contains("deadline has elapsed")));
+
+                Ok(response)
+            },
+        );
+        router
+            .message
+            .inner_middlewares
+            .register(Timeout::new(Duration::from_millis(10)));
+        router.message.register(sleeping_handler);
+
+        let result = MockBot::new()
+            .propagate(router, test::message("text"))
+            .await;
+
+        // The timeout doesn't abort propagation like a middleware failure, it's handled as the handler error
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_timeout_event_return() {
+        let mock = MockBot::new();
+        mock.push_result::<SendMessage>(test::sent_message("text"));
+
+        let mut router = Router::new("main");
+        router
+            .message
+            .inner_middlewares
+            .register(Timeout::new(Duration::from_millis(10)).event_return(EventReturn::Skip));
+        router.message.register(sleeping_handler);
+        router.message.register(|bot: Bot<MockBot>| async move {
+            bot.send(SendMessage::new(test::CHAT_ID, "text")).await?;
+
+            Ok(EventReturn::Finish)
+        });
+
+        let calls = mock.propagate(router, test::message("text")).await.unwrap();
+
+        // The processing continues to the next handler after the timeout
+        assert_eq!(calls.len(), 1);
+        assert!(calls[0].is::<SendMessage>());
+    }
+}
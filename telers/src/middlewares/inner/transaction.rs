@@ -0,0 +1,358 @@
+//! This module contains [`Transaction`] middleware, which wraps each handler call in the database transaction.
+//!
+//! The transaction is started before the handler and injected to the [`Context`] by [`TRANSACTION_KEY`] key,
+//! so the handler can use it as [`Tx`] argument.
+//! After the handler the transaction is committed if the handler returns `Ok`,
+//! and rolled back if the handler returns `Err` or panics.
+//!
+//! The middleware isn't bound to the specific database, implement [`TransactionPool`] for your pool to use it.
+//!
+//! # Examples
+//! ```rust
+//! use async_trait::async_trait;
+//! use telers::{
+//!     event::{telegram::HandlerResult, EventReturn},
+//!     middlewares::inner::{Transaction, TransactionPool, Tx},
+//!     client::Reqwest,
+//!     Router,
+//! };
+//!
+//! struct Pool;
+//! struct Connection;
+//!
+//! #[async_trait]
+//! impl TransactionPool for Pool {
+//!     type Transaction = Connection;
+//!     type Error = std::io::Error;
+//!
+//!     async fn begin(&self) -> Result<Self::Transaction, Self::Error> {
+//!         Ok(Connection) // `BEGIN`
+//!     }
+//!
+//!     async fn commit(&self, transaction: Self::Transaction) -> Result<(), Self::Error> {
+//!         Ok(()) // `COMMIT`
+//!     }
+//!
+//!     async fn rollback(&self, transaction: Self::Transaction) -> Result<(), Self::Error> {
+//!         Ok(()) // `ROLLBACK`
+//!     }
+//! }
+//!
+//! async fn handler(tx: Tx<Connection>) -> HandlerResult {
+//!     let _connection = tx.lock().await.unwrap();
+//!     // Execute queries in the transaction
+//!
+//!     Ok(EventReturn::Finish)
+//! }
+//!
+//! let mut router = Router::<Reqwest>::new("main");
+//! router.message.inner_middlewares.register(Transaction::new(Pool));
+//! router.message.register(handler);
+//! ```
+//!
+//! [`Context`]: crate::context::Context
+
+use super::base::{Middleware, Next};
+
+use crate::{
+    client::Bot,
+    context::Context,
+    errors::{EventErrorKind, ExtractionError, MiddlewareError},
+    event::telegram::{HandlerRequest, HandlerResponse},
+    extractors::FromEventAndContext,
+    types::Update,
+};
+
+use async_trait::async_trait;
+use futures::FutureExt as _;
+use std::{
+    fmt::{self, Debug, Display, Formatter},
+    panic::{resume_unwind, AssertUnwindSafe},
+    sync::Arc,
+};
+use tokio::sync::{MappedMutexGuard, Mutex, MutexGuard};
+use tracing::{event, instrument, Level};
+
+/// Key of the [`Tx`] in the [`Context`]
+pub const TRANSACTION_KEY: &str = "transaction";
+
+/// Pool of database connections, which can start, commit and roll back transactions
+#[async_trait]
+pub trait TransactionPool: Send + Sync {
+    type Transaction: Send + 'static;
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// Starts a new transaction
+    /// # Errors
+    /// If the transaction can't be started
+    async fn begin(&self) -> Result<Self::Transaction, Self::Error>;
+
+    /// Commits the transaction
+    /// # Errors
+    /// If the transaction can't be committed
+    async fn commit(&self, transaction: Self::Transaction) -> Result<(), Self::Error>;
+
+    /// Rolls back the transaction
+    /// # Errors
+    /// If the transaction can't be rolled back
+    async fn rollback(&self, transaction: Self::Transaction) -> Result<(), Self::Error>;
+}
+
+/// Transaction of the current handler call, which can be used as handler argument
+pub struct Tx<T> {
+    inner: Arc<Mutex<Option<T>>>,
+}
+
+impl<T> Tx<T> {
+    fn new(transaction: T) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(Some(transaction))),
+        }
+    }
+
+    /// Locks the transaction for executing queries
+    /// # Returns
+    /// `None` if the transaction is already committed or rolled back by the middleware
+    pub async fn lock(&self) -> Option<MappedMutexGuard<'_, T>> {
+        MutexGuard::try_map(self.inner.lock().await, Option::as_mut).ok()
+    }
+
+    async fn take(&self) -> Option<T> {
+        self.inner.lock().await.take()
+    }
+}
+
+impl<T> Clone for Tx<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<T> Debug for Tx<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tx").finish_non_exhaustive()
+    }
+}
+
+impl<Client, T: Send + 'static> FromEventAndContext<Client> for Tx<T> {
+    type Error = ExtractionError;
+
+    fn extract(
+        _bot: Arc<Bot<Client>>,
+        _update: Arc<Update>,
+        context: Arc<Context>,
+    ) -> Result<Self, Self::Error> {
+        context
+            .get(TRANSACTION_KEY)
+            .and_then(|tx| tx.downcast_ref::<Self>().cloned())
+            .ok_or_else(|| {
+                ExtractionError::new(
+                    "No transaction in the context. Did you forget to register `Transaction` middleware?",
+                )
+            })
+    }
+}
+
+/// Middleware, which wraps each handler call in the transaction of the pool
+#[derive(Debug, Clone)]
+pub struct Transaction<Pool> {
+    pool: Pool,
+}
+
+impl<Pool> Transaction<Pool> {
+    #[must_use]
+    pub const fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+}
+
+impl<Pool> Display for Transaction<Pool> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Transaction")
+    }
+}
+
+impl<Pool: TransactionPool> Transaction<Pool> {
+    async fn rollback(&self, transaction: Pool::Transaction) {
+        if let Err(err) = self.pool.rollback(transaction).await {
+            event!(Level::ERROR, error = %err, "Failed to roll back the transaction");
+        }
+    }
+}
+
+#[async_trait]
+impl<Client, Pool> Middleware<Client> for Transaction<Pool>
+where
+    Client: Send + Sync + 'static,
+    Pool: TransactionPool,
+{
+    #[instrument(skip(self, request, next))]
+    async fn call(
+        &self,
+        request: HandlerRequest<Client>,
+        next: Next<Client>,
+    ) -> Result<HandlerResponse<Client>, EventErrorKind> {
+        let transaction = self
+            .pool
+            .begin()
+            .await
+            .map_err(|err| EventErrorKind::Middleware(MiddlewareError::new(err)))?;
+
+        let tx = Tx::new(transaction);
+        request
+            .context
+            .insert(TRANSACTION_KEY, Box::new(tx.clone()));
+
+        let result = AssertUnwindSafe(next(request)).catch_unwind().await;
+
+        // Only the middleware takes the transaction, so it must be here
+        let Some(transaction) = tx.take().await else {
+            return result.unwrap_or_else(|panic| resume_unwind(panic));
+        };
+
+        match result {
+            Ok(Ok(response)) => {
+                self.pool
+                    .commit(transaction)
+                    .await
+                    .map_err(|err| EventErrorKind::Middleware(MiddlewareError::new(err)))?;
+
+                Ok(response)
+            }
+            Ok(Err(err)) => {
+                self.rollback(transaction).await;
+
+                Err(err)
+            }
+            Err(panic) => {
+                self.rollback(transaction).await;
+
+                resume_unwind(panic)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        errors::HandlerError,
+        event::{telegram::HandlerResult, EventReturn},
+        test::{self, MockBot},
+        Router,
+    };
+
+    use std::sync::Mutex as StdMutex;
+
+    #[derive(Default)]
+    struct MockPool {
+        committed: StdMutex<Vec<Vec<&'static str>>>,
+        rolled_back: StdMutex<Vec<Vec<&'static str>>>,
+    }
+
+    #[async_trait]
+    impl TransactionPool for Arc<MockPool> {
+        type Transaction = Vec<&'static str>;
+        type Error = std::io::Error;
+
+        async fn begin(&self) -> Result<Self::Transaction, Self::Error> {
+            Ok(vec![])
+        }
+
+        async fn commit(&self, transaction: Self::Transaction) -> Result<(), Self::Error> {
+            self.committed.lock().unwrap().push(transaction);
+            Ok(())
+        }
+
+        async fn rollback(&self, transaction: Self::Transaction) -> Result<(), Self::Error> {
+            self.rolled_back.lock().unwrap().push(transaction);
+            Ok(())
+        }
+    }
+
+    async fn insert(tx: Tx<Vec<&'static str>>, query: &'static str) {
+        tx.lock().await.unwrap().push(query);
+    }
+
+    #[tokio::test]
+    async fn test_transaction() {
+        let pool = Arc::new(MockPool::default());
+        let mock = MockBot::new();
+
+        let mut router = Router::new("main");
+        router
+            .message
+            .inner_middlewares
+            .register(Transaction::new(Arc::clone(&pool)));
+        router
+            .message
+            .register(|tx: Tx<Vec<&'static str>>| async move {
+                insert(tx, "INSERT 1").await;
+
+                Ok(EventReturn::Finish)
+            });
+
+        mock.propagate(router, test::message("text")).await.unwrap();
+
+        assert_eq!(*pool.committed.lock().unwrap(), [vec!["INSERT 1"]]);
+        assert!(pool.rolled_back.lock().unwrap().is_empty());
+
+        let mut router = Router::new("main");
+        router
+            .message
+            .inner_middlewares
+            .register(Transaction::new(Arc::clone(&pool)));
+        router
+            .message
+            .register(|tx: Tx<Vec<&'static str>>| async move {
+                insert(tx, "INSERT 2").await;
+
+                HandlerResult::Err(HandlerError::from_display("error"))
+            });
+
+        assert!(mock.propagate(router, test::message("text")).await.is_err());
+        assert_eq!(pool.committed.lock().unwrap().len(), 1);
+        assert_eq!(*pool.rolled_back.lock().unwrap(), [vec!["INSERT 2"]]);
+    }
+
+    #[tokio::test]
+    async fn test_transaction_panic() {
+        async fn handler(tx: Tx<Vec<&'static str>>) -> HandlerResult {
+            insert(tx, "INSERT 1").await;
+
+            panic!("handler panic");
+        }
+
+        let pool = Arc::new(MockPool::default());
+        let mock = MockBot::new();
+
+        let mut router = Router::new("main");
+        router
+            .message
+            .inner_middlewares
+            .register(Transaction::new(Arc::clone(&pool)));
+        router.message.register(handler);
+
+        let result = AssertUnwindSafe(mock.propagate(router, test::message("text")))
+            .catch_unwind()
+            .await;
+
+        assert!(result.is_err());
+        assert!(pool.committed.lock().unwrap().is_empty());
+        assert_eq!(*pool.rolled_back.lock().unwrap(), [vec!["INSERT 1"]]);
+    }
+
+    #[test]
+    fn test_extract_without_middleware() {
+        let result = Tx::<()>::extract(
+            Arc::new(MockBot::new().bot()),
+            Arc::new(Update::default()),
+            Arc::new(Context::new()),
+        );
+
+        assert!(result.is_err());
+    }
+}
@@ -16,11 +16,19 @@
 //! [`inner middlewares`]: crate::middlewares::inner
 
 pub mod base;
+pub mod business_connection;
 pub mod fsm_context;
+pub mod fsm_data;
+pub mod i18n;
 pub mod manager;
+pub mod trace;
 pub mod user_context;
 
 pub use base::{Middleware, MiddlewareResponse};
+pub use business_connection::BusinessConnection;
 pub use fsm_context::FSMContext;
+pub use fsm_data::FSMData;
+pub use i18n::I18n;
 pub use manager::Manager;
+pub use trace::Trace;
 pub use user_context::UserContext;
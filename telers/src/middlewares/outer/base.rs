@@ -15,6 +15,33 @@ pub type MiddlewareResponse<Client = Reqwest> = (Request<Client>, EventReturn);
 /// - If you need to manipulate with [`Request`] and [`crate::context::Context`] in it
 /// Usually outer middlewares are used to manipulate with [`Request`].
 ///
+/// To reject the update with a message for the user, send the method by [`Request::bot`]
+/// and return [`EventReturn::Cancel`], or use [`Request::send_and_cancel`] shortcut:
+/// ```rust
+/// use telers::{
+///     errors::EventErrorKind,
+///     event::EventReturn,
+///     methods::SendMessage,
+///     middlewares::outer::MiddlewareResponse,
+///     router::Request,
+/// };
+///
+/// const BANNED_USER_IDS: &[i64] = &[1, 2, 3];
+///
+/// async fn ban_middleware(request: Request) -> Result<MiddlewareResponse, EventErrorKind> {
+///     let (Some(user), Some(chat)) = (request.update.from(), request.update.chat()) else {
+///         return Ok((request, EventReturn::Skip));
+///     };
+///
+///     if BANNED_USER_IDS.contains(&user.id) {
+///         let method = SendMessage::new(chat.id(), "You're banned");
+///         return request.send_and_cancel(method).await;
+///     }
+///
+///     Ok((request, EventReturn::Skip))
+/// }
+/// ```
+///
 /// Implement this trait for your own middlewares
 #[async_trait]
 pub trait Middleware<Client = Reqwest>: Send + Sync {
@@ -65,7 +92,11 @@ mod tests {
     use crate::{
         client::{Bot, Reqwest},
         context::Context,
+        event::telegram::HandlerResult,
+        methods::SendMessage,
+        test::{self, MockBot},
         types::{Message, Update, UpdateKind},
+        Router,
     };
 
     use tokio;
@@ -89,4 +120,68 @@ mod tests {
             .unwrap();
         assert!(request == updated_request);
     }
+
+    #[tokio::test]
+    async fn test_send_and_cancel() {
+        async fn handler() -> HandlerResult {
+            unreachable!("Handler must not be called")
+        }
+
+        let mock = MockBot::new();
+        mock.push_result::<SendMessage>(test::sent_message("You're banned"));
+
+        let mut router = Router::new("main");
+        router
+            .message
+            .outer_middlewares
+            .register(|request: Request<MockBot>| async move {
+                request
+                    .send_and_cancel(SendMessage::new(test::CHAT_ID, "You're banned"))
+                    .await
+            });
+        router.message.register(handler);
+
+        let calls = mock.propagate(router, test::message("text")).await.unwrap();
+
+        assert_eq!(calls.len(), 1);
+        assert!(calls[0].is::<SendMessage>());
+        assert_eq!(calls[0].data["text"], "You're banned");
+    }
+
+    #[tokio::test]
+    async fn test_rewrite_update() {
+        async fn handler(bot: Bot<MockBot>, message: Message) -> HandlerResult {
+            bot.send(SendMessage::new(test::CHAT_ID, message.text().unwrap()))
+                .await?;
+
+            Ok(EventReturn::Finish)
+        }
+
+        let mock = MockBot::new();
+        mock.push_result::<SendMessage>(test::sent_message("hello"));
+
+        let mut router = Router::new("main");
+        router
+            .update
+            .outer_middlewares
+            .register(|mut request: Request<MockBot>| async move {
+                if let UpdateKind::Message(Message::Text(message)) = &mut request.update_mut().kind
+                {
+                    message.text = message.text.to_lowercase().into();
+                }
+
+                Ok((request, EventReturn::Finish))
+            });
+        let mut sub_router = Router::new("sub");
+        sub_router.message.register(handler);
+        router.include(sub_router);
+
+        let update = test::message("HeLLo");
+        let calls = mock.propagate(router, update.clone()).await.unwrap();
+
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].data["text"], "hello");
+        // The original update isn't changed
+        assert_eq!(update.text(), Some("HeLLo"));
+    }
 }
@@ -0,0 +1,134 @@
+//! This module contains [`BusinessConnection`] middleware, which scopes the bot to the business connection of the update.
+//!
+//! Bots, which handle business messages, must pass `business_connection_id` to the methods to act on behalf of the business account.
+//! For updates with business connection, the middleware puts its identifier to the context by `event_business_connection_id` key
+//! and replaces the bot of the request by its clone with [`Bot::business_connection_id`],
+//! so the methods sent by the bot from handlers get the identifier automatically, if it isn't set explicitly.
+//!
+//! # Examples
+//! ```rust
+//! use telers::{
+//!     client::Reqwest,
+//!     event::{telegram::HandlerResult, EventReturn},
+//!     methods::SendMessage,
+//!     middlewares::outer::BusinessConnection,
+//!     types::Message,
+//!     Bot, Router,
+//! };
+//!
+//! async fn handler(bot: Bot, message: Message) -> HandlerResult {
+//!     // `business_connection_id` is filled by the bot
+//!     bot.send(SendMessage::new(message.chat().id(), "Hello!")).await?;
+//!
+//!     Ok(EventReturn::Finish)
+//! }
+//!
+//! let mut router = Router::<Reqwest>::new("main");
+//! router.business_message.outer_middlewares.register(BusinessConnection);
+//! router.business_message.register(handler);
+//! ```
+
+use super::{Middleware, MiddlewareResponse};
+
+use crate::{client::Bot, errors::EventErrorKind, event::EventReturn, router::Request};
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::instrument;
+
+/// Middleware, which scopes the bot of the request to the business connection of the update
+#[derive(Debug, Default, Clone)]
+pub struct BusinessConnection;
+
+impl BusinessConnection {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+#[async_trait]
+impl<Client> Middleware<Client> for BusinessConnection
+where
+    Client: Clone + Send + Sync + 'static,
+{
+    #[instrument(skip(self, request))]
+    async fn call(
+        &self,
+        mut request: Request<Client>,
+    ) -> Result<MiddlewareResponse<Client>, EventErrorKind> {
+        if let Some(business_connection_id) = request.update.business_connection_id() {
+            request.context.insert(
+                "event_business_connection_id",
+                Box::new(business_connection_id.to_owned()),
+            );
+
+            request.bot =
+                Arc::new(Bot::clone(&request.bot).business_connection_id(business_connection_id));
+        }
+
+        Ok((request, EventReturn::default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        methods::SendMessage,
+        test::{self, MockBot},
+        types::{Message, Update, UpdateKind},
+        Router,
+    };
+
+    fn business_message() -> Update {
+        let update = test::message("text");
+        let UpdateKind::Message(Message::Text(mut message)) = update.kind else {
+            unreachable!()
+        };
+
+        message.business_connection_id = Some("connection".into());
+
+        Update {
+            kind: UpdateKind::BusinessMessage(Message::Text(message)),
+            ..update
+        }
+    }
+
+    fn router() -> Router<MockBot> {
+        let mut router = Router::new("main");
+        router
+            .business_message
+            .outer_middlewares
+            .register(BusinessConnection);
+        router
+            .business_message
+            .register(|bot: Bot<MockBot>| async move {
+                bot.send(SendMessage::new(test::CHAT_ID, "auto")).await?;
+                bot.send(
+                    SendMessage::new(test::CHAT_ID, "explicit").business_connection_id("other"),
+                )
+                .await?;
+
+                Ok(EventReturn::Finish)
+            });
+        router
+    }
+
+    #[tokio::test]
+    async fn test_business_connection() {
+        let mock = MockBot::new();
+        for text in ["auto", "explicit"] {
+            mock.push_result::<SendMessage>(test::sent_message(text));
+        }
+
+        let calls = mock.propagate(router(), business_message()).await.unwrap();
+
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].data["business_connection_id"], "connection");
+        // Explicit connection isn't overridden
+        assert_eq!(calls[1].data["business_connection_id"], "other");
+        // The bot isn't changed outside of the request
+        assert_eq!(mock.bot().business_connection_id, None);
+    }
+}
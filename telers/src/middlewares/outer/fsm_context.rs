@@ -1,16 +1,14 @@
 use super::{Middleware, MiddlewareResponse};
 
 use crate::{
-    context::Context as RequestContext,
     errors::{EventErrorKind, MiddlewareError},
     event::EventReturn,
     fsm::{
-        storage::base::{StorageKey, DEFAULT_DESTINY},
+        key_builder::{StorageKeyBuilder, StorageKeyBuilderImpl},
         strategy::Strategy,
         Context, Storage,
     },
     router::Request,
-    types::{Chat, User},
 };
 
 use async_trait::async_trait;
@@ -18,10 +16,14 @@ use std::fmt::{self, Debug, Formatter};
 use tracing::instrument;
 
 /// Middleware for creating FSM [`Context`]
-pub struct FSMContext<S> {
+/// # Notes
+/// [`StorageKey`] of the update is built by [`StorageKeyBuilderImpl`] by default,
+/// use [`FSMContext::key_builder`] to set custom [`StorageKeyBuilder`].
+///
+/// [`StorageKey`]: crate::fsm::StorageKey
+pub struct FSMContext<S, K = StorageKeyBuilderImpl> {
     storage: S,
-    strategy: Strategy,
-    destiny: &'static str,
+    key_builder: K,
 }
 
 impl<S> FSMContext<S> {
@@ -29,15 +31,14 @@ impl<S> FSMContext<S> {
     pub fn new(storage: S) -> Self {
         Self {
             storage,
-            strategy: Strategy::default(),
-            destiny: DEFAULT_DESTINY,
+            key_builder: StorageKeyBuilderImpl::default(),
         }
     }
 
     #[must_use]
     pub fn strategy(self, val: Strategy) -> Self {
         Self {
-            strategy: val,
+            key_builder: self.key_builder.strategy(val),
             ..self
         }
     }
@@ -45,117 +46,47 @@ impl<S> FSMContext<S> {
     #[must_use]
     pub fn destiny(self, val: &'static str) -> Self {
         Self {
-            destiny: val,
+            key_builder: self.key_builder.destiny(val),
             ..self
         }
     }
 }
 
+impl<S, K> FSMContext<S, K> {
+    /// Set builder of the [`StorageKey`](crate::fsm::StorageKey) of the update
+    #[must_use]
+    pub fn key_builder<T>(self, val: T) -> FSMContext<S, T> {
+        FSMContext {
+            storage: self.storage,
+            key_builder: val,
+        }
+    }
+}
+
 impl<S> Default for FSMContext<S>
 where
     S: Default,
 {
     #[must_use]
     fn default() -> Self {
-        Self {
-            storage: S::default(),
-            strategy: Strategy::default(),
-            destiny: DEFAULT_DESTINY,
-        }
+        Self::new(S::default())
     }
 }
 
-impl<S> Debug for FSMContext<S> {
+impl<S, K: Debug> Debug for FSMContext<S, K> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_struct("FSMContext")
-            .field("strategy", &self.strategy)
-            .field("destiny", &self.destiny)
+            .field("key_builder", &self.key_builder)
             .finish_non_exhaustive()
     }
 }
 
-impl<S> FSMContext<S>
-where
-    S: Clone,
-{
-    #[must_use]
-    fn resolve_event_context(&self, bot_id: i64, context: &RequestContext) -> Option<Context<S>> {
-        let user = context.get("event_user");
-        let chat = context.get("event_chat");
-        let message_thread_id = context.get("event_message_thread_id");
-        let business_connection_id = context.get("event_business_connection_id");
-
-        let user_id = user.and_then(|user| user.downcast_ref().map(|user: &User| user.id));
-        let chat_id = chat.and_then(|chat| chat.downcast_ref().map(|chat: &Chat| chat.id()));
-        let message_thread_id = message_thread_id
-            .and_then(|message_thread_id| message_thread_id.downcast_ref().copied());
-        let business_connection_id = business_connection_id
-            .and_then(|business_connection_id| business_connection_id.downcast_ref().cloned());
-
-        self.resolve_context(
-            bot_id,
-            chat_id,
-            user_id,
-            message_thread_id,
-            business_connection_id,
-        )
-    }
-
-    #[must_use]
-    fn resolve_context(
-        &self,
-        bot_id: i64,
-        chat_id: Option<i64>,
-        user_id: Option<i64>,
-        message_thread_id: Option<i64>,
-        business_connection_id: Option<String>,
-    ) -> Option<Context<S>> {
-        user_id.map(|user_id| {
-            let id_pair = self.strategy.apply(
-                chat_id.unwrap_or(user_id),
-                user_id,
-                message_thread_id,
-                business_connection_id,
-            );
-
-            self.get_context(
-                bot_id,
-                id_pair.chat_id,
-                id_pair.user_id,
-                id_pair.message_thread_id,
-                id_pair.business_connection_id,
-            )
-        })
-    }
-
-    #[must_use]
-    fn get_context(
-        &self,
-        bot_id: i64,
-        chat_id: i64,
-        user_id: i64,
-        message_thread_id: Option<i64>,
-        business_connection_id: Option<String>,
-    ) -> Context<S> {
-        Context::new(
-            self.storage.clone(),
-            StorageKey {
-                bot_id,
-                chat_id,
-                user_id,
-                message_thread_id,
-                business_connection_id,
-                destiny: self.destiny,
-            },
-        )
-    }
-}
-
 #[async_trait]
-impl<Client, S> Middleware<Client> for FSMContext<S>
+impl<Client, S, K> Middleware<Client> for FSMContext<S, K>
 where
     Client: Send + Sync + 'static,
     S: Storage + Send + Sync + 'static,
+    K: StorageKeyBuilder,
 {
     #[instrument(skip(self, request))]
     async fn call(
@@ -164,7 +95,11 @@ where
     ) -> Result<MiddlewareResponse<Client>, EventErrorKind> {
         let context = request.context.as_ref();
 
-        if let Some(fsm_context) = self.resolve_event_context(request.bot.bot_id, context) {
+        if let Some(fsm_context) = self
+            .key_builder
+            .build(request.bot.bot_id, &request.update, context)
+            .map(|key| Context::new(self.storage.clone(), key))
+        {
             if let Some(state) = fsm_context
                 .get_state()
                 .await
@@ -181,3 +116,123 @@ where
         Ok((request, EventReturn::default()))
     }
 }
+
+#[cfg(all(test, feature = "memory-storage"))]
+mod tests {
+    use super::*;
+    use crate::{
+        context::Context as RequestContext,
+        fsm::{MemoryStorage, StorageKey},
+        test::{self, MockBot},
+        types::{Chat, Group as ChatGroup, Message, Update, UpdateKind},
+        Router,
+    };
+
+    use std::sync::{Arc, Mutex};
+
+    struct UserKeyBuilder;
+
+    impl StorageKeyBuilder for UserKeyBuilder {
+        fn build(
+            &self,
+            bot_id: i64,
+            update: &Update,
+            _context: &RequestContext,
+        ) -> Option<StorageKey> {
+            update
+                .from_id()
+                .map(|user_id| StorageKey::new(bot_id, user_id, user_id, None, None))
+        }
+    }
+
+    fn group_message() -> Update {
+        let update = test::message("text");
+        let UpdateKind::Message(Message::Text(mut message)) = update.kind else {
+            unreachable!()
+        };
+
+        message.chat = Chat::Group(Box::new(ChatGroup {
+            id: -1,
+            title: "Group".into(),
+        }));
+
+        Update {
+            kind: UpdateKind::Message(Message::Text(message)),
+            ..update
+        }
+    }
+
+    async fn set_state<K>(mock: &MockBot, middleware: FSMContext<MemoryStorage, K>)
+    where
+        K: StorageKeyBuilder + 'static,
+    {
+        let mut router = Router::new("main");
+        router.message.outer_middlewares.register(middleware);
+        router
+            .message
+            .register(|fsm: Context<MemoryStorage>| async move {
+                fsm.set_state("state").await?;
+
+                Ok(EventReturn::Finish)
+            });
+
+        mock.propagate(router, test::message("text")).await.unwrap();
+    }
+
+    async fn get_state<K>(
+        mock: &MockBot,
+        middleware: FSMContext<MemoryStorage, K>,
+    ) -> Option<String>
+    where
+        K: StorageKeyBuilder + 'static,
+    {
+        let state = Arc::new(Mutex::new(None));
+
+        let mut router = Router::new("main");
+        router.message.outer_middlewares.register(middleware);
+        router.message.register({
+            let state = Arc::clone(&state);
+
+            move |fsm: Context<MemoryStorage>| {
+                let state = Arc::clone(&state);
+
+                async move {
+                    *state.lock().unwrap() = fsm.get_state().await?.map(String::from);
+
+                    Ok(EventReturn::Finish)
+                }
+            }
+        });
+
+        mock.propagate(router, group_message()).await.unwrap();
+
+        let state = state.lock().unwrap().clone();
+        state
+    }
+
+    #[tokio::test]
+    async fn test_key_builder() {
+        let mock = MockBot::new();
+
+        // Default builder keys the state by the chat too
+        let storage = MemoryStorage::new();
+        set_state(&mock, FSMContext::new(storage.clone())).await;
+
+        assert_eq!(get_state(&mock, FSMContext::new(storage)).await, None);
+
+        // Custom builder ignores the chat, so the state is global across chats
+        let storage = MemoryStorage::new();
+        set_state(
+            &mock,
+            FSMContext::new(storage.clone()).key_builder(UserKeyBuilder),
+        )
+        .await;
+
+        assert_eq!(
+            get_state(&mock, FSMContext::new(storage).key_builder(UserKeyBuilder))
+                .await
+                .as_deref(),
+            Some("state")
+        );
+    }
+}
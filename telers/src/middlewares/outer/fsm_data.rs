@@ -0,0 +1,236 @@
+use super::{Middleware, MiddlewareResponse};
+
+use crate::{
+    context::Context as RequestContext,
+    errors::{EventErrorKind, MiddlewareError},
+    event::{
+        telegram::{HandlerRequest, HandlerResponse},
+        EventReturn,
+    },
+    fsm::{Context, Data, Storage},
+    middlewares::inner::{Middleware as InnerMiddleware, Next},
+    router::Request,
+};
+
+use async_trait::async_trait;
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+};
+use tracing::instrument;
+
+/// Middleware for loading FSM [`Data`] (state and data of the user) to the context before handlers run,
+/// so handlers can use [`Data`] as an argument instead of requesting the storage.
+///
+/// `FSMContext` middleware with the same storage should be registered before this middleware,
+/// because [`Data`] is loaded by the storage key of the FSM context.
+///
+/// By default data is read-only. If [`FSMData::write_back`] is enabled and this middleware is registered as an inner middleware too,
+/// then values changed by the handler are saved to the storage after the handler is called.
+pub struct FSMData<S> {
+    write_back: bool,
+    _storage: PhantomData<fn() -> S>,
+}
+
+impl<S> FSMData<S> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            write_back: false,
+            _storage: PhantomData,
+        }
+    }
+
+    /// Save values changed by the handler to the storage
+    #[must_use]
+    pub const fn write_back(self, val: bool) -> Self {
+        Self {
+            write_back: val,
+            ..self
+        }
+    }
+}
+
+impl<S> Default for FSMData<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Clone for FSMData<S> {
+    fn clone(&self) -> Self {
+        Self {
+            write_back: self.write_back,
+            _storage: PhantomData,
+        }
+    }
+}
+
+impl<S> Debug for FSMData<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FSMData")
+            .field("write_back", &self.write_back)
+            .finish_non_exhaustive()
+    }
+}
+
+#[must_use]
+fn fsm_context<S>(context: &RequestContext) -> Option<Context<S>>
+where
+    S: Clone + 'static,
+{
+    context
+        .get("fsm_context")
+        .and_then(|fsm_context| fsm_context.downcast_ref::<Context<S>>().cloned())
+}
+
+#[async_trait]
+impl<Client, S> Middleware<Client> for FSMData<S>
+where
+    Client: Send + Sync + 'static,
+    S: Storage + Send + Sync + 'static,
+{
+    #[instrument(skip(self, request))]
+    async fn call(
+        &self,
+        request: Request<Client>,
+    ) -> Result<MiddlewareResponse<Client>, EventErrorKind> {
+        let context = request.context.as_ref();
+
+        let Some(fsm_context) = fsm_context::<S>(context) else {
+            return Ok((request, EventReturn::default()));
+        };
+
+        // State is already loaded by `FSMContext` middleware
+        let state = context
+            .get("fsm_state")
+            .and_then(|state| state.downcast_ref::<Box<str>>().cloned());
+        let values = fsm_context
+            .get_data::<Value>()
+            .await
+            .map_err(|err| MiddlewareError::new(err.into()))?;
+
+        context.insert("fsm_data", Box::new(Data::new(state, values)));
+
+        Ok((request, EventReturn::default()))
+    }
+}
+
+#[async_trait]
+impl<Client, S> InnerMiddleware<Client> for FSMData<S>
+where
+    Client: Send + Sync + 'static,
+    S: Storage + Send + Sync + 'static,
+{
+    #[instrument(skip(self, request, next))]
+    async fn call(
+        &self,
+        request: HandlerRequest<Client>,
+        next: Next<Client>,
+    ) -> Result<HandlerResponse<Client>, EventErrorKind> {
+        if !self.write_back {
+            return next(request).await;
+        }
+
+        let context = request.context.clone();
+        let response = next(request).await?;
+
+        let data = context
+            .get("fsm_data")
+            .and_then(|data| data.downcast_ref::<Data>().cloned());
+
+        if let (Some(data), Some(fsm_context)) = (data, fsm_context::<S>(&context)) {
+            if let Some(values) = data.changed_values() {
+                fsm_context
+                    .set_data(
+                        values
+                            .into_iter()
+                            .map(|(key, value)| (String::from(key), value))
+                            .collect::<HashMap<_, _>>(),
+                    )
+                    .await
+                    .map_err(|err| MiddlewareError::new(err.into()))?;
+            }
+        }
+
+        Ok(response)
+    }
+}
+
+#[cfg(all(test, feature = "memory-storage"))]
+mod tests {
+    use super::*;
+    use crate::{
+        event::telegram::HandlerResult,
+        fsm::MemoryStorage,
+        middlewares::outer::FSMContext,
+        test::{self, MockBot},
+        Router,
+    };
+
+    fn router(storage: MemoryStorage, fsm_data: FSMData<MemoryStorage>) -> Router<MockBot> {
+        let mut router = Router::new("main");
+        router
+            .message
+            .outer_middlewares
+            .register(FSMContext::new(storage));
+        router.message.outer_middlewares.register(fsm_data.clone());
+        router.message.inner_middlewares.register(fsm_data);
+        router
+    }
+
+    async fn handler(data: Data) -> HandlerResult {
+        assert_eq!(data.state(), Some("state"));
+        assert_eq!(data.get_value::<String>("name").as_deref(), Some("Bob"));
+
+        data.set_value("name", "Alice").unwrap();
+
+        Ok(EventReturn::Finish)
+    }
+
+    #[tokio::test]
+    async fn test_fsm_data() {
+        let storage = MemoryStorage::new();
+        let mock = MockBot::new();
+
+        // Fill the storage by the key of the user that sends the message
+        let mut fill = Router::new("fill");
+        fill.message
+            .outer_middlewares
+            .register(FSMContext::new(storage.clone()));
+        fill.message
+            .register(|fsm: Context<MemoryStorage>| async move {
+                fsm.set_state("state").await?;
+                fsm.set_value("name", "Bob").await?;
+
+                Ok(EventReturn::Finish)
+            });
+        mock.propagate(fill, test::message("fill")).await.unwrap();
+
+        // Changes aren't saved in read-only mode, so the handler sees the same data next time
+        for fsm_data in [FSMData::new(), FSMData::new().write_back(true)] {
+            let mut router = router(storage.clone(), fsm_data);
+            router.message.register(handler);
+            mock.propagate(router, test::message("text")).await.unwrap();
+        }
+
+        let mut check = Router::new("check");
+        check
+            .message
+            .outer_middlewares
+            .register(FSMContext::new(storage));
+        check
+            .message
+            .register(|fsm: Context<MemoryStorage>| async move {
+                assert_eq!(
+                    fsm.get_value::<_, String>("name").await?.as_deref(),
+                    Some("Alice")
+                );
+
+                Ok(EventReturn::Finish)
+            });
+        mock.propagate(check, test::message("check")).await.unwrap();
+    }
+}
@@ -0,0 +1,246 @@
+use super::{Middleware, MiddlewareResponse};
+
+use crate::{
+    client::Reqwest,
+    errors::EventErrorKind,
+    event::EventReturn,
+    i18n::{Backend, LocaleResolver, Translator},
+    router::Request,
+};
+
+use async_trait::async_trait;
+use std::{
+    fmt::{self, Debug, Formatter},
+    sync::Arc,
+};
+use tracing::instrument;
+
+/// Middleware for adding [`Translator`] with the resolved locale of the user to context.
+///
+/// Locale is resolved in the following order:
+/// 1. Locale resolvers in the order of their registration
+/// 2. `language_code` of the user from the update
+/// 3. Default locale
+///
+/// If the backend doesn't support the locale, then the language part of the locale is checked (`en` for `en-US`),
+/// and if it isn't supported too, then the next candidate is used.
+/// # Notes
+/// If you use [`crate::i18n::FSMLocale`] resolver, then `FSMContext` middleware should be registered before this middleware
+pub struct I18n<Client = Reqwest> {
+    backend: Arc<dyn Backend>,
+    resolvers: Vec<Box<dyn LocaleResolver<Client>>>,
+    default_locale: Box<str>,
+}
+
+impl<Client> I18n<Client> {
+    /// Creates a new [`I18n`] middleware
+    /// # Arguments
+    /// * `backend` - Backend to translate messages
+    /// * `default_locale` - Locale which is used if the locale of the user can't be resolved or isn't supported by the backend
+    #[must_use]
+    pub fn new(backend: impl Backend + 'static, default_locale: impl Into<Box<str>>) -> Self {
+        Self {
+            backend: Arc::new(backend),
+            resolvers: vec![],
+            default_locale: default_locale.into(),
+        }
+    }
+
+    /// Adds a locale resolver, which is called before getting the locale from the update
+    #[must_use]
+    pub fn resolver(mut self, val: impl LocaleResolver<Client> + 'static) -> Self {
+        self.resolvers.push(Box::new(val));
+        self
+    }
+}
+
+impl<Client> I18n<Client> {
+    /// Gets the locale supported by the backend for the candidate locale
+    #[must_use]
+    fn supported_locale<'a>(&self, locale: &'a str) -> Option<&'a str> {
+        if self.backend.has_locale(locale) {
+            return Some(locale);
+        }
+
+        locale
+            .split_once(['-', '_'])
+            .map(|(language, _)| language)
+            .filter(|language| self.backend.has_locale(language))
+    }
+}
+
+impl<Client> Debug for I18n<Client> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("I18n")
+            .field("resolvers", &self.resolvers.len())
+            .field("default_locale", &self.default_locale)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl<Client> Middleware<Client> for I18n<Client>
+where
+    Client: Send + Sync + 'static,
+{
+    #[instrument(skip(self, request))]
+    async fn call(
+        &self,
+        request: Request<Client>,
+    ) -> Result<MiddlewareResponse<Client>, EventErrorKind> {
+        let mut locale = None;
+
+        for resolver in &self.resolvers {
+            if let Some(candidate) = resolver
+                .resolve(&request.bot, &request.update, &request.context)
+                .await
+            {
+                if let Some(supported_locale) = self.supported_locale(&candidate) {
+                    locale = Some(supported_locale.into());
+                    break;
+                }
+            }
+        }
+
+        if locale.is_none() {
+            locale = request
+                .update
+                .from()
+                .and_then(|user| user.language_code.as_deref())
+                .and_then(|language_code| self.supported_locale(language_code))
+                .map(Into::into);
+        }
+
+        let translator = Translator::new(
+            Arc::clone(&self.backend),
+            locale.unwrap_or_else(|| self.default_locale.clone()),
+        );
+
+        request
+            .context
+            .insert("i18n_translator", Box::new(translator));
+
+        Ok((request, EventReturn::default()))
+    }
+}
+
+#[cfg(all(test, feature = "fluent"))]
+mod tests {
+    use super::*;
+    use crate::{
+        client::Bot,
+        context::Context,
+        enums::UpdateType,
+        event::{bases::PropagateEventResult, ToServiceProvider as _},
+        i18n::{Args, Fluent},
+        router::{PropagateEvent as _, Router},
+        types::{Message, MessageText, Update, UpdateKind, User},
+    };
+
+    fn backend() -> Fluent {
+        Fluent::new()
+            .resource("en", "hello = Hello, { $name }!")
+            .unwrap()
+            .resource("ru", "hello = Привет, { $name }!")
+            .unwrap()
+    }
+
+    fn update(language_code: Option<&str>) -> Update {
+        Update {
+            kind: UpdateKind::Message(Message::Text(Box::new(MessageText {
+                from: Some(User {
+                    language_code: language_code.map(Into::into),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }))),
+            ..Default::default()
+        }
+    }
+
+    async fn propagate(i18n: I18n, update: Update, expected: &'static str) {
+        let mut router = Router::new("main");
+        router.update.outer_middlewares.register(i18n);
+        router
+            .message
+            .register(move |translator: Translator| async move {
+                let args = Args::new().arg("name", "Alice");
+
+                assert_eq!(translator.t("hello", &args), expected);
+                assert_eq!(translator.t("unknown", None), "unknown");
+
+                Ok(EventReturn::default())
+            });
+
+        let router_service = router.to_service_provider_default().unwrap();
+
+        let request = Request::new(
+            Arc::new(Bot::<Reqwest>::default()),
+            Arc::new(update),
+            Arc::new(Context::new()),
+        );
+        let response = router_service
+            .propagate_event(UpdateType::Message, request)
+            .await
+            .unwrap();
+
+        // Handler should be called, otherwise its assertions are skipped
+        match response.propagate_result {
+            PropagateEventResult::Handled(_) => {}
+            _ => panic!("Unexpected result"),
+        }
+    }
+
+    #[test]
+    fn test_supported_locale() {
+        let i18n = I18n::<Reqwest>::new(backend(), "en");
+
+        assert_eq!(i18n.supported_locale("en"), Some("en"));
+        assert_eq!(i18n.supported_locale("ru"), Some("ru"));
+        assert_eq!(i18n.supported_locale("en-US"), Some("en"));
+        assert_eq!(i18n.supported_locale("ru_RU"), Some("ru"));
+        assert_eq!(i18n.supported_locale("de"), None);
+        assert_eq!(i18n.supported_locale("de-DE"), None);
+    }
+
+    #[tokio::test]
+    async fn test_user_locale() {
+        propagate(
+            I18n::new(backend(), "en"),
+            update(Some("ru")),
+            "Привет, Alice!",
+        )
+        .await;
+        propagate(
+            I18n::new(backend(), "en"),
+            update(Some("en-GB")),
+            "Hello, Alice!",
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_default_locale() {
+        propagate(
+            I18n::new(backend(), "en"),
+            update(Some("de")),
+            "Hello, Alice!",
+        )
+        .await;
+        propagate(I18n::new(backend(), "ru"), update(None), "Привет, Alice!").await;
+    }
+
+    #[tokio::test]
+    async fn test_resolver_locale() {
+        let i18n = I18n::new(backend(), "en")
+            .resolver(|_: &Bot, _: &Update, _: &Context| async { None })
+            .resolver(|_: &Bot, _: &Update, _: &Context| async { Some("ru".into()) });
+
+        propagate(i18n, update(Some("en")), "Привет, Alice!").await;
+
+        let i18n = I18n::new(backend(), "en")
+            .resolver(|_: &Bot, _: &Update, _: &Context| async { Some("de".into()) });
+
+        propagate(i18n, update(Some("ru")), "Привет, Alice!").await;
+    }
+}
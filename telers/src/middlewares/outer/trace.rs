@@ -0,0 +1,46 @@
+//! This module contains [`Trace`] middleware, which enables recording of [`PropagationTrace`] for each update.
+//!
+//! Register the middleware to the update observer of the main router,
+//! so the trace contains the path of the update through all routers:
+//! ```rust
+//! use telers::{client::Reqwest, middlewares::outer::Trace, Router};
+//!
+//! let mut router = Router::<Reqwest>::new("main");
+//! router.update.outer_middlewares.register(Trace);
+//! ```
+
+use super::{Middleware, MiddlewareResponse};
+
+use crate::{
+    errors::EventErrorKind,
+    event::{telegram::trace::PropagationTrace, EventReturn},
+    router::Request,
+};
+
+use async_trait::async_trait;
+
+/// Middleware, which enables recording of [`PropagationTrace`] in the context by [`crate::event::telegram::trace::TRACE_KEY`] key
+#[derive(Debug, Default, Clone)]
+pub struct Trace;
+
+impl Trace {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+#[async_trait]
+impl<Client> Middleware<Client> for Trace
+where
+    Client: Send + Sync + 'static,
+{
+    async fn call(
+        &self,
+        request: Request<Client>,
+    ) -> Result<MiddlewareResponse<Client>, EventErrorKind> {
+        let _ = PropagationTrace::enable(&request.context);
+
+        Ok((request, EventReturn::default()))
+    }
+}
@@ -94,26 +94,33 @@
 //! [`Router::include_router`]: Router#method.include_router
 
 use crate::{
-    client::Reqwest,
+    client::{Reqwest, Session},
     enums::{SimpleObserverName, TelegramObserverName, UpdateType},
-    errors::EventErrorKind,
+    errors::{EventErrorKind, MiddlewareError, RouterInitError},
     event::{
         bases::{EventReturn, PropagateEventResult},
+        bot::{Observer as BotObserver, Service as BotObserverService},
         service::{ServiceProvider, ToServiceProvider},
         simple::{
             observer::Service as SimpleObserverService, HandlerResult as SimpleHandlerResult,
             Observer as SimpleObserver,
         },
         telegram::{
+            handler::HandlerObject,
             observer::{Request as TelegramObserverRequest, Service as TelegramObserverService},
+            trace::{PropagationStep, PropagationTrace},
+            Handler, HandlerId, HandlerResult as TelegramHandlerResult,
             Observer as TelegramObserver,
         },
     },
+    extractors::FromEventAndContext,
+    filters::Filter,
+    methods::TelegramMethod,
     middlewares::{
         inner::Logging as LoggingMiddleware, outer::UserContext as UserContextMiddleware,
         InnerMiddleware, OuterMiddleware,
     },
-    types::Update,
+    types::{BotCommand, Update},
     Bot, Context,
 };
 
@@ -141,6 +148,42 @@ impl<Client> Request<Client> {
             context,
         }
     }
+
+    /// Get mutable reference to the update, the update is cloned if it's shared with other requests.
+    /// # Notes
+    /// The changed update is seen by the next middlewares, filters and handlers, if the outer middleware returns [`EventReturn::Finish`].
+    /// Changes made in outer middlewares of the `update` observer are seen by other telegram observers of the router and its sub routers.
+    /// The update type is resolved before propagation, so changing kind of the update doesn't change the observer, which handles it.
+    #[must_use]
+    pub fn update_mut(&mut self) -> &mut Update {
+        Arc::make_mut(&mut self.update)
+    }
+
+    /// Replace the update of the request.
+    /// See [`Request::update_mut`] for more details about visibility of the changes.
+    pub fn replace_update(&mut self, update: impl Into<Arc<Update>>) {
+        self.update = update.into();
+    }
+}
+
+impl<Client: Session> Request<Client> {
+    /// Send the method by the bot of the request and cancel the event processing.
+    /// This method is useful for outer middlewares that reject the update with a message for the user,
+    /// for example, to notify banned users.
+    /// # Errors
+    /// If the request to the Telegram Bot API fails
+    pub async fn send_and_cancel<T>(self, method: T) -> Result<(Self, EventReturn), EventErrorKind>
+    where
+        T: TelegramMethod + Send + Sync,
+        T::Method: Send + Sync,
+    {
+        self.bot
+            .send_request(&method, self.bot.request_timeout_secs())
+            .await
+            .map_err(MiddlewareError::new)?;
+
+        Ok((self, EventReturn::Cancel))
+    }
 }
 
 impl<Client> Clone for Request<Client> {
@@ -240,6 +283,26 @@ pub trait PropagateEvent<Client>: Send + Sync {
     /// # Errors
     /// If any shutdown observer returns error
     async fn emit_shutdown(&self) -> SimpleHandlerResult;
+
+    /// Emit bot startup events for the bot
+    /// # Errors
+    /// If any bot startup observer returns error
+    async fn emit_bot_startup(&self, bot: &Bot<Client>) -> SimpleHandlerResult
+    where
+        Client: Clone + Send + Sync + 'static;
+
+    /// Emit bot shutdown events for the bot
+    /// # Errors
+    /// If any bot shutdown observer returns error
+    async fn emit_bot_shutdown(&self, bot: &Bot<Client>) -> SimpleHandlerResult
+    where
+        Client: Clone + Send + Sync + 'static;
+
+    /// Resolve update types that have handlers
+    /// # Notes
+    /// This method is used by the dispatcher to check that all update types with handlers are allowed to be received,
+    /// so handlers of the `update` observer aren't taken into account
+    fn resolve_used_update_types(&self) -> HashSet<UpdateType>;
 }
 
 #[async_trait]
@@ -275,6 +338,24 @@ where
     async fn emit_shutdown(&self) -> SimpleHandlerResult {
         P::emit_shutdown(self).await
     }
+
+    async fn emit_bot_startup(&self, bot: &Bot<Client>) -> SimpleHandlerResult
+    where
+        Client: Clone + Send + Sync + 'static,
+    {
+        P::emit_bot_startup(self, bot).await
+    }
+
+    async fn emit_bot_shutdown(&self, bot: &Bot<Client>) -> SimpleHandlerResult
+    where
+        Client: Clone + Send + Sync + 'static,
+    {
+        P::emit_bot_shutdown(self, bot).await
+    }
+
+    fn resolve_used_update_types(&self) -> HashSet<UpdateType> {
+        P::resolve_used_update_types(self)
+    }
 }
 
 /// Router combines all event observers.
@@ -336,6 +417,8 @@ where
 pub struct Router<Client> {
     router_name: &'static str,
     sub_routers: Vec<Router<Client>>,
+    /// Update types of the active observers. If `None`, then all observers are active
+    update_types: Option<HashSet<UpdateType>>,
 
     pub message: TelegramObserver<Client>,
     pub edited_message: TelegramObserver<Client>,
@@ -363,10 +446,17 @@ pub struct Router<Client> {
     /// It's called for router and its sub routers and before other telegram observers.
     /// This observer is useful for register important middlewares (often libraries) like `FSMContext` and `UserContext`,
     /// that set up context for other.
+    /// Its outer middlewares also can rewrite the update by [`Request::update_mut`] or [`Request::replace_update`] before other telegram observers see it.
     pub update: TelegramObserver<Client>,
 
     pub startup: SimpleObserver,
     pub shutdown: SimpleObserver,
+    /// This observer is called on startup for each bot after [`Router::startup`] observer.
+    /// Its handlers receive the bot, so they can make requests to the Telegram Bot API.
+    pub bot_startup: BotObserver<Client>,
+    /// This observer is called on shutdown for each bot after [`Router::shutdown`] observer.
+    /// Its handlers receive the bot, so they can make requests to the Telegram Bot API.
+    pub bot_shutdown: BotObserver<Client>,
 }
 
 impl<Client> Router<Client>
@@ -381,6 +471,7 @@ where
         Self {
             router_name,
             sub_routers: vec![],
+            update_types: None,
             message: TelegramObserver::new(TelegramObserverName::Message),
             edited_message: TelegramObserver::new(TelegramObserverName::EditedMessage),
             channel_post: TelegramObserver::new(TelegramObserverName::ChannelPost),
@@ -406,6 +497,30 @@ where
             update: TelegramObserver::new(TelegramObserverName::Update),
             startup: SimpleObserver::new(SimpleObserverName::Startup),
             shutdown: SimpleObserver::new(SimpleObserverName::Shutdown),
+            bot_startup: BotObserver::new(SimpleObserverName::Startup),
+            bot_shutdown: BotObserver::new(SimpleObserverName::Shutdown),
+        }
+    }
+
+    /// Create a router with only observers of the given update types active
+    /// # Arguments
+    /// * `router_name` - Name of the router. It can be used for logging and debugging and code clarity.
+    /// * `update_types` - Update types of the active observers
+    /// # Notes
+    /// The [`Router::update`] observer is always active.
+    /// Middlewares registered to all observers and middlewares from the dispatcher config aren't registered to the inactive observers
+    /// and their update types aren't used in [`Router::resolve_used_update_types`].
+    ///
+    /// Handlers mustn't be registered to the inactive observers,
+    /// otherwise converting the router to the service returns [`RouterInitError::InactiveObserver`].
+    #[must_use]
+    pub fn with_update_types(
+        router_name: &'static str,
+        update_types: impl IntoIterator<Item = UpdateType>,
+    ) -> Self {
+        Self {
+            update_types: Some(update_types.into_iter().collect()),
+            ..Self::new(router_name)
         }
     }
 
@@ -429,6 +544,97 @@ where
     pub fn include(&mut self, router: impl Into<Router<Client>>) -> &mut Self {
         self.include_router(router)
     }
+
+    /// Register the handler to the observers of the update types, for example, to handle both new and edited messages.
+    /// Update types are deduplicated.
+    /// # Returns
+    /// Handlers registered to the observers, which can be used to attach filters to all of them or to get their identifiers
+    pub fn register_for<H, Args>(
+        &mut self,
+        update_types: impl IntoIterator<Item = UpdateType>,
+        handler: H,
+    ) -> RegisteredHandlers<'_, Client>
+    where
+        H: Handler<Args> + Clone + Send + Sync + 'static,
+        H::Future: Send,
+        H::Output: Into<TelegramHandlerResult>,
+        Args: FromEventAndContext<Client> + Send,
+        Args::Error: Send,
+    {
+        let mut ids: Vec<(UpdateType, HandlerId)> = vec![];
+
+        for update_type in update_types {
+            if ids.iter().any(|(registered, _)| *registered == update_type) {
+                continue;
+            }
+
+            let id = self
+                .telegram_observer_by_update_type_mut(update_type)
+                .register(handler.clone())
+                .id();
+            ids.push((update_type, id));
+        }
+
+        RegisteredHandlers { router: self, ids }
+    }
+
+    /// Register the inner middleware to all telegram event observers, including [`Router::update`] observer
+    /// # Notes
+    /// The middleware is shared between the observers, so it's created once
+    pub fn register_inner_middleware_all<T>(&mut self, middleware: T) -> &mut Self
+    where
+        T: InnerMiddleware<Client> + 'static,
+    {
+        let middleware = Arc::new(middleware);
+
+        for observer in self.active_telegram_observers_mut() {
+            observer.inner_middlewares.register(Arc::clone(&middleware));
+        }
+        self
+    }
+
+    /// Register the inner middleware to all telegram event observers, except [`Router::update`] observer
+    pub fn register_inner_middleware_all_except_update<T>(&mut self, middleware: T) -> &mut Self
+    where
+        T: InnerMiddleware<Client> + 'static,
+    {
+        let middleware = Arc::new(middleware);
+
+        for observer in self.telegram_observers_except_update_mut() {
+            observer.inner_middlewares.register(Arc::clone(&middleware));
+        }
+        self
+    }
+
+    /// Register the outer middleware to all telegram event observers, including [`Router::update`] observer
+    /// # Notes
+    /// Outer middlewares of the update observer are called for every update before the outer middlewares of the update type observer,
+    /// so the middleware is called twice for each update.
+    /// Use [`Router::register_outer_middleware_all_except_update`] if it's unwanted.
+    pub fn register_outer_middleware_all<T>(&mut self, middleware: T) -> &mut Self
+    where
+        T: OuterMiddleware<Client> + 'static,
+    {
+        let middleware = Arc::new(middleware);
+
+        for observer in self.active_telegram_observers_mut() {
+            observer.outer_middlewares.register(Arc::clone(&middleware));
+        }
+        self
+    }
+
+    /// Register the outer middleware to all telegram event observers, except [`Router::update`] observer
+    pub fn register_outer_middleware_all_except_update<T>(&mut self, middleware: T) -> &mut Self
+    where
+        T: OuterMiddleware<Client> + 'static,
+    {
+        let middleware = Arc::new(middleware);
+
+        for observer in self.telegram_observers_except_update_mut() {
+            observer.outer_middlewares.register(Arc::clone(&middleware));
+        }
+        self
+    }
 }
 
 impl<Client> Router<Client> {
@@ -498,6 +704,96 @@ impl<Client> Router<Client> {
         observers
     }
 
+    fn active_telegram_observers_mut(
+        &mut self,
+    ) -> impl Iterator<Item = &mut TelegramObserver<Client>> {
+        let update_types = self.update_types.clone();
+
+        self.telegram_observers_mut()
+            .into_iter()
+            .filter(move |observer| is_observer_active(update_types.as_ref(), observer.event_name))
+    }
+
+    fn telegram_observers_except_update_mut(
+        &mut self,
+    ) -> impl Iterator<Item = &mut TelegramObserver<Client>> {
+        self.active_telegram_observers_mut()
+            .filter(|observer| observer.event_name != TelegramObserverName::Update)
+    }
+
+    /// Check if the observer with the given name is active.
+    /// See [`Router::with_update_types`] for more details.
+    #[must_use]
+    pub fn is_observer_active(&self, event_name: TelegramObserverName) -> bool {
+        is_observer_active(self.update_types.as_ref(), event_name)
+    }
+
+    /// Get telegram event observer by update type
+    #[must_use]
+    pub const fn telegram_observer_by_update_type(
+        &self,
+        update_type: UpdateType,
+    ) -> &TelegramObserver<Client> {
+        match update_type {
+            UpdateType::Message => &self.message,
+            UpdateType::EditedMessage => &self.edited_message,
+            UpdateType::ChannelPost => &self.channel_post,
+            UpdateType::EditedChannelPost => &self.edited_channel_post,
+            UpdateType::BusinessConnection => &self.business_connection,
+            UpdateType::BusinessMessage => &self.business_message,
+            UpdateType::EditedBusinessMessage => &self.edited_business_message,
+            UpdateType::DeletedBusinessMessages => &self.deleted_business_messages,
+            UpdateType::MessageReaction => &self.message_reaction,
+            UpdateType::MessageReactionCount => &self.message_reaction_count,
+            UpdateType::InlineQuery => &self.inline_query,
+            UpdateType::ChosenInlineResult => &self.chosen_inline_result,
+            UpdateType::CallbackQuery => &self.callback_query,
+            UpdateType::ShippingQuery => &self.shipping_query,
+            UpdateType::PreCheckoutQuery => &self.pre_checkout_query,
+            UpdateType::Poll => &self.poll,
+            UpdateType::PollAnswer => &self.poll_answer,
+            UpdateType::MyChatMember => &self.my_chat_member,
+            UpdateType::ChatMember => &self.chat_member,
+            UpdateType::ChatJoinRequest => &self.chat_join_request,
+            UpdateType::ChatBoost => &self.chat_boost,
+            UpdateType::RemovedChatBoost => &self.removed_chat_boost,
+        }
+    }
+
+    /// Get telegram event observer by update type as mutable reference
+    /// # Notes
+    /// This method is useful for registering handlers and middlewares to the observers of dynamically chosen update types
+    #[must_use]
+    pub fn telegram_observer_by_update_type_mut(
+        &mut self,
+        update_type: UpdateType,
+    ) -> &mut TelegramObserver<Client> {
+        match update_type {
+            UpdateType::Message => &mut self.message,
+            UpdateType::EditedMessage => &mut self.edited_message,
+            UpdateType::ChannelPost => &mut self.channel_post,
+            UpdateType::EditedChannelPost => &mut self.edited_channel_post,
+            UpdateType::BusinessConnection => &mut self.business_connection,
+            UpdateType::BusinessMessage => &mut self.business_message,
+            UpdateType::EditedBusinessMessage => &mut self.edited_business_message,
+            UpdateType::DeletedBusinessMessages => &mut self.deleted_business_messages,
+            UpdateType::MessageReaction => &mut self.message_reaction,
+            UpdateType::MessageReactionCount => &mut self.message_reaction_count,
+            UpdateType::InlineQuery => &mut self.inline_query,
+            UpdateType::ChosenInlineResult => &mut self.chosen_inline_result,
+            UpdateType::CallbackQuery => &mut self.callback_query,
+            UpdateType::ShippingQuery => &mut self.shipping_query,
+            UpdateType::PreCheckoutQuery => &mut self.pre_checkout_query,
+            UpdateType::Poll => &mut self.poll,
+            UpdateType::PollAnswer => &mut self.poll_answer,
+            UpdateType::MyChatMember => &mut self.my_chat_member,
+            UpdateType::ChatMember => &mut self.chat_member,
+            UpdateType::ChatJoinRequest => &mut self.chat_join_request,
+            UpdateType::ChatBoost => &mut self.chat_boost,
+            UpdateType::RemovedChatBoost => &mut self.removed_chat_boost,
+        }
+    }
+
     /// Get all simple event observers
     #[must_use]
     pub const fn event_observers(&self) -> [&SimpleObserver; 2] {
@@ -508,18 +804,51 @@ impl<Client> Router<Client> {
     /// If observer has no handlers, then it will be skipped.
     /// If observer update type is in the skip list, then it will be skipped.
     /// This method is useful for getting updates only for registered update types.
-    /// # Panics
-    /// If can't convert observer event name to [`UpdateType`]
+    /// # Notes
+    /// The `update` observer handles updates of all types, so if only it has handlers,
+    /// then all update types are returned (except update types in the skip list),
+    /// otherwise the `update` observer is ignored and receives only update types of other observers.
     #[must_use]
     pub fn resolve_used_update_types_with_skip(
         &self,
         skip_update_types: impl IntoIterator<Item = UpdateType>,
     ) -> HashSet<UpdateType> {
         let skip_update_types = skip_update_types.into_iter().collect::<HashSet<_>>();
+        let used_update_types = self.resolve_observers_update_types(&skip_update_types);
+
+        if used_update_types.is_empty() && self.has_update_handlers() {
+            return UpdateType::all()
+                .into_iter()
+                .filter(|update_type| !skip_update_types.contains(update_type))
+                .filter(|update_type| {
+                    self.update_types
+                        .as_ref()
+                        .map_or(true, |update_types| update_types.contains(update_type))
+                })
+                .collect();
+        }
+
+        used_update_types
+    }
+
+    /// Resolve used update types from the current router and its sub routers.
+    /// If observer has no handlers, then it will be skipped.
+    /// This method is useful for getting updates only for registered update types.
+    /// # Notes
+    /// Check [`Router::resolve_used_update_types_with_skip`] for how the `update` observer is handled
+    #[must_use]
+    pub fn resolve_used_update_types(&self) -> HashSet<UpdateType> {
+        self.resolve_used_update_types_with_skip([])
+    }
+
+    fn resolve_observers_update_types(
+        &self,
+        skip_update_types: &HashSet<UpdateType>,
+    ) -> HashSet<UpdateType> {
         let mut used_update_types = HashSet::new();
 
         for observer in self.telegram_observers() {
-            if observer.handlers().is_empty() {
+            if observer.handlers().is_empty() || !self.is_observer_active(observer.event_name) {
                 continue;
             }
 
@@ -536,21 +865,57 @@ impl<Client> Router<Client> {
         }
 
         for router in &self.sub_routers {
-            used_update_types
-                .extend(router.resolve_used_update_types_with_skip(skip_update_types.clone()));
+            used_update_types.extend(router.resolve_observers_update_types(skip_update_types));
         }
 
         used_update_types
     }
 
-    /// Resolve used update types from the current router and its sub routers.
-    /// If observer has no handlers, then it will be skipped.
-    /// This method is useful for getting updates only for registered update types.
-    /// # Panics
-    /// If can't convert observer event name to [`UpdateType`]
+    fn has_update_handlers(&self) -> bool {
+        !self.update.handlers().is_empty() || self.sub_routers.iter().any(Self::has_update_handlers)
+    }
+
+    /// Collect bot commands from filters of the handlers of the current router and its sub routers
+    /// in the order of registration.
+    /// If the same command is handled by several handlers, then the first one is used.
+    /// This method is useful for setting the list of the bot commands by [`crate::methods::SetMyCommands`]
+    /// to keep it in sync with the registered handlers.
+    /// # Notes
+    /// Commands are collected by [`crate::filters::Filter::bot_commands`] method,
+    /// see [`crate::filters::Command`] filter for which commands are collected
     #[must_use]
-    pub fn resolve_used_update_types(&self) -> HashSet<UpdateType> {
-        self.resolve_used_update_types_with_skip([])
+    pub fn collect_commands(&self) -> Vec<BotCommand> {
+        let mut commands = vec![];
+        self.collect_commands_to(&mut commands);
+        commands
+    }
+
+    fn collect_commands_to(&self, commands: &mut Vec<BotCommand>) {
+        for observer in self.telegram_observers() {
+            for handler in observer.handlers() {
+                for filter in &handler.filters {
+                    for command in filter.bot_commands() {
+                        if commands.iter().all(|val| val.command != command.command) {
+                            commands.push(command);
+                        }
+                    }
+                }
+            }
+        }
+
+        for router in &self.sub_routers {
+            router.collect_commands_to(commands);
+        }
+    }
+}
+
+fn is_observer_active(
+    update_types: Option<&HashSet<UpdateType>>,
+    event_name: TelegramObserverName,
+) -> bool {
+    match (update_types, Option::<UpdateType>::from(event_name)) {
+        (Some(update_types), Some(update_type)) => update_types.contains(&update_type),
+        _ => true,
     }
 }
 
@@ -586,17 +951,33 @@ where
 {
     type Config = Config<Client>;
     type ServiceProvider = Service<Client>;
-    type InitError = ();
+    type InitError = RouterInitError;
 
+    /// # Errors
+    /// - If handlers are registered to the inactive observer, see [`Router::with_update_types`]
+    /// - If the observer can't be converted to the service
     #[allow(clippy::too_many_lines)]
     fn to_service_provider(
         mut self,
         mut config: Self::Config,
     ) -> Result<Self::ServiceProvider, Self::InitError> {
+        if let Some(observer) = self.telegram_observers().into_iter().find(|observer| {
+            !observer.handlers().is_empty() && !self.is_observer_active(observer.event_name)
+        }) {
+            return Err(RouterInitError::InactiveObserver {
+                router_name: self.router_name,
+                observer: observer.event_name,
+            });
+        }
+
         macro_rules! register_inner_middlewares_to_sub_routers {
             ($($observers:ident),+) => {
                 $(
                     self.sub_routers.iter_mut().for_each(|sub_router| {
+                        if !sub_router.is_observer_active(sub_router.$observers.event_name) {
+                            return;
+                        }
+
                         let mut index = 0;
                         for middleware in &self.$observers.inner_middlewares.middlewares {
                             sub_router.$observers.inner_middlewares.register_at_position(index, Arc::clone(middleware));
@@ -636,18 +1017,18 @@ where
         macro_rules! register_middlewares_from_config {
             ($($observer:ident),+) => {
                 $(
-                    let mut index = 0;
-                    for middleware in config.outer_middlewares.$observer.iter() {
-                        self.$observer.outer_middlewares.register_at_position(index, Arc::clone(middleware));
-                        index += 1;
-                    }
-                )+
+                    if self.is_observer_active(self.$observer.event_name) {
+                        let mut index = 0;
+                        for middleware in config.outer_middlewares.$observer.iter() {
+                            self.$observer.outer_middlewares.register_at_position(index, Arc::clone(middleware));
+                            index += 1;
+                        }
 
-                $(
-                    let mut index = 0;
-                    for middleware in config.inner_middlewares.$observer.iter() {
-                        self.$observer.inner_middlewares.register_at_position(index, Arc::clone(middleware));
-                        index += 1;
+                        let mut index = 0;
+                        for middleware in config.inner_middlewares.$observer.iter() {
+                            self.$observer.inner_middlewares.register_at_position(index, Arc::clone(middleware));
+                            index += 1;
+                        }
                     }
                 )+
             };
@@ -716,6 +1097,8 @@ where
             update: self.update.to_service_provider_default()?,
             startup: self.startup.to_service_provider_default()?,
             shutdown: self.shutdown.to_service_provider_default()?,
+            bot_startup: self.bot_startup.to_service_provider_default()?,
+            bot_shutdown: self.bot_shutdown.to_service_provider_default()?,
         })
     }
 }
@@ -751,6 +1134,8 @@ pub struct Service<Client> {
 
     startup: SimpleObserverService,
     shutdown: SimpleObserverService,
+    bot_startup: BotObserverService<Client>,
+    bot_shutdown: BotObserverService<Client>,
 }
 
 impl<Client> ServiceProvider for Service<Client> {}
@@ -761,7 +1146,7 @@ impl<Client> PropagateEvent<Client> for Service<Client> {
     async fn propagate_event(
         &self,
         update_type: UpdateType,
-        request: Request<Client>,
+        mut request: Request<Client>,
     ) -> Result<Response<Client>, EventErrorKind>
     where
         Client: Send + Sync + 'static,
@@ -787,18 +1172,20 @@ impl<Client> PropagateEvent<Client> for Service<Client> {
                     propagate_result: PropagateEventResult::Rejected,
                 });
             }
-            // If update event unhandled by router, then continue propagation
+            // If update event unhandled by router, then continue propagation with the request,
+            // which could have been changed by the update outer middlewares
             Response {
-                request: _,
+                request: updated_request,
                 propagate_result: PropagateEventResult::Unhandled,
-            } => {}
+            } => {
+                request = updated_request;
+            }
         };
 
         event!(Level::TRACE, "Propagate event to router");
 
         let observer = self.telegram_observer_by_update_type(update_type);
 
-        let mut request = request;
         for middleware in observer.outer_middlewares() {
             let (updated_request, event_return) = middleware.call(request.clone()).await?;
 
@@ -819,6 +1206,12 @@ impl<Client> PropagateEvent<Client> for Service<Client> {
                 EventReturn::Cancel => {
                     event!(Level::TRACE, "Outer middleware returns cancel");
 
+                    if let Some(trace) = PropagationTrace::from_context(&request.context) {
+                        trace.push(PropagationStep::OuterMiddlewareCancel {
+                            observer: observer.event_name,
+                        });
+                    }
+
                     return Ok(Response {
                         request,
                         propagate_result: PropagateEventResult::Rejected,
@@ -919,6 +1312,15 @@ impl<Client> PropagateEvent<Client> for Service<Client> {
                 EventReturn::Cancel => {
                     event!(Level::TRACE, "Update outer middleware returns cancel");
 
+                    if let Some(trace) = PropagationTrace::from_context(&request.context) {
+                        trace.push(PropagationStep::Router {
+                            name: self.router_name,
+                        });
+                        trace.push(PropagationStep::OuterMiddlewareCancel {
+                            observer: TelegramObserverName::Update,
+                        });
+                    }
+
                     return Ok(Response {
                         request,
                         propagate_result: PropagateEventResult::Rejected,
@@ -927,6 +1329,12 @@ impl<Client> PropagateEvent<Client> for Service<Client> {
             }
         }
 
+        if let Some(trace) = PropagationTrace::from_context(&request.context) {
+            trace.push(PropagationStep::Router {
+                name: self.router_name,
+            });
+        }
+
         let observer_request = request.clone().into();
         let observer_response = self.update.trigger(observer_request).await?;
 
@@ -981,6 +1389,53 @@ impl<Client> PropagateEvent<Client> for Service<Client> {
         }
         Ok(())
     }
+
+    #[instrument(skip(self, bot), fields(router_name = self.router_name))]
+    async fn emit_bot_startup(&self, bot: &Bot<Client>) -> SimpleHandlerResult
+    where
+        Client: Clone + Send + Sync + 'static,
+    {
+        for startup in
+            once(&self.bot_startup).chain(self.sub_routers.iter().map(|router| &router.bot_startup))
+        {
+            startup.trigger(bot).await?;
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self, bot), fields(router_name = self.router_name))]
+    async fn emit_bot_shutdown(&self, bot: &Bot<Client>) -> SimpleHandlerResult
+    where
+        Client: Clone + Send + Sync + 'static,
+    {
+        for shutdown in once(&self.bot_shutdown)
+            .chain(self.sub_routers.iter().map(|router| &router.bot_shutdown))
+        {
+            shutdown.trigger(bot).await?;
+        }
+        Ok(())
+    }
+
+    fn resolve_used_update_types(&self) -> HashSet<UpdateType> {
+        let mut used_update_types = HashSet::new();
+
+        for observer in self.telegram_observers() {
+            if observer.handlers_len() == 0 {
+                continue;
+            }
+
+            // If can't convert observer event name to `UpdateType`, then skip it, because it's `TelegramObserverName::Update`
+            if let Some(update_type) = observer.event_name.into() {
+                used_update_types.insert(update_type);
+            }
+        }
+
+        for router in &*self.sub_routers {
+            used_update_types.extend(router.resolve_used_update_types());
+        }
+
+        used_update_types
+    }
 }
 
 impl<Client> Service<Client> {
@@ -1450,7 +1905,7 @@ where
 {
     #[must_use]
     fn default() -> Self {
-        let logging_middleware = Arc::new(LoggingMiddleware);
+        let logging_middleware = Arc::new(LoggingMiddleware::new());
 
         Self::builder()
             .message(logging_middleware.clone())
@@ -1739,6 +2194,61 @@ impl<Client> Default for InnerMiddlewaresConfigBuilder<Client> {
     }
 }
 
+/// Handlers registered with [`Router::register_for`] to the observers of several update types
+pub struct RegisteredHandlers<'a, Client> {
+    router: &'a mut Router<Client>,
+    ids: Vec<(UpdateType, HandlerId)>,
+}
+
+impl<Client> RegisteredHandlers<'_, Client>
+where
+    Client: Sync,
+{
+    /// Gets update types of the observers and identifiers of the handlers in them
+    #[must_use]
+    pub fn ids(&self) -> &[(UpdateType, HandlerId)] {
+        &self.ids
+    }
+
+    /// Register the filter to all handlers, the filter is shared between them
+    pub fn filter<T>(&mut self, val: T) -> &mut Self
+    where
+        T: Filter<Client> + 'static,
+    {
+        let filter = Arc::new(val);
+
+        self.for_each_handler(|handler| {
+            handler.filter(Arc::clone(&filter));
+        });
+        self
+    }
+
+    /// Register the cheap filter to all handlers, see [`HandlerObject::filter_fast`] for more details
+    pub fn filter_fast<T>(&mut self, val: T) -> &mut Self
+    where
+        T: Filter<Client> + 'static,
+    {
+        let filter = Arc::new(val);
+
+        self.for_each_handler(|handler| {
+            handler.filter_fast(Arc::clone(&filter));
+        });
+        self
+    }
+
+    fn for_each_handler(&mut self, mut f: impl FnMut(&mut HandlerObject<Client>)) {
+        for (update_type, id) in &self.ids {
+            if let Some(handler) = self
+                .router
+                .telegram_observer_by_update_type_mut(*update_type)
+                .handler_mut(*id)
+            {
+                f(handler);
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1746,6 +2256,7 @@ mod tests {
         client::Reqwest,
         event::{telegram::HandlerResult as TelegramHandlerResult, EventReturn},
         middlewares::inner::Next,
+        types::{Message, MessageText},
     };
 
     use tokio;
@@ -1903,6 +2414,120 @@ mod tests {
         assert_eq!(router.message.outer_middlewares.middlewares.len(), 1);
     }
 
+    #[test]
+    fn test_telegram_observer_by_update_type_mut() {
+        async fn telegram_handler() -> TelegramHandlerResult {
+            Ok(EventReturn::Finish)
+        }
+
+        let update_types = [
+            UpdateType::Message,
+            UpdateType::CallbackQuery,
+            UpdateType::ChatBoost,
+        ];
+
+        let mut router = Router::<Reqwest>::new("main");
+
+        for update_type in update_types {
+            let observer = router.telegram_observer_by_update_type_mut(update_type);
+
+            assert_eq!(observer.event_name, update_type);
+
+            observer.register(telegram_handler);
+            observer
+                .inner_middlewares
+                .register(|request, next: Next<_>| next(request));
+        }
+
+        router
+            .telegram_observers()
+            .into_iter()
+            .for_each(|observer| {
+                let expected = usize::from(
+                    update_types
+                        .iter()
+                        .any(|update_type| observer.event_name == *update_type),
+                );
+
+                assert_eq!(observer.handlers().len(), expected);
+                assert_eq!(observer.inner_middlewares.middlewares.len(), expected);
+            });
+
+        for update_type in update_types {
+            assert_eq!(
+                router
+                    .telegram_observer_by_update_type(update_type)
+                    .handlers()
+                    .len(),
+                1
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_register_for() {
+        use crate::{filters::Text, types::UpdateKind};
+
+        let mut router = Router::<Reqwest>::new("main");
+        let mut handlers = router.register_for(
+            [
+                UpdateType::Message,
+                UpdateType::EditedMessage,
+                UpdateType::Message,
+            ],
+            |message: MessageText| async move {
+                assert_eq!(&*message.text, "hi");
+
+                Ok(EventReturn::Finish)
+            },
+        );
+        handlers.filter(Text::one("hi"));
+
+        assert_eq!(handlers.ids().len(), 2);
+        assert_eq!(router.message.handlers().len(), 1);
+        assert_eq!(router.edited_message.handlers().len(), 1);
+
+        let router_service = router.to_service_provider_default().unwrap();
+
+        let request = |kind: fn(Message) -> UpdateKind, text: &str| {
+            Request::new(
+                Arc::new(Bot::default()),
+                Arc::new(Update {
+                    kind: kind(Message::Text(Box::new(MessageText {
+                        text: text.into(),
+                        ..Default::default()
+                    }))),
+                    ..Default::default()
+                }),
+                Arc::new(Context::new()),
+            )
+        };
+
+        for (update_type, kind) in [
+            (UpdateType::Message, UpdateKind::Message as fn(_) -> _),
+            (UpdateType::EditedMessage, UpdateKind::EditedMessage),
+        ] {
+            let response = router_service
+                .propagate_event(update_type, request(kind, "hi"))
+                .await
+                .unwrap();
+            assert!(matches!(
+                response.propagate_result,
+                PropagateEventResult::Handled(_)
+            ));
+
+            // The filter is registered to the handlers of all update types
+            let response = router_service
+                .propagate_event(update_type, request(kind, "bye"))
+                .await
+                .unwrap();
+            assert!(matches!(
+                response.propagate_result,
+                PropagateEventResult::Unhandled
+            ));
+        }
+    }
+
     #[tokio::test]
     async fn test_propagate_event() {
         let bot = Bot::<Reqwest>::default();
@@ -2090,6 +2715,72 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_propagate_event_with_observer_filter() {
+        async fn unreachable_handler() -> TelegramHandlerResult {
+            unreachable!("Observer filter returns `false`, so handlers shouldn't be called")
+        }
+
+        let bot = Bot::<Reqwest>::default();
+        let context = Context::new();
+        let update = Update::default();
+
+        let request = Request::new(Arc::new(bot), Arc::new(update), Arc::new(context));
+
+        let mut sub_router = Router::new("sub");
+        sub_router.message.register(unreachable_handler);
+
+        let mut router = Router::new("main");
+        router
+            .message
+            .filter(|_: &Bot<_>, _: &Update, _: &Context| async move { false });
+        router
+            .message
+            .register(unreachable_handler)
+            .filter(|_: &Bot<_>, _: &Update, _: &Context| async move { true });
+        router.message.register(unreachable_handler);
+        router.include(sub_router);
+
+        let router_service = router.to_service_provider_default().unwrap();
+        let response = router_service
+            .propagate_event(UpdateType::Message, request.clone())
+            .await
+            .unwrap();
+
+        // Observer filter is checked before handler filters and blocks handlers of the sub routers too
+        match response.propagate_result {
+            PropagateEventResult::Unhandled => {}
+            _ => panic!("Unexpected result"),
+        }
+
+        let mut router = Router::new("main");
+        router
+            .message
+            .filter(|_: &Bot<_>, _: &Update, _: &Context| async move { true });
+        router
+            .message
+            .register(unreachable_handler)
+            .filter(|_: &Bot<_>, _: &Update, _: &Context| async move { false });
+        router
+            .message
+            .register(|| async move { Ok(EventReturn::Finish) });
+
+        let router_service = router.to_service_provider_default().unwrap();
+        let response = router_service
+            .propagate_event(UpdateType::Message, request)
+            .await
+            .unwrap();
+
+        // Observer filter and handler filters are composed, so the first handler is skipped
+        match response.propagate_result {
+            PropagateEventResult::Handled(response) => match response.handler_result {
+                Ok(EventReturn::Finish) => {}
+                _ => panic!("Unexpected result"),
+            },
+            _ => panic!("Unexpected result"),
+        }
+    }
+
     #[test]
     fn test_resolve_used_update_types() {
         let mut router = Router::<Reqwest>::new("test");
@@ -2135,4 +2826,246 @@ mod tests {
         assert!(update_types.contains(&UpdateType::EditedMessage));
         assert!(update_types.contains(&UpdateType::ChannelPost));
     }
+
+    #[test]
+    fn test_resolve_used_update_types_with_update_observer() {
+        let mut router = Router::<Reqwest>::new("main");
+        let mut router2 = Router::<Reqwest>::new("sub");
+
+        router2
+            .update
+            .register(|| async { Ok(EventReturn::Finish) });
+        router.include(router2);
+
+        // Only `update` observer has handlers, so all update types are requested
+        assert_eq!(
+            router.resolve_used_update_types(),
+            HashSet::from(UpdateType::all())
+        );
+        assert_eq!(
+            router
+                .resolve_used_update_types_with_skip([UpdateType::ChatMember])
+                .len(),
+            UpdateType::all().len() - 1
+        );
+
+        router
+            .message
+            .register(|| async { Ok(EventReturn::Finish) });
+
+        // `update` observer receives only update types of other observers
+        assert_eq!(
+            router.resolve_used_update_types(),
+            HashSet::from([UpdateType::Message])
+        );
+    }
+
+    #[test]
+    fn test_collect_commands() {
+        use crate::enums::ContentType as ContentTypeEnum;
+        use crate::filters::{Command, ContentType, Filter as _};
+
+        async fn telegram_handler() -> TelegramHandlerResult {
+            Ok(EventReturn::Finish)
+        }
+
+        let mut router = Router::<Reqwest>::new("main");
+        router
+            .message
+            .register(telegram_handler)
+            .filter(Command::one("start").description("Start the bot"));
+        router
+            .message
+            .register(telegram_handler)
+            .filter(ContentType::one(ContentTypeEnum::Text));
+        // Commands without description aren't collected
+        router
+            .message
+            .register(telegram_handler)
+            .filter(Command::one("secret"));
+
+        let mut sub_router = Router::new("sub");
+        sub_router.message.register(telegram_handler).filter(
+            Command::one(BotCommand::new("help", "Show help"))
+                .or(Command::one("start").description("Duplicate")),
+        );
+
+        router.include(sub_router);
+
+        assert_eq!(
+            router.collect_commands(),
+            [
+                BotCommand::new("start", "Start the bot"),
+                BotCommand::new("help", "Show help"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_register_middleware_all() {
+        let mut router = Router::<Reqwest>::new("main");
+        router
+            .register_inner_middleware_all(LoggingMiddleware::new())
+            .register_outer_middleware_all(UserContextMiddleware);
+
+        for observer in router.telegram_observers() {
+            assert_eq!(observer.inner_middlewares.middlewares.len(), 1);
+            assert_eq!(observer.outer_middlewares.middlewares.len(), 1);
+        }
+
+        let mut router = Router::<Reqwest>::new("main");
+        router
+            .register_inner_middleware_all_except_update(LoggingMiddleware::new())
+            .register_outer_middleware_all_except_update(UserContextMiddleware);
+
+        for observer in router.telegram_observers() {
+            let count = usize::from(observer.event_name != TelegramObserverName::Update);
+
+            assert_eq!(observer.inner_middlewares.middlewares.len(), count);
+            assert_eq!(observer.outer_middlewares.middlewares.len(), count);
+        }
+    }
+
+    #[test]
+    fn test_with_update_types() {
+        let mut router = Router::<Reqwest>::with_update_types("main", [UpdateType::Message]);
+        router
+            .register_inner_middleware_all(LoggingMiddleware::new())
+            .register_outer_middleware_all_except_update(UserContextMiddleware);
+        router
+            .message
+            .register(|| async { Ok(EventReturn::Finish) });
+
+        for observer in router.telegram_observers() {
+            let count = usize::from(router.is_observer_active(observer.event_name));
+
+            assert_eq!(observer.inner_middlewares.middlewares.len(), count);
+        }
+        assert_eq!(router.message.outer_middlewares.middlewares.len(), 1);
+        assert_eq!(router.update.outer_middlewares.middlewares.len(), 0);
+        assert_eq!(router.callback_query.outer_middlewares.middlewares.len(), 0);
+
+        assert_eq!(
+            router.resolve_used_update_types(),
+            HashSet::from([UpdateType::Message])
+        );
+
+        let service = router.to_service_provider_default().unwrap();
+
+        for observer in service.telegram_observers() {
+            let active = matches!(
+                observer.event_name,
+                TelegramObserverName::Message | TelegramObserverName::Update
+            );
+
+            assert_eq!(
+                observer.handlers_len(),
+                usize::from(observer.event_name == TelegramObserverName::Message)
+            );
+            assert_eq!(!observer.inner_middlewares().is_empty(), active);
+            assert_eq!(!observer.outer_middlewares().is_empty(), active);
+        }
+
+        let mut router = Router::<Reqwest>::with_update_types("main", [UpdateType::Message]);
+        router
+            .callback_query
+            .register(|| async { Ok(EventReturn::Finish) });
+
+        assert!(matches!(
+            router.to_service_provider_default(),
+            Err(RouterInitError::InactiveObserver {
+                router_name: "main",
+                observer: TelegramObserverName::CallbackQuery,
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_propagation_trace() {
+        use crate::middlewares::outer::Trace;
+
+        let mut router = Router::<Reqwest>::new("main");
+        router.update.outer_middlewares.register(Trace);
+        router
+            .message
+            .register(|| async { Ok(EventReturn::Finish) })
+            .filter(|_: &Bot<_>, _: &Update, _: &Context| async { false });
+
+        let mut sub_router = Router::new("sub");
+        sub_router
+            .message
+            .register(|| async { Ok(EventReturn::Finish) })
+            .filter(|_: &Bot<_>, _: &Update, _: &Context| async { true })
+            .filter(|_: &Bot<_>, _: &Update, _: &Context| async { false });
+        sub_router
+            .message
+            .register(|| async { Ok(EventReturn::Skip) });
+        sub_router
+            .message
+            .register(|| async { Ok(EventReturn::Finish) });
+
+        router.include(sub_router);
+
+        let context = Arc::new(Context::new());
+        let request = Request::new(
+            Arc::new(Bot::default()),
+            Arc::new(Update::default()),
+            Arc::clone(&context),
+        );
+
+        router
+            .to_service_provider_default()
+            .unwrap()
+            .propagate_event(UpdateType::Message, request)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            PropagationTrace::from_context(&context).unwrap().steps(),
+            [
+                PropagationStep::Router { name: "main" },
+                PropagationStep::Observer {
+                    name: TelegramObserverName::Update,
+                    filters_passed: true,
+                },
+                PropagationStep::Observer {
+                    name: TelegramObserverName::Message,
+                    filters_passed: true,
+                },
+                PropagationStep::Handler {
+                    index: 0,
+                    rejected_by: Some(0),
+                },
+                PropagationStep::Router { name: "sub" },
+                PropagationStep::Observer {
+                    name: TelegramObserverName::Update,
+                    filters_passed: true,
+                },
+                PropagationStep::Observer {
+                    name: TelegramObserverName::Message,
+                    filters_passed: true,
+                },
+                PropagationStep::Handler {
+                    index: 0,
+                    rejected_by: Some(1),
+                },
+                PropagationStep::Handler {
+                    index: 1,
+                    rejected_by: None,
+                },
+                PropagationStep::HandlerReturn {
+                    index: 1,
+                    event_return: Some(EventReturn::Skip),
+                },
+                PropagationStep::Handler {
+                    index: 2,
+                    rejected_by: None,
+                },
+                PropagationStep::HandlerReturn {
+                    index: 2,
+                    event_return: Some(EventReturn::Finish),
+                },
+            ]
+        );
+    }
 }
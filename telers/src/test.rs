@@ -0,0 +1,479 @@
+//! This module contains helpers for testing handlers, filters and middlewares without network.
+//!
+//! [`MockBot`] is a [`Session`] implementation that doesn't send requests to the Telegram Bot API,
+//! but records them as [`Call`] and returns canned responses queued by the type of the method.
+//! If there is no queued response for the method, then the request fails.
+//!
+//! Helpers [`message`] and [`callback_query`] are used to build fake updates,
+//! and [`MockBot::propagate`] is used to propagate them through the router and get the recorded calls.
+//! Helper [`sent_message`] is used to build the result of the send methods.
+//!
+//! # Examples
+//! ```rust
+//! use telers::{
+//!     event::{telegram::HandlerResult, EventReturn},
+//!     methods::CopyMessage,
+//!     test::{self, MockBot},
+//!     types::{Message, MessageId},
+//!     Bot, Router,
+//! };
+//!
+//! async fn echo_handler(bot: Bot<MockBot>, message: Message) -> HandlerResult {
+//!     bot.send(CopyMessage::new(message.chat().id(), message.chat().id(), message.id()))
+//!         .await?;
+//!
+//!     Ok(EventReturn::Finish)
+//! }
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let mut router = Router::new("main");
+//! router.message.register(echo_handler);
+//!
+//! let mock = MockBot::new();
+//! mock.push_result::<CopyMessage>(MessageId { id: 2 });
+//!
+//! let calls = mock.propagate(router, test::message("Hello")).await.unwrap();
+//!
+//! assert_eq!(calls.len(), 1);
+//! assert!(calls[0].is::<CopyMessage>());
+//! # }
+//! ```
+
+use crate::{
+    client::{
+        session::base::{ClientResponse, Session},
+        telegram::{self, APIServer},
+        Bot,
+    },
+    enums::UpdateType,
+    errors::EventErrorKind,
+    event::ToServiceProvider as _,
+    methods::TelegramMethod,
+    router::{PropagateEvent as _, Request},
+    types::{
+        CallbackQuery, Chat, Message, MessageText, Private as ChatPrivate, Update, UpdateKind, User,
+    },
+    Context, Router,
+};
+
+use async_trait::async_trait;
+use serde::Serialize;
+use std::{
+    any::type_name,
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+};
+
+/// Token of the bot, which is used by [`MockBot::bot`]
+pub const TOKEN: &str = "1234567890:ABC-DEF1234ghIkl-zyx57W2v1u123ew11";
+/// ID of the user, which is used in fake updates
+pub const USER_ID: i64 = 1;
+/// ID of the private chat, which is used in fake updates
+pub const CHAT_ID: i64 = USER_ID;
+
+/// Recorded request to the Telegram Bot API
+#[derive(Debug, Clone, PartialEq)]
+pub struct Call {
+    type_name: &'static str,
+    /// Telegram API method name, for example, `sendMessage`
+    pub method_name: &'static str,
+    /// Telegram API method data serialized to JSON
+    pub data: serde_json::Value,
+    /// Files sent in `multipart/form-data`, represented by `attach://<id>` strings used in the data
+    pub files: Box<[Box<str>]>,
+    /// Request timeout
+    pub timeout: Option<f32>,
+}
+
+impl Call {
+    /// Check if the call is a request of the method
+    #[must_use]
+    pub fn is<T: TelegramMethod>(&self) -> bool {
+        self.type_name == type_name::<T>()
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    calls: Vec<Call>,
+    responses: HashMap<&'static str, VecDeque<ClientResponse>>,
+}
+
+/// Session that records requests to the Telegram Bot API and returns canned responses.
+/// # Notes
+/// This structure is cheap to clone, because all clones share the same recorded calls and queued responses.
+#[derive(Debug, Default, Clone)]
+pub struct MockBot {
+    state: Arc<Mutex<State>>,
+}
+
+impl MockBot {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a [`Bot`] with [`TOKEN`] which uses this session
+    #[must_use]
+    pub fn bot(&self) -> Bot<Self> {
+        Bot::with_client(TOKEN, self.clone())
+    }
+
+    /// Creates a [`Bot`] with the token which uses this session.
+    /// Use it to test several bots with different identifiers, see [`Bot::bot_id`].
+    #[must_use]
+    pub fn bot_with_token(&self, token: &str) -> Bot<Self> {
+        Bot::with_client(token, self.clone())
+    }
+
+    /// Queues a raw response for the method
+    /// # Panics
+    /// If the state mutex is poisoned
+    pub fn push_response<T: TelegramMethod>(&self, response: ClientResponse) {
+        self.state
+            .lock()
+            .unwrap()
+            .responses
+            .entry(type_name::<T>())
+            .or_default()
+            .push_back(response);
+    }
+
+    /// Queues a successful response with the result for the method
+    /// # Panics
+    /// - If the result can't be serialized
+    /// - If the state mutex is poisoned
+    pub fn push_result<T: TelegramMethod>(&self, result: impl Serialize) {
+        let content = serde_json::json!({
+            "ok": true,
+            "result": result,
+        });
+
+        self.push_response::<T>(ClientResponse::new(200, content.to_string()));
+    }
+
+    /// Queues an error response for the method
+    /// # Panics
+    /// If the state mutex is poisoned
+    pub fn push_error<T: TelegramMethod>(&self, status_code: u16, description: &str) {
+        let content = serde_json::json!({
+            "ok": false,
+            "error_code": status_code,
+            "description": description,
+        });
+
+        self.push_response::<T>(ClientResponse::new(status_code, content.to_string()));
+    }
+
+    /// Gets recorded calls
+    /// # Panics
+    /// If the state mutex is poisoned
+    #[must_use]
+    pub fn calls(&self) -> Vec<Call> {
+        self.state.lock().unwrap().calls.clone()
+    }
+
+    /// Gets recorded calls and clears them
+    /// # Panics
+    /// If the state mutex is poisoned
+    #[must_use]
+    pub fn take_calls(&self) -> Vec<Call> {
+        std::mem::take(&mut self.state.lock().unwrap().calls)
+    }
+
+    /// Propagates the update through the router with the default config and returns calls recorded during propagation
+    /// # Errors
+    /// If any outer middleware, inner middleware or handler returns error
+    /// # Panics
+    /// If the state mutex is poisoned
+    pub async fn propagate(
+        &self,
+        router: Router<Self>,
+        update: Update,
+    ) -> Result<Vec<Call>, EventErrorKind> {
+        let service = router
+            .to_service_provider_default()
+            .expect("Router init error is `()`, so it can't fail");
+
+        let calls_before = self.state.lock().unwrap().calls.len();
+
+        service
+            .propagate_event(
+                UpdateType::from(&update),
+                Request::new(
+                    Arc::new(self.bot()),
+                    Arc::new(update),
+                    Arc::new(Context::new()),
+                ),
+            )
+            .await?;
+
+        Ok(self.state.lock().unwrap().calls[calls_before..].to_vec())
+    }
+}
+
+#[async_trait]
+impl Session for MockBot {
+    fn api(&self) -> &APIServer {
+        &telegram::PRODUCTION
+    }
+
+    async fn send_request<Client, T>(
+        &self,
+        bot: &Bot<Client>,
+        method: &T,
+        timeout: Option<f32>,
+    ) -> Result<ClientResponse, anyhow::Error>
+    where
+        Client: Session,
+        T: TelegramMethod + Send + Sync,
+        T::Method: Send + Sync,
+    {
+        let request = method.build_request(bot);
+
+        let mut state = self.state.lock().unwrap();
+
+        state.calls.push(Call {
+            type_name: type_name::<T>(),
+            method_name: request.method_name,
+            data: serde_json::to_value(request.data)?,
+            files: request
+                .files
+                .iter()
+                .flatten()
+                .map(|file| file.str_to_file().into())
+                .collect(),
+            timeout,
+        });
+
+        state
+            .responses
+            .get_mut(type_name::<T>())
+            .and_then(VecDeque::pop_front)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "No response is queued for the `{}` method",
+                    request.method_name
+                )
+            })
+    }
+}
+
+/// Builds a fake update with the text message from the user with [`USER_ID`] in the private chat with [`CHAT_ID`]
+#[must_use]
+pub fn message(text: impl Into<Box<str>>) -> Update {
+    Update {
+        kind: UpdateKind::Message(Message::Text(Box::new(MessageText {
+            id: 1,
+            from: Some(user()),
+            chat: chat(),
+            text: text.into(),
+            ..Default::default()
+        }))),
+        ..Default::default()
+    }
+}
+
+/// Builds a text message sent by the bot to the private chat with [`CHAT_ID`].
+/// It's used as the result of the send methods, for example, `mock.push_result::<SendMessage>(test::sent_message("text"))`
+#[must_use]
+pub fn sent_message(text: impl Into<Box<str>>) -> Message {
+    Message::Text(Box::new(MessageText {
+        id: 2,
+        chat: chat(),
+        text: text.into(),
+        ..Default::default()
+    }))
+}
+
+/// Builds a fake update with the callback query from the user with [`USER_ID`]
+#[must_use]
+pub fn callback_query(data: impl Into<Box<str>>) -> Update {
+    Update {
+        kind: UpdateKind::CallbackQuery(CallbackQuery {
+            id: "1".into(),
+            from: user(),
+            chat_instance: "1".into(),
+            data: Some(data.into()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+fn user() -> User {
+    User {
+        id: USER_ID,
+        first_name: "Test".into(),
+        ..Default::default()
+    }
+}
+
+fn chat() -> Chat {
+    Chat::Private(Box::new(ChatPrivate {
+        id: CHAT_ID,
+        first_name: Some("Test".into()),
+        ..Default::default()
+    }))
+}
+
+/// Tracing subscriber for the crate tests, which records levels and fields of the events
+#[cfg(test)]
+pub(crate) mod recorder {
+    use std::{
+        collections::HashMap,
+        fmt,
+        sync::{Arc, Mutex},
+    };
+    use tracing::{
+        field::{Field, Visit},
+        span, Event, Level, Metadata, Subscriber,
+    };
+
+    pub(crate) type Fields = HashMap<&'static str, String>;
+
+    #[derive(Default, Clone)]
+    pub(crate) struct Recorder {
+        events: Arc<Mutex<Vec<(Level, Fields)>>>,
+    }
+
+    impl Recorder {
+        /// Get recorded events
+        pub(crate) fn events(&self) -> Vec<(Level, Fields)> {
+            self.events.lock().unwrap().clone()
+        }
+    }
+
+    struct FieldsVisitor<'a>(&'a mut Fields);
+
+    impl Visit for FieldsVisitor<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.0.insert(field.name(), format!("{value:?}"));
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _span: &span::Attributes<'_>) -> span::Id {
+            span::Id::from_u64(1)
+        }
+
+        fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut fields = Fields::new();
+            event.record(&mut FieldsVisitor(&mut fields));
+
+            self.events
+                .lock()
+                .unwrap()
+                .push((*event.metadata().level(), fields));
+        }
+
+        fn enter(&self, _span: &span::Id) {}
+
+        fn exit(&self, _span: &span::Id) {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        event::{telegram::HandlerResult, EventReturn},
+        methods::{AnswerCallbackQuery, CopyMessage, SendMessage},
+        types::MessageId,
+    };
+
+    async fn echo_handler(bot: Bot<MockBot>, message: Message) -> HandlerResult {
+        bot.send(CopyMessage::new(
+            message.chat().id(),
+            message.chat().id(),
+            message.id(),
+        ))
+        .await?;
+
+        Ok(EventReturn::Finish)
+    }
+
+    #[tokio::test]
+    async fn test_echo() {
+        let mut router = Router::new("main");
+        router.message.register(echo_handler);
+
+        let mock = MockBot::new();
+        mock.push_result::<CopyMessage>(MessageId { id: 2 });
+
+        let calls = mock.propagate(router, message("Hello")).await.unwrap();
+
+        assert_eq!(calls.len(), 1);
+        assert!(calls[0].is::<CopyMessage>());
+        assert!(!calls[0].is::<SendMessage>());
+        assert_eq!(calls[0].method_name, "copyMessage");
+        assert_eq!(calls[0].data["chat_id"], CHAT_ID);
+        assert_eq!(calls[0].data["from_chat_id"], CHAT_ID);
+        assert_eq!(calls[0].data["message_id"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_callback_query() {
+        let mut router = Router::new("main");
+        router
+            .callback_query
+            .register(|bot: Bot<MockBot>, query: CallbackQuery| async move {
+                assert_eq!(query.data.as_deref(), Some("data"));
+
+                bot.send(AnswerCallbackQuery::new(query.id)).await?;
+
+                Ok(EventReturn::Finish)
+            });
+
+        let mock = MockBot::new();
+        mock.push_result::<AnswerCallbackQuery>(true);
+
+        let calls = mock
+            .propagate(router, callback_query("data"))
+            .await
+            .unwrap();
+
+        assert_eq!(calls.len(), 1);
+        assert!(calls[0].is::<AnswerCallbackQuery>());
+        assert_eq!(mock.take_calls(), calls);
+        assert!(mock.calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_responses() {
+        let mock = MockBot::new();
+        let bot = mock.bot();
+
+        // No queued response
+        assert!(bot.send(SendMessage::new(CHAT_ID, "text")).await.is_err());
+
+        mock.push_error::<SendMessage>(400, "Bad Request: chat not found");
+        assert!(bot.send(SendMessage::new(CHAT_ID, "text")).await.is_err());
+
+        mock.push_result::<CopyMessage>(MessageId { id: 1 });
+        mock.push_result::<CopyMessage>(MessageId { id: 2 });
+        assert_eq!(
+            bot.send(CopyMessage::new(CHAT_ID, CHAT_ID, 1))
+                .await
+                .unwrap(),
+            MessageId { id: 1 }
+        );
+        assert_eq!(
+            bot.send(CopyMessage::new(CHAT_ID, CHAT_ID, 1))
+                .await
+                .unwrap(),
+            MessageId { id: 2 }
+        );
+
+        assert_eq!(mock.calls().len(), 4);
+    }
+}
@@ -197,15 +197,25 @@ pub mod paid_media_photo;
 pub mod paid_media_preview;
 pub mod paid_media_video;
 pub mod passport_data;
+#[cfg(feature = "passport")]
 pub mod passport_element_error;
+#[cfg(feature = "passport")]
 pub mod passport_element_error_data_field;
+#[cfg(feature = "passport")]
 pub mod passport_element_error_file;
+#[cfg(feature = "passport")]
 pub mod passport_element_error_files;
+#[cfg(feature = "passport")]
 pub mod passport_element_error_front_side;
+#[cfg(feature = "passport")]
 pub mod passport_element_error_reverse_side;
+#[cfg(feature = "passport")]
 pub mod passport_element_error_selfie;
+#[cfg(feature = "passport")]
 pub mod passport_element_error_translation_file;
+#[cfg(feature = "passport")]
 pub mod passport_element_error_translation_files;
+#[cfg(feature = "passport")]
 pub mod passport_element_error_unspecified;
 pub mod passport_file;
 pub mod photo_size;
@@ -229,6 +239,7 @@ pub mod revenue_withdrawal_state;
 pub mod revenue_withdrawal_state_failed;
 pub mod revenue_withdrawal_state_pending;
 pub mod revenue_withdrawal_state_succeeded;
+pub mod sender;
 pub mod sent_web_app_message;
 pub mod shared_user;
 pub mod shipping_address;
@@ -312,7 +323,7 @@ pub use chat_boost_updated::ChatBoostUpdated;
 pub use chat_full_info::{
     ChannelFullInfo, ChatFullInfo, GroupFullInfo, PrivateFullInfo, SupergroupFullInfo,
 };
-pub use chat_id_kind::ChatIdKind;
+pub use chat_id_kind::{ChatIdKind, InvalidChatIdKind};
 pub use chat_invite_link::ChatInviteLink;
 pub use chat_join_request::ChatJoinRequest;
 pub use chat_location::ChatLocation;
@@ -326,7 +337,7 @@ pub use chat_member_restricted::ChatMemberRestricted;
 pub use chat_member_updated::ChatMemberUpdated;
 pub use chat_permissions::ChatPermissions;
 pub use chat_photo::ChatPhoto;
-pub use chat_shared::ChatShared;
+pub use chat_shared::{ChatShared, ChatSharedFor};
 pub use chosen_inline_result::ChosenInlineResult;
 pub use contact::Contact;
 pub use dice::Dice;
@@ -486,31 +497,41 @@ pub use paid_media_photo::PaidMediaPhoto;
 pub use paid_media_preview::PaidMediaPreview;
 pub use paid_media_video::PaidMediaVideo;
 pub use passport_data::PassportData;
+#[cfg(feature = "passport")]
 pub use passport_element_error::PassportElementError;
+#[cfg(feature = "passport")]
 pub use passport_element_error_data_field::{
     ElementType as PassportElementErrorDataFieldType, PassportElementErrorDataField,
 };
+#[cfg(feature = "passport")]
 pub use passport_element_error_file::{
     ElementType as PassportElementErrorFileType, PassportElementErrorFile,
 };
+#[cfg(feature = "passport")]
 pub use passport_element_error_files::{
     ElementType as PassportElementErrorFilesType, PassportElementErrorFiles,
 };
+#[cfg(feature = "passport")]
 pub use passport_element_error_front_side::{
     ElementType as PassportElementErrorFrontSideType, PassportElementErrorFrontSide,
 };
+#[cfg(feature = "passport")]
 pub use passport_element_error_reverse_side::{
     ElementType as PassportElementErrorReverseSideType, PassportElementErrorReverseSide,
 };
+#[cfg(feature = "passport")]
 pub use passport_element_error_selfie::{
     ElementType as PassportElementErrorSelfieType, PassportElementErrorSelfie,
 };
+#[cfg(feature = "passport")]
 pub use passport_element_error_translation_file::{
     ElementType as PassportElementErrorTranslationFileType, PassportElementErrorTranslationFile,
 };
+#[cfg(feature = "passport")]
 pub use passport_element_error_translation_files::{
     ElementType as PassportElementErrorTranslationFilesType, PassportElementErrorTranslationFiles,
 };
+#[cfg(feature = "passport")]
 pub use passport_element_error_unspecified::PassportElementErrorUnspecified;
 pub use passport_file::PassportFile;
 pub use photo_size::PhotoSize;
@@ -534,6 +555,7 @@ pub use revenue_withdrawal_state::RevenueWithdrawalState;
 pub use revenue_withdrawal_state_failed::RevenueWithdrawalStateFailed;
 pub use revenue_withdrawal_state_pending::RevenueWithdrawalStatePending;
 pub use revenue_withdrawal_state_succeeded::RevenueWithdrawalStateSucceeded;
+pub use sender::Sender;
 pub use sent_web_app_message::SentWebAppMessage;
 pub use shared_user::SharedUser;
 pub use shipping_address::ShippingAddress;
@@ -558,7 +580,7 @@ pub use update::{Kind as UpdateKind, Update};
 pub use user::User;
 pub use user_chat_boosts::UserChatBoosts;
 pub use user_profile_photos::UserProfilePhotos;
-pub use users_shared::UsersShared;
+pub use users_shared::{UsersShared, UsersSharedFor};
 pub use venue::Venue;
 pub use video::Video;
 pub use video_chat_ended::VideoChatEnded;
@@ -1,6 +1,14 @@
-use super::{InaccessibleMessage, MaybeInaccessibleMessage, Update, UpdateKind, User};
+use super::{
+    InaccessibleMessage, InlineKeyboardMarkup, MaybeInaccessibleMessage, MessageOrTrue, Update,
+    UpdateKind, User,
+};
 
-use crate::{errors::ConvertToTypeError, FromEvent};
+use crate::{
+    client::{Bot, Session},
+    errors::{ConvertToTypeError, SessionErrorKind},
+    methods::{EditMessageReplyMarkup, EditMessageText},
+    FromEvent,
+};
 
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -58,6 +66,16 @@ impl CallbackQuery {
         }
     }
 
+    /// Checks if the message with the callback button was deleted or is otherwise inaccessible to the bot.
+    /// If the query has no message (e.g. it's from the inline mode), `false` is returned.
+    #[must_use]
+    pub const fn is_message_inaccessible(&self) -> bool {
+        matches!(
+            self.message,
+            Some(MaybeInaccessibleMessage::InaccessibleMessage(_))
+        )
+    }
+
     #[must_use]
     pub fn message_text(&self) -> Option<&str> {
         if let Some(message) = &self.message {
@@ -95,6 +113,92 @@ impl CallbackQuery {
     }
 }
 
+/// Message to edit: the inline message or the message in the chat
+struct EditTarget {
+    business_connection_id: Option<String>,
+    chat_id: Option<i64>,
+    message_id: Option<i64>,
+    inline_message_id: Option<String>,
+}
+
+impl CallbackQuery {
+    /// Gets the message with the callback button.
+    /// Inline message (sent via the bot in inline mode) is targeted by `inline_message_id`,
+    /// other messages are targeted by `chat_id` and `message_id`.
+    fn edit_target(&self) -> EditTarget {
+        if let Some(inline_message_id) = &self.inline_message_id {
+            return EditTarget {
+                business_connection_id: None,
+                chat_id: None,
+                message_id: None,
+                inline_message_id: Some(inline_message_id.to_string()),
+            };
+        }
+
+        let business_connection_id = match &self.message {
+            Some(MaybeInaccessibleMessage::Message(message)) => message.business_connection_id(),
+            _ => None,
+        };
+
+        EditTarget {
+            business_connection_id: business_connection_id.map(ToOwned::to_owned),
+            chat_id: self.chat_id(),
+            message_id: self.message_id(),
+            inline_message_id: None,
+        }
+    }
+
+    /// Edits text of the message with the callback button
+    /// # Returns
+    /// The edited message, if it isn't an inline message, otherwise `true`
+    /// # Errors
+    /// If the request to the Telegram API fails
+    pub async fn edit_text<Client>(
+        &self,
+        bot: &Bot<Client>,
+        text: impl Into<String>,
+    ) -> Result<MessageOrTrue, SessionErrorKind>
+    where
+        Client: Session,
+    {
+        let target = self.edit_target();
+
+        bot.send(EditMessageText {
+            business_connection_id: target.business_connection_id,
+            chat_id: target.chat_id.map(Into::into),
+            message_id: target.message_id,
+            inline_message_id: target.inline_message_id,
+            ..EditMessageText::new(text)
+        })
+        .await
+    }
+
+    /// Edits reply markup of the message with the callback button
+    /// # Returns
+    /// The edited message, if it isn't an inline message, otherwise `true`
+    /// # Errors
+    /// If the request to the Telegram API fails
+    pub async fn edit_reply_markup<Client>(
+        &self,
+        bot: &Bot<Client>,
+        reply_markup: impl Into<InlineKeyboardMarkup>,
+    ) -> Result<MessageOrTrue, SessionErrorKind>
+    where
+        Client: Session,
+    {
+        let target = self.edit_target();
+
+        bot.send(EditMessageReplyMarkup {
+            business_connection_id: target.business_connection_id,
+            chat_id: target.chat_id.map(Into::into),
+            message_id: target.message_id,
+            inline_message_id: target.inline_message_id,
+            reply_markup: Some(reply_markup.into()),
+        })
+        .await
+    }
+}
+
 impl TryFrom<Update> for CallbackQuery {
     type Error = ConvertToTypeError;
 
@@ -105,3 +209,96 @@ impl TryFrom<Update> for CallbackQuery {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test::{self, MockBot},
+        types::{InlineKeyboardButton, Message},
+    };
+
+    use serde_json::json;
+
+    fn query(message: Option<Message>, inline_message_id: Option<&str>) -> CallbackQuery {
+        let UpdateKind::CallbackQuery(query) = test::callback_query("data").kind else {
+            unreachable!()
+        };
+
+        CallbackQuery {
+            message: message.map(MaybeInaccessibleMessage::Message),
+            inline_message_id: inline_message_id.map(Into::into),
+            ..query
+        }
+    }
+
+    fn message() -> Message {
+        let UpdateKind::Message(message) = test::message("text").kind else {
+            unreachable!()
+        };
+
+        message
+    }
+
+    #[tokio::test]
+    async fn test_edit_text() {
+        let mock = MockBot::new();
+        mock.push_result::<EditMessageText>(true);
+        mock.push_result::<EditMessageText>(test::sent_message("edited"));
+
+        let bot = mock.bot();
+
+        query(None, Some("inline"))
+            .edit_text(&bot, "edited")
+            .await
+            .unwrap();
+        query(Some(message()), None)
+            .edit_text(&bot, "edited")
+            .await
+            .unwrap();
+
+        let calls = mock.take_calls();
+
+        assert_eq!(
+            calls[0].data,
+            json!({"inline_message_id": "inline", "text": "edited"})
+        );
+        assert_eq!(
+            calls[1].data,
+            json!({"chat_id": test::CHAT_ID, "message_id": message().id(), "text": "edited"})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_edit_reply_markup() {
+        let mock = MockBot::new();
+        mock.push_result::<EditMessageReplyMarkup>(true);
+        mock.push_result::<EditMessageReplyMarkup>(true);
+
+        let bot = mock.bot();
+        let markup = InlineKeyboardMarkup::new([[
+            InlineKeyboardButton::new("button").callback_data("data")
+        ]]);
+
+        query(None, Some("inline"))
+            .edit_reply_markup(&bot, markup.clone())
+            .await
+            .unwrap();
+        query(Some(message()), None)
+            .edit_reply_markup(&bot, markup)
+            .await
+            .unwrap();
+
+        let calls = mock.take_calls();
+
+        assert_eq!(calls[0].data["inline_message_id"], "inline");
+        assert!(calls[0].data.get("chat_id").is_none());
+        assert_eq!(calls[1].data["chat_id"], test::CHAT_ID);
+        assert_eq!(calls[1].data["message_id"], message().id());
+        assert!(calls[1].data.get("inline_message_id").is_none());
+        assert_eq!(
+            calls[1].data["reply_markup"]["inline_keyboard"][0][0]["callback_data"],
+            "data"
+        );
+    }
+}
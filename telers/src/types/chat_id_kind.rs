@@ -1,6 +1,12 @@
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 use strum_macros::Display;
 
+/// This error occurs when the string is neither a chat identifier nor a username in the format `@username`
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("`{0}` isn't a chat identifier or a username in the format `@username`")]
+pub struct InvalidChatIdKind(pub Box<str>);
+
 #[derive(Debug, Display, Clone, Hash, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(untagged, rename_all = "snake_case")]
 pub enum ChatIdKind {
@@ -9,6 +15,17 @@ pub enum ChatIdKind {
 }
 
 impl ChatIdKind {
+    /// Placeholder of the chat identifier, which is replaced with the chat of the current update by [`ReplyTo`]
+    ///
+    /// [`ReplyTo`]: crate::client::ReplyTo
+    pub const UNSET: Self = Self::Id(0);
+
+    /// Checks if the chat identifier is the [`ChatIdKind::UNSET`] placeholder
+    #[must_use]
+    pub const fn is_unset(&self) -> bool {
+        matches!(self, Self::Id(0))
+    }
+
     #[must_use]
     pub fn id(val: i64) -> Self {
         Self::Id(val)
@@ -18,6 +35,50 @@ impl ChatIdKind {
     pub fn username(val: impl Into<Box<str>>) -> Self {
         Self::Username(val.into())
     }
+
+    /// Creates username with validation: it must start with `@`,
+    /// followed by 4-32 latin letters, digits and underscores, starting with a letter
+    /// # Errors
+    /// If the username is invalid
+    pub fn try_username(val: impl Into<Box<str>>) -> Result<Self, InvalidChatIdKind> {
+        let val = val.into();
+
+        if is_valid_username(&val) {
+            Ok(Self::Username(val))
+        } else {
+            Err(InvalidChatIdKind(val))
+        }
+    }
+}
+
+fn is_valid_username(val: &str) -> bool {
+    let Some(username) = val.strip_prefix('@') else {
+        return false;
+    };
+
+    (4..=32).contains(&username.len())
+        && username
+            .chars()
+            .next()
+            .map_or(false, |char| char.is_ascii_alphabetic())
+        && username
+            .chars()
+            .all(|char| char.is_ascii_alphanumeric() || char == '_')
+}
+
+impl FromStr for ChatIdKind {
+    type Err = InvalidChatIdKind;
+
+    /// Parses chat identifier, if the string is numeric, otherwise username, see [`ChatIdKind::try_username`]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with('@') {
+            return Self::try_username(s);
+        }
+
+        s.parse()
+            .map(Self::Id)
+            .map_err(|_| InvalidChatIdKind(s.into()))
+    }
 }
 
 impl From<i64> for ChatIdKind {
@@ -37,3 +98,27 @@ impl From<&str> for ChatIdKind {
         Self::username(val)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            "@channel".parse::<ChatIdKind>().unwrap(),
+            ChatIdKind::username("@channel")
+        );
+        assert_eq!(
+            "-1001234".parse::<ChatIdKind>().unwrap(),
+            ChatIdKind::id(-1_001_234)
+        );
+
+        for invalid in ["channel", "@", "@ch", "@1channel", "@chan-nel", "12a", ""] {
+            assert_eq!(
+                invalid.parse::<ChatIdKind>().unwrap_err(),
+                InvalidChatIdKind(invalid.into())
+            );
+        }
+    }
+}
@@ -1,7 +1,12 @@
-use super::PhotoSize;
+use super::{PhotoSize, Update};
+
+use crate::{
+    client::Bot, context::Context, errors::ConvertToTypeError, extractors::FromEventAndContext,
+};
 
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use std::sync::Arc;
 
 /// This object contains information about a chat that was shared with the bot using a [`KeyboardButtonRequestChat`](crate::types::KeyboardButtonRequestChat) button.
 /// # Documentation
@@ -20,3 +25,40 @@ pub struct ChatShared {
     /// Available sizes of the chat photo, if the photo was requested by the bot
     pub photo: Option<Box<[PhotoSize]>>,
 }
+
+/// [`ChatShared`] of the request with `REQUEST_ID` identifier.
+/// # Notes
+/// As handler argument, it's extracted only if the identifier of the request matches,
+/// so use `Option<ChatSharedFor<REQUEST_ID>>` to handle responses to several request buttons in one handler.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct ChatSharedFor<const REQUEST_ID: i32>(pub ChatShared);
+
+impl<const REQUEST_ID: i32> ChatSharedFor<REQUEST_ID> {
+    #[must_use]
+    pub fn into_inner(self) -> ChatShared {
+        self.0
+    }
+}
+
+impl<const REQUEST_ID: i32> TryFrom<Update> for ChatSharedFor<REQUEST_ID> {
+    type Error = ConvertToTypeError;
+
+    fn try_from(update: Update) -> Result<Self, Self::Error> {
+        match update.message().and_then(|message| message.chat_shared()) {
+            Some(shared) if shared.request_id == i64::from(REQUEST_ID) => Ok(Self(shared.clone())),
+            _ => Err(ConvertToTypeError::new("Update", "ChatSharedFor")),
+        }
+    }
+}
+
+impl<Client, const REQUEST_ID: i32> FromEventAndContext<Client> for ChatSharedFor<REQUEST_ID> {
+    type Error = ConvertToTypeError;
+
+    fn extract(
+        _bot: Arc<Bot<Client>>,
+        update: Arc<Update>,
+        _context: Arc<Context>,
+    ) -> Result<Self, Self::Error> {
+        (*update).clone().try_into()
+    }
+}
@@ -11,6 +11,7 @@ use std::{
     sync::Arc,
 };
 use takecell::TakeOwnCell;
+use tokio::io::AsyncRead;
 use tokio_util::codec::{BytesCodec, FramedRead};
 use uuid::Uuid;
 
@@ -18,6 +19,9 @@ const ATTACH_PREFIX: &str = "attach://";
 
 pub const DEFAULT_CAPACITY: usize = 64 * 1024; // 64 KiB
 
+/// MIME type of the file, if it can't be guessed by the filename
+pub const DEFAULT_MIME_TYPE: &str = "application/octet-stream";
+
 /// This object represents the contents of a file to be uploaded.
 /// # Notes
 /// You can use instead of [`InputFile`] any type that implements [`Into<InputFile>`]:
@@ -105,9 +109,52 @@ impl<'a> InputFile<'a> {
     ) -> Self {
         Self::Stream(StreamFile::new_with_name(stream, name))
     }
+
+    /// Creates a new [`InputFile`] with [`StreamFile`] from the reader, for example, from in-memory generated content
+    /// # Warning
+    /// Check [`InputFile::stream`] for more information about taken stream.
+    #[must_use]
+    pub fn reader(reader: impl AsyncRead + Unpin + Send + Sync + 'static) -> Self {
+        Self::Stream(StreamFile::from_reader(reader))
+    }
+
+    /// Creates a new [`InputFile`] with [`StreamFile`] from the reader and specified filename
+    #[must_use]
+    pub fn reader_with_name(
+        reader: impl AsyncRead + Unpin + Send + Sync + 'static,
+        name: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self::Stream(StreamFile::from_reader_with_name(reader, name))
+    }
 }
 
 impl<'a> InputFile<'a> {
+    /// Gets MIME type of the file, which is uploaded in `multipart/form-data` format.
+    /// See [`FSFile::mime_type`], [`BufferedFile::mime_type`] and [`StreamFile::mime_type`] for more information.
+    /// # Returns
+    /// If this file isn't uploaded in `multipart/form-data` format, returns `None`
+    #[must_use]
+    pub fn mime_type(&self) -> Option<&str> {
+        match self {
+            Self::Id(_) | Self::Url(_) => None,
+            Self::FS(file) => Some(file.mime_type()),
+            Self::Buffered(file) => Some(file.mime_type()),
+            Self::Stream(file) => Some(file.mime_type()),
+        }
+    }
+
+    /// Sets MIME type of the file, which is uploaded in `multipart/form-data` format.
+    /// For [`InputFile::Id`] and [`InputFile::Url`] the MIME type is ignored.
+    #[must_use]
+    pub fn with_mime_type(self, val: impl Into<Cow<'a, str>>) -> Self {
+        match self {
+            Self::Id(_) | Self::Url(_) => self,
+            Self::FS(file) => Self::FS(file.with_mime_type(val)),
+            Self::Buffered(file) => Self::Buffered(file.with_mime_type(val)),
+            Self::Stream(file) => Self::Stream(file.with_mime_type(val)),
+        }
+    }
+
     /// Some variants can be uploaded in `multipart/form-data` format,
     /// others can be uploaded as URL or path (depends on [`InputFile`]).
     /// If the file in `multipart/form-data` format,
@@ -233,6 +280,7 @@ impl<'a> UrlFile<'a> {
 pub struct FSFile<'a> {
     id: Uuid,
     file_name: Option<Cow<'a, str>>,
+    mime_type: Option<Cow<'a, str>>,
     path: PathBuf,
     str_to_file: String,
 }
@@ -247,6 +295,7 @@ impl<'a> FSFile<'a> {
         Self {
             id,
             file_name: None,
+            mime_type: None,
             path: path.as_ref().to_owned(),
             str_to_file,
         }
@@ -261,6 +310,7 @@ impl<'a> FSFile<'a> {
         Self {
             id,
             file_name: Some(name.into()),
+            mime_type: None,
             path: path.as_ref().to_owned(),
             str_to_file,
         }
@@ -286,6 +336,25 @@ impl<'a> FSFile<'a> {
             .or(self.path.file_name().and_then(OsStr::to_str))
     }
 
+    /// Gets passed MIME type or MIME type guessed by the filename.
+    /// If MIME type is unknown, returns [`DEFAULT_MIME_TYPE`]
+    #[must_use]
+    pub fn mime_type(&self) -> &str {
+        self.mime_type
+            .as_deref()
+            .or_else(|| self.file_name().and_then(guess_mime_type))
+            .unwrap_or(DEFAULT_MIME_TYPE)
+    }
+
+    /// Sets MIME type explicitly instead of guessed by the filename
+    #[must_use]
+    pub fn with_mime_type(self, val: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            mime_type: Some(val.into()),
+            ..self
+        }
+    }
+
     /// Gets path to file
     #[must_use]
     pub fn path(&self) -> &Path {
@@ -327,6 +396,16 @@ impl<'a> FSFile<'a> {
     }
 }
 
+fn guess_mime_type(file_name: &str) -> Option<&'static str> {
+    mime_guess::from_path(file_name).first_raw()
+}
+
+fn reader_stream(
+    reader: impl AsyncRead + Send + Sync + Unpin + 'static,
+) -> impl Stream<Item = Result<Bytes, io::Error>> + Send + Sync + Unpin + 'static {
+    FramedRead::with_capacity(reader, BytesCodec::new(), DEFAULT_CAPACITY).map_ok(BytesMut::freeze)
+}
+
 impl Hash for FSFile<'_> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.id.hash(state);
@@ -338,6 +417,7 @@ pub struct BufferedFile<'a> {
     id: Uuid,
     bytes: Bytes,
     file_name: Option<Cow<'a, str>>,
+    mime_type: Option<Cow<'a, str>>,
     str_to_file: String,
 }
 
@@ -353,6 +433,7 @@ impl<'a> BufferedFile<'a> {
             id,
             bytes,
             file_name: None,
+            mime_type: None,
             str_to_file,
         }
     }
@@ -369,6 +450,7 @@ impl<'a> BufferedFile<'a> {
             id,
             bytes,
             file_name: Some(name.into()),
+            mime_type: None,
             str_to_file,
         }
     }
@@ -389,6 +471,25 @@ impl<'a> BufferedFile<'a> {
         self.file_name.as_deref()
     }
 
+    /// Gets passed MIME type or MIME type guessed by the filename.
+    /// If MIME type is unknown, returns [`DEFAULT_MIME_TYPE`]
+    #[must_use]
+    pub fn mime_type(&self) -> &str {
+        self.mime_type
+            .as_deref()
+            .or_else(|| self.file_name().and_then(guess_mime_type))
+            .unwrap_or(DEFAULT_MIME_TYPE)
+    }
+
+    /// Sets MIME type explicitly instead of guessed by the filename
+    #[must_use]
+    pub fn with_mime_type(self, val: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            mime_type: Some(val.into()),
+            ..self
+        }
+    }
+
     /// Gets bytes of file
     #[must_use]
     pub fn bytes(&self) -> &Bytes {
@@ -407,6 +508,7 @@ impl Debug for BufferedFile<'_> {
         f.debug_struct("BufferedFile")
             .field("id", &self.id)
             .field("file_name", &self.file_name)
+            .field("mime_type", &self.mime_type)
             .field("bytes", &"...")
             .field("str_to_file", &self.str_to_file)
             .finish()
@@ -431,6 +533,7 @@ type SharedStream =
 pub struct StreamFile<'a> {
     id: Uuid,
     file_name: Option<Cow<'a, str>>,
+    mime_type: Option<Cow<'a, str>>,
     stream: SharedStream,
     str_to_file: String,
 }
@@ -447,6 +550,7 @@ impl<'a> StreamFile<'a> {
         Self {
             id,
             file_name: None,
+            mime_type: None,
             stream: Arc::new(TakeOwnCell::new(Box::new(stream))),
             str_to_file,
         }
@@ -464,11 +568,28 @@ impl<'a> StreamFile<'a> {
         Self {
             id,
             file_name: Some(name.into()),
+            mime_type: None,
             stream: Arc::new(TakeOwnCell::new(Box::new(stream))),
             str_to_file,
         }
     }
 
+    /// Creates a new [`StreamFile`] from the reader.
+    /// The reader is read by chunks with [`DEFAULT_CAPACITY`] on uploading.
+    #[must_use]
+    pub fn from_reader(reader: impl AsyncRead + Send + Sync + Unpin + 'static) -> Self {
+        Self::new(reader_stream(reader))
+    }
+
+    /// Creates a new [`StreamFile`] from the reader with specified filename
+    #[must_use]
+    pub fn from_reader_with_name(
+        reader: impl AsyncRead + Send + Sync + Unpin + 'static,
+        name: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self::new_with_name(reader_stream(reader), name)
+    }
+
     #[must_use]
     pub const fn is_require_multipart(&self) -> bool {
         true
@@ -485,6 +606,25 @@ impl<'a> StreamFile<'a> {
         self.file_name.as_deref()
     }
 
+    /// Gets passed MIME type or MIME type guessed by the filename.
+    /// If MIME type is unknown, returns [`DEFAULT_MIME_TYPE`]
+    #[must_use]
+    pub fn mime_type(&self) -> &str {
+        self.mime_type
+            .as_deref()
+            .or_else(|| self.file_name().and_then(guess_mime_type))
+            .unwrap_or(DEFAULT_MIME_TYPE)
+    }
+
+    /// Sets MIME type explicitly instead of guessed by the filename
+    #[must_use]
+    pub fn with_mime_type(self, val: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            mime_type: Some(val.into()),
+            ..self
+        }
+    }
+
     /// Takes stream.
     /// # Warning
     /// If stream is taken, default client implementation raises an error,
@@ -548,6 +688,7 @@ impl Debug for StreamFile<'_> {
         f.debug_struct("StreamFile")
             .field("id", &self.id)
             .field("file_name", &self.file_name)
+            .field("mime_type", &self.mime_type)
             .field("stream", &"...")
             .field("str_to_file", &self.str_to_file)
             .finish()
@@ -561,6 +702,7 @@ impl Clone for StreamFile<'_> {
         Self {
             id,
             file_name: self.file_name.clone(),
+            mime_type: self.mime_type.clone(),
             stream: self.stream.clone(),
             str_to_file: format!("{ATTACH_PREFIX}{id}"),
         }
@@ -578,3 +720,37 @@ impl PartialEq for StreamFile<'_> {
         self.id == other.id
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mime_type() {
+        assert_eq!(FSFile::new("photo.jpg").mime_type(), "image/jpeg");
+        assert_eq!(
+            FSFile::new_with_name("photo.jpg", "photo").mime_type(),
+            DEFAULT_MIME_TYPE
+        );
+        assert_eq!(
+            BufferedFile::new_with_name(vec![], "video.mp4").mime_type(),
+            "video/mp4"
+        );
+        assert_eq!(BufferedFile::new(vec![]).mime_type(), DEFAULT_MIME_TYPE);
+
+        assert_eq!(InputFile::fs("photo.jpg").mime_type(), Some("image/jpeg"));
+        assert_eq!(
+            InputFile::fs("photo.jpg")
+                .with_mime_type("image/png")
+                .mime_type(),
+            Some("image/png")
+        );
+        assert_eq!(
+            InputFile::buffered(vec![])
+                .with_mime_type("audio/ogg")
+                .mime_type(),
+            Some("audio/ogg")
+        );
+        assert_eq!(InputFile::id("file_id").mime_type(), None);
+    }
+}
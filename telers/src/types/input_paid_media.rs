@@ -1,4 +1,4 @@
-use super::{InputPaidMediaPhoto, InputPaidMediaVideo};
+use super::{InputFile, InputPaidMediaPhoto, InputPaidMediaVideo};
 
 use serde::Serialize;
 
@@ -14,6 +14,21 @@ pub enum InputPaidMedia<'a> {
     Video(InputPaidMediaVideo<'a>),
 }
 
+impl<'a> InputPaidMedia<'a> {
+    /// Creates [`InputPaidMedia::Photo`] with the given file
+    #[must_use]
+    pub fn photo(media: impl Into<InputFile<'a>>) -> Self {
+        Self::Photo(InputPaidMediaPhoto::new(media))
+    }
+
+    /// Creates [`InputPaidMedia::Video`] with the given file.
+    /// Use [`InputPaidMediaVideo`] directly to set the thumbnail and other video parameters.
+    #[must_use]
+    pub fn video(media: impl Into<InputFile<'a>>) -> Self {
+        Self::Video(InputPaidMediaVideo::new(media))
+    }
+}
+
 impl<'a> From<InputPaidMediaPhoto<'a>> for InputPaidMedia<'a> {
     fn from(fill: InputPaidMediaPhoto<'a>) -> Self {
         Self::Photo(fill)
@@ -51,6 +51,29 @@ impl KeyboardButtonRequestChat {
         }
     }
 
+    /// Creates a request of a group or a supergroup chat
+    #[must_use]
+    pub fn group(request_id: i32) -> Self {
+        Self::new(request_id, false)
+    }
+
+    /// Creates a request of a channel chat
+    #[must_use]
+    pub fn channel(request_id: i32) -> Self {
+        Self::new(request_id, true)
+    }
+
+    /// Request the title, username and photo of the chat
+    #[must_use]
+    pub fn request_all(self) -> Self {
+        Self {
+            request_title: Some(true),
+            request_username: Some(true),
+            request_photo: Some(true),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn request_id(self, val: i32) -> Self {
         Self {
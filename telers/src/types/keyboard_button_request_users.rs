@@ -37,6 +37,35 @@ impl KeyboardButtonRequestUsers {
         }
     }
 
+    /// Creates a request of regular users, which aren't bots
+    #[must_use]
+    pub const fn users(request_id: i32) -> Self {
+        Self {
+            user_is_bot: Some(false),
+            ..Self::new(request_id)
+        }
+    }
+
+    /// Creates a request of bots
+    #[must_use]
+    pub const fn bots(request_id: i32) -> Self {
+        Self {
+            user_is_bot: Some(true),
+            ..Self::new(request_id)
+        }
+    }
+
+    /// Request the name, username and photo of the users
+    #[must_use]
+    pub fn request_all(self) -> Self {
+        Self {
+            request_name: Some(true),
+            request_username: Some(true),
+            request_photo: Some(true),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn request_id(self, val: i32) -> Self {
         Self {
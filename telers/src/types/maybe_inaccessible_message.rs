@@ -1,4 +1,6 @@
-use super::{InaccessibleMessage, Message};
+use super::{Chat, InaccessibleMessage, Message, Update, UpdateKind};
+
+use crate::{errors::ConvertToTypeError, FromEvent};
 
 use serde::{Deserialize, Serialize};
 
@@ -7,13 +9,67 @@ use serde::{Deserialize, Serialize};
 /// - [`InaccessibleMessage`]
 /// # Documentation
 /// <https://core.telegram.org/bots/api#maybeinaccessiblemessage>
-#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+/// # Notes
+/// As handler argument, it's extracted from the message of the callback query or from the message update,
+/// so callback handlers can respond to the deleted or old messages instead of failing on [`Message`] extraction.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
+#[event(try_from = Update)]
 #[serde(untagged)]
 pub enum MaybeInaccessibleMessage {
     Message(Message),
     InaccessibleMessage(InaccessibleMessage),
 }
 
+impl MaybeInaccessibleMessage {
+    /// Checks if the message is accessible to the bot
+    #[must_use]
+    pub const fn is_accessible(&self) -> bool {
+        matches!(self, Self::Message(_))
+    }
+
+    /// Checks if the message was deleted or is otherwise inaccessible to the bot
+    #[must_use]
+    pub const fn is_inaccessible(&self) -> bool {
+        matches!(self, Self::InaccessibleMessage(_))
+    }
+
+    /// Gets the message if it's accessible to the bot
+    #[must_use]
+    pub const fn accessible(&self) -> Option<&Message> {
+        match self {
+            Self::Message(message) => Some(message),
+            Self::InaccessibleMessage(_) => None,
+        }
+    }
+
+    /// Gets the message if it's inaccessible to the bot
+    #[must_use]
+    pub const fn inaccessible(&self) -> Option<&InaccessibleMessage> {
+        match self {
+            Self::Message(_) => None,
+            Self::InaccessibleMessage(message) => Some(message),
+        }
+    }
+
+    /// Gets the message identifier, it's available for inaccessible messages too
+    #[must_use]
+    pub const fn id(&self) -> i64 {
+        match self {
+            Self::Message(message) => message.id(),
+            Self::InaccessibleMessage(message) => message.id,
+        }
+    }
+
+    /// Gets the chat the message belongs to, it's available for inaccessible messages too
+    #[must_use]
+    pub const fn chat(&self) -> &Chat {
+        match self {
+            Self::Message(message) => message.chat(),
+            Self::InaccessibleMessage(message) => &message.chat,
+        }
+    }
+}
+
 impl From<Message> for MaybeInaccessibleMessage {
     fn from(message: Message) -> Self {
         Self::Message(message)
@@ -25,3 +81,90 @@ impl From<InaccessibleMessage> for MaybeInaccessibleMessage {
         Self::InaccessibleMessage(inaccessible_message)
     }
 }
+
+impl TryFrom<Update> for MaybeInaccessibleMessage {
+    type Error = ConvertToTypeError;
+
+    fn try_from(update: Update) -> Result<Self, Self::Error> {
+        match update.kind {
+            UpdateKind::CallbackQuery(query) => query
+                .message
+                .ok_or_else(|| ConvertToTypeError::new("Update", "MaybeInaccessibleMessage")),
+            UpdateKind::Message(val)
+            | UpdateKind::EditedMessage(val)
+            | UpdateKind::ChannelPost(val)
+            | UpdateKind::EditedChannelPost(val)
+            | UpdateKind::BusinessMessage(val)
+            | UpdateKind::EditedBusinessMessage(val) => Ok(Self::Message(val)),
+            _ => Err(ConvertToTypeError::new(
+                "Update",
+                "MaybeInaccessibleMessage",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{context::Context, extractors::FromEventAndContext, test, types::CallbackQuery};
+
+    use std::sync::Arc;
+
+    fn extract(update: Update) -> Result<MaybeInaccessibleMessage, ConvertToTypeError> {
+        MaybeInaccessibleMessage::extract(
+            Arc::new(test::MockBot::new().bot()),
+            Arc::new(update),
+            Arc::new(Context::new()),
+        )
+    }
+
+    fn callback_query(message: Option<MaybeInaccessibleMessage>) -> Update {
+        let update = test::callback_query("data");
+        let UpdateKind::CallbackQuery(query) = update.kind else {
+            unreachable!()
+        };
+
+        Update {
+            kind: UpdateKind::CallbackQuery(CallbackQuery { message, ..query }),
+            ..update
+        }
+    }
+
+    #[test]
+    fn test_extract_inaccessible() {
+        let UpdateKind::Message(message) = test::message("text").kind else {
+            unreachable!()
+        };
+        let inaccessible = InaccessibleMessage {
+            chat: message.chat().clone(),
+            id: 2,
+            date: 0,
+        };
+
+        let extracted = extract(callback_query(Some(inaccessible.clone().into()))).unwrap();
+
+        assert!(extracted.is_inaccessible());
+        assert!(
+            CallbackQuery::try_from(callback_query(Some(extracted.clone())))
+                .unwrap()
+                .is_message_inaccessible()
+        );
+        assert!(!extracted.is_accessible());
+        assert_eq!(extracted.inaccessible(), Some(&inaccessible));
+        assert_eq!(extracted.id(), 2);
+        assert_eq!(extracted.chat().id(), test::CHAT_ID);
+        // Regular message extractor fails for the same update
+        assert!(Message::try_from(callback_query(Some(inaccessible.into()))).is_err());
+
+        let extracted = extract(callback_query(Some(message.clone().into()))).unwrap();
+
+        assert!(extracted.is_accessible());
+        assert_eq!(extracted.accessible(), Some(&message));
+
+        let extracted = extract(test::message("text")).unwrap();
+
+        assert_eq!(extracted.accessible(), Some(&message));
+        assert!(extract(callback_query(None)).is_err());
+    }
+}
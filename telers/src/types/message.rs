@@ -3,10 +3,16 @@ use super::{
     MessageEntity, MessageOrigin, PhotoSize, TextQuote, Update, UpdateKind, User,
 };
 
-use crate::{errors::ConvertToTypeError, extractors::FromEvent, types};
+use crate::{
+    errors::ConvertToTypeError,
+    extractors::FromEvent,
+    methods::SendMessage,
+    types::{self, ReplyParameters},
+};
 
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use std::ops::Range;
 
 /// This object represents a message.
 /// # Documentation
@@ -737,6 +743,7 @@ impl Photo {
     }
 }
 
+#[skip_serializing_none]
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct Story {
@@ -2007,6 +2014,38 @@ impl Message {
         }
     }
 
+    #[must_use]
+    pub const fn is_topic_message(&self) -> Option<bool> {
+        match self {
+            Message::Text(message) => message.is_topic_message,
+            Message::Animation(message) => message.is_topic_message,
+            Message::Audio(message) => message.is_topic_message,
+            Message::Document(message) => message.is_topic_message,
+            Message::Photo(message) => message.is_topic_message,
+            Message::Sticker(message) => message.is_topic_message,
+            Message::Story(message) => message.is_topic_message,
+            Message::Video(message) => message.is_topic_message,
+            Message::VideoNote(message) => message.is_topic_message,
+            Message::Voice(message) => message.is_topic_message,
+            Message::Contact(message) => message.is_topic_message,
+            Message::Dice(message) => message.is_topic_message,
+            Message::Game(message) => message.is_topic_message,
+            Message::Poll(message) => message.is_topic_message,
+            Message::Venue(message) => message.is_topic_message,
+            Message::Location(message) => message.is_topic_message,
+            Message::Pinned(message) => message.is_topic_message,
+            Message::Invoice(message) => message.is_topic_message,
+            Message::PassportData(message) => message.is_topic_message,
+            Message::ForumTopicCreated(message) => message.is_topic_message,
+            Message::ForumTopicEdited(message) => message.is_topic_message,
+            Message::ForumTopicClosed(message) => message.is_topic_message,
+            Message::ForumTopicReopened(message) => message.is_topic_message,
+            Message::GeneralForumTopicHidden(message) => message.is_topic_message,
+            Message::GeneralForumTopicUnhidden(message) => message.is_topic_message,
+            _ => None,
+        }
+    }
+
     #[must_use]
     pub const fn date(&self) -> i64 {
         match self {
@@ -2351,6 +2390,26 @@ impl Message {
         }
     }
 
+    /// Gets entities of the text or caption with their byte ranges in the text or caption.
+    /// Entities with bounds out of the text or splitting a character are skipped.
+    #[must_use]
+    pub fn entities_utf8(&self) -> Option<Vec<(Range<usize>, &MessageEntity)>> {
+        let text = self.text_or_caption()?;
+
+        Some(
+            self.entities()?
+                .iter()
+                .filter_map(|entity| entity.byte_range(text).map(|range| (range, entity)))
+                .collect(),
+        )
+    }
+
+    /// Gets the part of the text or caption which the entity covers
+    #[must_use]
+    pub fn entity_text(&self, entity: &MessageEntity) -> Option<&str> {
+        entity.extract_from(self.text_or_caption()?)
+    }
+
     #[must_use]
     pub const fn show_caption_above_media(&self) -> Option<bool> {
         match self {
@@ -2847,6 +2906,25 @@ impl Message {
             Message::Video(message) => message.has_protected_content,
             Message::Voice(message) => message.has_protected_content,
             Message::Photo(message) => message.has_protected_content,
+            Message::Sticker(message) => message.has_protected_content,
+            Message::VideoNote(message) => message.has_protected_content,
+            Message::Contact(message) => message.has_protected_content,
+            Message::Dice(message) => message.has_protected_content,
+            Message::Game(message) => message.has_protected_content,
+            Message::Poll(message) => message.has_protected_content,
+            Message::Venue(message) => message.has_protected_content,
+            Message::Location(message) => message.has_protected_content,
+            Message::Invoice(message) => message.has_protected_content,
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn has_media_spoiler(&self) -> Option<bool> {
+        match self {
+            Message::Animation(message) => message.has_media_spoiler,
+            Message::Photo(message) => message.has_media_spoiler,
+            Message::Video(message) => message.has_media_spoiler,
             _ => None,
         }
     }
@@ -3512,10 +3590,131 @@ impl_try_from_update!(UsersShared);
 impl_try_from_update!(ChatShared);
 impl_try_from_update!(MessageAutoDeleteTimerChanged);
 
+fn answer_method(
+    chat_id: i64,
+    thread_id: Option<i64>,
+    business_connection_id: Option<&str>,
+    text: impl Into<String>,
+) -> SendMessage {
+    SendMessage::new(chat_id, text)
+        .message_thread_id_option(thread_id)
+        .business_connection_id_option(business_connection_id)
+}
+
+impl Message {
+    /// Creates the text message to the chat (and the message thread) of the message.
+    /// The method can be customised before sending
+    #[must_use]
+    pub fn answer(&self, text: impl Into<String>) -> SendMessage {
+        answer_method(
+            self.chat().id(),
+            self.thread_id(),
+            self.business_connection_id(),
+            text,
+        )
+    }
+
+    /// Creates the text message as a reply to the message.
+    /// The method can be customised before sending
+    #[must_use]
+    pub fn reply(&self, text: impl Into<String>) -> SendMessage {
+        self.answer(text)
+            .reply_parameters(ReplyParameters::new(self.id()))
+    }
+}
+
+impl Text {
+    /// Creates the text message to the chat (and the message thread) of the message.
+    /// The method can be customised before sending
+    #[must_use]
+    pub fn answer(&self, text: impl Into<String>) -> SendMessage {
+        answer_method(
+            self.chat.id(),
+            self.thread_id,
+            self.business_connection_id.as_deref(),
+            text,
+        )
+    }
+
+    /// Creates the text message as a reply to the message.
+    /// The method can be customised before sending
+    #[must_use]
+    pub fn reply(&self, text: impl Into<String>) -> SendMessage {
+        self.answer(text)
+            .reply_parameters(ReplyParameters::new(self.id))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_entities_utf8() {
+        let message = Message::Text(Box::new(Text {
+            text: "Привет, 👋 @user!".into(),
+            entities: Some(Box::new([
+                MessageEntity::new_bold(0, 6),
+                MessageEntity::new_custom_emoji(8, 2, "1"),
+                MessageEntity::new_mention(11, 5),
+                // Out of the text
+                MessageEntity::new_bold(16, 2),
+            ])),
+            ..Default::default()
+        }));
+
+        let entities = message.entities_utf8().unwrap();
+
+        assert_eq!(entities.len(), 3);
+        assert_eq!(entities[0].0, 0..12);
+        assert_eq!(entities[1].0, 14..18);
+        assert_eq!(entities[2].0, 19..24);
+        assert_eq!(
+            entities
+                .iter()
+                .map(|(_, entity)| message.entity_text(entity).unwrap())
+                .collect::<Vec<_>>(),
+            ["Привет", "👋", "@user"]
+        );
+        assert!(Message::default().entities_utf8().is_none());
+    }
+
+    #[test]
+    fn test_answer_and_reply() {
+        let message = Text {
+            id: 1,
+            thread_id: Some(3),
+            chat: serde_json::from_value(serde_json::json!({
+                "id": -1,
+                "type": "supergroup",
+                "title": "test",
+            }))
+            .unwrap(),
+            business_connection_id: Some("4".into()),
+            text: "text".into(),
+            ..Default::default()
+        };
+
+        let methods = [(message.answer("answer"), message.reply("reply")), {
+            let message = Message::Text(Box::new(message.clone()));
+
+            (message.answer("answer"), message.reply("reply"))
+        }];
+
+        for (answer, reply) in methods {
+            for method in [&answer, &reply] {
+                assert_eq!(method.chat_id, (-1).into());
+                assert_eq!(method.message_thread_id, Some(3));
+                assert_eq!(method.business_connection_id.as_deref(), Some("4"));
+            }
+
+            assert_eq!(answer.text, "answer");
+            assert!(answer.reply_parameters.is_none());
+            assert_eq!(reply.text, "reply");
+            assert_eq!(reply.reply_parameters.unwrap().message_id, 1);
+        }
+    }
+
     #[test]
     fn deserialize_text() {
         let jsons = [
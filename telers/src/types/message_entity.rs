@@ -2,6 +2,7 @@ use super::User;
 
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use std::ops::Range;
 use strum_macros::Display;
 
 /// This object represents one special entity in a text message. For example, hashtags, usernames, URLs, etc.
@@ -239,6 +240,43 @@ impl MessageEntity {
     pub fn kind(&self) -> &Kind {
         &self.kind
     }
+
+    /// Converts UTF-16 offset and length of the entity to the byte range in the text
+    /// # Returns
+    /// Byte range or `None` if the entity is out of the text or its bounds split a character
+    #[must_use]
+    pub fn byte_range(&self, text: &str) -> Option<Range<usize>> {
+        let start = utf16_to_byte_index(text, usize::from(self.offset))?;
+        let end = start + utf16_to_byte_index(&text[start..], usize::from(self.length))?;
+
+        Some(start..end)
+    }
+
+    /// Gets the part of the text which the entity covers
+    /// # Returns
+    /// Part of the text or `None` if the entity is out of the text or its bounds split a character
+    #[must_use]
+    pub fn extract_from<'a>(&self, text: &'a str) -> Option<&'a str> {
+        self.byte_range(text).map(|range| &text[range])
+    }
+}
+
+/// Converts the index in UTF-16 code units to the byte index in the text
+fn utf16_to_byte_index(text: &str, utf16_index: usize) -> Option<usize> {
+    let mut utf16_len = 0;
+
+    for (byte_index, char) in text.char_indices() {
+        if utf16_len == utf16_index {
+            return Some(byte_index);
+        }
+        if utf16_len > utf16_index {
+            return None;
+        }
+
+        utf16_len += char.len_utf16();
+    }
+
+    (utf16_len == utf16_index).then_some(text.len())
 }
 
 impl From<Pre> for Kind {
@@ -264,3 +302,31 @@ impl From<CustomEmoji> for Kind {
         Self::CustomEmoji(custom_emoji)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_from() {
+        // "🦀" is a surrogate pair (2 UTF-16 code units, 4 bytes), "é" is 1 code unit and 2 bytes
+        let text = "🦀 café @user";
+
+        let emoji = MessageEntity::new_custom_emoji(0, 2, "1");
+        let word = MessageEntity::new_bold(3, 4);
+        let mention = MessageEntity::new_mention(8, 5);
+
+        assert_eq!(emoji.byte_range(text), Some(0..4));
+        assert_eq!(emoji.extract_from(text), Some("🦀"));
+        assert_eq!(word.byte_range(text), Some(5..10));
+        assert_eq!(word.extract_from(text), Some("café"));
+        assert_eq!(mention.extract_from(text), Some("@user"));
+
+        // Bounds split the surrogate pair
+        assert_eq!(MessageEntity::new_bold(1, 2).extract_from(text), None);
+        assert_eq!(MessageEntity::new_bold(0, 1).extract_from(text), None);
+        // Out of the text
+        assert_eq!(MessageEntity::new_bold(8, 6).extract_from(text), None);
+        assert_eq!(MessageEntity::new_bold(14, 0).extract_from(text), None);
+    }
+}
@@ -1,9 +1,11 @@
+use super::Message;
+
 use serde::{Deserialize, Serialize};
 
 /// This object represents a unique message identifier.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#messageid>
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize, Serialize)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Deserialize, Serialize)]
 pub struct MessageId {
     /// Unique message identifier
     #[serde(rename = "message_id")]
@@ -15,3 +17,15 @@ impl From<MessageId> for i64 {
         val.id
     }
 }
+
+impl From<&Message> for MessageId {
+    fn from(val: &Message) -> Self {
+        Self { id: val.id() }
+    }
+}
+
+impl From<Message> for MessageId {
+    fn from(val: Message) -> Self {
+        Self::from(&val)
+    }
+}
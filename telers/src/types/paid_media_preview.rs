@@ -1,8 +1,10 @@
 use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
 /// The paid media isn't available before the payment.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#paidmediapreview>
+#[skip_serializing_none]
 #[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Deserialize, Serialize)]
 pub struct PaidMediaPreview {
     /// Media width as defined by the sender
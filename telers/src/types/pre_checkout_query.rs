@@ -1,5 +1,11 @@
 use super::{OrderInfo, Update, UpdateKind, User};
 
+#[cfg(feature = "payments")]
+use crate::{
+    client::{Bot, Session},
+    errors::SessionErrorKind,
+    methods::AnswerPreCheckoutQuery,
+};
 use crate::{errors::ConvertToTypeError, FromEvent};
 
 use serde::{Deserialize, Serialize};
@@ -28,6 +34,38 @@ pub struct PreCheckoutQuery {
     pub order_info: Option<OrderInfo>,
 }
 
+#[cfg(feature = "payments")]
+impl PreCheckoutQuery {
+    /// Confirms that the bot is ready to proceed with the order
+    /// # Errors
+    /// If the request to the Telegram API fails
+    pub async fn answer_ok<Client>(&self, bot: &Bot<Client>) -> Result<bool, SessionErrorKind>
+    where
+        Client: Session,
+    {
+        bot.send(AnswerPreCheckoutQuery::new_ok(self.id.as_ref()))
+            .await
+    }
+
+    /// Rejects the checkout with the error message, which Telegram displays to the user
+    /// # Errors
+    /// - If the error message is empty, the request isn't sent (see [`AnswerPreCheckoutQuery::try_new_error`])
+    /// - If the request to the Telegram API fails
+    pub async fn answer_error<Client>(
+        &self,
+        bot: &Bot<Client>,
+        error_message: impl Into<String>,
+    ) -> Result<bool, SessionErrorKind>
+    where
+        Client: Session,
+    {
+        let method = AnswerPreCheckoutQuery::try_new_error(self.id.as_ref(), error_message)
+            .map_err(anyhow::Error::from)?;
+
+        bot.send(method).await
+    }
+}
+
 impl TryFrom<Update> for PreCheckoutQuery {
     type Error = ConvertToTypeError;
 
@@ -38,3 +76,39 @@ impl TryFrom<Update> for PreCheckoutQuery {
         }
     }
 }
+
+#[cfg(all(test, feature = "payments"))]
+mod tests {
+    use super::*;
+    use crate::test::MockBot;
+
+    #[tokio::test]
+    async fn test_answer() {
+        let mock = MockBot::new();
+        mock.push_result::<AnswerPreCheckoutQuery>(true);
+        mock.push_result::<AnswerPreCheckoutQuery>(true);
+
+        let bot = mock.bot();
+        let query = PreCheckoutQuery {
+            id: "id".into(),
+            ..Default::default()
+        };
+
+        assert!(query.answer_ok(&bot).await.unwrap());
+        assert!(query.answer_error(&bot, "Out of stock").await.unwrap());
+        // Invalid answer isn't sent
+        assert!(query.answer_error(&bot, "").await.is_err());
+
+        let calls = mock.calls();
+
+        assert_eq!(calls.len(), 2);
+        assert_eq!(
+            calls[0].data,
+            serde_json::json!({"pre_checkout_query_id": "id", "ok": true})
+        );
+        assert_eq!(
+            calls[1].data,
+            serde_json::json!({"pre_checkout_query_id": "id", "ok": false, "error_message": "Out of stock"})
+        );
+    }
+}
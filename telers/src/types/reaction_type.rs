@@ -54,6 +54,18 @@ impl From<ReactionTypePaid> for ReactionType {
     }
 }
 
+impl From<&str> for ReactionType {
+    fn from(emoji: &str) -> Self {
+        Self::emoji(emoji)
+    }
+}
+
+impl From<String> for ReactionType {
+    fn from(emoji: String) -> Self {
+        Self::emoji(emoji)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -66,6 +78,15 @@ mod tests {
         assert_eq!(json, r#"{"type":"emoji","emoji":"👍"}"#);
     }
 
+    #[test]
+    fn from_str() {
+        assert_eq!(ReactionType::from("👍"), ReactionType::emoji("👍"));
+        assert_eq!(
+            ReactionType::from(String::from("🔥")),
+            ReactionType::emoji("🔥")
+        );
+    }
+
     #[test]
     fn serialize_custom_emoji() {
         let data = ReactionType::custom_emoji("123");
@@ -22,3 +22,15 @@ impl ReactionTypeEmoji {
         Self { emoji: val.into() }
     }
 }
+
+impl From<&str> for ReactionTypeEmoji {
+    fn from(emoji: &str) -> Self {
+        Self::new(emoji)
+    }
+}
+
+impl From<String> for ReactionTypeEmoji {
+    fn from(emoji: String) -> Self {
+        Self::new(emoji)
+    }
+}
@@ -1,4 +1,4 @@
-use super::{ChatIdKind, MessageEntity};
+use super::{ChatIdKind, Message, MessageEntity, MessageEntityKind};
 
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -174,3 +174,154 @@ impl ReplyParameters {
         }
     }
 }
+
+impl ReplyParameters {
+    /// Quotes the part of the message to be replied to, which starts at `position` in UTF-16 code units
+    #[must_use]
+    pub fn quote_at(self, text: impl Into<String>, position: u16) -> Self {
+        Self {
+            quote: Some(text.into()),
+            quote_position: Some(position),
+            ..self
+        }
+    }
+
+    /// Finds the first occurrence of the quote in the original text and quotes it with its position.
+    /// Entities of the original text, which are allowed in quotes (`bold`, `italic`, `underline`, `strikethrough`, `spoiler` and `custom_emoji`),
+    /// are moved to the quote, other entities are skipped.
+    /// # Returns
+    /// `None` if the quote is empty or isn't a substring of the original text
+    #[must_use]
+    pub fn quote_in(
+        self,
+        original: &str,
+        original_entities: &[MessageEntity],
+        quote: &str,
+    ) -> Option<Self> {
+        if quote.is_empty() {
+            return None;
+        }
+
+        let start = utf16_len(&original[..original.find(quote)?]);
+        let end = start + utf16_len(quote);
+
+        let entities = original_entities
+            .iter()
+            .filter_map(|entity| {
+                let entity_start = usize::from(entity.offset);
+                let entity_end = entity_start + usize::from(entity.length);
+
+                match entity.kind {
+                    MessageEntityKind::Bold
+                    | MessageEntityKind::Italic
+                    | MessageEntityKind::Underline
+                    | MessageEntityKind::Strikethrough
+                    | MessageEntityKind::Spoiler => {}
+                    // Custom emoji can't be split
+                    MessageEntityKind::CustomEmoji(_)
+                        if entity_start >= start && entity_end <= end => {}
+                    _ => return None,
+                }
+
+                let entity_start = entity_start.max(start);
+                let entity_end = entity_end.min(end);
+
+                if entity_start >= entity_end {
+                    return None;
+                }
+
+                Some(MessageEntity {
+                    offset: u16::try_from(entity_start - start).ok()?,
+                    length: u16::try_from(entity_end - entity_start).ok()?,
+                    kind: entity.kind.clone(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        Some(Self {
+            quote: Some(quote.to_owned()),
+            quote_position: Some(u16::try_from(start).ok()?),
+            quote_entities: (!entities.is_empty()).then_some(entities),
+            quote_parse_mode: None,
+            ..self
+        })
+    }
+
+    /// Creates reply parameters to quote the part of the message text or caption.
+    /// The chat of the message is set, so the reply can be sent to another chat.
+    /// # Returns
+    /// `None` if the message has no text or caption, or the quote isn't its substring, see [`ReplyParameters::quote_in`]
+    #[must_use]
+    pub fn quote_message(message: &Message, quote: &str) -> Option<Self> {
+        Self::new(message.id())
+            .chat_id(message.chat().id())
+            .quote_in(
+                message.text_or_caption()?,
+                message.entities().unwrap_or_default(),
+                quote,
+            )
+    }
+}
+
+fn utf16_len(text: &str) -> usize {
+    text.encode_utf16().count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::message::Text;
+
+    #[test]
+    fn test_quote_in() {
+        // "🙂" is 2 UTF-16 code units
+        let original = "🙂 Hello, world!";
+        let entities = [
+            MessageEntity::new(3, 5, MessageEntityKind::Bold),
+            MessageEntity::new(10, 5, MessageEntityKind::Url),
+        ];
+
+        let value = serde_json::to_value(
+            ReplyParameters::new(1)
+                .quote_in(original, &entities, "llo, wor")
+                .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(value["quote"], "llo, wor");
+        assert_eq!(value["quote_position"], 5);
+        assert_eq!(
+            value["quote_entities"],
+            serde_json::json!([{"offset": 0, "length": 3, "type": "bold"}])
+        );
+
+        assert!(ReplyParameters::new(1)
+            .quote_in(original, &entities, "not found")
+            .is_none());
+        assert!(ReplyParameters::new(1)
+            .quote_in(original, &entities, "")
+            .is_none());
+    }
+
+    #[test]
+    fn test_quote_message() {
+        let message = Message::Text(Box::new(Text {
+            id: 2,
+            text: "Привет, мир".into(),
+            ..Default::default()
+        }));
+
+        let reply = ReplyParameters::quote_message(&message, "мир").unwrap();
+
+        assert_eq!(reply.message_id, 2);
+        assert_eq!(reply.chat_id, Some(ChatIdKind::id(message.chat().id())));
+        assert_eq!(reply.quote.as_deref(), Some("мир"));
+        assert_eq!(reply.quote_position, Some(8));
+        assert_eq!(reply.quote_entities, None);
+
+        let value = serde_json::to_value(ReplyParameters::new(1).quote_at("text", 3)).unwrap();
+
+        assert_eq!(value["quote"], "text");
+        assert_eq!(value["quote_position"], 3);
+    }
+}
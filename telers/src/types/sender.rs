@@ -0,0 +1,135 @@
+use super::{Chat, Update, User};
+
+use crate::{errors::ConvertToTypeError, FromEvent};
+
+/// Sender of the update on whose behalf the action is performed, see [`Update::effective_sender`] for more details.
+/// It's one of
+/// - [`User`]
+/// - [`Chat`], for example, for channel posts and messages of anonymous group administrators
+/// # Notes
+/// This isn't a Telegram Bot API type, it unifies the user and the sender chat of the update,
+/// so handlers can attribute actions consistently.
+#[derive(Debug, Clone, PartialEq, FromEvent)]
+#[event(try_from = Update)]
+pub enum Sender {
+    User(User),
+    Chat(Chat),
+}
+
+impl Sender {
+    /// Gets identifier of the user or of the chat
+    #[must_use]
+    pub const fn id(&self) -> i64 {
+        match self {
+            Self::User(user) => user.id,
+            Self::Chat(chat) => chat.id(),
+        }
+    }
+
+    #[must_use]
+    pub const fn user(&self) -> Option<&User> {
+        match self {
+            Self::User(user) => Some(user),
+            Self::Chat(_) => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn chat(&self) -> Option<&Chat> {
+        match self {
+            Self::User(_) => None,
+            Self::Chat(chat) => Some(chat),
+        }
+    }
+}
+
+impl From<User> for Sender {
+    fn from(user: User) -> Self {
+        Self::User(user)
+    }
+}
+
+impl From<Chat> for Sender {
+    fn from(chat: Chat) -> Self {
+        Self::Chat(chat)
+    }
+}
+
+impl TryFrom<Update> for Sender {
+    type Error = ConvertToTypeError;
+
+    fn try_from(update: Update) -> Result<Self, Self::Error> {
+        update
+            .effective_sender()
+            .ok_or_else(|| ConvertToTypeError::new("Update", "Sender"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    use serde_json::json;
+
+    fn update(value: serde_json::Value) -> Update {
+        serde_json::from_str(&value.to_string()).unwrap()
+    }
+
+    #[test]
+    fn test_channel_post() {
+        let sender = update(json!({
+            "update_id": 1,
+            "channel_post": {
+                "message_id": 1,
+                "date": 0,
+                "chat": {"id": -100, "type": "channel", "title": "Channel"},
+                "sender_chat": {"id": -100, "type": "channel", "title": "Channel"},
+                "text": "text",
+            },
+        }))
+        .effective_sender()
+        .unwrap();
+
+        assert_eq!(sender.id(), -100);
+        assert!(sender.chat().is_some());
+    }
+
+    #[test]
+    fn test_user_message() {
+        let sender = test::message("text").effective_sender().unwrap();
+
+        assert_eq!(sender.id(), test::USER_ID);
+        assert!(sender.user().is_some());
+
+        let sender = Sender::try_from(test::callback_query("data")).unwrap();
+
+        assert_eq!(sender.id(), test::USER_ID);
+    }
+
+    #[test]
+    fn test_anonymous_admin() {
+        let sender = update(json!({
+            "update_id": 1,
+            "message": {
+                "message_id": 1,
+                "date": 0,
+                "chat": {"id": -1, "type": "supergroup", "title": "Group"},
+                // Telegram sets the placeholder user for messages of anonymous administrators
+                "from": {"id": 1_087_968_824, "is_bot": true, "first_name": "Group", "username": "GroupAnonymousBot"},
+                "sender_chat": {"id": -1, "type": "supergroup", "title": "Group"},
+                "text": "text",
+            },
+        }))
+        .effective_sender()
+        .unwrap();
+
+        assert!(matches!(sender, Sender::Chat(_)));
+        assert_eq!(sender.id(), -1);
+    }
+
+    #[test]
+    fn test_without_sender() {
+        assert!(Sender::try_from(Update::default()).is_err());
+    }
+}
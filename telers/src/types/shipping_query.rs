@@ -1,5 +1,13 @@
+#[cfg(feature = "payments")]
+use super::ShippingOption;
 use super::{ShippingAddress, Update, UpdateKind, User};
 
+#[cfg(feature = "payments")]
+use crate::{
+    client::{Bot, Session},
+    errors::SessionErrorKind,
+    methods::AnswerShippingQuery,
+};
 use crate::{errors::ConvertToTypeError, FromEvent};
 
 use serde::{Deserialize, Serialize};
@@ -20,6 +28,45 @@ pub struct ShippingQuery {
     pub shipping_address: ShippingAddress,
 }
 
+#[cfg(feature = "payments")]
+impl ShippingQuery {
+    /// Confirms that delivery to the address is possible with the shipping options
+    /// # Errors
+    /// - If the shipping options are empty, the request isn't sent (see [`AnswerShippingQuery::try_new_ok`])
+    /// - If the request to the Telegram API fails
+    pub async fn answer_ok<Client>(
+        &self,
+        bot: &Bot<Client>,
+        shipping_options: impl IntoIterator<Item = ShippingOption>,
+    ) -> Result<bool, SessionErrorKind>
+    where
+        Client: Session,
+    {
+        let method = AnswerShippingQuery::try_new_ok(self.id.as_ref(), shipping_options)
+            .map_err(anyhow::Error::from)?;
+
+        bot.send(method).await
+    }
+
+    /// Rejects the order with the error message, which Telegram displays to the user
+    /// # Errors
+    /// - If the error message is empty, the request isn't sent (see [`AnswerShippingQuery::try_new_error`])
+    /// - If the request to the Telegram API fails
+    pub async fn answer_error<Client>(
+        &self,
+        bot: &Bot<Client>,
+        error_message: impl Into<String>,
+    ) -> Result<bool, SessionErrorKind>
+    where
+        Client: Session,
+    {
+        let method = AnswerShippingQuery::try_new_error(self.id.as_ref(), error_message)
+            .map_err(anyhow::Error::from)?;
+
+        bot.send(method).await
+    }
+}
+
 impl TryFrom<Update> for ShippingQuery {
     type Error = ConvertToTypeError;
 
@@ -30,3 +77,50 @@ impl TryFrom<Update> for ShippingQuery {
         }
     }
 }
+
+#[cfg(all(test, feature = "payments"))]
+mod tests {
+    use super::*;
+    use crate::{test::MockBot, types::LabeledPrice};
+
+    #[tokio::test]
+    async fn test_answer() {
+        let mock = MockBot::new();
+        mock.push_result::<AnswerShippingQuery>(true);
+        mock.push_result::<AnswerShippingQuery>(true);
+
+        let bot = mock.bot();
+        let query = ShippingQuery {
+            id: "id".into(),
+            ..Default::default()
+        };
+
+        query
+            .answer_ok(
+                &bot,
+                [ShippingOption::new(
+                    "standard",
+                    "Standard",
+                    [LabeledPrice::new("Delivery", 500)],
+                )],
+            )
+            .await
+            .unwrap();
+        query
+            .answer_error(&bot, "Delivery is unavailable")
+            .await
+            .unwrap();
+        // Invalid answers aren't sent
+        assert!(query.answer_ok(&bot, []).await.is_err());
+        assert!(query.answer_error(&bot, "").await.is_err());
+
+        let calls = mock.calls();
+
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].data["ok"], true);
+        assert_eq!(calls[0].data["shipping_options"][0]["id"], "standard");
+        assert_eq!(calls[1].data["ok"], false);
+        assert_eq!(calls[1].data["error_message"], "Delivery is unavailable");
+        assert!(calls[1].data.get("shipping_options").is_none());
+    }
+}
@@ -3,7 +3,7 @@ use super::{
     ChatBoostSource, ChatBoostSourcePremium, ChatBoostUpdated, ChatJoinRequest, ChatMemberUpdated,
     ChosenInlineResult, InaccessibleMessage, InlineQuery, MaybeInaccessibleMessage, Message,
     MessageReactionCountUpdated, MessageReactionUpdated, Poll, PollAnswer, PreCheckoutQuery,
-    ShippingQuery, User,
+    Sender, ShippingQuery, User,
 };
 
 use crate::{enums::UpdateType, extractors::FromEvent};
@@ -224,6 +224,30 @@ impl Kind {
         }
     }
 
+    /// Gets the sender of the update on whose behalf the action is performed.
+    /// The chat is the sender of channel posts, messages of anonymous group administrators,
+    /// messages automatically forwarded from the linked channel, as well as anonymous poll answers and reactions.
+    /// Otherwise, the user is the sender.
+    #[must_use]
+    pub fn effective_sender(&self) -> Option<Sender> {
+        let sender_chat = match self {
+            Kind::Message(message)
+            | Kind::EditedMessage(message)
+            | Kind::BusinessMessage(message)
+            | Kind::EditedBusinessMessage(message)
+            | Kind::ChannelPost(message)
+            | Kind::EditedChannelPost(message) => message.sender_chat(),
+            Kind::PollAnswer(PollAnswer { voter_chat, .. }) => voter_chat.as_ref(),
+            Kind::MessageReaction(MessageReactionUpdated { actor_chat, .. }) => actor_chat.as_ref(),
+            _ => None,
+        };
+
+        match sender_chat {
+            Some(chat) => Some(Sender::Chat(chat.clone())),
+            None => self.from().cloned().map(Sender::User),
+        }
+    }
+
     #[must_use]
     pub const fn message_thread_id(&self) -> Option<i64> {
         match self {
@@ -252,7 +276,9 @@ impl Kind {
                 id: business_connection_id,
                 ..
             }) => Some(business_connection_id),
-            Kind::BusinessMessage(message) => message.business_connection_id(),
+            Kind::BusinessMessage(message) | Kind::EditedBusinessMessage(message) => {
+                message.business_connection_id()
+            }
             _ => None,
         }
     }
@@ -273,6 +299,30 @@ impl Kind {
             _ => None,
         }
     }
+
+    /// Gets identifier of the message related to the update: the message itself, the message with the callback button
+    /// (even if it's inaccessible) or the message with changed reactions
+    #[must_use]
+    pub const fn message_id(&self) -> Option<i64> {
+        match self {
+            Kind::CallbackQuery(CallbackQuery {
+                message:
+                    Some(MaybeInaccessibleMessage::InaccessibleMessage(InaccessibleMessage {
+                        id, ..
+                    })),
+                ..
+            })
+            | Kind::MessageReaction(MessageReactionUpdated { id, .. })
+            | Kind::MessageReactionCount(MessageReactionCountUpdated { id, .. }) => Some(*id),
+            _ => {
+                if let Some(message) = self.message() {
+                    Some(message.id())
+                } else {
+                    None
+                }
+            }
+        }
+    }
 }
 
 impl Default for Kind {
@@ -534,6 +584,12 @@ impl Update {
         self.kind().sender_chat_id()
     }
 
+    /// Gets the sender of the update on whose behalf the action is performed, see [`Kind::effective_sender`] for more details
+    #[must_use]
+    pub fn effective_sender(&self) -> Option<Sender> {
+        self.kind().effective_sender()
+    }
+
     #[must_use]
     pub const fn message_thread_id(&self) -> Option<i64> {
         self.kind().message_thread_id()
@@ -548,4 +604,99 @@ impl Update {
     pub const fn message(&self) -> Option<&Message> {
         self.kind().message()
     }
+
+    #[must_use]
+    pub const fn message_id(&self) -> Option<i64> {
+        self.kind().message_id()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    use serde_json::json;
+
+    fn update(value: serde_json::Value) -> Update {
+        serde_json::from_str(&value.to_string()).unwrap()
+    }
+
+    #[test]
+    fn test_message_id() {
+        let message = json!({
+            "message_id": 2,
+            "date": 0,
+            "chat": {"id": test::CHAT_ID, "type": "private"},
+            "text": "text",
+        });
+
+        assert_eq!(
+            update(json!({"update_id": 1, "message": message})).message_id(),
+            Some(2)
+        );
+        assert_eq!(
+            update(json!({"update_id": 1, "edited_message": message})).message_id(),
+            Some(2)
+        );
+        assert_eq!(
+            update(json!({
+                "update_id": 1,
+                "callback_query": {
+                    "id": "1",
+                    "from": {"id": test::USER_ID, "is_bot": false, "first_name": "Test"},
+                    "chat_instance": "1",
+                    "message": message,
+                },
+            }))
+            .message_id(),
+            Some(2)
+        );
+        assert_eq!(
+            update(json!({
+                "update_id": 1,
+                "callback_query": {
+                    "id": "1",
+                    "from": {"id": test::USER_ID, "is_bot": false, "first_name": "Test"},
+                    "chat_instance": "1",
+                    "message": {
+                        "message_id": 3,
+                        "date": 0,
+                        "chat": {"id": test::CHAT_ID, "type": "private"},
+                    },
+                },
+            }))
+            .message_id(),
+            Some(3)
+        );
+        assert_eq!(
+            update(json!({
+                "update_id": 1,
+                "message_reaction": {
+                    "chat": {"id": test::CHAT_ID, "type": "private"},
+                    "message_id": 4,
+                    "date": 0,
+                    "old_reaction": [],
+                    "new_reaction": [{"type": "emoji", "emoji": "👍"}],
+                },
+            }))
+            .message_id(),
+            Some(4)
+        );
+        assert_eq!(
+            update(json!({
+                "update_id": 1,
+                "message_reaction_count": {
+                    "chat": {"id": test::CHAT_ID, "type": "private"},
+                    "message_id": 5,
+                    "date": 0,
+                    "reactions": [],
+                },
+            }))
+            .message_id(),
+            Some(5)
+        );
+        // Inline callback query without message
+        assert_eq!(test::callback_query("data").message_id(), None);
+    }
 }
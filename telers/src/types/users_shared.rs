@@ -1,6 +1,11 @@
-use super::SharedUser;
+use super::{SharedUser, Update};
+
+use crate::{
+    client::Bot, context::Context, errors::ConvertToTypeError, extractors::FromEventAndContext,
+};
 
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 /// This object contains information about the users whose identifiers were shared with the bot using a  [`KeyboardButtonRequestUsers`](crate::types::KeyboardButtonRequestUsers) button.
 /// # Documentation
@@ -12,3 +17,121 @@ pub struct UsersShared {
     /// Information about users shared with the bot.
     pub users: Box<[SharedUser]>,
 }
+
+impl UsersShared {
+    /// Gets identifiers of the shared users
+    #[must_use]
+    pub fn user_ids(&self) -> Box<[i64]> {
+        self.users.iter().map(|user| user.id).collect()
+    }
+}
+
+/// [`UsersShared`] of the request with `REQUEST_ID` identifier.
+/// # Notes
+/// As handler argument, it's extracted only if the identifier of the request matches,
+/// so use `Option<UsersSharedFor<REQUEST_ID>>` to handle responses to several request buttons in one handler.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct UsersSharedFor<const REQUEST_ID: i32>(pub UsersShared);
+
+impl<const REQUEST_ID: i32> UsersSharedFor<REQUEST_ID> {
+    #[must_use]
+    pub fn into_inner(self) -> UsersShared {
+        self.0
+    }
+}
+
+impl<const REQUEST_ID: i32> TryFrom<Update> for UsersSharedFor<REQUEST_ID> {
+    type Error = ConvertToTypeError;
+
+    fn try_from(update: Update) -> Result<Self, Self::Error> {
+        match update.message().and_then(|message| message.users_shared()) {
+            Some(shared) if shared.request_id == i64::from(REQUEST_ID) => Ok(Self(shared.clone())),
+            _ => Err(ConvertToTypeError::new("Update", "UsersSharedFor")),
+        }
+    }
+}
+
+impl<Client, const REQUEST_ID: i32> FromEventAndContext<Client> for UsersSharedFor<REQUEST_ID> {
+    type Error = ConvertToTypeError;
+
+    fn extract(
+        _bot: Arc<Bot<Client>>,
+        update: Arc<Update>,
+        _context: Arc<Context>,
+    ) -> Result<Self, Self::Error> {
+        (*update).clone().try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test,
+        types::{KeyboardButton, KeyboardButtonRequestUsers},
+    };
+
+    use serde_json::json;
+
+    fn users_shared(request_id: i64) -> Update {
+        serde_json::from_str(
+            &json!({
+                "update_id": 1,
+                "message": {
+                    "message_id": 1,
+                    "date": 0,
+                    "chat": {"id": test::CHAT_ID, "type": "private"},
+                    "users_shared": {"request_id": request_id, "users": [{"user_id": 2}, {"user_id": 3}]},
+                },
+            })
+            .to_string(),
+        )
+        .unwrap()
+    }
+
+    fn extract<const REQUEST_ID: i32>(
+        update: Update,
+    ) -> Result<UsersSharedFor<REQUEST_ID>, ConvertToTypeError> {
+        UsersSharedFor::extract(
+            Arc::new(test::MockBot::new().bot()),
+            Arc::new(update),
+            Arc::new(Context::new()),
+        )
+    }
+
+    #[test]
+    fn test_request_users_button() {
+        let button = KeyboardButton::new("Choose friends").request_users(
+            KeyboardButtonRequestUsers::users(1)
+                .max_quantity(2)
+                .request_all(),
+        );
+
+        assert_eq!(
+            serde_json::to_value(button).unwrap(),
+            json!({
+                "text": "Choose friends",
+                "request_users": {
+                    "request_id": 1,
+                    "user_is_bot": false,
+                    "max_quantity": 2,
+                    "request_name": true,
+                    "request_username": true,
+                    "request_photo": true,
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn test_extract_matching_request() {
+        let shared = extract::<1>(users_shared(1)).unwrap().into_inner();
+
+        assert_eq!(shared.request_id, 1);
+        assert_eq!(shared.user_ids().as_ref(), [2, 3]);
+
+        // Response to another request button
+        assert!(extract::<2>(users_shared(1)).is_err());
+        assert!(extract::<1>(test::message("text")).is_err());
+    }
+}
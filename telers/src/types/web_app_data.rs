@@ -1,12 +1,83 @@
-use serde::{Deserialize, Serialize};
+use super::{Update, UpdateKind};
+
+use crate::{errors::ConvertToTypeError, FromEvent};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 /// Describes data sent from a [`Web App`](https://core.telegram.org/bots/webapps) to the bot.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#webappdata>
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize, Serialize)]
+/// # Notes
+/// As handler argument, it's extracted from the `web_app_data` service message
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize, Serialize, FromEvent)]
+#[event(try_from = Update)]
 pub struct WebAppData {
     /// The data. Be aware that a bad client can send arbitrary data in this field.
     pub data: Box<str>,
     /// Text of the `web_app` keyboard button from which the Web App was opened. Be aware that a bad client can send arbitrary data in this field.
     pub button_text: Box<str>,
 }
+
+impl WebAppData {
+    /// Deserializes the data sent by the Web App as JSON
+    /// # Errors
+    /// If the data isn't a valid JSON of the type
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_str(&self.data)
+    }
+}
+
+impl TryFrom<Update> for WebAppData {
+    type Error = ConvertToTypeError;
+
+    fn try_from(update: Update) -> Result<Self, Self::Error> {
+        match update.kind {
+            UpdateKind::Message(message) => message
+                .web_app_data()
+                .cloned()
+                .ok_or_else(|| ConvertToTypeError::new("Update", "WebAppData")),
+            _ => Err(ConvertToTypeError::new("Update", "WebAppData")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{context::Context, extractors::FromEventAndContext, test};
+
+    use serde_json::json;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_extract() {
+        let update: Update = serde_json::from_str(
+            &json!({
+                "update_id": 1,
+                "message": {
+                    "message_id": 1,
+                    "date": 0,
+                    "chat": {"id": test::CHAT_ID, "type": "private"},
+                    "web_app_data": {"data": r#"{"item":"book","count":2}"#, "button_text": "Order"},
+                },
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        let data = WebAppData::extract(
+            Arc::new(test::MockBot::new().bot()),
+            Arc::new(update),
+            Arc::new(Context::new()),
+        )
+        .unwrap();
+
+        assert_eq!(&*data.button_text, "Order");
+        assert_eq!(
+            data.json::<serde_json::Value>().unwrap(),
+            json!({"item": "book", "count": 2})
+        );
+
+        assert!(WebAppData::try_from(test::message("text")).is_err());
+    }
+}
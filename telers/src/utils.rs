@@ -1,2 +1,4 @@
+pub mod inline_answer;
+pub mod poll_tracker;
 pub mod text;
 pub mod token;
@@ -0,0 +1,288 @@
+//! This module contains [`InlineAnswerBuilder`] for building [`AnswerInlineQuery`] method
+//! with shortcuts for common results.
+//!
+//! # Examples
+//! ```rust
+//! use telers::{
+//!     event::{telegram::HandlerResult, EventReturn},
+//!     types::InlineQuery,
+//!     utils::inline_answer::InlineAnswerBuilder,
+//!     Bot,
+//! };
+//!
+//! async fn handler(bot: Bot, query: InlineQuery) -> HandlerResult {
+//!     let method = InlineAnswerBuilder::new(&query)
+//!         .article("1", "Hello", "Hello, world!")
+//!         .cached_sticker("2", "sticker_file_id")
+//!         .cache_time(60)
+//!         .is_personal(true)
+//!         .build()?;
+//!
+//!     bot.send(method).await?;
+//!
+//!     Ok(EventReturn::Finish)
+//! }
+//! ```
+
+use crate::{
+    methods::AnswerInlineQuery,
+    types::{
+        InlineQuery, InlineQueryResult, InlineQueryResultArticle, InlineQueryResultCachedDocument,
+        InlineQueryResultCachedPhoto, InlineQueryResultCachedSticker, InlineQueryResultPhoto,
+        InlineQueryResultsButton, InputTextMessageContent,
+    },
+};
+
+/// Maximum number of results per inline query
+pub const MAX_RESULTS: usize = 50;
+/// Maximum length of the next offset in bytes
+pub const MAX_NEXT_OFFSET_LEN: usize = 64;
+
+/// Error that can occur when building the inline query answer
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum InlineAnswerError {
+    #[error("No more than {MAX_RESULTS} results per query are allowed, got {0}")]
+    TooManyResults(usize),
+    #[error("Next offset can't exceed {MAX_NEXT_OFFSET_LEN} bytes, got {0}")]
+    NextOffsetTooLong(usize),
+}
+
+/// Builder of [`AnswerInlineQuery`] method with shortcuts for common results.
+/// Limits of the method are validated in [`InlineAnswerBuilder::build`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct InlineAnswerBuilder {
+    inline_query_id: String,
+    results: Vec<InlineQueryResult>,
+    cache_time: Option<i64>,
+    is_personal: Option<bool>,
+    next_offset: Option<String>,
+    button: Option<InlineQueryResultsButton>,
+}
+
+impl InlineAnswerBuilder {
+    /// Creates a new builder of the answer to the inline query
+    #[must_use]
+    pub fn new(query: &InlineQuery) -> Self {
+        Self::with_query_id(query.id.as_ref())
+    }
+
+    /// Creates a new builder of the answer to the inline query with the identifier
+    #[must_use]
+    pub fn with_query_id(inline_query_id: impl Into<String>) -> Self {
+        Self {
+            inline_query_id: inline_query_id.into(),
+            results: vec![],
+            cache_time: None,
+            is_personal: None,
+            next_offset: None,
+            button: None,
+        }
+    }
+
+    /// Adds any result
+    #[must_use]
+    pub fn result(self, val: impl Into<InlineQueryResult>) -> Self {
+        Self {
+            results: self.results.into_iter().chain(Some(val.into())).collect(),
+            ..self
+        }
+    }
+
+    /// Adds article result, which sends the text message without formatting
+    #[must_use]
+    pub fn article(
+        self,
+        id: impl Into<String>,
+        title: impl Into<String>,
+        message_text: impl Into<String>,
+    ) -> Self {
+        self.result(InlineQueryResultArticle::new(
+            id,
+            title,
+            InputTextMessageContent::new(message_text),
+        ))
+    }
+
+    /// Adds photo result by the URL of the photo and its thumbnail
+    #[must_use]
+    pub fn photo(
+        self,
+        id: impl Into<String>,
+        photo_url: impl Into<String>,
+        thumbnail_url: impl Into<String>,
+    ) -> Self {
+        self.result(InlineQueryResultPhoto::new(id, photo_url, thumbnail_url))
+    }
+
+    /// Adds photo result by the file identifier of the photo stored on the Telegram servers
+    #[must_use]
+    pub fn cached_photo(self, id: impl Into<String>, photo_file_id: impl Into<String>) -> Self {
+        self.result(InlineQueryResultCachedPhoto::new(id, photo_file_id))
+    }
+
+    /// Adds sticker result by the file identifier of the sticker stored on the Telegram servers
+    #[must_use]
+    pub fn cached_sticker(self, id: impl Into<String>, sticker_file_id: impl Into<String>) -> Self {
+        self.result(InlineQueryResultCachedSticker::new(id, sticker_file_id))
+    }
+
+    /// Adds document result by the file identifier of the document stored on the Telegram servers
+    #[must_use]
+    pub fn cached_document(
+        self,
+        id: impl Into<String>,
+        title: impl Into<String>,
+        document_file_id: impl Into<String>,
+    ) -> Self {
+        self.result(InlineQueryResultCachedDocument::new(
+            id,
+            title,
+            document_file_id,
+        ))
+    }
+
+    /// The maximum amount of time in seconds that the result of the inline query may be cached on the server
+    #[must_use]
+    pub fn cache_time(self, val: i64) -> Self {
+        Self {
+            cache_time: Some(val),
+            ..self
+        }
+    }
+
+    /// Pass `true` if results may be cached on the server side only for the user that sent the query
+    #[must_use]
+    pub fn is_personal(self, val: bool) -> Self {
+        Self {
+            is_personal: Some(val),
+            ..self
+        }
+    }
+
+    /// The offset that a client should send in the next query with the same text to receive more results
+    #[must_use]
+    pub fn next_offset(self, val: impl Into<String>) -> Self {
+        Self {
+            next_offset: Some(val.into()),
+            ..self
+        }
+    }
+
+    /// The button to be shown above inline query results
+    #[must_use]
+    pub fn button(self, val: InlineQueryResultsButton) -> Self {
+        Self {
+            button: Some(val),
+            ..self
+        }
+    }
+
+    /// Builds [`AnswerInlineQuery`] method
+    /// # Errors
+    /// - If there are more than [`MAX_RESULTS`] results
+    /// - If the next offset is longer than [`MAX_NEXT_OFFSET_LEN`] bytes
+    pub fn build(self) -> Result<AnswerInlineQuery, InlineAnswerError> {
+        if self.results.len() > MAX_RESULTS {
+            return Err(InlineAnswerError::TooManyResults(self.results.len()));
+        }
+
+        if let Some(len) = self
+            .next_offset
+            .as_ref()
+            .map(String::len)
+            .filter(|len| *len > MAX_NEXT_OFFSET_LEN)
+        {
+            return Err(InlineAnswerError::NextOffsetTooLong(len));
+        }
+
+        Ok(AnswerInlineQuery {
+            inline_query_id: self.inline_query_id,
+            results: self.results,
+            cache_time: self.cache_time,
+            is_personal: self.is_personal,
+            next_offset: self.next_offset,
+            button: self.button,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    #[test]
+    fn test_build() {
+        let query = InlineQuery {
+            id: "query".into(),
+            ..Default::default()
+        };
+
+        let method = InlineAnswerBuilder::new(&query)
+            .article("1", "Article", "text")
+            .photo(
+                "2",
+                "https://example.com/photo.jpg",
+                "https://example.com/thumb.jpg",
+            )
+            .cached_sticker("3", "sticker_file_id")
+            .cache_time(60)
+            .is_personal(true)
+            .next_offset("10")
+            .button(InlineQueryResultsButton::new("Button").start_parameter("start"))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&method).unwrap(),
+            json!({
+                "inline_query_id": "query",
+                "results": [
+                    {
+                        "type": "article",
+                        "id": "1",
+                        "title": "Article",
+                        "input_message_content": {"message_text": "text"},
+                    },
+                    {
+                        "type": "photo",
+                        "id": "2",
+                        "photo_url": "https://example.com/photo.jpg",
+                        "thumbnail_url": "https://example.com/thumb.jpg",
+                    },
+                    {
+                        "type": "sticker",
+                        "id": "3",
+                        "sticker_file_id": "sticker_file_id",
+                    },
+                ],
+                "cache_time": 60,
+                "is_personal": true,
+                "next_offset": "10",
+                "button": {"text": "Button", "start_parameter": "start"},
+            })
+        );
+    }
+
+    #[test]
+    fn test_build_limits() {
+        let builder = (0..=MAX_RESULTS).fold(
+            InlineAnswerBuilder::with_query_id("query"),
+            |builder, id| builder.cached_photo(id.to_string(), "photo_file_id"),
+        );
+
+        assert_eq!(
+            builder.build(),
+            Err(InlineAnswerError::TooManyResults(MAX_RESULTS + 1))
+        );
+        assert_eq!(
+            InlineAnswerBuilder::with_query_id("query")
+                .next_offset("0".repeat(MAX_NEXT_OFFSET_LEN + 1))
+                .build(),
+            Err(InlineAnswerError::NextOffsetTooLong(
+                MAX_NEXT_OFFSET_LEN + 1
+            ))
+        );
+    }
+}
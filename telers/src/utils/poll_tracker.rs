@@ -0,0 +1,279 @@
+//! This module contains [`PollTracker`] for correlating [`PollAnswer`] updates with the sent polls and tallying the answers.
+//!
+//! The tracker doesn't make requests to the Telegram Bot API, it only keeps the polls and answers in memory by poll identifiers.
+//! Voters are identified by the user identifier or, if the voter is anonymous, by the chat identifier.
+//! Only answers to non-anonymous polls are received by the bot, see [`PollAnswer`] for more details.
+//!
+//! # Examples
+//! ```rust
+//! use telers::{
+//!     client::Reqwest,
+//!     context::Data,
+//!     event::{telegram::HandlerResult, EventReturn},
+//!     methods::SendPoll,
+//!     types::{Message, PollAnswer},
+//!     utils::poll_tracker::PollTracker,
+//!     Bot, Dispatcher, Router,
+//! };
+//!
+//! async fn send_quiz(bot: Bot, message: Message, tracker: Data<PollTracker>) -> HandlerResult {
+//!     let method = SendPoll::new(message.chat().id(), "2 + 2 = ?", ["3", "4"])
+//!         .poll_type("quiz")
+//!         .correct_option_id(1)
+//!         .is_anonymous(false);
+//!
+//!     if let Message::Poll(message) = bot.send(method).await? {
+//!         tracker.track(&message.poll);
+//!     }
+//!
+//!     Ok(EventReturn::Finish)
+//! }
+//!
+//! async fn poll_answer(answer: PollAnswer, tracker: Data<PollTracker>) -> HandlerResult {
+//!     tracker.record_answer(&answer);
+//!
+//!     Ok(EventReturn::Finish)
+//! }
+//!
+//! let mut router = Router::<Reqwest>::new("main");
+//! router.message.register(send_quiz);
+//! router.poll_answer.register(poll_answer);
+//!
+//! let dispatcher = Dispatcher::builder()
+//!     .main_router(router)
+//!     .data(PollTracker::new())
+//!     .build();
+//! ```
+
+use crate::types::{Poll, PollAnswer};
+
+use dashmap::DashMap;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+struct TrackedPoll {
+    options_count: usize,
+    correct_option_id: Option<i64>,
+    answers: HashMap<i64, Box<[i64]>>,
+}
+
+/// Keeps sent polls by their identifiers and accumulates answers to them.
+/// The tracker is thread-safe, so it can be shared between handlers, for example, as [`Data<PollTracker>`]
+///
+/// [`Data<PollTracker>`]: crate::context::Data
+#[derive(Debug, Default)]
+pub struct PollTracker {
+    polls: DashMap<Box<str>, TrackedPoll>,
+}
+
+impl PollTracker {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start tracking the poll. If the poll is already tracked, its answers are kept
+    pub fn track(&self, poll: &Poll) {
+        let (id, options_count, correct_option_id) = match poll {
+            Poll::Regular(poll) => (&poll.id, poll.options.len(), None),
+            Poll::Quiz(poll) => (&poll.id, poll.options.len(), poll.correct_option_id),
+        };
+
+        self.polls
+            .entry(id.clone())
+            .and_modify(|tracked| {
+                tracked.options_count = options_count;
+                tracked.correct_option_id = correct_option_id;
+            })
+            .or_insert_with(|| TrackedPoll {
+                options_count,
+                correct_option_id,
+                answers: HashMap::new(),
+            });
+    }
+
+    /// Stop tracking the poll
+    /// # Returns
+    /// `true` if the poll was tracked
+    pub fn untrack(&self, poll_id: &str) -> bool {
+        self.polls.remove(poll_id).is_some()
+    }
+
+    /// Check if the poll is tracked
+    #[must_use]
+    pub fn is_tracked(&self, poll_id: &str) -> bool {
+        self.polls.contains_key(poll_id)
+    }
+
+    /// Record the answer to the tracked poll.
+    /// The previous answer of the voter is replaced, and an answer with empty options is considered as retracted vote.
+    /// # Returns
+    /// `true` if the poll is tracked and the voter is known, otherwise the answer is ignored
+    pub fn record_answer(&self, answer: &PollAnswer) -> bool {
+        let Some(voter_id) = voter_id(answer) else {
+            return false;
+        };
+        let Some(mut poll) = self.polls.get_mut(&*answer.poll_id) else {
+            return false;
+        };
+
+        if answer.option_ids.is_empty() {
+            poll.answers.remove(&voter_id);
+        } else {
+            poll.answers.insert(voter_id, answer.option_ids.clone());
+        }
+
+        true
+    }
+
+    /// Get the number of votes for each option of the tracked poll
+    #[must_use]
+    pub fn tallies(&self, poll_id: &str) -> Option<Box<[u64]>> {
+        let poll = self.polls.get(poll_id)?;
+        let mut tallies = vec![0; poll.options_count];
+
+        for option_id in poll
+            .answers
+            .values()
+            .flat_map(|option_ids| option_ids.iter())
+        {
+            if let Some(tally) = usize::try_from(*option_id)
+                .ok()
+                .and_then(|option_id| tallies.get_mut(option_id))
+            {
+                *tally += 1;
+            }
+        }
+
+        Some(tallies.into())
+    }
+
+    /// Get the number of voters of the tracked poll
+    #[must_use]
+    pub fn voters_count(&self, poll_id: &str) -> Option<usize> {
+        self.polls.get(poll_id).map(|poll| poll.answers.len())
+    }
+
+    /// Get the chosen options of the voter
+    /// # Arguments
+    /// * `poll_id` - Identifier of the tracked poll
+    /// * `voter_id` - Identifier of the user or of the chat, if the voter is anonymous
+    #[must_use]
+    pub fn answer(&self, poll_id: &str, voter_id: i64) -> Option<Box<[i64]>> {
+        self.polls.get(poll_id)?.answers.get(&voter_id).cloned()
+    }
+
+    /// Check if the voter answered the tracked poll
+    /// # Arguments
+    /// * `poll_id` - Identifier of the tracked poll
+    /// * `voter_id` - Identifier of the user or of the chat, if the voter is anonymous
+    #[must_use]
+    pub fn has_answered(&self, poll_id: &str, voter_id: i64) -> bool {
+        self.polls
+            .get(poll_id)
+            .map_or(false, |poll| poll.answers.contains_key(&voter_id))
+    }
+
+    /// Check if the voter answered the tracked quiz correctly
+    /// # Returns
+    /// `None` if the poll isn't tracked, it isn't a quiz with known correct option or the voter didn't answer
+    #[must_use]
+    pub fn is_correct(&self, poll_id: &str, voter_id: i64) -> Option<bool> {
+        let poll = self.polls.get(poll_id)?;
+        let correct_option_id = poll.correct_option_id?;
+
+        poll.answers
+            .get(&voter_id)
+            .map(|option_ids| option_ids.as_ref() == [correct_option_id])
+    }
+}
+
+fn voter_id(answer: &PollAnswer) -> Option<i64> {
+    answer
+        .user
+        .as_ref()
+        .map(|user| user.id)
+        .or_else(|| answer.voter_chat.as_ref().map(|chat| chat.id()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{PollOption, PollQuiz, PollRegular, User};
+
+    fn options(count: usize) -> Box<[PollOption]> {
+        (0..count)
+            .map(|index| PollOption {
+                text: index.to_string().into(),
+                entities: None,
+                voter_count: 0,
+            })
+            .collect()
+    }
+
+    fn answer(poll_id: &str, user_id: i64, option_ids: &[i64]) -> PollAnswer {
+        PollAnswer {
+            poll_id: poll_id.into(),
+            user: Some(User {
+                id: user_id,
+                ..Default::default()
+            }),
+            option_ids: option_ids.into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_tallies() {
+        let tracker = PollTracker::new();
+        tracker.track(&Poll::Regular(PollRegular {
+            id: "poll".into(),
+            options: options(3),
+            allows_multiple_answers: true,
+            ..Default::default()
+        }));
+
+        assert!(tracker.record_answer(&answer("poll", 1, &[0])));
+        assert!(tracker.record_answer(&answer("poll", 2, &[0, 2])));
+        assert!(tracker.record_answer(&answer("poll", 3, &[1])));
+        // Changed answer
+        assert!(tracker.record_answer(&answer("poll", 3, &[2])));
+        // Unknown poll
+        assert!(!tracker.record_answer(&answer("unknown", 1, &[0])));
+
+        assert_eq!(tracker.tallies("poll").unwrap().as_ref(), [2, 0, 2]);
+        assert_eq!(tracker.voters_count("poll"), Some(3));
+        assert_eq!(tracker.answer("poll", 2).unwrap().as_ref(), [0, 2]);
+        assert!(tracker.has_answered("poll", 1));
+        assert!(!tracker.has_answered("poll", 4));
+        assert_eq!(tracker.tallies("unknown"), None);
+
+        // Retracted vote
+        assert!(tracker.record_answer(&answer("poll", 1, &[])));
+
+        assert_eq!(tracker.tallies("poll").unwrap().as_ref(), [1, 0, 2]);
+        assert!(!tracker.has_answered("poll", 1));
+
+        assert!(tracker.untrack("poll"));
+        assert!(!tracker.is_tracked("poll"));
+    }
+
+    #[test]
+    fn test_quiz() {
+        let tracker = PollTracker::new();
+        tracker.track(&Poll::Quiz(PollQuiz {
+            id: "quiz".into(),
+            options: options(2),
+            correct_option_id: Some(1),
+            ..Default::default()
+        }));
+
+        tracker.record_answer(&answer("quiz", 1, &[1]));
+        tracker.record_answer(&answer("quiz", 2, &[0]));
+
+        assert_eq!(tracker.is_correct("quiz", 1), Some(true));
+        assert_eq!(tracker.is_correct("quiz", 2), Some(false));
+        assert_eq!(tracker.is_correct("quiz", 3), None);
+        assert_eq!(tracker.tallies("quiz").unwrap().as_ref(), [1, 1]);
+    }
+}
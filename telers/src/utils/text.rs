@@ -2,6 +2,8 @@ pub mod builder;
 pub mod formatter;
 pub mod html_formatter;
 pub mod markdown_formatter;
+pub mod split;
+pub mod truncate;
 
 pub use builder::Builder;
 pub use formatter::{ErrorKind as FormatterErrorKind, Formatter};
@@ -18,3 +20,5 @@ pub use markdown_formatter::{
     text_link as markdown_text_link, text_mention as markdown_text_mention,
     underline as markdown_underline, Formatter as MarkdownFormatter,
 };
+pub use split::{split_message, split_message_with_entities, MESSAGE_MAX_LEN};
+pub use truncate::{truncate_with_entities, ELLIPSIS};
@@ -5,10 +5,11 @@ use crate::types::{
     TextLinkMessageEntity, TextMentionMessageEntity, User,
 };
 
+use std::fmt::{self, Display, Write as _};
 use tracing::{event, Level};
 
-const CHARS: [char; 18] = [
-    '_', '*', '[', ']', '(', ')', '~', '`', '>', '#', '+', '-', '=', '|', '{', '}', '.', '!',
+const CHARS: [char; 19] = [
+    '_', '*', '[', ']', '(', ')', '~', '`', '>', '#', '+', '-', '=', '|', '{', '}', '.', '!', '\\',
 ];
 
 /// This is a legacy mode, retained for backward compatibility. To use this mode, pass `Markdown` in the `parse_mode` field.
@@ -281,9 +282,36 @@ pub fn quote(text: impl AsRef<str>) -> String {
     FORMATTER.quote(text)
 }
 
+/// Wrapper, which escapes the reserved characters of the `MarkdownV2` style in the [`Display`] output of the value.
+/// Format parameters like width and precision are ignored.
+/// It's used by [`md!`](crate::md) macro to escape the interpolated values.
+#[derive(Debug, Clone, Copy)]
+pub struct Escaped<T>(pub T);
+
+impl<T: Display> Display for Escaped<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        struct Escaper<'a, 'b>(&'a mut fmt::Formatter<'b>);
+
+        impl fmt::Write for Escaper<'_, '_> {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                for ch in s.chars() {
+                    if CHARS.contains(&ch) {
+                        self.0.write_char('\\')?;
+                    }
+                    self.0.write_char(ch)?;
+                }
+                Ok(())
+            }
+        }
+
+        write!(Escaper(f), "{}", self.0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::md;
 
     #[test]
     fn test_bold() {
@@ -378,6 +406,7 @@ mod tests {
         assert_eq!(formatter.quote("test _ test"), r"test \_ test");
         assert_eq!(formatter.quote("test ~ test"), r"test \~ test");
         assert_eq!(formatter.quote("test | test"), r"test \| test");
+        assert_eq!(formatter.quote(r"test \ test"), r"test \\ test");
         assert_eq!(formatter.quote("test > test"), r"test \> test");
         assert_eq!(formatter.quote("test # test"), r"test \# test");
         assert_eq!(formatter.quote("test + test"), r"test \+ test");
@@ -398,4 +427,34 @@ mod tests {
             r"test \` \* \_ \~ \| \> \# \+ \- \= \. \! \[ \] \( \) \{ \} test"
         );
     }
+
+    #[test]
+    fn test_md() {
+        let name = "John_Doe";
+        let input = "*bold* [link](url) 1.5!";
+
+        assert_eq!(md!("*{}*", name), r"*John\_Doe*");
+        assert_eq!(
+            md!("*Name:* {}\n_Input:_ {}", name, input),
+            "*Name:* John\\_Doe\n_Input:_ \\*bold\\* \\[link\\]\\(url\\) 1\\.5\\!",
+        );
+        // Escaped the same way as `quote`
+        assert_eq!(md!("{}", input), quote(input));
+        assert_eq!(md!("{} {}", 1.5, r"\"), r"1\.5 \\");
+        assert_eq!(md!("*plain*"), "*plain*");
+    }
+
+    #[test]
+    fn test_md_captures() {
+        let user_input = "*_[";
+        let width = 5;
+
+        assert_eq!(md!("*{user_input}*"), r"*\*\_\[*");
+        assert_eq!(
+            md!("{} {name}", user_input, name = user_input),
+            r"\*\_\[ \*\_\["
+        );
+        // Width isn't escaped and is ignored by `Escaped`
+        assert_eq!(md!("{user_input:width$}"), r"\*\_\[");
+    }
 }
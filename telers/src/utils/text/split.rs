@@ -0,0 +1,202 @@
+//! This module contains functions for splitting long texts into several messages.
+//!
+//! Length of the text is measured in UTF-16 code units, like offsets and lengths of the [`MessageEntity`].
+//! Text is split on the last newline that fits the limit, then on the last space, and only then in the middle of a word.
+//! The newline or space on which the text is split isn't included in the chunks.
+
+use crate::types::MessageEntity;
+
+use std::ops::Range;
+
+/// Maximum length of the message text after entities parsing
+pub const MESSAGE_MAX_LEN: usize = 4096;
+/// Maximum length of the media caption after entities parsing
+pub const CAPTION_MAX_LEN: usize = 1024;
+
+/// Splits the text into chunks of at most `limit` UTF-16 code units
+/// # Returns
+/// Chunks of the text in order, empty text has no chunks
+/// # Notes
+/// If a single character doesn't fit the limit, then it's returned as a chunk anyway
+#[must_use]
+pub fn split_message(text: &str, limit: usize) -> Vec<&str> {
+    split_ranges(text, limit)
+        .into_iter()
+        .map(|range| &text[range])
+        .collect()
+}
+
+/// Splits the text into chunks of at most `limit` UTF-16 code units, like [`split_message`],
+/// and moves entities to the chunks they cover.
+/// Offsets of the entities are recomputed relative to the chunk,
+/// and entities crossing the chunk bounds are cut, so each chunk can be sent as the independent message.
+/// # Returns
+/// Chunks of the text with their entities in order, empty text has no chunks
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub fn split_message_with_entities<'a>(
+    text: &'a str,
+    entities: &[MessageEntity],
+    limit: usize,
+) -> Vec<(&'a str, Vec<MessageEntity>)> {
+    let mut utf16_start = 0;
+    let mut byte_start = 0;
+
+    split_ranges(text, limit)
+        .into_iter()
+        .map(|range| {
+            // Skip the separator between the previous chunk and this one
+            utf16_start += utf16_len(&text[byte_start..range.start]);
+
+            let chunk = &text[range.clone()];
+            let utf16_end = utf16_start + utf16_len(chunk);
+
+            let chunk_entities = entities
+                .iter()
+                .filter_map(|entity| {
+                    let offset = usize::from(entity.offset);
+                    let start = offset.max(utf16_start);
+                    let end = (offset + usize::from(entity.length)).min(utf16_end);
+
+                    (start < end).then(|| MessageEntity {
+                        // Offsets are less than offsets of the original entities, so they fit in `u16`
+                        offset: (start - utf16_start) as u16,
+                        length: (end - start) as u16,
+                        kind: entity.kind.clone(),
+                    })
+                })
+                .collect();
+
+            utf16_start = utf16_end;
+            byte_start = range.end;
+
+            (chunk, chunk_entities)
+        })
+        .collect()
+}
+
+fn utf16_len(text: &str) -> usize {
+    text.chars().map(char::len_utf16).sum()
+}
+
+/// Gets byte ranges of the chunks of the text
+fn split_ranges(text: &str, limit: usize) -> Vec<Range<usize>> {
+    let mut ranges = vec![];
+    let mut start = 0;
+
+    loop {
+        let rest = &text[start..];
+
+        // Byte index of the end of the longest prefix that fits the limit
+        let mut fit_end = rest.len();
+        let mut len = 0;
+        for (index, char) in rest.char_indices() {
+            len += char.len_utf16();
+
+            if len > limit {
+                fit_end = index;
+                break;
+            }
+        }
+
+        if fit_end == rest.len() {
+            if !rest.is_empty() {
+                ranges.push(start..text.len());
+            }
+            return ranges;
+        }
+
+        let prefix = &rest[..fit_end];
+
+        let (end, next) = if let Some(index) = prefix.rfind('\n').filter(|index| *index > 0) {
+            (index, index + 1)
+        } else if let Some(index) = prefix.rfind(' ').filter(|index| *index > 0) {
+            (index, index + 1)
+        } else if fit_end == 0 {
+            // The first character doesn't fit the limit, so we take it anyway to avoid infinite loop
+            let len = rest.chars().next().map_or(0, char::len_utf8);
+            (len, len)
+        } else {
+            (fit_end, fit_end)
+        };
+
+        ranges.push(start..start + end);
+        start += next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_message() {
+        assert!(split_message("", 10).is_empty());
+        assert_eq!(split_message("short", 10), ["short"]);
+        // Newlines are preferred to spaces
+        assert_eq!(
+            split_message("first line\nsecond line", 15),
+            ["first line", "second line"]
+        );
+        assert_eq!(
+            split_message("one two three four", 9),
+            ["one two", "three", "four"]
+        );
+        // Words longer than the limit are split
+        assert_eq!(split_message("abcdefghij", 4), ["abcd", "efgh", "ij"]);
+        // Length is measured in UTF-16 code units
+        assert_eq!(split_message("👋👋👋", 4), ["👋👋", "👋"]);
+        assert_eq!(split_message("👋", 1), ["👋"]);
+    }
+
+    #[test]
+    fn test_split_message_with_entities() {
+        let text = "Hello, 👋 world!\nThis is bold and italic text";
+        let entities = [
+            // "Hello"
+            MessageEntity::new_bold(0, 5),
+            // "👋 world!\nThis" crosses the chunks
+            MessageEntity::new_italic(7, 14),
+            // "bold"
+            MessageEntity::new_underline(25, 4),
+            // "italic text"
+            MessageEntity::new_text_link(34, 11, "https://example.com"),
+        ];
+
+        let chunks = split_message_with_entities(text, &entities, 20);
+
+        assert_eq!(chunks.len(), 3);
+
+        let (chunk, entities) = &chunks[0];
+
+        assert_eq!(*chunk, "Hello, 👋 world!");
+        assert_eq!(
+            *entities,
+            [
+                MessageEntity::new_bold(0, 5),
+                MessageEntity::new_italic(7, 9),
+            ]
+        );
+        assert_eq!(entities[1].extract_from(chunk), Some("👋 world!"));
+
+        let (chunk, entities) = &chunks[1];
+
+        assert_eq!(*chunk, "This is bold and");
+        assert_eq!(
+            *entities,
+            [
+                MessageEntity::new_italic(0, 4),
+                MessageEntity::new_underline(8, 4),
+            ]
+        );
+        assert_eq!(entities[1].extract_from(chunk), Some("bold"));
+
+        let (chunk, entities) = &chunks[2];
+
+        assert_eq!(*chunk, "italic text");
+        assert_eq!(
+            *entities,
+            [MessageEntity::new_text_link(0, 11, "https://example.com")]
+        );
+    }
+}
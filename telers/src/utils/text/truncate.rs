@@ -0,0 +1,149 @@
+//! This module contains functions for truncating texts with entities, for example, for previews.
+//!
+//! Length of the text is measured in UTF-16 code units, like offsets and lengths of the [`MessageEntity`],
+//! and the text is never cut in the middle of a character (including surrogate pairs).
+
+use crate::types::{MessageEntity, MessageEntityKind};
+
+/// Ellipsis, which is usually appended to the truncated text
+pub const ELLIPSIS: &str = "…";
+
+/// Truncates the text to at most `max_len` UTF-16 code units and cuts entities to the truncated text.
+/// # Arguments
+/// * `text` - Text to truncate
+/// * `entities` - Entities of the text
+/// * `max_len` - Maximum length of the result in UTF-16 code units, the ellipsis included
+/// * `ellipsis` - Text appended to the truncated text, for example, [`ELLIPSIS`]. It isn't covered by entities
+///   and is dropped if it's longer than `max_len`, so the result never exceeds `max_len`
+/// # Returns
+/// The text and entities as is if the text fits, otherwise the truncated text and its entities.
+/// Entities crossing the cut are shortened, except custom emoji, which can't be partial, so they are removed.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub fn truncate_with_entities(
+    text: &str,
+    entities: &[MessageEntity],
+    max_len: usize,
+    ellipsis: Option<&str>,
+) -> (String, Vec<MessageEntity>) {
+    if utf16_len(text) <= max_len {
+        return (text.to_owned(), entities.to_vec());
+    }
+
+    let ellipsis = ellipsis
+        .filter(|ellipsis| utf16_len(ellipsis) <= max_len)
+        .unwrap_or_default();
+    let prefix_max_len = max_len - utf16_len(ellipsis);
+
+    let mut prefix_len = 0;
+    let mut prefix_end = 0;
+    for (index, char) in text.char_indices() {
+        if prefix_len + char.len_utf16() > prefix_max_len {
+            break;
+        }
+
+        prefix_len += char.len_utf16();
+        prefix_end = index + char.len_utf8();
+    }
+
+    let entities = entities
+        .iter()
+        .filter_map(|entity| {
+            let start = usize::from(entity.offset);
+            let end = (start + usize::from(entity.length)).min(prefix_len);
+
+            if start >= end {
+                return None;
+            }
+            // Partial custom emoji is invalid
+            if end - start < usize::from(entity.length)
+                && matches!(entity.kind, MessageEntityKind::CustomEmoji(_))
+            {
+                return None;
+            }
+
+            Some(MessageEntity {
+                offset: entity.offset,
+                // Length is less than the original one, so it fits in `u16`
+                length: (end - start) as u16,
+                kind: entity.kind.clone(),
+            })
+        })
+        .collect();
+
+    (format!("{}{ellipsis}", &text[..prefix_end]), entities)
+}
+
+fn utf16_len(text: &str) -> usize {
+    text.chars().map(char::len_utf16).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_with_entities() {
+        let text = "Hello, bold world!";
+        let entities = [
+            MessageEntity::new_italic(0, 5),
+            // "bold world!"
+            MessageEntity::new_bold(7, 11),
+        ];
+
+        // Text fits, so it isn't changed
+        assert_eq!(
+            truncate_with_entities(text, &entities, 18, Some(ELLIPSIS)),
+            (text.to_owned(), entities.to_vec())
+        );
+
+        // The cut is in the middle of the bold entity
+        let (truncated, truncated_entities) =
+            truncate_with_entities(text, &entities, 11, Some(ELLIPSIS));
+
+        assert_eq!(truncated, "Hello, bol…");
+        assert_eq!(
+            truncated_entities,
+            [
+                MessageEntity::new_italic(0, 5),
+                MessageEntity::new_bold(7, 3),
+            ]
+        );
+        assert_eq!(truncated_entities[1].extract_from(&truncated), Some("bol"));
+
+        // Entities after the cut are removed
+        let (truncated, truncated_entities) = truncate_with_entities(text, &entities, 6, None);
+
+        assert_eq!(truncated, "Hello,");
+        assert_eq!(truncated_entities, [MessageEntity::new_italic(0, 5)]);
+
+        // Ellipsis longer than the maximum length is dropped
+        assert_eq!(
+            truncate_with_entities(text, &entities, 0, Some(ELLIPSIS)),
+            (String::new(), vec![])
+        );
+        assert_eq!(
+            truncate_with_entities(text, &entities, 2, Some("...")),
+            ("He".to_owned(), vec![MessageEntity::new_italic(0, 2)])
+        );
+    }
+
+    #[test]
+    fn test_truncate_utf16() {
+        // Emoji with the skin tone modifier is 2 characters of 2 UTF-16 code units each
+        let text = "👍🏻👋 hi";
+        let entities = [
+            MessageEntity::new_bold(0, 6),
+            MessageEntity::new_custom_emoji(0, 4, "1"),
+        ];
+
+        // Surrogate pair isn't cut in half
+        let (truncated, truncated_entities) =
+            truncate_with_entities(text, &entities, 4, Some(ELLIPSIS));
+
+        assert_eq!(truncated, "👍…");
+        // Partial custom emoji is removed
+        assert_eq!(truncated_entities, [MessageEntity::new_bold(0, 2)]);
+        assert_eq!(truncated_entities[0].extract_from(&truncated), Some("👍"));
+    }
+}